@@ -0,0 +1,50 @@
+//! Size metrics for exported documents, for tracking down export bloat.
+
+use crate::turn_render::MathDocument;
+
+/// Byte length of a value's JSON serialization, as written by the exporter.
+pub trait SerializedSize {
+    fn serialized_size(&self) -> usize;
+}
+
+impl SerializedSize for MathDocument {
+    fn serialized_size(&self) -> usize {
+        serde_json::to_vec(self)
+            .map(|bytes| bytes.len())
+            .unwrap_or(0)
+    }
+}
+
+/// `(document id, serialized size)` for each document, largest first.
+/// Documents of equal size are ordered by id.
+pub fn size_report(docs: &[MathDocument]) -> Vec<(String, usize)> {
+    let mut report: Vec<(String, usize)> = docs
+        .iter()
+        .map(|doc| (doc.id.clone(), doc.serialized_size()))
+        .collect();
+    report.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    report
+}
+
+#[cfg(all(test, feature = "groups"))]
+mod tests {
+    use super::*;
+    use crate::subjects::math::export::unified_exporter::TheoryExporter;
+    use crate::subjects::math::theories::groups::render::GroupTheoryExporter;
+
+    #[test]
+    fn test_size_report_is_sorted_and_adds_up() {
+        let docs = GroupTheoryExporter.export_definitions();
+        let report = size_report(&docs);
+
+        assert_eq!(report.len(), docs.len());
+        assert!(report.windows(2).all(|pair| pair[0].1 >= pair[1].1));
+
+        let reported: usize = report.iter().map(|(_, size)| size).sum();
+        let total: usize = docs
+            .iter()
+            .map(|doc| serde_json::to_vec(doc).unwrap().len())
+            .sum();
+        assert_eq!(reported, total);
+    }
+}
@@ -1,7 +1,15 @@
+pub mod metrics;
 pub mod unified_exporter;
+pub mod usage;
 
 // Re-export the main exporter for convenience
-pub use unified_exporter::UnifiedExporter;
+pub use unified_exporter::{
+    ExportCache, UnifiedExporter, compute_content_hash, to_canonical_json, to_compact_json,
+    to_public_json, write_theorems_json,
+};
+
+pub use metrics::{SerializedSize, size_report};
+pub use usage::{build_usage_index, link_usages};
 
 // Re-export key types for external use
 pub use unified_exporter::{ContentBundle, ContentFile, ContentManifest, TheoryManifest};
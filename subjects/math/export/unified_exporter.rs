@@ -9,13 +9,18 @@ use crate::subjects::math::formalism::traits::abstraction_level::{
     AbstractionLevel, GetAbstractionLevel,
 };
 
+use crate::subjects::math::export::usage::{build_usage_index, link_usages};
 use crate::subjects::math::formalism::extract::Parametrizable;
+use crate::subjects::math::formalism::location::strip_ids;
 use crate::subjects::math::formalism::theorem::Theorem;
 use crate::subjects::math::theories::VariantSet;
 use crate::subjects::math::theories::fields::definitions::{Field, FieldBasic};
+#[cfg(feature = "groups")]
 use crate::subjects::math::theories::groups::definitions::{Group, GroupExpression, GroupRelation};
 
+#[cfg(feature = "groups")]
 use crate::subjects::math::theories::groups::render::GroupTheoryExporter;
+#[cfg(feature = "probability")]
 use crate::subjects::math::theories::probability::render::ProbabilityTheoryExporter;
 // use crate::subjects::math::theories::groups::theorems::{
 //     prove_abelian_squared_criterion, prove_example_chaining_theorems, prove_inverse_product_rule,
@@ -28,6 +33,7 @@ use crate::turn_render::*;
 /// Content manifest for efficient loading across ALL theories
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ContentManifest {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub theories: Vec<TheoryManifest>,
     pub total_items: usize,
     pub generated_at: String,
@@ -39,6 +45,7 @@ pub struct ContentManifest {
 pub struct TheoryManifest {
     pub theory_id: String,
     pub theory_name: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub files: Vec<ContentFile>,
     pub item_count: usize,
 }
@@ -49,6 +56,7 @@ pub struct ContentFile {
     pub file_path: String,
     pub content_type: String, // "l1_definitions", "l3_constructors", "theorems"
     pub item_count: usize,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub items: Vec<String>, // List of content IDs in this file
 }
 
@@ -59,6 +67,7 @@ pub struct ContentBundle {
     pub content_type: String,
     pub version: String,
     pub exported_at: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub content: Vec<MathDocument>,
 }
 
@@ -83,6 +92,108 @@ pub trait TheoryExporter<O, E, R> {
     fn export_relation_definitions(&self, relations: Vec<R>) -> Vec<MathDocument>;
     // theorems - separate from the overview
     fn export_theorems(&self) -> Vec<MathDocument>;
+
+    /// Same documents as [`TheoryExporter::export_definitions`], but each input
+    /// object is looked up in `cache` by content hash and only rendered on a miss.
+    /// Fails if an input object cannot be serialized to JSON for hashing.
+    fn export_definitions_cached(
+        &self,
+        cache: &mut ExportCache,
+    ) -> serde_json::Result<Vec<MathDocument>>
+    where
+        O: Serialize,
+        E: Serialize,
+        R: Serialize,
+    {
+        let theory_id = self.theory_id().to_string();
+        let mut content = Vec::new();
+        for object in self.generate_object_definitions() {
+            let key = compute_content_hash(&(&theory_id, "object", &object))?;
+            content
+                .extend(cache.get_or_render(key, || self.export_object_definitions(vec![object])));
+        }
+        for expression in self.generate_expression_definitions() {
+            let key = compute_content_hash(&(&theory_id, "expression", &expression))?;
+            content.extend(
+                cache.get_or_render(key, || self.export_expression_definitions(vec![expression])),
+            );
+        }
+        for relation in self.generate_relation_definitions() {
+            let key = compute_content_hash(&(&theory_id, "relation", &relation))?;
+            content.extend(
+                cache.get_or_render(key, || self.export_relation_definitions(vec![relation])),
+            );
+        }
+        Ok(content)
+    }
+}
+
+/// **CONTENT HASH** - Hex digest of the public JSON form of `value`
+/// Ids are stripped first (see [`to_public_json`]), so two objects that differ
+/// only in their randomly generated `Located` ids hash the same. The digest is
+/// taken over the canonical form (see [`to_canonical_json`]), so it does not
+/// depend on map iteration order either.
+///
+/// The digest comes from `DefaultHasher`, whose output may change between Rust
+/// releases, so it identifies content within a process only; don't persist it.
+pub fn compute_content_hash<T: Serialize>(value: &T) -> serde_json::Result<String> {
+    use std::hash::{Hash, Hasher};
+
+    let json = to_canonical_json(&to_public_json(value)?)?;
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    json.hash(&mut hasher);
+    Ok(format!("{:016x}", hasher.finish()))
+}
+
+/// **EXPORT CACHE** - In-memory store of the documents rendered from each input
+/// object, keyed by the object's content hash, so re-exporting with the same
+/// cache does not re-render unchanged objects. It lives as long as the value
+/// does; nothing is written to disk.
+#[derive(Debug, Clone, Default)]
+pub struct ExportCache {
+    documents: HashMap<String, Vec<MathDocument>>,
+    hits: usize,
+    misses: usize,
+}
+
+impl ExportCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of lookups answered from the cache
+    pub fn hits(&self) -> usize {
+        self.hits
+    }
+
+    /// Number of lookups that had to render
+    pub fn misses(&self) -> usize {
+        self.misses
+    }
+
+    pub fn len(&self) -> usize {
+        self.documents.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.documents.is_empty()
+    }
+
+    /// Returns the cached documents for `key`, rendering and storing them on a miss.
+    pub fn get_or_render(
+        &mut self,
+        key: String,
+        render: impl FnOnce() -> Vec<MathDocument>,
+    ) -> Vec<MathDocument> {
+        if let Some(documents) = self.documents.get(&key) {
+            self.hits += 1;
+            return documents.clone();
+        }
+        self.misses += 1;
+        let documents = render();
+        self.documents.insert(key, documents.clone());
+        documents
+    }
 }
 
 /// **TYPE-ERASED THEORY EXPORTER** - Allows working with different theory types
@@ -162,26 +273,8 @@ impl UnifiedExporter {
             version: "1.0.0".to_string(),
         };
 
-        // **GENERIC THEORY DISCOVERY** - Add available theory exporters here
-        // Now we can use different theory types thanks to the AnyTheoryExporter trait
-        let available_theories: Vec<Box<dyn AnyTheoryExporter>> = vec![
-            Box::new(TheoryExporterWrapper::new(GroupTheoryExporter)),
-            // Add probability theory exporter
-            Box::new(TheoryExporterWrapper::new(ProbabilityTheoryExporter)),
-            // Add other theories when they become available:
-            // Box::new(TheoryExporterWrapper::new(FieldTheoryExporter)),
-            // Box::new(TheoryExporterWrapper::new(NumberTheoryExporter)),
-            // Box::new(TheoryExporterWrapper::new(ZFCTheoryExporter)),
-
-            // **EXAMPLE**: To add a field theory exporter, you would:
-            // 1. Create a FieldTheoryExporter struct
-            // 2. Implement TheoryExporter<Field, FieldExpression, FieldRelation> for it
-            // 3. Add it here: Box::new(TheoryExporterWrapper::new(FieldTheoryExporter))
-            // The export_theory_to_files function will work automatically!
-        ];
-
         // Export each discovered theory
-        for theory_exporter in available_theories {
+        for theory_exporter in Self::available_theories() {
             let theory_manifest =
                 Self::export_theory_to_files(output_dir, theory_exporter.as_ref())?;
             manifest.total_items += theory_manifest.item_count;
@@ -202,6 +295,31 @@ impl UnifiedExporter {
         Ok(())
     }
 
+    /// **GENERIC THEORY DISCOVERY** - The exporters of every theory enabled by Cargo features.
+    /// Now we can use different theory types thanks to the AnyTheoryExporter trait
+    pub fn available_theories() -> Vec<Box<dyn AnyTheoryExporter>> {
+        #[allow(unused_mut)]
+        let mut theories: Vec<Box<dyn AnyTheoryExporter>> = Vec::new();
+        #[cfg(feature = "groups")]
+        theories.push(Box::new(TheoryExporterWrapper::new(GroupTheoryExporter)));
+        // Add probability theory exporter
+        #[cfg(feature = "probability")]
+        theories.push(Box::new(TheoryExporterWrapper::new(
+            ProbabilityTheoryExporter,
+        )));
+        // Add other theories when they become available:
+        // theories.push(Box::new(TheoryExporterWrapper::new(FieldTheoryExporter)));
+        // theories.push(Box::new(TheoryExporterWrapper::new(NumberTheoryExporter)));
+        // theories.push(Box::new(TheoryExporterWrapper::new(ZFCTheoryExporter)));
+
+        // **EXAMPLE**: To add a field theory exporter, you would:
+        // 1. Create a FieldTheoryExporter struct
+        // 2. Implement TheoryExporter<Field, FieldExpression, FieldRelation> for it
+        // 3. Push it here behind its feature flag
+        // The export_theory_to_files function will work automatically!
+        theories
+    }
+
     /// **GENERIC METHOD** - Export any theory using the type-erased AnyTheoryExporter trait
     /// This now works for ALL theory types, not just group theory
     fn export_theory_to_files(
@@ -234,8 +352,10 @@ impl UnifiedExporter {
         });
         theory_manifest.item_count += 1;
 
-        // Export Definitions - separate from overview
-        let definition_content = theory.export_definitions();
+        // Export Definitions - separate from overview, each listing the theorems that use it
+        let theorems_content = theory.export_theorems();
+        let mut definition_content = theory.export_definitions();
+        link_usages(&mut definition_content, &build_usage_index(&theorems_content));
         if !definition_content.is_empty() {
             let filename = format!("{}.definitions.json", theory.theory_id());
             Self::write_content_bundle(
@@ -256,7 +376,6 @@ impl UnifiedExporter {
         }
 
         // Export Theorems - separate from overview
-        let theorems_content = theory.export_theorems();
         if !theorems_content.is_empty() {
             let filename = format!("{}.theorems.json", theory.theory_id());
             Self::write_content_bundle(
@@ -300,7 +419,10 @@ impl UnifiedExporter {
         };
 
         let file_path = Path::new(output_dir).join(filename);
-        fs::write(&file_path, serde_json::to_string_pretty(&bundle)?)?;
+        fs::write(
+            &file_path,
+            serde_json::to_string_pretty(&to_compact_json(&bundle)?)?,
+        )?;
         Ok(())
     }
 
@@ -322,3 +444,229 @@ impl UnifiedExporter {
     //     Ok(())
     // }
 }
+
+/// **PUBLIC JSON** - Serializes `value` without its `Located` and math node ids
+/// The internal format keeps ids so sub-terms can be targeted; user-facing exports
+/// should not leak them. Document and section ids are kept (see [`strip_ids`]).
+pub fn to_public_json<T: Serialize>(value: &T) -> serde_json::Result<Value> {
+    let mut json = serde_json::to_value(value)?;
+    strip_ids(&mut json);
+    Ok(json)
+}
+
+/// **COMPACT JSON** - Serializes `value` without the fields that are `null`
+/// Documents carry many optional fields that are usually unset (venue, doi, ...).
+/// A missing field deserializes back to `None`, so dropping them loses nothing.
+/// Empty lists are kept, as a missing list only deserializes where the field has a
+/// serde default.
+pub fn to_compact_json<T: Serialize>(value: &T) -> serde_json::Result<Value> {
+    let mut json = serde_json::to_value(value)?;
+    drop_null_fields(&mut json);
+    Ok(json)
+}
+
+/// Recursively removes the entries of every object in `value` whose value is `null`.
+fn drop_null_fields(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            map.retain(|_, entry| !entry.is_null());
+            map.values_mut().for_each(drop_null_fields);
+        }
+        Value::Array(items) => items.iter_mut().for_each(drop_null_fields),
+        _ => {}
+    }
+}
+
+/// **CANONICAL JSON** - Serializes `value` with the keys of every object sorted
+/// Objects serialized from a `HashMap` otherwise come out in whatever order the
+/// map iterates, which breaks golden files and content hashes. Arrays keep their
+/// order: once serialized, a list cannot be told apart from a set.
+pub fn to_canonical_json<T: Serialize>(value: &T) -> serde_json::Result<String> {
+    let mut json = serde_json::to_value(value)?;
+    sort_keys(&mut json);
+    serde_json::to_string(&json)
+}
+
+/// Recursively reorders the entries of every object in `value` by key.
+fn sort_keys(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            let mut entries: Vec<(String, Value)> = std::mem::take(map).into_iter().collect();
+            entries.sort_by(|(left, _), (right, _)| left.cmp(right));
+            for (key, mut entry) in entries {
+                sort_keys(&mut entry);
+                map.insert(key, entry);
+            }
+        }
+        Value::Array(items) => items.iter_mut().for_each(sort_keys),
+        _ => {}
+    }
+}
+
+/// Number of theorem records written between flushes in [`write_theorems_json`].
+const THEOREMS_PER_FLUSH: usize = 32;
+
+/// **STREAMING EXPORT** - Writes each exported theorem as one JSON line (JSONL)
+/// Records are serialized straight into `writer`, so the full serialized blob is
+/// never held in memory; the writer is flushed every few records and at the end.
+pub fn write_theorems_json<O, E, R, W: std::io::Write>(
+    exporter: &dyn TheoryExporter<O, E, R>,
+    writer: &mut W,
+) -> std::io::Result<()> {
+    for (index, theorem) in exporter.export_theorems().iter().enumerate() {
+        serde_json::to_writer(&mut *writer, theorem)?;
+        writer.write_all(b"\n")?;
+        if (index + 1) % THEOREMS_PER_FLUSH == 0 {
+            writer.flush()?;
+        }
+    }
+    writer.flush()
+}
+
+#[cfg(all(test, feature = "groups"))]
+mod tests {
+    use super::*;
+
+    /// Whether any rendered math node in `value` still carries an id
+    fn has_math_node_id(value: &Value) -> bool {
+        match value {
+            Value::Object(map) => {
+                (map.len() == 2 && map.contains_key("id") && map.contains_key("content"))
+                    || map.values().any(has_math_node_id)
+            }
+            Value::Array(items) => items.iter().any(has_math_node_id),
+            _ => false,
+        }
+    }
+
+    #[test]
+    fn test_public_json_omits_internal_ids() {
+        let document = GroupTheoryExporter.export_theory_overview();
+
+        let internal = serde_json::to_value(&document).unwrap();
+        let public = to_public_json(&document).unwrap();
+        assert!(has_math_node_id(&internal));
+        assert!(!has_math_node_id(&public));
+
+        // Document ids are content, not targeting handles
+        assert_eq!(public["id"], Value::String(document.id.clone()));
+
+        // Apart from the ids, the two views carry the same content
+        let mut internal_without_ids = internal.clone();
+        strip_ids(&mut internal_without_ids);
+        assert_eq!(public, internal_without_ids);
+        assert_ne!(public, internal);
+    }
+
+    #[test]
+    fn test_write_theorems_json_emits_one_line_per_theorem() {
+        let exporter = GroupTheoryExporter;
+        let expected = exporter.export_theorems().len();
+
+        let mut buffer: Vec<u8> = Vec::new();
+        write_theorems_json(&exporter, &mut buffer).unwrap();
+
+        let output = String::from_utf8(buffer).unwrap();
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines.len(), expected);
+        for line in lines {
+            let document: MathDocument = serde_json::from_str(line).unwrap();
+            assert!(!document.id.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_export_definitions_cached_reuses_unchanged_objects() {
+        let exporter = GroupTheoryExporter;
+        let mut cache = ExportCache::new();
+
+        let first = exporter.export_definitions_cached(&mut cache).unwrap();
+        assert_eq!(cache.hits(), 0);
+        let rendered = cache.misses();
+        assert!(rendered > 0);
+
+        let second = exporter.export_definitions_cached(&mut cache).unwrap();
+        assert_eq!(cache.hits(), rendered);
+        assert_eq!(cache.misses(), rendered);
+        assert_eq!(
+            serde_json::to_value(&first).unwrap(),
+            serde_json::to_value(&second).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_canonical_json_ignores_map_insertion_order() {
+        let mut inner = serde_json::Map::new();
+        inner.insert("order".to_string(), Value::from(6));
+        inner.insert("abelian".to_string(), Value::from(false));
+        let mut first = serde_json::Map::new();
+        first.insert("name".to_string(), Value::from("S3"));
+        first.insert("properties".to_string(), Value::Object(inner));
+
+        let mut inner = serde_json::Map::new();
+        inner.insert("abelian".to_string(), Value::from(false));
+        inner.insert("order".to_string(), Value::from(6));
+        let mut second = serde_json::Map::new();
+        second.insert("properties".to_string(), Value::Object(inner));
+        second.insert("name".to_string(), Value::from("S3"));
+
+        let (first, second) = (Value::Object(first), Value::Object(second));
+        // Plain serialization keeps insertion order
+        assert_ne!(first.to_string(), second.to_string());
+        assert_eq!(
+            to_canonical_json(&first).unwrap(),
+            to_canonical_json(&second).unwrap()
+        );
+        assert_eq!(
+            compute_content_hash(&first).unwrap(),
+            compute_content_hash(&second).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_content_hash_reports_unserializable_input() {
+        // JSON object keys must be strings
+        let mut by_pair = HashMap::new();
+        by_pair.insert((1, 2), "pair");
+        assert!(compute_content_hash(&by_pair).is_err());
+    }
+
+    #[test]
+    fn test_compact_json_omits_unset_fields() {
+        let document = GroupTheoryExporter.export_theory_overview();
+
+        let full = serde_json::to_string(&document).unwrap();
+        let compact = to_compact_json(&document).unwrap();
+        // The overview has no doi, venue or publication dates
+        assert!(!compact.to_string().contains("\"doi\""));
+        assert!(!compact.to_string().contains("null"));
+
+        // Unset fields come back as None
+        let restored: MathDocument = serde_json::from_value(compact).unwrap();
+        assert_eq!(
+            serde_json::to_string(&restored).unwrap(),
+            full,
+            "compact JSON should deserialize to the same document"
+        );
+
+        let file = ContentFile {
+            file_path: "groups/theorems.json".to_string(),
+            content_type: "theorems".to_string(),
+            item_count: 0,
+            items: vec![],
+        };
+        let json = serde_json::to_value(&file).unwrap();
+        assert!(json.get("items").is_none());
+        let restored: ContentFile = serde_json::from_value(json).unwrap();
+        assert!(restored.items.is_empty());
+    }
+
+    #[test]
+    fn test_group_exporter_available_with_groups_feature() {
+        let theory_ids: Vec<String> = UnifiedExporter::available_theories()
+            .iter()
+            .map(|theory| theory.theory_id().to_string())
+            .collect();
+        assert!(theory_ids.contains(&"group_theory".to_string()));
+    }
+}
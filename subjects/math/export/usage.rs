@@ -0,0 +1,110 @@
+//! "Where is this used" cross-links between theorems and the definitions they reference.
+
+use std::collections::HashMap;
+
+use crate::turn_render::{DocumentRelationships, MathDocument, MathDocumentType, Section};
+
+/// Maps each referenced document id to the ids of the theorem documents that
+/// reference it, in the order the theorems appear in `docs`.
+///
+/// A theorem references a document when it lists that document's id among its
+/// parent documents or related concepts.
+pub fn build_usage_index(docs: &[MathDocument]) -> HashMap<String, Vec<String>> {
+    let mut index: HashMap<String, Vec<String>> = HashMap::new();
+    for doc in docs.iter().filter(|doc| is_theorem_document(doc)) {
+        let Some(relationships) = relationships(doc) else {
+            continue;
+        };
+        for referenced in relationships
+            .parent_documents
+            .iter()
+            .chain(&relationships.related_concepts)
+        {
+            let users = index.entry(referenced.clone()).or_default();
+            if !users.contains(&doc.id) {
+                users.push(doc.id.clone());
+            }
+        }
+    }
+    index
+}
+
+/// Adds the users recorded in `index` to the related concepts of each document.
+pub fn link_usages(docs: &mut [MathDocument], index: &HashMap<String, Vec<String>>) {
+    for doc in docs.iter_mut() {
+        let Some(users) = index.get(&doc.id) else {
+            continue;
+        };
+        if let Some(relationships) = relationships_mut(doc) {
+            for user in users {
+                if !relationships.related_concepts.contains(user) {
+                    relationships.related_concepts.push(user.clone());
+                }
+            }
+        }
+    }
+}
+
+fn relationships(doc: &MathDocument) -> Option<&DocumentRelationships> {
+    match &doc.content_type {
+        MathDocumentType::ScientificPaper(content) => Some(&content.relationships),
+        MathDocumentType::WikiPage(content) => Some(&content.relationships),
+        _ => None,
+    }
+}
+
+fn relationships_mut(doc: &mut MathDocument) -> Option<&mut DocumentRelationships> {
+    match &mut doc.content_type {
+        MathDocumentType::ScientificPaper(content) => Some(&mut content.relationships),
+        MathDocumentType::WikiPage(content) => Some(&mut content.relationships),
+        _ => None,
+    }
+}
+
+/// Theorem documents are recognized by the `type: theorem` metadata on their main section.
+fn is_theorem_document(doc: &MathDocument) -> bool {
+    let body: &[Section] = match &doc.content_type {
+        MathDocumentType::ScientificPaper(content) => &content.structure.body,
+        MathDocumentType::WikiPage(content) => &content.structure.body,
+        _ => return false,
+    };
+    body.iter().any(|section| {
+        section
+            .metadata
+            .iter()
+            .any(|(key, value)| key == "type" && value == "theorem")
+    })
+}
+
+#[cfg(all(test, feature = "groups"))]
+mod tests {
+    use super::*;
+    use crate::subjects::math::export::unified_exporter::TheoryExporter;
+    use crate::subjects::math::theories::groups::render::GroupTheoryExporter;
+
+    #[test]
+    fn test_definition_referenced_by_two_theorems_lists_both() {
+        let exporter = GroupTheoryExporter;
+        let definition = exporter.export_definitions().remove(0);
+        let mut theorems = exporter.export_theorems();
+        theorems.truncate(3);
+        for theorem in &mut theorems[..2] {
+            relationships_mut(theorem)
+                .unwrap()
+                .parent_documents
+                .push(definition.id.clone());
+        }
+        let users = vec![theorems[0].id.clone(), theorems[1].id.clone()];
+
+        let mut docs = vec![definition.clone()];
+        docs.extend(theorems);
+
+        let index = build_usage_index(&docs);
+        assert_eq!(index.get(&definition.id), Some(&users));
+
+        link_usages(&mut docs, &index);
+        let related = &relationships(&docs[0]).unwrap().related_concepts;
+        assert!(users.iter().all(|user| related.contains(user)));
+        assert!(!related.contains(&docs[3].id));
+    }
+}
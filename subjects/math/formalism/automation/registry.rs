@@ -1,19 +1,24 @@
 use std::collections::HashMap;
 use std::sync::{Arc, LazyLock, Mutex};
 
-use crate::subjects::math::theories::groups::theorems::group_inverse_uniqueness;
-use crate::subjects::math::{
-    formalism::{
-        foundational_axioms::{
-            double_negation_axiom, equality_refl_axiom, equality_symm_axiom, equality_tran_axiom,
-            existential_generalization_axiom, law_of_identity_axiom, modus_ponens_axiom,
-            universal_instantiation_axiom,
-        },
-        theorem::Theorem,
-    },
-    theories::groups::axioms::{
-        group_associativity_axiom, group_closure_axiom, group_identity_axiom, group_inverse_axiom,
+use crate::subjects::math::formalism::{
+    error::FormalismError,
+    foundational_axioms::{
+        double_negation_axiom, equality_refl_axiom, equality_symm_axiom, equality_tran_axiom,
+        existential_generalization_axiom, law_of_identity_axiom, modus_ponens_axiom,
+        universal_instantiation_axiom,
     },
+    theorem::Theorem,
+};
+#[cfg(feature = "groups")]
+use crate::subjects::math::theories::groups::axioms::{
+    group_associativity_axiom, group_closure_axiom, group_identity_axiom, group_inverse_axiom,
+};
+#[cfg(feature = "groups")]
+use crate::subjects::math::theories::groups::theorems::{
+    cauchy_theorem, cayley_theorem, center_is_normal_subgroup, element_order_divides_group_order,
+    first_isomorphism_theorem, fundamental_theorem_finite_abelian, group_inverse_uniqueness,
+    lagrange_theorem, normal_subgroup_test, subgroup_intersection_is_subgroup, sylow_first_theorem,
 };
 
 /// Compile-time theorem dispatch macro inspired by SurrealDB's function dispatch.
@@ -42,14 +47,27 @@ macro_rules! theorem_dispatch_with_registry {
     };
 }
 
+/// A theorem registered at runtime instead of through the dispatch table.
+///
+/// The theorem is only built, by `build`, the first time it is looked up.
+#[derive(Debug, Clone)]
+pub struct TheoremMeta {
+    pub id: String,
+    pub build: fn() -> Theorem,
+    pub categories: Vec<String>,
+}
+
 /// A simplified, thread-safe theorem registry using compile-time dispatch.
 ///
-/// Instead of complex runtime registration, all theorems are compiled into
-/// the binary and dispatched using pattern matching. This eliminates
-/// initialization ordering issues and potential stack overflows.
+/// Built-in theorems are compiled into the binary and dispatched using pattern
+/// matching, which avoids initialization ordering issues and potential stack
+/// overflows. Consumers can add their own theorems explicitly at startup with
+/// [`register`] or [`register_fn`].
 pub struct TheoremRegistry {
     // Simple cache for computed theorems
     cache: Mutex<HashMap<String, Theorem>>,
+    // Theorems registered at runtime, by id
+    registered: Mutex<HashMap<String, TheoremMeta>>,
 }
 
 impl TheoremRegistry {
@@ -57,6 +75,7 @@ impl TheoremRegistry {
         println!("DEBUG: TheoremRegistry::new() called");
         let result = Self {
             cache: std::sync::Mutex::new(std::collections::HashMap::new()),
+            registered: Mutex::new(HashMap::new()),
         };
         println!("DEBUG: TheoremRegistry::new() returning");
         result
@@ -80,8 +99,17 @@ impl TheoremRegistry {
 
         println!("DEBUG: not in cache, calling dispatch_theorem for: {}", id);
 
-        // Dispatch to appropriate theorem function
-        let theorem = match self.dispatch_theorem(id) {
+        // Dispatch to appropriate theorem function, then to runtime registrations
+        let registered = || {
+            let build = self
+                .registered
+                .lock()
+                .unwrap()
+                .get(id)
+                .map(|meta| meta.build);
+            build.map(|build| build())
+        };
+        let theorem = match self.dispatch_theorem(id).or_else(registered) {
             Some(theorem) => {
                 println!("DEBUG: dispatch_theorem returned theorem for: {}", id);
                 theorem
@@ -117,7 +145,14 @@ impl TheoremRegistry {
             "double_negation_axiom" => double_negation_axiom,
             "universal_instantiation_axiom" => universal_instantiation_axiom,
             "existential_generalization_axiom" => existential_generalization_axiom,
-            // Group Theory Axioms
+        ) {
+            return Some(theorem);
+        }
+
+        // Group Theory Axioms
+        #[cfg(feature = "groups")]
+        if let Some(theorem) = theorem_dispatch!(
+            id, self,
             "group_closure_axiom" => group_closure_axiom,
             "group_associativity_axiom" => group_associativity_axiom,
             "group_identity_axiom" => group_identity_axiom,
@@ -126,21 +161,69 @@ impl TheoremRegistry {
             return Some(theorem);
         }
 
-        // Then try complex theorems (need registry access)
-        // NOTE: Commented out to avoid circular dependency
-        // Complex theorems that use registry-accessing tactics during construction
-        // should not be included in the registry itself
+        // Group Theory Theorems. Their proofs look up the axioms above while
+        // being built, which is safe because no registry lock is held here.
+        #[cfg(feature = "groups")]
+        if let Some(theorem) = theorem_dispatch!(
+            id, self,
+            "group_inverse_uniqueness" => group_inverse_uniqueness,
+            "subgroup_intersection_is_subgroup" => subgroup_intersection_is_subgroup,
+            "first_isomorphism_theorem" => first_isomorphism_theorem,
+            "element_order_divides_group_order" => element_order_divides_group_order,
+            "lagrange_theorem" => lagrange_theorem,
+            "cayley_theorem" => cayley_theorem,
+            "sylow_first_theorem" => sylow_first_theorem,
+            "fundamental_theorem_finite_abelian" => fundamental_theorem_finite_abelian,
+            "normal_subgroup_test" => normal_subgroup_test,
+            "cauchy_theorem" => cauchy_theorem,
+            "center_is_normal_subgroup" => center_is_normal_subgroup,
+        ) {
+            return Some(theorem);
+        }
+
         None
+    }
+
+    /// Registers a theorem at runtime. Registering an id again replaces the
+    /// earlier registration; ids of built-in theorems are refused.
+    pub fn register(&self, meta: TheoremMeta) -> Result<(), FormalismError> {
+        if self.dispatch_theorem(&meta.id).is_some() {
+            return Err(FormalismError::DuplicateTheorem { id: meta.id });
+        }
+        self.cache.lock().unwrap().remove(&meta.id);
+        self.registered
+            .lock()
+            .unwrap()
+            .insert(meta.id.clone(), meta);
+        Ok(())
+    }
+
+    /// Whether `id` was registered at runtime rather than built in.
+    pub fn is_registered(&self, id: &str) -> bool {
+        self.registered.lock().unwrap().contains_key(id)
+    }
 
-        // theorem_dispatch!(
-        //     id, self,
-        //     "group_inverse_uniqueness" => prove_inverse_uniqueness,
-        // )
+    /// IDs of the runtime-registered theorems in `category`, sorted.
+    pub fn list_ids_in_category(&self, category: &str) -> Vec<String> {
+        let mut ids: Vec<String> = self
+            .registered
+            .lock()
+            .unwrap()
+            .values()
+            .filter(|meta| meta.categories.iter().any(|c| c == category))
+            .map(|meta| meta.id.clone())
+            .collect();
+        ids.sort();
+        ids
     }
 
     /// Returns a list of all available theorem IDs.
+    ///
+    /// Built-in theorems come first, followed by the runtime registrations in
+    /// sorted order.
     pub fn list_ids(&self) -> Vec<String> {
-        vec![
+        #[allow(unused_mut)]
+        let mut ids = vec![
             // Foundational Axioms
             "equality_refl_axiom".to_string(),
             "equality_symm_axiom".to_string(),
@@ -150,6 +233,9 @@ impl TheoremRegistry {
             "double_negation_axiom".to_string(),
             "universal_instantiation_axiom".to_string(),
             "existential_generalization_axiom".to_string(),
+        ];
+        #[cfg(feature = "groups")]
+        ids.extend([
             // Group Theory Axioms
             "group_closure_axiom".to_string(),
             "group_associativity_axiom".to_string(),
@@ -157,7 +243,21 @@ impl TheoremRegistry {
             "group_inverse_axiom".to_string(),
             // Group Theory Theorems
             "group_inverse_uniqueness".to_string(),
-        ]
+            "subgroup_intersection_is_subgroup".to_string(),
+            "first_isomorphism_theorem".to_string(),
+            "element_order_divides_group_order".to_string(),
+            "lagrange_theorem".to_string(),
+            "cayley_theorem".to_string(),
+            "sylow_first_theorem".to_string(),
+            "fundamental_theorem_finite_abelian".to_string(),
+            "normal_subgroup_test".to_string(),
+            "cauchy_theorem".to_string(),
+            "center_is_normal_subgroup".to_string(),
+        ]);
+        let mut registered: Vec<String> = self.registered.lock().unwrap().keys().cloned().collect();
+        registered.sort();
+        ids.extend(registered);
+        ids
     }
 }
 
@@ -179,6 +279,30 @@ pub fn get_theorem_registry() -> Arc<TheoremRegistry> {
     // Arc::new(TheoremRegistry::new())
 }
 
+/// Looks up a theorem by id in the global registry.
+pub fn get_theorem(id: &str) -> Option<Theorem> {
+    get_theorem_registry().get(id)
+}
+
+/// Registers a theorem with the global registry, for environments where
+/// theorems cannot be compiled into the dispatch table.
+pub fn register(meta: TheoremMeta) -> Result<(), FormalismError> {
+    get_theorem_registry().register(meta)
+}
+
+/// Shorthand for [`register`] from an id, a builder and category names.
+pub fn register_fn(
+    id: &str,
+    build: fn() -> Theorem,
+    categories: &[&str],
+) -> Result<(), FormalismError> {
+    register(TheoremMeta {
+        id: id.to_string(),
+        build,
+        categories: categories.iter().map(|c| c.to_string()).collect(),
+    })
+}
+
 // pub fn get_theorem_registry() -> TheoremRegistry {
 //     //     // println!("DEBUG: get_theorem_registry() called");
 //     //     // let result = GLOBAL_THEOREMS.clone();
@@ -250,4 +374,51 @@ mod tests {
         // assert!(theorem.is_some());
         // assert_eq!(theorem.unwrap().id, "inverse_uniqueness");
     }
+    fn runtime_theorem() -> Theorem {
+        Theorem {
+            id: "runtime_registered_theorem".to_string(),
+            ..law_of_identity_axiom()
+        }
+    }
+
+    #[test]
+    fn test_register_theorem_at_runtime() {
+        // A local registry, so other tests' registrations cannot interfere
+        let registry = TheoremRegistry::new();
+        registry
+            .register(TheoremMeta {
+                id: "runtime_registered_theorem".to_string(),
+                build: runtime_theorem,
+                categories: vec!["testing".to_string()],
+            })
+            .unwrap();
+
+        let theorem = registry.get("runtime_registered_theorem");
+        assert_eq!(theorem.unwrap().id, "runtime_registered_theorem");
+        assert!(
+            registry
+                .list_ids()
+                .contains(&"runtime_registered_theorem".to_string())
+        );
+        assert_eq!(
+            registry.list_ids_in_category("testing"),
+            vec!["runtime_registered_theorem".to_string()]
+        );
+
+        // Built-in ids cannot be taken over
+        assert_eq!(
+            register_fn("law_of_identity_axiom", runtime_theorem, &[]),
+            Err(FormalismError::DuplicateTheorem {
+                id: "law_of_identity_axiom".to_string()
+            })
+        );
+
+        // The global registry lists it alongside whatever else has been registered
+        register_fn("runtime_registered_theorem", runtime_theorem, &["testing"]).unwrap();
+        assert!(
+            get_theorem_registry()
+                .list_ids_in_category("testing")
+                .contains(&"runtime_registered_theorem".to_string())
+        );
+    }
 }
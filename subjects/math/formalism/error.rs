@@ -0,0 +1,50 @@
+// Module: src/formalize_v2/subjects/math/formalism/error.rs
+// Unified error type for the formalism layer (goals, tactics, contexts)
+
+use thiserror::Error;
+
+/// Errors raised while building, checking or transforming formal objects.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum FormalismError {
+    /// A proof goal violates a structural invariant
+    #[error("Ill-formed goal: {message}")]
+    IllFormedGoal { message: String },
+
+    /// A tactic could not be applied
+    #[error("Tactic failed: {message}")]
+    TacticFailed { message: String },
+
+    /// A name is referenced but not declared in the context
+    #[error("Unknown identifier: {name}")]
+    UnknownIdentifier { name: String },
+
+    /// An expression has a different kind than required
+    #[error("Type mismatch: expected {expected}, found {found}")]
+    TypeMismatch { expected: String, found: String },
+
+    /// A proof forest's parent and child links disagree
+    #[error("Proof forest is inconsistent: {message}")]
+    InconsistentForest { message: String },
+
+    /// A proof still has open branches
+    #[error("Proof is incomplete: {message}")]
+    IncompleteProof { message: String },
+
+    /// A theorem id is already taken by a built-in theorem
+    #[error("Theorem {id} is built in")]
+    DuplicateTheorem { id: String },
+
+    /// Adding nodes would take a proof forest past its node cap
+    #[error("Proof forest is limited to {max_nodes} nodes")]
+    ForestTooLarge { max_nodes: usize },
+}
+
+/// Result type for formalism operations
+pub type FormalismResult<T> = Result<T, FormalismError>;
+
+// Transitional: lets callers that still speak `Result<_, String>` use `?`.
+impl From<FormalismError> for String {
+    fn from(error: FormalismError) -> Self {
+        error.to_string()
+    }
+}
@@ -7,15 +7,16 @@ use serde::{Deserialize, Serialize};
 
 use crate::turn_render::Identifier;
 
+#[cfg(feature = "groups")]
 use super::super::theories::groups::definitions::{Group, GroupExpression};
+#[cfg(feature = "rings")]
 use super::super::theories::rings::definitions::{FieldExpression, RingExpression};
 
 use super::traits::complexity::Complexity;
 
-use super::super::theories::{
-    number_theory::definitions::Number,
-    rings::{Ring, definitions::Field},
-};
+use super::super::theories::number_theory::definitions::Number;
+#[cfg(feature = "rings")]
+use super::super::theories::rings::{Ring, definitions::Field};
 
 use super::super::formalism::interpretation::TypeViewOperator;
 use super::extract::Parametrizable;
@@ -25,8 +26,11 @@ use crate::turn_render::{RichText, RichTextSegment};
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Hash)]
 pub enum TheoryExpression {
+    #[cfg(feature = "groups")]
     Group(GroupExpression),
+    #[cfg(feature = "rings")]
     Ring(RingExpression),
+    #[cfg(feature = "rings")]
     Field(FieldExpression),
 }
 
@@ -199,6 +203,7 @@ impl SameRole for MathExpression {
 }
 
 // Implementation to convert GroupExpression into MathExpression
+#[cfg(feature = "groups")]
 impl From<GroupExpression> for MathExpression {
     fn from(group_expr: GroupExpression) -> Self {
         MathExpression::Expression(TheoryExpression::Group(group_expr))
@@ -206,6 +211,7 @@ impl From<GroupExpression> for MathExpression {
 }
 
 // Implementation to convert RingExpression into MathExpression
+#[cfg(feature = "rings")]
 impl From<RingExpression> for MathExpression {
     fn from(ring_expr: RingExpression) -> Self {
         MathExpression::Expression(TheoryExpression::Ring(ring_expr))
@@ -213,6 +219,7 @@ impl From<RingExpression> for MathExpression {
 }
 
 // Implementation to convert FieldExpression into MathExpression
+#[cfg(feature = "rings")]
 impl From<FieldExpression> for MathExpression {
     fn from(field_expr: FieldExpression) -> Self {
         MathExpression::Expression(TheoryExpression::Field(field_expr))
@@ -1,6 +1,9 @@
 use super::super::formalism::objects::MathObject;
+#[cfg(feature = "groups")]
 use super::super::theories::groups::definitions::Group;
+#[cfg(feature = "rings")]
 use super::super::theories::rings::Ring;
+#[cfg(feature = "rings")]
 use super::super::theories::rings::definitions::Field;
 use super::super::theories::zfc::definitions::Set;
 use super::location::Located;
@@ -12,18 +15,21 @@ use std::{collections::HashMap, sync::Arc};
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Hash)]
 pub enum TypeViewOperator {
     /// View a number as an element of a group
+    #[cfg(feature = "groups")]
     AsGroupElement {
         /// Optional group context (e.g., Z/nZ with modulus n)
         group: Group,
     },
 
     /// View a number as an element of a ring
+    #[cfg(feature = "rings")]
     AsRingElement {
         /// Optional ring context
         ring: Ring,
     },
 
     /// View a number as an element of a field
+    #[cfg(feature = "rings")]
     AsFieldElement {
         /// Optional field context
         field: Field,
@@ -232,6 +238,7 @@ pub enum EnhancedTypeViewOperator {
 /// Helper methods for creating view expressions
 impl MathExpression {
     /// Create a view of this expression as a group element
+    #[cfg(feature = "groups")]
     pub fn as_group_element(&self, group: Group) -> Self {
         MathExpression::ViewAs {
             view: Located::new_concrete(TypeViewOperator::AsGroupElement { group }),
@@ -240,6 +247,7 @@ impl MathExpression {
     }
 
     /// Create a view of this expression as a ring element
+    #[cfg(feature = "rings")]
     pub fn as_ring_element(&self, ring: Ring) -> Self {
         MathExpression::ViewAs {
             view: Located::new_concrete(TypeViewOperator::AsRingElement { ring }),
@@ -256,6 +264,7 @@ impl MathExpression {
     }
 
     /// Create a view of this expression as a field element
+    #[cfg(feature = "rings")]
     pub fn as_field_element(&self, field: Field) -> Self {
         MathExpression::ViewAs {
             view: Located::new_concrete(TypeViewOperator::AsFieldElement { field }),
@@ -299,8 +308,11 @@ impl TypeViewOperator {
     /// Get the name of this view operator
     pub fn name(&self) -> String {
         match self {
+            #[cfg(feature = "groups")]
             TypeViewOperator::AsGroupElement { .. } => "AsGroupElement".to_string(),
+            #[cfg(feature = "rings")]
             TypeViewOperator::AsRingElement { .. } => "AsRingElement".to_string(),
+            #[cfg(feature = "rings")]
             TypeViewOperator::AsFieldElement { .. } => "AsFieldElement".to_string(),
             TypeViewOperator::AsGroup { .. } => "AsGroup".to_string(),
             TypeViewOperator::AsRing { .. } => "AsRing".to_string(),
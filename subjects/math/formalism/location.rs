@@ -85,3 +85,98 @@ where
         self.id.hash(state);
     }
 }
+
+/// Removes the `id` of every serialized `Located` value and rendered `MathNode`.
+///
+/// `Located` ids (and the render ids derived from them) are internal handles for
+/// targeting sub-terms; stripping them gives the content-only view used for
+/// comparisons and public exports. Other ids, such as those of documents,
+/// sections and link targets, are part of the content and are kept.
+pub fn strip_ids(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Object(map) => {
+            if is_located_shape(map) || is_math_node_shape(map) {
+                map.remove("id");
+            }
+            map.values_mut().for_each(strip_ids);
+        }
+        serde_json::Value::Array(items) => items.iter_mut().for_each(strip_ids),
+        _ => {}
+    }
+}
+
+/// Serializes `value` with its located ids stripped (see [`strip_ids`]).
+pub fn content_json<T: Serialize>(value: &T) -> Option<serde_json::Value> {
+    let mut json = serde_json::to_value(value).ok()?;
+    strip_ids(&mut json);
+    Some(json)
+}
+
+/// Compares two values by their [`content_json`], so that terms differing only
+/// in the ids of located sub-terms are equal.
+pub fn content_eq<T: Serialize>(left: &T, right: &T) -> bool {
+    match (content_json(left), content_json(right)) {
+        (Some(left), Some(right)) => left == right,
+        _ => false,
+    }
+}
+
+/// `{"id": .., "data": {"Concrete": ..}}` or `{"id": .., "data": {"Variable": ..}}`
+fn is_located_shape(map: &serde_json::Map<String, serde_json::Value>) -> bool {
+    map.len() == 2
+        && map.contains_key("id")
+        && map
+            .get("data")
+            .and_then(|data| data.as_object())
+            .is_some_and(|data| {
+                data.len() == 1 && (data.contains_key("Concrete") || data.contains_key("Variable"))
+            })
+}
+
+/// `{"id": .., "content": ..}`, the serialized form of a rendered `MathNode`
+fn is_math_node_shape(map: &serde_json::Map<String, serde_json::Value>) -> bool {
+    map.len() == 2 && map.contains_key("id") && map.contains_key("content")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_strip_ids_only_removes_located_and_math_node_ids() {
+        let mut value = json!({
+            "id": "doc",
+            "sections": [{
+                "id": "doc-section",
+                "title": null,
+                "math": {"id": "node-1", "content": {"Text": "x"}},
+                "term": {"id": "term-1", "data": {"Variable": {"body": "x"}}},
+            }],
+        });
+        strip_ids(&mut value);
+
+        assert_eq!(
+            value,
+            json!({
+                "id": "doc",
+                "sections": [{
+                    "id": "doc-section",
+                    "title": null,
+                    "math": {"content": {"Text": "x"}},
+                    "term": {"data": {"Variable": {"body": "x"}}},
+                }],
+            })
+        );
+    }
+
+    #[test]
+    fn test_content_eq_ignores_located_ids() {
+        let x = || Located::<String>::new_concrete("x".to_string());
+        let y = Located::<String>::new_concrete("y".to_string());
+
+        assert_ne!(x(), x());
+        assert!(content_eq(&x(), &x()));
+        assert!(!content_eq(&x(), &y));
+    }
+}
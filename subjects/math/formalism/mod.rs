@@ -6,6 +6,7 @@
 // pub mod counter_example;
 
 pub mod automation;
+pub mod error;
 pub mod expressions;
 pub mod extract;
 pub mod foundational_axioms;
@@ -32,7 +33,9 @@ use traits::detag::TryDetag;
 
 use crate::turn_render::Identifier;
 
+#[cfg(feature = "groups")]
 use super::theories::groups::definitions::{Group, GroupExpression};
+#[cfg(feature = "groups")]
 pub fn group_identity_theorem_2() -> Theorem {
     let group = Located::new_concrete(Group::new_generic());
     let x_var = Identifier::new_simple("x".to_string());
@@ -2,10 +2,12 @@ use crate::subjects::math::theories::fields::Field;
 use crate::subjects::math::theories::topology::definitions::TopologicalSpace;
 
 use super::super::theories::analysis::definition::functions::Function;
+#[cfg(feature = "groups")]
 use super::super::theories::groups::definitions::{
     Group, GroupOperation, GroupProperty, GroupRelation, LieGroup, TopologicalGroup,
 };
 use super::super::theories::linear_algebra::definitions::VectorSpace;
+#[cfg(feature = "rings")]
 use super::super::theories::rings::definitions::{
     Algebra, Module, Ring, RingExpression, RingProperty,
 };
@@ -18,12 +20,16 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub enum MathObject {
     // Group theory objects
+    #[cfg(feature = "groups")]
     Group(Group),
 
     // Ring theory objects
+    #[cfg(feature = "rings")]
     Ring(Ring),
     Field(Field),
+    #[cfg(feature = "rings")]
     Module(Module),
+    #[cfg(feature = "rings")]
     Algebra(Algebra),
 
     // Topology objects
@@ -56,6 +62,7 @@ impl SameRole for MathObject {
         candidate_context: &Vec<crate::subjects::math::formalism::proof::ContextEntry>,
     ) -> bool {
         match (self, candidate) {
+            #[cfg(feature = "groups")]
             (MathObject::Group(a), MathObject::Group(b)) => {
                 a.same_role(target_context, b, candidate_context)
             }
@@ -0,0 +1,313 @@
+//! Cursor-based editing session over a proof forest.
+//!
+//! The forest itself is append-only: undoing a step does not delete the nodes it
+//! created, it moves the cursor back, detaches those nodes from their parents so
+//! they no longer count towards the proof, and marks them as orphaned. Redoing
+//! the step reattaches and revives them.
+
+use std::collections::{HashMap, HashSet};
+
+use super::super::error::FormalismError;
+use super::tactics::Tactic;
+use super::{ProofForest, ProofNode};
+
+/// One applied tactic, as recorded in the undo/redo stacks.
+#[derive(Debug, Clone)]
+struct HistoryStep {
+    /// Cursor before the tactic was applied
+    from: String,
+    /// Cursor after the tactic was applied
+    to: String,
+    /// Nodes added to the forest by the tactic
+    created: Vec<String>,
+}
+
+/// An interactive proof: a forest, the node currently being worked on, and the
+/// history needed to step back and forth through applied tactics.
+#[derive(Debug, Clone)]
+pub struct InteractiveProof {
+    forest: ProofForest,
+    current: String,
+    undo_stack: Vec<HistoryStep>,
+    redo_stack: Vec<HistoryStep>,
+    orphaned: HashSet<String>,
+    /// Parents of detached nodes, kept so redo can reattach them
+    detached_parents: HashMap<String, String>,
+}
+
+impl InteractiveProof {
+    /// Start a session at the forest's first root.
+    pub fn new(forest: ProofForest) -> Result<Self, FormalismError> {
+        let current = forest
+            .get_root()
+            .map(|root| root.id.clone())
+            .ok_or_else(|| FormalismError::IllFormedGoal {
+                message: "Cannot start an interactive proof on a forest without roots".to_string(),
+            })?;
+        Ok(Self {
+            forest,
+            current,
+            undo_stack: Vec::new(),
+            redo_stack: Vec::new(),
+            orphaned: HashSet::new(),
+            detached_parents: HashMap::new(),
+        })
+    }
+
+    pub fn forest(&self) -> &ProofForest {
+        &self.forest
+    }
+
+    pub fn current_node_id(&self) -> &str {
+        &self.current
+    }
+
+    pub fn current_node(&self) -> &ProofNode {
+        self.forest
+            .get_node(&self.current)
+            .expect("the cursor always points into the forest")
+    }
+
+    /// Whether the node was created by a step that has since been undone.
+    pub fn is_orphaned(&self, node_id: &str) -> bool {
+        self.orphaned.contains(node_id)
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.undo_stack.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.redo_stack.is_empty()
+    }
+
+    /// Apply a tactic to the current node and move the cursor to the resulting
+    /// goal (the first one, if the tactic split the goal).
+    ///
+    /// Discards the redo history: nodes of undone steps stay orphaned.
+    pub fn apply(&mut self, tactic: Tactic) -> Result<&ProofNode, FormalismError> {
+        let node = self.current_node().clone();
+        let outcome = node.try_apply_tactic(tactic, &mut self.forest)?;
+        let mut created = vec![outcome.manager.id.clone()];
+        created.extend(outcome.sub_nodes.iter().map(|sub_node| sub_node.id.clone()));
+        let next = outcome.first_goal_node().id;
+
+        self.undo_stack.push(HistoryStep {
+            from: std::mem::replace(&mut self.current, next.clone()),
+            to: next,
+            created,
+        });
+        self.redo_stack.clear();
+        Ok(self.current_node())
+    }
+
+    /// Step back to where the cursor was before the last applied tactic.
+    /// Returns false if there is nothing to undo.
+    pub fn undo(&mut self) -> bool {
+        let Some(step) = self.undo_stack.pop() else {
+            return false;
+        };
+        for node_id in &step.created {
+            self.set_attached(node_id, false);
+        }
+        self.orphaned.extend(step.created.iter().cloned());
+        self.current = step.from.clone();
+        self.redo_stack.push(step);
+        true
+    }
+
+    /// Re-apply the last undone tactic by reviving its nodes.
+    /// Returns false if there is nothing to redo.
+    pub fn redo(&mut self) -> bool {
+        let Some(step) = self.redo_stack.pop() else {
+            return false;
+        };
+        for node_id in &step.created {
+            self.set_attached(node_id, true);
+            self.orphaned.remove(node_id);
+        }
+        self.current = step.to.clone();
+        self.undo_stack.push(step);
+        true
+    }
+
+    /// Adds the node to, or removes it from, its parent's children. Steps append
+    /// their nodes to the parent, so reattaching in creation order restores it.
+    ///
+    /// A detached node also drops its `parent` link, which keeps the forest's
+    /// parent/child invariants intact while the node is orphaned.
+    fn set_attached(&mut self, node_id: &str, attached: bool) {
+        let parent_id = if attached {
+            self.detached_parents.remove(node_id)
+        } else {
+            self.forest
+                .get_node_mut(node_id)
+                .and_then(|node| node.parent.take())
+        };
+        let Some(parent_id) = parent_id else {
+            return;
+        };
+        if let Some(parent) = self.forest.get_node_mut(&parent_id) {
+            parent.children.retain(|child_id| child_id != node_id);
+            if attached {
+                parent.children.push(node_id.to_string());
+            }
+        }
+        if attached {
+            if let Some(node) = self.forest.get_node_mut(node_id) {
+                node.parent = Some(parent_id);
+            }
+        } else {
+            self.detached_parents.insert(node_id.to_string(), parent_id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::subjects::math::formalism::expressions::MathExpression;
+    use crate::subjects::math::formalism::location::Located;
+    use crate::subjects::math::formalism::proof::{NodeRole, ProofGoal};
+    use crate::subjects::math::formalism::relations::MathRelation;
+    use crate::subjects::math::theories::number_theory::definitions::Number;
+
+    #[test]
+    fn test_undo_moves_cursor_back_and_orphans_step() {
+        let part = || {
+            Located::new_concrete(MathRelation::equal(
+                MathExpression::Number(Number::int(1)),
+                MathExpression::Number(Number::int(1)),
+            ))
+        };
+        let goal = ProofGoal {
+            context: vec![],
+            quantifiers: vec![],
+            statement: Located::new_concrete(MathRelation::And(vec![part(), part()])),
+        };
+
+        let mut forest = ProofForest::new_from_goal(goal.clone());
+        forest
+            .add_node(ProofNode {
+                id: "root".to_string(),
+                parent: None,
+                children: vec![],
+                role: NodeRole::Goal(goal),
+                tactic: Tactic::SearchAssumptions,
+                description: None,
+                hint: None,
+            })
+            .unwrap();
+        forest.roots.push("root".to_string());
+        let mut proof = InteractiveProof::new(forest).unwrap();
+
+        proof.apply(Tactic::SplitGoalConjunction).unwrap();
+        let first_conjunct = proof.current_node_id().to_string();
+        assert_ne!(first_conjunct, "root");
+
+        proof.apply(Tactic::ByReflexivity).unwrap();
+        let completed = proof.current_node_id().to_string();
+        assert!(matches!(proof.current_node().role, NodeRole::Completed));
+
+        assert!(proof.undo());
+        assert_eq!(proof.current_node_id(), first_conjunct);
+        assert!(proof.is_orphaned(&completed));
+        assert!(!proof.is_orphaned(&first_conjunct));
+        // The forest only grows
+        assert!(proof.forest().get_node(&completed).is_some());
+
+        assert!(proof.redo());
+        assert_eq!(proof.current_node_id(), completed);
+        assert!(!proof.is_orphaned(&completed));
+        assert!(!proof.redo());
+    }
+
+    #[test]
+    fn test_undo_reopens_a_completed_proof() {
+        let goal = ProofGoal {
+            context: vec![],
+            quantifiers: vec![],
+            statement: Located::new_concrete(MathRelation::equal(
+                MathExpression::Number(Number::int(1)),
+                MathExpression::Number(Number::int(1)),
+            )),
+        };
+        let mut forest = ProofForest::new_from_goal(goal.clone());
+        forest
+            .add_node(ProofNode {
+                id: "root".to_string(),
+                parent: None,
+                children: vec![],
+                role: NodeRole::Goal(goal),
+                tactic: Tactic::SearchAssumptions,
+                description: None,
+                hint: None,
+            })
+            .unwrap();
+        forest.roots.push("root".to_string());
+        let mut proof = InteractiveProof::new(forest).unwrap();
+
+        proof.apply(Tactic::ByReflexivity).unwrap();
+        assert!(proof.forest().is_fully_proven());
+
+        assert!(proof.undo());
+        assert!(!proof.forest().is_fully_proven());
+        assert!(proof.forest().get_node("root").unwrap().children.is_empty());
+
+        assert!(proof.redo());
+        assert!(proof.forest().is_fully_proven());
+    }
+
+    #[test]
+    fn test_apply_after_undo_keeps_the_forest_consistent() {
+        let goal = ProofGoal {
+            context: vec![],
+            quantifiers: vec![],
+            statement: Located::new_concrete(MathRelation::equal(
+                MathExpression::Number(Number::int(1)),
+                MathExpression::Number(Number::int(1)),
+            )),
+        };
+        let mut forest = ProofForest::new_from_goal(goal.clone());
+        forest
+            .add_node(ProofNode {
+                id: "root".to_string(),
+                parent: None,
+                children: vec![],
+                role: NodeRole::Goal(goal),
+                tactic: Tactic::SearchAssumptions,
+                description: None,
+                hint: None,
+            })
+            .unwrap();
+        forest.roots.push("root".to_string());
+        let mut proof = InteractiveProof::new(forest).unwrap();
+
+        proof.apply(Tactic::ByReflexivity).unwrap();
+        let undone = proof.current_node_id().to_string();
+        assert!(proof.undo());
+        assert_eq!(proof.forest().get_node(&undone).unwrap().parent, None);
+        assert_eq!(proof.forest().check_invariants(), Ok(()));
+
+        proof.apply(Tactic::ByReflexivity).unwrap();
+        assert_eq!(proof.forest().check_invariants(), Ok(()));
+        assert!(proof.forest().is_fully_proven());
+        assert!(proof.is_orphaned(&undone));
+        assert_eq!(proof.forest().get_node("root").unwrap().children.len(), 1);
+    }
+
+    #[test]
+    fn test_new_rejects_forest_without_roots() {
+        let goal = ProofGoal {
+            context: vec![],
+            quantifiers: vec![],
+            statement: Located::new_concrete(MathRelation::True),
+        };
+        let forest = ProofForest::new_from_goal(goal);
+
+        assert!(matches!(
+            InteractiveProof::new(forest),
+            Err(FormalismError::IllFormedGoal { .. })
+        ));
+    }
+}
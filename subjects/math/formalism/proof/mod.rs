@@ -13,7 +13,9 @@ use std::rc::Rc;
 use std::sync::{Arc, Mutex, OnceLock};
 #[cfg(not(target_arch = "wasm32"))]
 use std::time::{SystemTime, UNIX_EPOCH};
-use tactics::{TacticApplicationResult, Target};
+use tactics::{
+    ContextOrStatement, RelationSource, RewriteDirection, TacticApplicationResult, Target,
+};
 use uuid::Uuid;
 #[cfg(target_arch = "wasm32")]
 use wasm_bindgen::prelude::*;
@@ -21,14 +23,19 @@ use wasm_bindgen::prelude::*;
 use super::super::theories::zfc::definitions::SetRelation;
 use super::expressions::{MathExpression, TheoryExpression};
 use super::interpretation::TypeViewOperator;
-use super::location::Located;
+use super::location::{Located, content_eq, content_json};
 use super::relations::{MathRelation, Quantification};
 use super::{objects::MathObject, theorem::Theorem};
 
 use self::tactics::Tactic;
+use super::automation::registry::get_theorem_registry;
+use super::error::FormalismError;
+use super::traits::complexity::Complexity;
 use crate::subjects::math::formalism::extract::Parametrizable;
 
+#[cfg(feature = "groups")]
 use crate::subjects::math::theories::groups::definitions::GroupExpression;
+#[cfg(feature = "rings")]
 use crate::subjects::math::theories::rings::definitions::{FieldExpression, RingExpression};
 use crate::turn_render::{
     Identifier, MathNode, MathNodeContent, RichText, RichTextSegment, Section, SectionContentNode,
@@ -36,7 +43,10 @@ use crate::turn_render::{
 };
 
 pub mod helpers;
+pub mod interactive;
+pub mod serialization;
 pub mod tactics;
+pub mod test_support;
 
 /// Describes how the value of a context entry is defined.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -59,6 +69,10 @@ pub struct ContextEntry {
     pub ty: Located<MathExpression>,
     pub definition: DefinitionState,
     pub description: Option<RichText>,
+    /// Bookkeeping entry that proof displays may hide, see
+    /// `RenderOptions::hide_internal_context`. It is still checked by `verify()`.
+    #[serde(default)]
+    pub is_internal: bool,
 }
 
 /// Extension trait for Vec<ContextEntry> to provide context-specific operations.
@@ -259,6 +273,7 @@ impl ProofGoal {
                 segments: vec![RichTextSegment::Text(s)],
                 alignment: None,
             }),
+            is_internal: false,
         };
         self.context.push(entry);
         (self, variable_name)
@@ -286,6 +301,7 @@ impl ProofGoal {
                 segments: vec![RichTextSegment::Text(s)],
                 alignment: None,
             }),
+            is_internal: false,
         };
         self.context.push(entry);
         (self, hypothesis_name)
@@ -348,11 +364,31 @@ impl ProofGoal {
         self
     }
 
+    /// Build the goal `h₁: A, h₂: B, … ⊢ C` from named hypotheses and a conclusion.
+    /// Hypotheses are added to the context in the given order.
+    pub fn from_implication(
+        hypotheses: Vec<(&str, MathRelation)>,
+        conclusion: MathRelation,
+    ) -> ProofGoal {
+        let goal = ProofGoal {
+            context: vec![],
+            quantifiers: vec![],
+            statement: Located::new_concrete(conclusion),
+        };
+        hypotheses
+            .into_iter()
+            .fold(goal, |goal, (name, hypothesis)| {
+                goal.with_hypothesis(name, hypothesis, None).0
+            })
+    }
+
     /// Verifies that the proof goal is well-formed.
-    pub fn verify(&self) -> Result<(), String> {
+    pub fn verify(&self) -> Result<(), FormalismError> {
         if let Some(statement_arc) = self.statement.concrete_value() {
             if matches!(statement_arc.as_ref(), MathRelation::False) {
-                return Err("Statement has not been set.".to_string());
+                return Err(FormalismError::IllFormedGoal {
+                    message: "Statement has not been set.".to_string(),
+                });
             }
         }
 
@@ -362,17 +398,18 @@ impl ProofGoal {
         for q in &self.quantifiers {
             match context_map.get(&q.variable_name) {
                 None => {
-                    return Err(format!(
-                        "Quantifier '{:?}' has no definition in the context.",
-                        q.variable_name
-                    ));
+                    return Err(FormalismError::UnknownIdentifier {
+                        name: q.variable_name.to_string(),
+                    });
                 }
                 Some(entry) => {
                     if !matches!(entry.definition, DefinitionState::Abstract) {
-                        return Err(format!(
-                            "Quantified variable '{:?}' must be abstract, but it has a concrete definition.",
-                            q.variable_name
-                        ));
+                        return Err(FormalismError::IllFormedGoal {
+                            message: format!(
+                                "Quantified variable '{}' must be abstract, but it has a concrete definition.",
+                                q.variable_name
+                            ),
+                        });
                     }
                 }
             }
@@ -380,7 +417,9 @@ impl ProofGoal {
 
         // Check 2: No duplicate names in the context.
         if context_map.len() != self.context.len() {
-            return Err("Duplicate names found in the context.".to_string());
+            return Err(FormalismError::IllFormedGoal {
+                message: "Duplicate names found in the context.".to_string(),
+            });
         }
 
         // A more advanced check would verify that all free variables in the statement
@@ -389,14 +428,124 @@ impl ProofGoal {
 
         Ok(())
     }
+
+    /// Checks that each context entry has a type suited to its role.
+    ///
+    /// Roles are read off the goal's structure, not off identifier names: an
+    /// entry bound by a quantifier is a variable and needs an object or type
+    /// expression, and an abstract entry must be typed by a relation (a
+    /// hypothesis) or by a type (a variable), never by a bare number.
+    pub fn typecheck(&self) -> Result<(), FormalismError> {
+        let quantified: HashSet<&Identifier> = self
+            .quantifiers
+            .iter()
+            .map(|quantifier| &quantifier.variable_name)
+            .collect();
+
+        for entry in &self.context {
+            let Some(ty) = entry.ty.concrete_value() else {
+                continue;
+            };
+            match ty.as_ref() {
+                MathExpression::Relation(_) if quantified.contains(&entry.name) => {
+                    return Err(FormalismError::TypeMismatch {
+                        expected: format!(
+                            "object or type expression for variable '{}'",
+                            entry.name
+                        ),
+                        found: "relation".to_string(),
+                    });
+                }
+                MathExpression::Number(_)
+                    if matches!(entry.definition, DefinitionState::Abstract) =>
+                {
+                    return Err(FormalismError::TypeMismatch {
+                        expected: format!(
+                            "relation or type expression for context entry '{}'",
+                            entry.name
+                        ),
+                        found: "number".to_string(),
+                    });
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Compares two goals by content, ignoring the ids of located sub-terms.
+    ///
+    /// Tactics that rebuild a goal hand out fresh ids even when nothing changed,
+    /// so plain id-based equality on `Located` cannot tell a stall from progress.
+    pub fn semantically_eq(&self, other: &ProofGoal) -> bool {
+        content_eq(self, other)
+    }
+
+    /// Compares two goals up to a consistent renaming of their quantified variables.
+    ///
+    /// Quantified variables are matched by position, so `∀x, P(x)` and `∀y, P(y)`
+    /// are alpha-equal while `∀x ∀y, R(x, y)` and `∀y ∀x, R(x, y)` are not. The
+    /// renaming also applies to the context entries that declare those variables.
+    pub fn alpha_eq(&self, other: &ProofGoal) -> bool {
+        if self.quantifiers.len() != other.quantifiers.len() {
+            return false;
+        }
+        match (self.alpha_normalized(), other.alpha_normalized()) {
+            (Some(left), Some(right)) => left == right,
+            _ => false,
+        }
+    }
+
+    /// Serializes the goal with ids stripped and every quantified variable
+    /// replaced by a canonical name derived from its position.
+    fn alpha_normalized(&self) -> Option<serde_json::Value> {
+        fn rename(
+            value: &mut serde_json::Value,
+            renaming: &[(serde_json::Value, serde_json::Value)],
+        ) {
+            if let Some((_, canonical)) = renaming.iter().find(|(bound, _)| bound == value) {
+                *value = canonical.clone();
+                return;
+            }
+            match value {
+                serde_json::Value::Object(map) => {
+                    map.values_mut().for_each(|v| rename(v, renaming));
+                }
+                serde_json::Value::Array(items) => {
+                    items.iter_mut().for_each(|v| rename(v, renaming));
+                }
+                _ => {}
+            }
+        }
+
+        let renaming = self
+            .quantifiers
+            .iter()
+            .enumerate()
+            .map(|(index, quantifier)| {
+                let bound = serde_json::to_value(&quantifier.variable_name).ok()?;
+                let canonical = Identifier::new_simple(format!("#bound{}", index));
+                let canonical = serde_json::to_value(canonical).ok()?;
+                Some((bound, canonical))
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        let mut value = content_json(self)?;
+        rename(&mut value, &renaming);
+        Some(value)
+    }
 }
 
 /// Represents what role a ProofNode plays in the proof structure
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", content = "content")]
 pub enum NodeRole {
     /// A normal goal that needs to be proven
+    #[serde(rename = "goal")]
     Goal(ProofGoal),
     /// A manager node that coordinates multiple sub-goals (e.g., from conjunction splits)
+    #[serde(rename = "subgoal_manager")]
     SubgoalManager {
         /// IDs of the sub-goals this node manages
         subgoal_ids: Vec<String>,
@@ -409,6 +558,7 @@ pub enum NodeRole {
     ///
     /// This node encapsulates a complex internal proof that justifies the
     /// transformation from its parent's goal to a new, transformed state.
+    #[serde(rename = "automated_tactic_step")]
     AutomatedTacticStep {
         /// A human-readable description of the automated tactic that was run
         /// (e.g., "Simplified the goal using 5 rewrites").
@@ -426,9 +576,11 @@ pub enum NodeRole {
 
     /// Represents a goal that has been proven false by citing a
     /// counter-theorem. This is a terminal failure state for a proof branch.
+    #[serde(rename = "disproved")]
     Disproved(String), // The ID of the theorem that proves the negation of this node's goal.
 
     /// A goal that was transformed by a rewrite tactic.
+    #[serde(rename = "rewrite_step")]
     RewriteStep {
         /// The goal state after the rewrite.
         goal: ProofGoal,
@@ -438,6 +590,7 @@ pub enum NodeRole {
         rewritten_to_id: Target,
     },
 
+    #[serde(rename = "completed")]
     Completed,
 }
 
@@ -448,6 +601,9 @@ pub enum SubgoalCombination {
     And,
     /// At least one sub-goal must be proven (disjunction)
     Or,
+    /// All sub-goals must be proven, in order: tactics on a sub-goal are refused
+    /// until every earlier one is proven (e.g. a `have` lemma before its continuation)
+    Sequential,
     /// Custom combination logic
     Custom(String),
 }
@@ -459,6 +615,8 @@ pub struct TacticOutcome {
     pub manager: ProofNode,
     /// All sub-nodes that were created by the tactic
     pub sub_nodes: Vec<ProofNode>,
+    /// Whether the tactic changed the goal it was applied to
+    made_progress: bool,
 }
 
 impl TacticOutcome {
@@ -491,6 +649,33 @@ impl TacticOutcome {
         }
     }
 
+    /// Whether the tactic moved the proof forward. False when the produced goal
+    /// is semantically the same as the parent's, which lets automated loops
+    /// detect stalls.
+    pub fn made_progress(&self) -> bool {
+        self.made_progress
+    }
+
+    /// Runs `ProofGoal::verify` on every goal the tactic produced, including the
+    /// goals of rewrite steps, naming the tactic in the error.
+    pub fn verify_goals(&self) -> Result<(), FormalismError> {
+        for node in std::iter::once(&self.manager).chain(&self.sub_nodes) {
+            let goal = match &node.role {
+                NodeRole::Goal(goal) | NodeRole::RewriteStep { goal, .. } => goal,
+                _ => continue,
+            };
+            if let Err(error) = goal.verify() {
+                return Err(FormalismError::IllFormedGoal {
+                    message: format!(
+                        "Tactic {} produced an ill-formed goal in node '{}': {}",
+                        node.tactic, node.id, error
+                    ),
+                });
+            }
+        }
+        Ok(())
+    }
+
     /// Check if this tactic created multiple goals that need separate handling
     pub fn is_multi_goal(&self) -> bool {
         !self.sub_nodes.is_empty()
@@ -553,6 +738,23 @@ pub struct ProofNode {
 
     /// Structured description of this proof step
     pub description: Option<RichText>,
+    /// Author-supplied hint shown alongside the step, e.g. as a tooltip
+    #[serde(default)]
+    pub hint: Option<RichText>,
+}
+
+impl NodeRole {
+    /// Single-character marker for the role, used by `ProofForest::write_tree`.
+    fn tree_glyph(&self) -> &'static str {
+        match self {
+            NodeRole::Goal(_) => "○",
+            NodeRole::SubgoalManager { .. } => "◇",
+            NodeRole::AutomatedTacticStep { .. } => "⚙",
+            NodeRole::Disproved(_) => "✗",
+            NodeRole::RewriteStep { .. } => "↻",
+            NodeRole::Completed => "✓",
+        }
+    }
 }
 
 impl ProofNode {
@@ -573,6 +775,7 @@ impl ProofNode {
             },
             tactic,
             description: None,
+            hint: None,
         }
     }
 
@@ -597,12 +800,49 @@ impl ProofNode {
         matches!(self.role, NodeRole::Goal(_))
     }
 
-    /// Apply a tactic to this node - handles both single and multi-goal results
+    /// Compute what `tactic` would do to this node's goal without recording
+    /// anything in the forest. Nodes without a goal yield an error.
+    pub fn preview_tactic(&self, tactic: &Tactic) -> TacticApplicationResult {
+        if !self.has_goal() {
+            return TacticApplicationResult::Error(format!("Node '{}' has no open goal", self.id));
+        }
+        tactic.apply_to_goal(self.get_goal())
+    }
+
+    /// Apply a tactic to this node - handles both single and multi-goal results.
+    /// Panics if the tactic fails; see `try_apply_tactic` for a fallible version.
     pub fn apply_tactic(&self, tactic: Tactic, forest: &mut ProofForest) -> TacticOutcome {
+        self.try_apply_tactic(tactic, forest)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Like `apply_tactic`, but returns an error instead of panicking when the
+    /// tactic fails, produces an ill-formed goal, or its nodes would take the
+    /// forest past its `max_nodes`. The forest is left untouched on error.
+    pub fn try_apply_tactic(
+        &self,
+        tactic: Tactic,
+        forest: &mut ProofForest,
+    ) -> Result<TacticOutcome, FormalismError> {
+        if let Some(earlier) = forest.blocking_subgoal(&self.id) {
+            return Err(FormalismError::TacticFailed {
+                message: format!(
+                    "Goal '{}' has to wait until the earlier sub-goal '{}' is proven",
+                    self.id, earlier
+                ),
+            });
+        }
         let current_goal = self.get_goal();
-        let result = tactic.apply_to_goal(current_goal);
+        let result = self.preview_tactic(&tactic);
 
-        match result {
+        let new_nodes = match &result {
+            TacticApplicationResult::MultiGoal(goals) => goals.len() + 1,
+            TacticApplicationResult::Error(_) => 0,
+            _ => 1,
+        };
+        forest.reserve_nodes(new_nodes)?;
+
+        let outcome = match result {
             TacticApplicationResult::SingleGoal(new_goal) => {
                 let new_node = ProofNode {
                     id: Uuid::new_v4().to_string(),
@@ -611,17 +851,20 @@ impl ProofNode {
                     role: NodeRole::Goal(new_goal),
                     tactic,
                     description: None,
+                    hint: None,
                 };
 
                 // Add the new node to the forest and update parent's children
-                forest.add_node(new_node.clone());
+                forest.add_node(new_node.clone())?;
                 if let Some(parent_node) = forest.get_node_mut(&self.id) {
                     parent_node.children.push(new_node.id.clone());
                 }
 
+                let made_progress = !new_node.get_goal().semantically_eq(current_goal);
                 TacticOutcome {
                     manager: new_node,
                     sub_nodes: vec![],
+                    made_progress,
                 }
             }
             TacticApplicationResult::MultiGoal(goals) => {
@@ -636,23 +879,29 @@ impl ProofNode {
                             role: NodeRole::Goal(goal),
                             tactic: tactic.clone(),
                             description: None,
+                            hint: None,
                         };
-                        forest.add_node(node.clone());
-                        node
+                        forest.add_node(node.clone())?;
+                        Ok(node)
                     })
-                    .collect();
+                    .collect::<Result<_, FormalismError>>()?;
 
                 // Create a manager node to coordinate the sub-goals
                 let subgoal_ids: Vec<String> = sub_nodes.iter().map(|n| n.id.clone()).collect();
+                // A `have` lemma has to be proven before its continuation counts
+                let combination_type = match &tactic {
+                    Tactic::Have { .. } => SubgoalCombination::Sequential,
+                    _ => SubgoalCombination::And,
+                };
                 let mut manager = ProofNode::new_manager(
                     Uuid::new_v4().to_string(),
                     subgoal_ids.clone(),
-                    SubgoalCombination::And,
+                    combination_type,
                     tactic,
                 );
                 manager.parent = Some(self.id.clone());
 
-                forest.add_node(manager.clone());
+                forest.add_node(manager.clone())?;
 
                 // Update parent's children to include manager and all sub-nodes
                 if let Some(parent_node) = forest.get_node_mut(&self.id) {
@@ -660,7 +909,11 @@ impl ProofNode {
                     parent_node.children.extend(subgoal_ids);
                 }
 
-                TacticOutcome { manager, sub_nodes }
+                TacticOutcome {
+                    manager,
+                    sub_nodes,
+                    made_progress: true,
+                }
             }
             TacticApplicationResult::ProofComplete => {
                 let completed_node = ProofNode {
@@ -670,9 +923,10 @@ impl ProofNode {
                     role: NodeRole::Completed,
                     tactic,
                     description: None,
+                    hint: None,
                 };
 
-                forest.add_node(completed_node.clone());
+                forest.add_node(completed_node.clone())?;
                 if let Some(parent_node) = forest.get_node_mut(&self.id) {
                     parent_node.children.push(completed_node.id.clone());
                 }
@@ -680,6 +934,7 @@ impl ProofNode {
                 TacticOutcome {
                     manager: completed_node,
                     sub_nodes: vec![],
+                    made_progress: true,
                 }
             }
             TacticApplicationResult::NoChange => {
@@ -690,9 +945,10 @@ impl ProofNode {
                     role: NodeRole::Goal(current_goal.clone()),
                     tactic,
                     description: None,
+                    hint: None,
                 };
 
-                forest.add_node(unchanged_node.clone());
+                forest.add_node(unchanged_node.clone())?;
                 if let Some(parent_node) = forest.get_node_mut(&self.id) {
                     parent_node.children.push(unchanged_node.id.clone());
                 }
@@ -700,12 +956,30 @@ impl ProofNode {
                 TacticOutcome {
                     manager: unchanged_node,
                     sub_nodes: vec![],
+                    made_progress: false,
                 }
             }
             TacticApplicationResult::Error(msg) => {
-                panic!("Tactic application failed: {}", msg);
+                return Err(FormalismError::TacticFailed { message: msg });
             }
+        };
+
+        // A tactic that corrupts its goal fails here, not steps later
+        if let Err(error) = outcome.verify_goals() {
+            forest.discard_outcome(&self.id, &outcome);
+            return Err(error);
+        }
+
+        // Catch tactics that leave the forest's links inconsistent
+        #[cfg(debug_assertions)]
+        if let Err(problems) = forest.check_invariants() {
+            panic!(
+                "Proof forest invariants violated after applying a tactic:\n{}",
+                problems.join("\n")
+            );
         }
+
+        Ok(outcome)
     }
 
     /// Convenience method to create a new forest from sub-goals
@@ -760,6 +1034,9 @@ pub struct ProofForest {
     nodes: HashMap<String, ProofNode>,
     /// Root node ID for each tree in the forest
     pub roots: Vec<String>,
+    /// Most nodes the forest may hold, if capped
+    #[serde(default)]
+    max_nodes: Option<usize>,
 }
 
 impl ProofForest {
@@ -768,6 +1045,40 @@ impl ProofForest {
             initial_goal: goal,
             nodes: HashMap::new(),
             roots: Vec::new(),
+            max_nodes: None,
+        }
+    }
+
+    /// A forest that refuses to grow past `max_nodes` nodes, so automated search
+    /// cannot blow up without bound.
+    pub fn with_max_nodes(goal: ProofGoal, max_nodes: usize) -> Self {
+        Self {
+            max_nodes: Some(max_nodes),
+            ..Self::new_from_goal(goal)
+        }
+    }
+
+    pub fn max_nodes(&self) -> Option<usize> {
+        self.max_nodes
+    }
+
+    /// Errors if `count` more nodes would take the forest past `max_nodes`.
+    pub fn reserve_nodes(&self, count: usize) -> Result<(), FormalismError> {
+        match self.max_nodes {
+            Some(max_nodes) if self.nodes.len() + count > max_nodes => {
+                Err(FormalismError::ForestTooLarge { max_nodes })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Removes the nodes a tactic just added under `parent_id`.
+    fn discard_outcome(&mut self, parent_id: &str, outcome: &TacticOutcome) {
+        for node in std::iter::once(&outcome.manager).chain(&outcome.sub_nodes) {
+            self.nodes.remove(&node.id);
+            if let Some(parent) = self.nodes.get_mut(parent_id) {
+                parent.children.retain(|child_id| child_id != &node.id);
+            }
         }
     }
 
@@ -776,8 +1087,13 @@ impl ProofForest {
         Self::new_from_goal(goal)
     }
 
-    pub fn add_node(&mut self, node: ProofNode) {
+    /// Adds or replaces a node. Refuses a new node once the forest is at `max_nodes`.
+    pub fn add_node(&mut self, node: ProofNode) -> Result<(), FormalismError> {
+        if !self.nodes.contains_key(&node.id) {
+            self.reserve_nodes(1)?;
+        }
         self.nodes.insert(node.id.clone(), node);
+        Ok(())
     }
 
     pub fn get_node(&self, node_id: &str) -> Option<&ProofNode> {
@@ -815,6 +1131,7 @@ impl ProofForest {
                 role: NodeRole::Goal(new_goal),
                 tactic,
                 description: None,
+                hint: None,
             },
             TacticApplicationResult::ProofComplete => ProofNode {
                 id: Uuid::new_v4().to_string(),
@@ -823,6 +1140,7 @@ impl ProofForest {
                 role: NodeRole::Completed,
                 tactic,
                 description: None,
+                hint: None,
             },
             TacticApplicationResult::NoChange => ProofNode {
                 id: Uuid::new_v4().to_string(),
@@ -831,6 +1149,7 @@ impl ProofForest {
                 role: NodeRole::Goal(initial_state),
                 tactic,
                 description: None,
+                hint: None,
             },
             TacticApplicationResult::MultiGoal(_) => {
                 panic!("Multi-goal tactics should use apply_tactic_with_subgoals method instead.");
@@ -841,7 +1160,9 @@ impl ProofForest {
         };
 
         let root_id = root_node.id.clone();
-        self.add_node(root_node);
+        if let Err(error) = self.add_node(root_node) {
+            panic!("Initial tactic application failed: {}", error);
+        }
         self.roots.push(root_id.clone());
 
         self.nodes.get(&root_id).unwrap()
@@ -872,6 +1193,10 @@ impl ProofForest {
     /// Recursively check if a branch starting at a given node is complete
     fn is_branch_complete(&self, node_id: &str) -> bool {
         if let Some(node) = self.nodes.get(node_id) {
+            // A closed manager vouches for the subgoals it holds
+            if matches!(node.role, NodeRole::Completed) {
+                return true;
+            }
             if node.children.is_empty() {
                 return matches!(node.role, NodeRole::Completed);
             }
@@ -885,6 +1210,483 @@ impl ProofForest {
             false
         }
     }
+
+    /// The earliest unproven sub-goal that `node_id`, or the sub-goal it was
+    /// derived from, waits for under a `Sequential` manager.
+    pub fn blocking_subgoal(&self, node_id: &str) -> Option<String> {
+        let mut current = self.nodes.get(node_id);
+        while let Some(node) = current {
+            let earlier = self.nodes.values().find_map(|manager| match &manager.role {
+                NodeRole::SubgoalManager {
+                    subgoal_ids,
+                    combination_type: SubgoalCombination::Sequential,
+                } => {
+                    let position = subgoal_ids.iter().position(|id| id == &node.id)?;
+                    subgoal_ids[..position]
+                        .iter()
+                        .find(|id| !self.is_branch_complete(id))
+                }
+                _ => None,
+            });
+            if let Some(earlier) = earlier {
+                return Some(earlier.clone());
+            }
+            current = node.parent.as_ref().and_then(|id| self.nodes.get(id));
+        }
+        None
+    }
+
+    /// Marks a subgoal manager as `Completed` once its subgoals justify it:
+    /// all of them under `And`, `Sequential` and `Custom`, at least one under `Or`.
+    ///
+    /// The manager takes its subgoals as children, so the closed branch keeps
+    /// their proofs (including unproven `Or` alternatives) without them counting
+    /// as open goals of the parent.
+    pub fn close_manager(&mut self, manager_id: &str) -> Result<(), FormalismError> {
+        let manager = self
+            .nodes
+            .get(manager_id)
+            .ok_or_else(|| FormalismError::IllFormedGoal {
+                message: format!("Manager '{}' is not in the forest", manager_id),
+            })?;
+        let NodeRole::SubgoalManager {
+            subgoal_ids,
+            combination_type,
+        } = &manager.role
+        else {
+            return Err(FormalismError::IllFormedGoal {
+                message: format!("Node '{}' is not a subgoal manager", manager_id),
+            });
+        };
+
+        let mut proven = subgoal_ids.iter().map(|id| self.is_branch_complete(id));
+        let closed = match combination_type {
+            SubgoalCombination::Or => proven.any(|complete| complete),
+            SubgoalCombination::And
+            | SubgoalCombination::Sequential
+            | SubgoalCombination::Custom(_) => proven.all(|complete| complete),
+        };
+        if !closed {
+            return Err(FormalismError::TacticFailed {
+                message: format!(
+                    "Subgoals of manager '{}' do not yet prove it ({:?})",
+                    manager_id, combination_type
+                ),
+            });
+        }
+
+        let subgoal_ids = subgoal_ids.clone();
+        if let Some(parent_id) = manager.parent.clone() {
+            if let Some(parent) = self.nodes.get_mut(&parent_id) {
+                parent.children.retain(|child_id| !subgoal_ids.contains(child_id));
+            }
+        }
+        for subgoal_id in &subgoal_ids {
+            if let Some(subgoal) = self.nodes.get_mut(subgoal_id) {
+                subgoal.parent = Some(manager_id.to_string());
+            }
+        }
+        if let Some(manager) = self.nodes.get_mut(manager_id) {
+            manager.children.extend(subgoal_ids);
+            manager.role = NodeRole::Completed;
+        }
+        Ok(())
+    }
+
+    /// Checks that the links between nodes are consistent:
+    /// - every child listed by a node exists and points back to that node
+    /// - every node's parent exists and lists it as a child
+    /// - every manager's subgoal ids exist
+    /// - every root exists and has no parent
+    ///
+    /// Returns one message per violation, in a deterministic order.
+    pub fn check_invariants(&self) -> Result<(), Vec<String>> {
+        let mut problems = Vec::new();
+
+        let mut node_ids: Vec<&String> = self.nodes.keys().collect();
+        node_ids.sort();
+        for node_id in node_ids {
+            let node = &self.nodes[node_id];
+            if &node.id != node_id {
+                problems.push(format!(
+                    "Node stored under id '{}' has id '{}'",
+                    node_id, node.id
+                ));
+            }
+
+            for child_id in &node.children {
+                match self.nodes.get(child_id) {
+                    None => problems.push(format!(
+                        "Node '{}' lists missing child '{}'",
+                        node_id, child_id
+                    )),
+                    Some(child) if child.parent.as_ref() != Some(node_id) => {
+                        problems.push(format!(
+                            "Child '{}' of node '{}' has parent {:?}",
+                            child_id, node_id, child.parent
+                        ));
+                    }
+                    Some(_) => {}
+                }
+            }
+
+            if let Some(parent_id) = &node.parent {
+                match self.nodes.get(parent_id) {
+                    None => problems.push(format!(
+                        "Node '{}' has missing parent '{}'",
+                        node_id, parent_id
+                    )),
+                    Some(parent) if !parent.children.contains(node_id) => {
+                        problems.push(format!(
+                            "Node '{}' is not listed among the children of its parent '{}'",
+                            node_id, parent_id
+                        ));
+                    }
+                    Some(_) => {}
+                }
+            }
+
+            if let NodeRole::SubgoalManager { subgoal_ids, .. } = &node.role {
+                for subgoal_id in subgoal_ids {
+                    if !self.nodes.contains_key(subgoal_id) {
+                        problems.push(format!(
+                            "Manager '{}' references missing subgoal '{}'",
+                            node_id, subgoal_id
+                        ));
+                    }
+                }
+            }
+        }
+
+        for root_id in &self.roots {
+            match self.nodes.get(root_id) {
+                None => problems.push(format!("Root '{}' is not in the forest", root_id)),
+                Some(root) if root.parent.is_some() => problems.push(format!(
+                    "Root '{}' has parent {:?}",
+                    root_id, root.parent
+                )),
+                Some(_) => {}
+            }
+        }
+
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(problems)
+        }
+    }
+
+    /// Re-applies each node's tactic to its parent's goal and checks that the
+    /// result is what the forest recorded: a completed node for a tactic that
+    /// closes the goal, the same goal up to ids for a single step, and the same
+    /// subgoals in order for a manager. Automated steps, rewrite steps and
+    /// disproofs are not replayed.
+    ///
+    /// Returns one message per node that does not replay, in a deterministic order.
+    pub fn validate_replay(&self) -> Result<(), Vec<String>> {
+        let mut problems = Vec::new();
+        let subgoals_match = |subgoal_ids: &[String], goals: &[ProofGoal]| {
+            subgoal_ids.len() == goals.len()
+                && subgoal_ids.iter().zip(goals).all(|(subgoal_id, replayed)| {
+                    matches!(
+                        self.nodes.get(subgoal_id).map(|subgoal| &subgoal.role),
+                        Some(NodeRole::Goal(goal)) if goal.semantically_eq(replayed)
+                    )
+                })
+        };
+
+        let mut node_ids: Vec<&String> = self.nodes.keys().collect();
+        node_ids.sort();
+        for node_id in node_ids {
+            let node = &self.nodes[node_id];
+            let parent_role = node
+                .parent
+                .as_ref()
+                .and_then(|parent_id| self.nodes.get(parent_id))
+                .map(|parent| &parent.role);
+            let Some(NodeRole::Goal(parent_goal)) = parent_role else {
+                continue;
+            };
+            // Subgoals are replayed through the manager that holds them
+            let is_subgoal = self.nodes.values().any(|other| match &other.role {
+                NodeRole::SubgoalManager { subgoal_ids, .. } => subgoal_ids.contains(node_id),
+                _ => false,
+            });
+            if is_subgoal
+                || matches!(
+                    node.role,
+                    NodeRole::AutomatedTacticStep { .. }
+                        | NodeRole::RewriteStep { .. }
+                        | NodeRole::Disproved(_)
+                )
+            {
+                continue;
+            }
+
+            let replays = match (&node.role, node.tactic.apply_to_goal(parent_goal)) {
+                (_, TacticApplicationResult::Error(message)) => {
+                    problems.push(format!(
+                        "Replaying the tactic of node '{}' failed: {}",
+                        node_id, message
+                    ));
+                    continue;
+                }
+                (NodeRole::Completed, TacticApplicationResult::ProofComplete) => true,
+                (NodeRole::Goal(goal), TacticApplicationResult::SingleGoal(replayed)) => {
+                    goal.semantically_eq(&replayed)
+                }
+                (NodeRole::Goal(goal), TacticApplicationResult::NoChange) => {
+                    goal.semantically_eq(parent_goal)
+                }
+                (
+                    NodeRole::SubgoalManager { subgoal_ids, .. },
+                    TacticApplicationResult::MultiGoal(goals),
+                ) => subgoals_match(subgoal_ids, &goals),
+                // A closed manager holds its subgoals as children
+                (NodeRole::Completed, TacticApplicationResult::MultiGoal(goals)) => {
+                    subgoals_match(&node.children, &goals)
+                }
+                _ => false,
+            };
+            if !replays {
+                problems.push(format!(
+                    "Node '{}' differs from replaying its tactic on its parent's goal",
+                    node_id
+                ));
+            }
+        }
+
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(problems)
+        }
+    }
+
+    /// Print the forest as an indented tree (like the `tree` command) to stdout.
+    /// See `write_tree`.
+    pub fn print_tree(&self, max_depth: Option<usize>) {
+        let stdout = std::io::stdout();
+        // Nothing sensible to do if stdout is gone
+        let _ = self.write_tree(&mut stdout.lock(), max_depth);
+    }
+
+    /// Write the forest as an indented tree, one line per node showing its role
+    /// glyph and tactic. Roots are at depth 0; children of nodes at `max_depth`
+    /// are collapsed into a single `…` line.
+    pub fn write_tree<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+        max_depth: Option<usize>,
+    ) -> std::io::Result<()> {
+        for root_id in &self.roots {
+            if let Some(root) = self.get_node(root_id) {
+                writeln!(writer, "{} {}", root.role.tree_glyph(), root.tactic)?;
+                self.write_subtree(writer, root, "", 0, max_depth)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn write_subtree<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+        node: &ProofNode,
+        prefix: &str,
+        depth: usize,
+        max_depth: Option<usize>,
+    ) -> std::io::Result<()> {
+        let children: Vec<&ProofNode> = node
+            .children
+            .iter()
+            .filter_map(|child_id| self.get_node(child_id))
+            .collect();
+        if children.is_empty() {
+            return Ok(());
+        }
+        if max_depth.is_some_and(|max| depth >= max) {
+            return writeln!(writer, "{}└── …", prefix);
+        }
+
+        for (index, child) in children.iter().enumerate() {
+            let last = index + 1 == children.len();
+            let (branch, indent) = if last {
+                ("└── ", "    ")
+            } else {
+                ("├── ", "│   ")
+            };
+            writeln!(
+                writer,
+                "{}{}{} {}",
+                prefix,
+                branch,
+                child.role.tree_glyph(),
+                child.tactic
+            )?;
+            self.write_subtree(
+                writer,
+                child,
+                &format!("{}{}", prefix, indent),
+                depth + 1,
+                max_depth,
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Deep-copy the subtree rooted at `node_id` into a fresh forest with new ids.
+    /// The copied root becomes the new forest's root, and its goal becomes the
+    /// new forest's initial goal. Panics if `node_id` is not in the forest.
+    pub fn clone_subtree(&self, node_id: &str) -> ProofForest {
+        self.clone_subtree_with_mapping(node_id).0
+    }
+
+    /// Like `clone_subtree`, also returning the mapping from old to new node ids.
+    pub fn clone_subtree_with_mapping(
+        &self,
+        node_id: &str,
+    ) -> (ProofForest, HashMap<String, String>) {
+        let root = self
+            .get_node(node_id)
+            .unwrap_or_else(|| panic!("Node '{}' not found in forest", node_id));
+
+        // First pass: assign a fresh id to every node in the subtree
+        let mut id_map: HashMap<String, String> = HashMap::new();
+        let mut stack = vec![node_id.to_string()];
+        while let Some(id) = stack.pop() {
+            if id_map.contains_key(&id) {
+                continue;
+            }
+            if let Some(node) = self.get_node(&id) {
+                id_map.insert(id.clone(), Uuid::new_v4().to_string());
+                stack.extend(node.children.iter().cloned());
+            }
+        }
+
+        let initial_goal = match &root.role {
+            NodeRole::Goal(goal) | NodeRole::RewriteStep { goal, .. } => goal.clone(),
+            _ => self.initial_goal.clone(),
+        };
+        let mut forest = ProofForest::new_from_goal(initial_goal);
+
+        // Second pass: copy each node with its links rewritten to the new ids
+        for (old_id, new_id) in &id_map {
+            let mut node = self.get_node(old_id).unwrap().clone();
+            node.id = new_id.clone();
+            node.parent = if old_id == node_id {
+                None
+            } else {
+                node.parent.as_ref().and_then(|p| id_map.get(p).cloned())
+            };
+            node.children = node
+                .children
+                .iter()
+                .filter_map(|child| id_map.get(child).cloned())
+                .collect();
+            if let NodeRole::SubgoalManager { subgoal_ids, .. } = &mut node.role {
+                *subgoal_ids = subgoal_ids
+                    .iter()
+                    .filter_map(|sub| id_map.get(sub).cloned())
+                    .collect();
+            }
+            forest
+                .add_node(node)
+                .expect("a fresh forest has no node cap");
+        }
+        forest.roots.push(id_map[node_id].clone());
+
+        (forest, id_map)
+    }
+
+    /// Suggest the most promising next steps at the given node without mutating the forest.
+    ///
+    /// Every candidate tactic (local assumptions, library theorems, rewrites with local
+    /// equalities, structural introductions) is tried against the node's goal. Each
+    /// successful application is scored by the complexity it removes from the goal, so
+    /// closing the goal scores highest and tactics that grow the goal score negative.
+    /// Results are sorted by descending score and capped at `top_n`.
+    pub fn suggest_next(&self, node_id: &str, top_n: usize) -> Vec<(Tactic, i64)> {
+        let Some(node) = self.get_node(node_id) else {
+            return vec![];
+        };
+        let NodeRole::Goal(goal) = &node.role else {
+            return vec![];
+        };
+
+        let before = goal.statement.complexity() as i64;
+        let mut scored: Vec<(Tactic, i64)> = Self::candidate_tactics(goal)
+            .into_iter()
+            .filter_map(|tactic| {
+                let after = match tactic.apply_to_goal(goal) {
+                    TacticApplicationResult::ProofComplete => 0,
+                    TacticApplicationResult::SingleGoal(new_goal) => {
+                        new_goal.statement.complexity() as i64
+                    }
+                    TacticApplicationResult::MultiGoal(goals) => goals
+                        .iter()
+                        .map(|g| g.statement.complexity() as i64)
+                        .sum(),
+                    TacticApplicationResult::NoChange | TacticApplicationResult::Error(_) => {
+                        return None;
+                    }
+                };
+                Some((tactic, before - after))
+            })
+            .collect();
+
+        // Stable sort keeps the enumeration order among equally scored suggestions
+        scored.sort_by(|a, b| b.1.cmp(&a.1));
+        scored.truncate(top_n);
+        scored
+    }
+
+    /// Enumerate the tactics worth trying on a goal for `suggest_next`.
+    /// Only tactics with a real implementation are listed.
+    fn candidate_tactics(goal: &ProofGoal) -> Vec<Tactic> {
+        let mut candidates = vec![Tactic::ByReflexivity];
+
+        if let Some(statement) = goal.statement.concrete_value() {
+            match statement.as_ref() {
+                MathRelation::Implies(_, _) => {
+                    let with_name = (1..)
+                        .map(|i| Identifier::new_simple(format!("H{}", i)))
+                        .find(|name| !goal.is_name_used(name))
+                        .unwrap();
+                    candidates.push(Tactic::AssumeImplicationAntecedent { with_name });
+                }
+                MathRelation::And(_) => candidates.push(Tactic::SplitGoalConjunction),
+                _ => {}
+            }
+        }
+
+        // Assumption matches and simplifications using local equalities
+        for entry in &goal.context {
+            let Some(relation) = goal.find_relation_by_name(&entry.name) else {
+                continue;
+            };
+            candidates.push(Tactic::ByRelation(RelationSource::LocalAssumption(
+                entry.name.clone(),
+            )));
+            if let Some(MathRelation::Equal { .. }) = relation.concrete_value().map(|r| r.as_ref())
+            {
+                for direction in [RewriteDirection::Forward, RewriteDirection::Backward] {
+                    candidates.push(Tactic::Rewrite {
+                        using_rule: RelationSource::LocalAssumption(entry.name.clone()),
+                        target: Target::new(ContextOrStatement::Statement, goal.statement.id.clone()),
+                        direction,
+                        instantiations: HashMap::new(),
+                    });
+                }
+            }
+        }
+
+        // Library theorem applications
+        for theorem_id in get_theorem_registry().list_ids() {
+            candidates.push(Tactic::ByRelation(RelationSource::Theorem(theorem_id, None)));
+        }
+
+        candidates
+    }
 }
 
 #[cfg(test)]
@@ -900,12 +1702,12 @@ mod tests {
         use crate::turn_render::Identifier;
 
         let antecedent = MathRelation::equal(
-            MathExpression::Number(Number {}),
-            MathExpression::Number(Number {}),
+            MathExpression::Number(Number::int(1)),
+            MathExpression::Number(Number::int(1)),
         );
         let consequent = MathRelation::equal(
-            MathExpression::Number(Number {}),
-            MathExpression::Number(Number {}),
+            MathExpression::Number(Number::int(1)),
+            MathExpression::Number(Number::int(1)),
         );
 
         let goal = ProofGoal {
@@ -947,16 +1749,16 @@ mod tests {
         use crate::subjects::math::theories::number_theory::definitions::Number;
 
         let part1 = MathRelation::equal(
-            MathExpression::Number(Number {}),
-            MathExpression::Number(Number {}),
+            MathExpression::Number(Number::int(1)),
+            MathExpression::Number(Number::int(1)),
         );
         let part2 = MathRelation::equal(
-            MathExpression::Number(Number {}),
-            MathExpression::Number(Number {}),
+            MathExpression::Number(Number::int(1)),
+            MathExpression::Number(Number::int(1)),
         );
         let part3 = MathRelation::equal(
-            MathExpression::Number(Number {}),
-            MathExpression::Number(Number {}),
+            MathExpression::Number(Number::int(1)),
+            MathExpression::Number(Number::int(1)),
         );
 
         let conjunction = MathRelation::And(vec![
@@ -991,6 +1793,2258 @@ mod tests {
             other => panic!("Expected MultiGoal, got {:?}", other),
         }
     }
+
+    #[test]
+    fn test_verify_reports_unknown_quantified_variable() {
+        use crate::subjects::math::formalism::error::FormalismError;
+        use crate::subjects::math::formalism::relations::MathRelation;
+        use crate::turn_render::Identifier;
+
+        let goal = ProofGoal {
+            context: vec![],
+            quantifiers: vec![Quantifier {
+                variable_name: Identifier::new_simple("x".to_string()),
+                quantification: Quantification::Universal,
+            }],
+            statement: Located::new_concrete(MathRelation::True),
+        };
+
+        match goal.verify() {
+            Err(FormalismError::UnknownIdentifier { name }) => assert_eq!(name, "x"),
+            other => panic!("Expected UnknownIdentifier, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_typecheck_flags_non_relation_hypothesis() {
+        use crate::subjects::math::formalism::error::FormalismError;
+        use crate::subjects::math::formalism::relations::MathRelation;
+        use crate::subjects::math::theories::number_theory::definitions::Number;
+        use crate::turn_render::Identifier;
+
+        let well_formed = ProofGoal {
+            context: vec![],
+            quantifiers: vec![],
+            statement: Located::new_concrete(MathRelation::True),
+        }
+        .with_hypothesis(
+            "h",
+            MathRelation::equal(
+                MathExpression::Number(Number::int(1)),
+                MathExpression::Number(Number::int(1)),
+            ),
+            None,
+        )
+        .0;
+        assert_eq!(well_formed.typecheck(), Ok(()));
+
+        let mut malformed = well_formed.clone();
+        malformed.context.push(ContextEntry {
+            name: Identifier::new_simple("h2".to_string()),
+            ty: Located::new_concrete(MathExpression::Number(Number::int(1))),
+            definition: DefinitionState::Abstract,
+            description: None,
+            is_internal: false,
+        });
+        match malformed.typecheck() {
+            Err(FormalismError::TypeMismatch { expected, found }) => {
+                assert!(expected.contains("h2"));
+                assert_eq!(found, "number");
+            }
+            other => panic!("Expected TypeMismatch, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_typecheck_reads_roles_from_structure() {
+        use crate::subjects::math::formalism::error::FormalismError;
+        use crate::subjects::math::formalism::objects::MathObject;
+        use crate::subjects::math::formalism::relations::{MathRelation, Quantification};
+        use crate::subjects::math::theories::zfc::definitions::Set;
+
+        let set_type = || MathExpression::Object(Arc::new(MathObject::Set(Set::empty())));
+        let truth = || MathRelation::True;
+        let goal = ProofGoal {
+            context: vec![],
+            quantifiers: vec![],
+            statement: Located::new_concrete(MathRelation::True),
+        };
+
+        // `S` never appears in the statement; it is still a variable, not a
+        // malformed hypothesis.
+        let (goal, _) = goal.with_variable("S", set_type(), None);
+        let (goal, h) = goal.with_hypothesis("h", truth(), None);
+        assert_eq!(goal.typecheck(), Ok(()));
+
+        // Quantifying over a hypothesis makes it a variable with a relation type.
+        let quantified = goal.with_quantifier(&h, Quantification::Universal);
+        match quantified.typecheck() {
+            Err(FormalismError::TypeMismatch { expected, found }) => {
+                assert!(expected.contains("variable 'h'"), "{}", expected);
+                assert_eq!(found, "relation");
+            }
+            other => panic!("Expected TypeMismatch, got {:?}", other),
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "groups")]
+    fn test_rewrite_with_theorem_instantiates_universals() {
+        use crate::subjects::math::formalism::expressions::{MathExpression, TheoryExpression};
+        use crate::subjects::math::formalism::proof::tactics::{Tactic, TacticApplicationResult};
+        use crate::subjects::math::formalism::relations::MathRelation;
+        use crate::subjects::math::theories::groups::definitions::{Group, GroupExpression};
+        use crate::turn_render::Identifier;
+
+        let g_id = Identifier::new_simple("g".to_string());
+        let e_id = Identifier::new_simple("e".to_string());
+        let group = Located::new_concrete(Group::new_generic());
+        let context = vec![
+            ContextEntry {
+                name: g_id.clone(),
+                ty: Located::new_concrete(MathExpression::Expression(TheoryExpression::Group(
+                    GroupExpression::Element {
+                        group: group.clone(),
+                        element: None,
+                    },
+                ))),
+                definition: DefinitionState::Abstract,
+                description: None,
+                is_internal: false,
+            },
+            ContextEntry {
+                name: e_id.clone(),
+                ty: Located::new_concrete(MathExpression::Expression(TheoryExpression::Group(
+                    GroupExpression::Identity(group.clone()),
+                ))),
+                definition: DefinitionState::Abstract,
+                description: None,
+                is_internal: false,
+            },
+        ];
+
+        // Goal: g * e = g
+        let g_times_e = Located::new_concrete(MathExpression::Expression(
+            TheoryExpression::Group(GroupExpression::Operation {
+                group: group.clone(),
+                left: Located::new_variable(g_id.clone()),
+                right: Located::new_variable(e_id.clone()),
+            }),
+        ));
+        let target_id = g_times_e.id.clone();
+        let goal = ProofGoal {
+            context,
+            quantifiers: vec![],
+            statement: Located::new_concrete(MathRelation::Equal {
+                left: g_times_e.clone(),
+                right: Located::new_variable(g_id.clone()),
+            }),
+        };
+
+        // Conjunct 1 of the identity axiom is `x * e = x`; x must be bound to g.
+        let tactic = Tactic::Rewrite {
+            using_rule: RelationSource::Theorem("group_identity_axiom".to_string(), Some(1)),
+            target: Target::new(ContextOrStatement::Statement, target_id),
+            direction: RewriteDirection::Forward,
+            instantiations: HashMap::new(),
+        };
+
+        match tactic.apply_to_goal(&goal) {
+            TacticApplicationResult::SingleGoal(new_goal) => {
+                let statement = new_goal.statement.data.unwrap(&new_goal.context);
+                let MathRelation::Equal { left, .. } = statement else {
+                    panic!("Expected an equality after rewriting, got {:?}", statement);
+                };
+                // x * e rewrites to x, and x is bound to g
+                let g: Located<MathExpression> = Located::new_variable(g_id.clone());
+                assert_eq!(left.data, g.data);
+            }
+            other => panic!("Expected SingleGoal, got {:?}", other),
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "groups")]
+    fn test_rewrite_with_conditional_theorem_adds_side_goal() {
+        use crate::subjects::math::formalism::automation::registry::register_fn;
+        use crate::subjects::math::formalism::expressions::{MathExpression, TheoryExpression};
+        use crate::subjects::math::formalism::proof::tactics::{Tactic, TacticApplicationResult};
+        use crate::subjects::math::formalism::relations::MathRelation;
+        use crate::subjects::math::formalism::theorem::Theorem;
+        use crate::subjects::math::theories::groups::definitions::{Group, GroupExpression};
+        use crate::turn_render::Identifier;
+
+        fn element(name: &Identifier) -> ContextEntry {
+            ContextEntry {
+                name: name.clone(),
+                ty: Located::new_concrete(MathExpression::Expression(TheoryExpression::Group(
+                    GroupExpression::Element {
+                        group: Located::new_concrete(Group::new_generic()),
+                        element: None,
+                    },
+                ))),
+                definition: DefinitionState::Abstract,
+                description: None,
+                is_internal: false,
+            }
+        }
+        fn product(left: &Identifier, right: &Identifier) -> Located<MathExpression> {
+            Located::new_concrete(MathExpression::Expression(TheoryExpression::Group(
+                GroupExpression::Operation {
+                    group: Located::new_concrete(Group::new_generic()),
+                    left: Located::new_variable(left.clone()),
+                    right: Located::new_variable(right.clone()),
+                },
+            )))
+        }
+        // a = b → a * b = b * a
+        fn equal_elements_commute() -> Theorem {
+            let a = Identifier::new_simple("a".to_string());
+            let b = Identifier::new_simple("b".to_string());
+            let statement = MathRelation::Implies(
+                Located::new_concrete(MathRelation::Equal {
+                    left: Located::new_variable(a.clone()),
+                    right: Located::new_variable(b.clone()),
+                }),
+                Located::new_concrete(MathRelation::Equal {
+                    left: product(&a, &b),
+                    right: product(&b, &a),
+                }),
+            );
+            Theorem {
+                id: "test_equal_elements_commute".to_string(),
+                name: "Equal elements commute".to_string(),
+                description: "If a = b then a * b = b * a".to_string(),
+                proofs: ProofForest::new_from_goal(ProofGoal {
+                    context: vec![element(&a), element(&b)],
+                    quantifiers: vec![],
+                    statement: Located::new_concrete(statement),
+                }),
+            }
+        }
+        register_fn(
+            "test_equal_elements_commute",
+            equal_elements_commute,
+            &["test_conditional_rewrite"],
+        )
+        .unwrap();
+
+        let g = Identifier::new_simple("g".to_string());
+        let h = Identifier::new_simple("h".to_string());
+        let g_times_h = product(&g, &h);
+        let goal = ProofGoal {
+            context: vec![element(&g), element(&h)],
+            quantifiers: vec![],
+            statement: Located::new_concrete(MathRelation::Equal {
+                left: g_times_h.clone(),
+                right: product(&h, &g),
+            }),
+        };
+        let tactic = Tactic::Rewrite {
+            using_rule: RelationSource::Theorem("test_equal_elements_commute".to_string(), None),
+            target: Target::new(ContextOrStatement::Statement, g_times_h.id.clone()),
+            direction: RewriteDirection::Forward,
+            instantiations: HashMap::new(),
+        };
+
+        let TacticApplicationResult::MultiGoal(goals) = tactic.apply_to_goal(&goal) else {
+            panic!("Expected the rewritten goal and the hypothesis as a side goal");
+        };
+        assert_eq!(goals.len(), 2);
+        let MathRelation::Equal { left, .. } = goals[0].statement.data.unwrap(&goals[0].context)
+        else {
+            panic!("Expected an equality after rewriting");
+        };
+        assert_ne!(left.data, g_times_h.data);
+
+        // The side goal is the hypothesis a = b with a := g and b := h
+        let MathRelation::Equal { left, right } = goals[1].statement.data.unwrap(&goals[1].context)
+        else {
+            panic!("Expected the instantiated hypothesis");
+        };
+        assert_eq!(left.variable_id(), Some(&g));
+        assert_eq!(right.variable_id(), Some(&h));
+    }
+
+    #[test]
+    #[cfg(feature = "groups")]
+    fn test_rewrite_failure_explains_where_the_pattern_diverges() {
+        use crate::subjects::math::formalism::expressions::{MathExpression, TheoryExpression};
+        use crate::subjects::math::formalism::proof::tactics::{Tactic, TacticApplicationResult};
+        use crate::subjects::math::formalism::relations::MathRelation;
+        use crate::subjects::math::theories::groups::definitions::{Group, GroupExpression};
+        use crate::turn_render::Identifier;
+
+        let g_id = Identifier::new_simple("g".to_string());
+        let e_id = Identifier::new_simple("e".to_string());
+        let h_id = Identifier::new_simple("h".to_string());
+        let group = Located::new_concrete(Group::new_generic());
+        let operation = |left: &Identifier, right: &Identifier| {
+            Located::new_concrete(MathExpression::Expression(TheoryExpression::Group(
+                GroupExpression::Operation {
+                    group: group.clone(),
+                    left: Located::new_variable(left.clone()),
+                    right: Located::new_variable(right.clone()),
+                },
+            )))
+        };
+        let entry = |name: &Identifier, ty: MathExpression| ContextEntry {
+            name: name.clone(),
+            ty: Located::new_concrete(ty),
+            definition: DefinitionState::Abstract,
+            description: None,
+            is_internal: false,
+        };
+
+        // Hypothesis h: g * e = g; goal: e * g = g
+        let e_times_g = operation(&e_id, &g_id);
+        let target_id = e_times_g.id.clone();
+        let goal = ProofGoal {
+            context: vec![
+                entry(
+                    &g_id,
+                    MathExpression::Expression(TheoryExpression::Group(GroupExpression::Element {
+                        group: group.clone(),
+                        element: None,
+                    })),
+                ),
+                entry(
+                    &e_id,
+                    MathExpression::Expression(TheoryExpression::Group(GroupExpression::Identity(
+                        group.clone(),
+                    ))),
+                ),
+                entry(
+                    &h_id,
+                    MathExpression::Relation(Arc::new(MathRelation::Equal {
+                        left: operation(&g_id, &e_id),
+                        right: Located::new_variable(g_id.clone()),
+                    })),
+                ),
+            ],
+            quantifiers: vec![],
+            statement: Located::new_concrete(MathRelation::Equal {
+                left: e_times_g,
+                right: Located::new_variable(g_id.clone()),
+            }),
+        };
+
+        let tactic = Tactic::Rewrite {
+            using_rule: RelationSource::LocalAssumption(h_id),
+            target: Target::new(ContextOrStatement::Statement, target_id),
+            direction: RewriteDirection::Forward,
+            instantiations: HashMap::new(),
+        };
+
+        match tactic.apply_to_goal(&goal) {
+            TacticApplicationResult::Error(message) => {
+                assert!(
+                    message.contains("could not match `e` with `g` at position [0]"),
+                    "unexpected explanation: {}",
+                    message
+                );
+            }
+            other => panic!("Expected the rewrite to fail, got {:?}", other),
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "groups")]
+    fn test_rewrite_sides_meets_in_the_middle() {
+        use crate::subjects::math::formalism::expressions::{MathExpression, TheoryExpression};
+        use crate::subjects::math::formalism::proof::tactics::{Tactic, TacticApplicationResult};
+        use crate::subjects::math::formalism::relations::MathRelation;
+        use crate::subjects::math::theories::groups::definitions::{Group, GroupExpression};
+        use crate::turn_render::Identifier;
+
+        let group = Located::new_concrete(Group::new_generic());
+        let element = |name: &str| ContextEntry {
+            name: Identifier::new_simple(name.to_string()),
+            ty: Located::new_concrete(MathExpression::Expression(TheoryExpression::Group(
+                GroupExpression::Element {
+                    group: group.clone(),
+                    element: None,
+                },
+            ))),
+            definition: DefinitionState::Abstract,
+            description: None,
+            is_internal: false,
+        };
+        let var = |name: &str| Located::new_variable(Identifier::new_simple(name.to_string()));
+        let equal = |left: &str, right: &str| MathRelation::Equal {
+            left: var(left),
+            right: var(right),
+        };
+
+        // Goal g = h, with h1 : g = k and h2 : h = k
+        let (goal, h1) = ProofGoal {
+            context: vec![element("g"), element("h"), element("k")],
+            quantifiers: vec![],
+            statement: Located::new_concrete(equal("g", "h")),
+        }
+        .with_hypothesis("h1", equal("g", "k"), None);
+        let (goal, h2) = goal.with_hypothesis("h2", equal("h", "k"), None);
+
+        let tactic = Tactic::RewriteSides {
+            left_rule: Some(RelationSource::LocalAssumption(h1)),
+            right_rule: Some(RelationSource::LocalAssumption(h2)),
+        };
+        assert!(matches!(
+            tactic.apply_to_goal(&goal),
+            TacticApplicationResult::ProofComplete
+        ));
+
+        let not_an_equality = ProofGoal {
+            statement: Located::new_concrete(MathRelation::Implies(
+                Located::new_concrete(equal("g", "k")),
+                Located::new_concrete(equal("h", "k")),
+            )),
+            ..goal
+        };
+        assert!(matches!(
+            tactic.apply_to_goal(&not_an_equality),
+            TacticApplicationResult::Error(_)
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "groups")]
+    fn test_rewrite_chain_closes_transitive_equality() {
+        use crate::subjects::math::formalism::expressions::{MathExpression, TheoryExpression};
+        use crate::subjects::math::formalism::proof::tactics::{Tactic, TacticApplicationResult};
+        use crate::subjects::math::formalism::relations::MathRelation;
+        use crate::subjects::math::theories::groups::definitions::{Group, GroupExpression};
+        use crate::turn_render::Identifier;
+
+        let group = Located::new_concrete(Group::new_generic());
+        let element = |name: &str| ContextEntry {
+            name: Identifier::new_simple(name.to_string()),
+            ty: Located::new_concrete(MathExpression::Expression(TheoryExpression::Group(
+                GroupExpression::Element {
+                    group: group.clone(),
+                    element: None,
+                },
+            ))),
+            definition: DefinitionState::Abstract,
+            description: None,
+            is_internal: false,
+        };
+        let var = |name: &str| Located::new_variable(Identifier::new_simple(name.to_string()));
+        let equal = |left: &str, right: &str| MathRelation::Equal {
+            left: var(left),
+            right: var(right),
+        };
+
+        // Goal a = d, with h1 : a = b, h2 : b = c and h3 : c = d
+        let (goal, h1) = ProofGoal {
+            context: vec![element("a"), element("b"), element("c"), element("d")],
+            quantifiers: vec![],
+            statement: Located::new_concrete(equal("a", "d")),
+        }
+        .with_hypothesis("h1", equal("a", "b"), None);
+        let (goal, h2) = goal.with_hypothesis("h2", equal("b", "c"), None);
+        let (goal, h3) = goal.with_hypothesis("h3", equal("c", "d"), None);
+
+        let chain = Tactic::RewriteChain {
+            equalities: vec![h1.clone(), h2.clone(), h3],
+        };
+        assert!(matches!(
+            chain.apply_to_goal(&goal),
+            TacticApplicationResult::ProofComplete
+        ));
+
+        // Stopping early leaves the partially rewritten goal c = d
+        let partial = Tactic::RewriteChain {
+            equalities: vec![h1.clone(), h2.clone()],
+        };
+        assert!(matches!(
+            partial.apply_to_goal(&goal),
+            TacticApplicationResult::SingleGoal(_)
+        ));
+
+        // h1 no longer matches once the left side has become b
+        let out_of_order = Tactic::RewriteChain {
+            equalities: vec![h2, h1],
+        };
+        assert!(matches!(
+            out_of_order.apply_to_goal(&goal),
+            TacticApplicationResult::Error(_)
+        ));
+    }
+
+    #[test]
+    fn test_suggest_next_sorted_and_capped() {
+        use crate::subjects::math::formalism::expressions::MathExpression;
+        use crate::subjects::math::formalism::proof::tactics::Tactic;
+        use crate::subjects::math::formalism::relations::MathRelation;
+        use crate::subjects::math::theories::number_theory::definitions::Number;
+        use crate::turn_render::Identifier;
+
+        let antecedent = MathRelation::equal(
+            MathExpression::Number(Number::int(1)),
+            MathExpression::Number(Number::int(1)),
+        );
+        let consequent = MathRelation::equal(
+            MathExpression::Number(Number::int(1)),
+            MathExpression::Number(Number::int(1)),
+        );
+
+        let goal = ProofGoal {
+            context: vec![],
+            quantifiers: vec![],
+            statement: Located::new_concrete(MathRelation::Implies(
+                Located::new_concrete(antecedent),
+                Located::new_concrete(consequent),
+            )),
+        };
+
+        let mut forest = ProofForest::new_from_goal(goal);
+        let root_id = forest
+            .apply_initial_tactic(Tactic::AssumeImplicationAntecedent {
+                with_name: Identifier::new_simple("H1".to_string()),
+            })
+            .id
+            .clone();
+        let nodes_before = forest.len();
+
+        let suggestions = forest.suggest_next(&root_id, 10);
+        assert!(!suggestions.is_empty());
+        assert!(suggestions.len() <= 10);
+        assert!(suggestions.windows(2).all(|pair| pair[0].1 >= pair[1].1));
+
+        let top_one = forest.suggest_next(&root_id, 1);
+        assert_eq!(top_one.len(), 1);
+        assert_eq!(top_one[0].1, suggestions[0].1);
+
+        // Suggesting never adds nodes to the forest
+        assert_eq!(forest.len(), nodes_before);
+    }
+
+    #[test]
+    fn test_simplify_on_simple_set_reports_no_progress() {
+        use crate::subjects::math::formalism::expressions::MathExpression;
+        use crate::subjects::math::formalism::objects::MathObject;
+        use crate::subjects::math::formalism::proof::tactics::{
+            ContextOrStatement, Tactic, Target,
+        };
+        use crate::subjects::math::formalism::relations::MathRelation;
+        use crate::subjects::math::theories::zfc::definitions::{Set, SetElement};
+
+        let set = || {
+            MathExpression::Object(Arc::new(MathObject::Set(Set::from_elements(vec![
+                SetElement::Integer(1),
+                SetElement::Integer(2),
+            ]))))
+        };
+        let statement = MathRelation::equal(set(), set());
+        let target_id = match &statement {
+            MathRelation::Equal { left, .. } => left.id.clone(),
+            _ => unreachable!(),
+        };
+        let goal = ProofGoal {
+            context: vec![],
+            quantifiers: vec![],
+            statement: Located::new_concrete(statement),
+        };
+
+        let mut forest = ProofForest::new_from_goal(goal.clone());
+        let root = ProofNode {
+            id: Uuid::new_v4().to_string(),
+            parent: None,
+            children: vec![],
+            role: NodeRole::Goal(goal),
+            tactic: Tactic::SearchAssumptions,
+            description: None,
+            hint: None,
+        };
+        forest.add_node(root.clone()).unwrap();
+        forest.roots.push(root.id.clone());
+
+        let outcome = root.apply_tactic(
+            Tactic::Simplify {
+                target: Target::new(ContextOrStatement::Statement, target_id),
+            },
+            &mut forest,
+        );
+        assert!(!outcome.made_progress());
+        assert!(outcome.manager.get_goal().semantically_eq(root.get_goal()));
+    }
+
+    #[test]
+    fn test_simplify_rewrites_a_context_entry_type() {
+        use crate::subjects::math::formalism::expressions::MathExpression;
+        use crate::subjects::math::formalism::objects::MathObject;
+        use crate::subjects::math::formalism::proof::tactics::{
+            ContextOrStatement, Tactic, TacticApplicationResult, Target,
+        };
+        use crate::subjects::math::formalism::relations::MathRelation;
+        use crate::subjects::math::theories::zfc::definitions::{Set, SetElement};
+        use crate::subjects::math::theories::zfc::difference_set;
+
+        // S : {1, 2} \ {1, 2}, which simplifies to ∅
+        let numbers = Set::from_elements(vec![SetElement::Integer(1), SetElement::Integer(2)]);
+        let name = Identifier::new_simple("S".to_string());
+        let ty = Located::new_concrete(MathExpression::Object(Arc::new(MathObject::Set(
+            difference_set(&numbers, &numbers),
+        ))));
+        let goal = ProofGoal {
+            context: vec![ContextEntry {
+                name: name.clone(),
+                ty: ty.clone(),
+                definition: DefinitionState::Abstract,
+                description: None,
+                is_internal: false,
+            }],
+            quantifiers: vec![],
+            statement: Located::new_concrete(MathRelation::True),
+        };
+        let simplify = |scope: ContextOrStatement| Tactic::Simplify {
+            target: Target::new(scope, ty.id.clone()),
+        };
+
+        let TacticApplicationResult::SingleGoal(simplified) =
+            simplify(ContextOrStatement::Context(name.clone(), None)).apply_to_goal(&goal)
+        else {
+            panic!("Expected the context entry to be simplified");
+        };
+        assert!(matches!(
+            simplified.context[0].ty.data.unwrap(&simplified.context),
+            MathExpression::Object(object) if matches!(object.as_ref(), MathObject::Set(Set::Empty))
+        ));
+        assert!(simplified.statement.semantically_eq(&goal.statement));
+
+        // The statement does not contain the entry's type
+        assert!(matches!(
+            simplify(ContextOrStatement::Statement).apply_to_goal(&goal),
+            TacticApplicationResult::Error(_)
+        ));
+        // Scopes spanning several places are refused with an explanation
+        let TacticApplicationResult::Error(message) =
+            simplify(ContextOrStatement::Both).apply_to_goal(&goal)
+        else {
+            panic!("Expected the combined scope to be refused");
+        };
+        assert!(message.contains("single scope"));
+    }
+
+    #[test]
+    fn test_serde_tags_are_self_describing() {
+        use crate::subjects::math::formalism::expressions::MathExpression;
+        use crate::subjects::math::formalism::proof::tactics::Tactic;
+        use crate::subjects::math::formalism::relations::MathRelation;
+        use crate::subjects::math::theories::number_theory::definitions::Number;
+        use crate::turn_render::Identifier;
+
+        let equality = MathRelation::equal(
+            MathExpression::Number(Number::int(1)),
+            MathExpression::Number(Number::int(1)),
+        );
+        let goal = ProofGoal {
+            context: vec![],
+            quantifiers: vec![],
+            statement: Located::new_concrete(MathRelation::Implies(
+                Located::new_concrete(equality.clone()),
+                Located::new_concrete(equality),
+            )),
+        };
+        let role = NodeRole::Goal(goal);
+        let tactic = Tactic::AssumeImplicationAntecedent {
+            with_name: Identifier::new_simple("H1".to_string()),
+        };
+
+        let role_json = serde_json::to_string(&role).unwrap();
+        assert!(role_json.contains(r#""type":"goal""#));
+        assert!(role_json.contains(r#""type":"implies""#));
+        assert!(role_json.contains(r#""type":"equal""#));
+
+        let tactic_json = serde_json::to_string(&tactic).unwrap();
+        assert!(tactic_json.contains(r#""type":"assume_implication_antecedent""#));
+
+        // Round trip keeps the same representation
+        let role_back: NodeRole = serde_json::from_str(&role_json).unwrap();
+        assert_eq!(serde_json::to_string(&role_back).unwrap(), role_json);
+        let tactic_back: Tactic = serde_json::from_str(&tactic_json).unwrap();
+        assert_eq!(serde_json::to_string(&tactic_back).unwrap(), tactic_json);
+    }
+
+    #[test]
+    #[cfg(feature = "groups")]
+    fn test_apply_first_isomorphism_adds_isomorphism_hypothesis() {
+        use crate::subjects::math::formalism::expressions::{MathExpression, TheoryExpression};
+        use crate::subjects::math::formalism::proof::tactics::{Tactic, TacticApplicationResult};
+        use crate::subjects::math::formalism::relations::MathRelation;
+        use crate::subjects::math::theories::groups::definitions::{
+            Group, GroupExpression, GroupHomomorphism, GroupRelation, HomomorphismConstruction,
+        };
+        use crate::turn_render::Identifier;
+
+        let g = Identifier::new_simple("G".to_string());
+        let h = Identifier::new_simple("H".to_string());
+        let phi = Identifier::new_simple("φ".to_string());
+        let group_entry = |name: &Identifier| ContextEntry {
+            name: name.clone(),
+            ty: Located::new_concrete(MathExpression::Object(Arc::new(MathObject::Group(
+                Group::new_generic(),
+            )))),
+            definition: DefinitionState::Abstract,
+            description: None,
+            is_internal: false,
+        };
+        let goal = ProofGoal {
+            context: vec![
+                group_entry(&g),
+                group_entry(&h),
+                ContextEntry {
+                    name: phi.clone(),
+                    ty: Located::new_concrete(MathExpression::Expression(
+                        TheoryExpression::Group(GroupExpression::Homomorphism(
+                            Located::new_concrete(GroupHomomorphism {
+                                domain: Located::new_variable(g.clone()),
+                                codomain: Located::new_variable(h.clone()),
+                                construction: HomomorphismConstruction::Abstract,
+                            }),
+                        )),
+                    )),
+                    definition: DefinitionState::Abstract,
+                    description: None,
+                    is_internal: false,
+                },
+            ],
+            quantifiers: vec![],
+            statement: Located::new_concrete(MathRelation::True),
+        };
+
+        let with_name = Identifier::new_simple("Iso".to_string());
+        let tactic = Tactic::ApplyFirstIsomorphism {
+            homomorphism: phi.clone(),
+            with_name: with_name.clone(),
+        };
+        let TacticApplicationResult::SingleGoal(new_goal) = tactic.apply_to_goal(&goal) else {
+            panic!("Expected the hypothesis to be added");
+        };
+
+        let entry = new_goal.context.last().unwrap();
+        assert_eq!(entry.name, with_name);
+        let ty = entry.ty.concrete_value().unwrap();
+        let MathExpression::Relation(relation) = ty.as_ref() else {
+            panic!("Expected a relation, got {:?}", ty);
+        };
+        let MathRelation::GroupTheory(GroupRelation::IsIsomorphicTo { first, second }) =
+            relation.as_ref()
+        else {
+            panic!("Expected an isomorphism, got {:?}", relation);
+        };
+        match first.concrete_value().unwrap().as_ref() {
+            Group::Quotient(quotient) => {
+                assert_eq!(quotient.group.variable_id(), Some(&g));
+                match quotient.normal_subgroup.concrete_value().unwrap().as_ref() {
+                    Group::Kernel(kernel) => {
+                        assert_eq!(kernel.defining_homomorphism.variable_id(), Some(&phi))
+                    }
+                    other => panic!("Expected ker(φ), got {:?}", other),
+                }
+            }
+            other => panic!("Expected G/ker(φ), got {:?}", other),
+        }
+        match second.concrete_value().unwrap().as_ref() {
+            Group::Image(image) => assert_eq!(image.defining_homomorphism.variable_id(), Some(&phi)),
+            other => panic!("Expected im(φ), got {:?}", other),
+        }
+
+        // A group is not a homomorphism
+        let not_a_homomorphism = Tactic::ApplyFirstIsomorphism {
+            homomorphism: g,
+            with_name,
+        };
+        assert!(matches!(
+            not_a_homomorphism.apply_to_goal(&goal),
+            TacticApplicationResult::Error(_)
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "groups")]
+    fn test_unfold_group_construction_unfolds_kernel() {
+        use crate::subjects::math::formalism::expressions::{MathExpression, TheoryExpression};
+        use crate::subjects::math::formalism::proof::tactics::{Tactic, TacticApplicationResult};
+        use crate::subjects::math::formalism::relations::{MathRelation, Quantification};
+        use crate::subjects::math::theories::groups::definitions::{
+            GenericGroup, Group, GroupExpression, GroupHomomorphism, GroupRelation, ImageGroup,
+            KernelGroup,
+        };
+        use crate::turn_render::Identifier;
+
+        let g = Identifier::new_simple("G".to_string());
+        let h = Identifier::new_simple("H".to_string());
+        let k = Identifier::new_simple("K".to_string());
+        let phi = Identifier::new_simple("φ".to_string());
+        let x = Identifier::new_simple("x".to_string());
+        let entry = |name: &Identifier, ty: MathExpression| ContextEntry {
+            name: name.clone(),
+            ty: Located::new_concrete(ty),
+            definition: DefinitionState::Abstract,
+            description: None,
+            is_internal: false,
+        };
+        let group = |group: Group| MathExpression::Object(Arc::new(MathObject::Group(group)));
+        let group_expr =
+            |expr: GroupExpression| MathExpression::Expression(TheoryExpression::Group(expr));
+        let homomorphism = GroupHomomorphism {
+            domain: Located::new_variable(g.clone()),
+            codomain: Located::new_variable(h.clone()),
+            construction: Default::default(),
+        };
+        let kernel = Group::Kernel(KernelGroup {
+            core: GenericGroup::default(),
+            defining_homomorphism: Located::new_variable(phi.clone()),
+        });
+        let goal_with = |k_group: Group| ProofGoal {
+            context: vec![
+                entry(&g, group(Group::new_generic())),
+                entry(&h, group(Group::new_generic())),
+                entry(
+                    &phi,
+                    group_expr(GroupExpression::Homomorphism(Located::new_concrete(
+                        homomorphism.clone(),
+                    ))),
+                ),
+                entry(&k, group(k_group)),
+                entry(
+                    &x,
+                    group_expr(GroupExpression::Element {
+                        group: Located::new_variable(g.clone()),
+                        element: None,
+                    }),
+                ),
+            ],
+            quantifiers: vec![],
+            statement: Located::new_concrete(MathRelation::GroupTheory(GroupRelation::ElementOf {
+                element: Located::new_variable(x.clone()),
+                group: Located::new_variable(k.clone()),
+            })),
+        };
+
+        // x ∈ ker φ unfolds to φ(x) = e_H
+        let goal = goal_with(kernel);
+        let tactic = Tactic::UnfoldGroupConstruction {
+            on_variable: k.clone(),
+        };
+        let TacticApplicationResult::SingleGoal(new_goal) = tactic.apply_to_goal(&goal) else {
+            panic!("Expected the kernel to be unfolded");
+        };
+        let MathRelation::Equal { left, right } = new_goal.statement.data.unwrap(&new_goal.context)
+        else {
+            panic!("Expected φ(x) = e, got {:?}", new_goal.statement);
+        };
+        let MathExpression::Expression(TheoryExpression::Group(
+            GroupExpression::HomomorphismApplication {
+                homomorphism: applied,
+                element,
+            },
+        )) = left.data.unwrap(&new_goal.context)
+        else {
+            panic!("Expected φ(x), got {:?}", left);
+        };
+        assert_eq!(applied.variable_id(), Some(&phi));
+        assert_eq!(element.variable_id(), Some(&x));
+        let MathExpression::Expression(TheoryExpression::Group(GroupExpression::Identity(of))) =
+            right.data.unwrap(&new_goal.context)
+        else {
+            panic!("Expected the identity of H, got {:?}", right);
+        };
+        assert_eq!(of.variable_id(), Some(&h));
+        // The construction stays in the context
+        let k_entry = |goal: &ProofGoal| {
+            goal.context
+                .iter()
+                .find(|e| e.name == k)
+                .map(|e| e.ty.data.clone())
+        };
+        assert_eq!(k_entry(&new_goal), k_entry(&goal));
+
+        // x ∈ im φ unfolds to ∃w. φ(w) = x
+        let image = Group::Image(ImageGroup {
+            core: GenericGroup::default(),
+            defining_homomorphism: Located::new_variable(phi.clone()),
+        });
+        let TacticApplicationResult::SingleGoal(new_goal) = tactic.apply_to_goal(&goal_with(image))
+        else {
+            panic!("Expected the image to be unfolded");
+        };
+        let witness = &new_goal.quantifiers.last().unwrap().variable_name;
+        assert_eq!(
+            new_goal.quantifiers.last().unwrap().quantification,
+            Quantification::Existential
+        );
+        assert!(new_goal.context.iter().any(|e| &e.name == witness));
+        assert!(matches!(
+            new_goal.statement.data.unwrap(&new_goal.context),
+            MathRelation::Equal { .. }
+        ));
+
+        // A generic group has no construction to unfold
+        let generic = Tactic::UnfoldGroupConstruction { on_variable: g };
+        assert!(matches!(
+            generic.apply_to_goal(&goal),
+            TacticApplicationResult::Error(_)
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "groups")]
+    fn test_assert_property_adds_hypothesis_and_justification() {
+        use crate::subjects::math::formalism::expressions::MathExpression;
+        use crate::subjects::math::formalism::proof::tactics::{Tactic, TacticApplicationResult};
+        use crate::subjects::math::formalism::relations::MathRelation;
+        use crate::subjects::math::theories::groups::definitions::{
+            AbelianPropertyVariant, CyclicGroup, GenericGroup, Group, GroupElement, GroupProperty,
+            GroupRelation,
+        };
+        use crate::turn_render::Identifier;
+
+        let g = Identifier::new_simple("G".to_string());
+        let cyclic = Group::Cyclic(CyclicGroup {
+            core: GenericGroup::default(),
+            generator: GroupElement::Integer(1),
+            order: Some(4),
+        });
+        let goal = ProofGoal {
+            context: vec![ContextEntry {
+                name: g.clone(),
+                ty: Located::new_concrete(MathExpression::Object(Arc::new(MathObject::Group(
+                    cyclic,
+                )))),
+                definition: DefinitionState::Abstract,
+                description: None,
+                is_internal: false,
+            }],
+            quantifiers: vec![],
+            statement: Located::new_concrete(MathRelation::True),
+        };
+        let abelian = GroupProperty::Abelian(AbelianPropertyVariant::Abelian);
+        let tactic = Tactic::AssertProperty {
+            on_variable: g.clone(),
+            property: abelian.clone(),
+        };
+
+        let TacticApplicationResult::MultiGoal(goals) = tactic.apply_to_goal(&goal) else {
+            panic!("Expected a justification and a continuation");
+        };
+        assert_eq!(goals.len(), 2);
+        let is_abelian_g = |relation: &MathRelation| {
+            matches!(
+                relation,
+                MathRelation::GroupTheory(GroupRelation::HasBasicProperty { target, property })
+                    if target.variable_id() == Some(&g) && property == &abelian
+            )
+        };
+
+        // First justify the property...
+        assert!(is_abelian_g(goals[0].statement.concrete_value().unwrap()));
+        assert_eq!(goals[0].context.len(), 1);
+
+        // ...then continue with it as a hypothesis
+        assert_eq!(goals[1].statement.id, goal.statement.id);
+        let hypothesis = goals[1].context.last().unwrap();
+        match hypothesis.ty.concrete_value().unwrap().as_ref() {
+            MathExpression::Relation(relation) => assert!(is_abelian_g(relation)),
+            other => panic!("Expected the property as a hypothesis, got {:?}", other),
+        }
+
+        let not_in_context = Tactic::AssertProperty {
+            on_variable: Identifier::new_simple("H".to_string()),
+            property: abelian.clone(),
+        };
+        assert!(matches!(
+            not_in_context.apply_to_goal(&goal),
+            TacticApplicationResult::Error(_)
+        ));
+    }
+
+    #[test]
+    fn test_clone_subtree_copies_structure_with_fresh_ids() {
+        use crate::subjects::math::formalism::expressions::MathExpression;
+        use crate::subjects::math::formalism::proof::tactics::Tactic;
+        use crate::subjects::math::formalism::relations::MathRelation;
+        use crate::subjects::math::theories::number_theory::definitions::Number;
+
+        let part = MathRelation::equal(
+            MathExpression::Number(Number::int(1)),
+            MathExpression::Number(Number::int(1)),
+        );
+        let goal = ProofGoal {
+            context: vec![],
+            quantifiers: vec![],
+            statement: Located::new_concrete(MathRelation::And(vec![
+                Located::new_concrete(part.clone()),
+                Located::new_concrete(part),
+            ])),
+        };
+
+        let mut forest = ProofForest::new_from_goal(goal.clone());
+        let root = ProofNode {
+            id: Uuid::new_v4().to_string(),
+            parent: None,
+            children: vec![],
+            role: NodeRole::Goal(goal),
+            tactic: Tactic::SearchAssumptions,
+            description: None,
+            hint: None,
+        };
+        forest.add_node(root.clone()).unwrap();
+        forest.roots.push(root.id.clone());
+        let outcome = root.apply_tactic(Tactic::SplitGoalConjunction, &mut forest);
+        assert_eq!(outcome.sub_nodes.len(), 2);
+
+        let (copy, mapping) = forest.clone_subtree_with_mapping(&root.id);
+
+        // Same shape: every node of the original tree is copied exactly once
+        assert_eq!(copy.len(), forest.len());
+        assert_eq!(mapping.len(), forest.len());
+        let copied_root = copy.get_root().unwrap();
+        assert_eq!(copied_root.id, mapping[&root.id]);
+        assert!(copied_root.parent.is_none());
+        assert_eq!(
+            copied_root.children.len(),
+            forest.get_node(&root.id).unwrap().children.len()
+        );
+        assert_eq!(copy.initial_goal.statement.id, root.get_goal().statement.id);
+
+        let copied_manager = copy.get_node(&mapping[&outcome.manager.id]).unwrap();
+        match &copied_manager.role {
+            NodeRole::SubgoalManager { subgoal_ids, .. } => {
+                for sub in &outcome.sub_nodes {
+                    assert!(subgoal_ids.contains(&mapping[&sub.id]));
+                }
+            }
+            other => panic!("Expected SubgoalManager, got {:?}", other),
+        }
+
+        // Disjoint ids: nothing in the copy reuses an original id
+        for node in copy.node_values() {
+            assert!(forest.get_node(&node.id).is_none());
+        }
+    }
+
+    #[test]
+    fn test_normalize_strips_double_negation_and_flattens_conjunction() {
+        use crate::subjects::math::formalism::proof::tactics::{
+            ContextOrStatement, Tactic, TacticApplicationResult, Target,
+        };
+        use crate::subjects::math::formalism::relations::MathRelation;
+        use crate::subjects::math::theories::number_theory::definitions::Number;
+
+        let a = MathRelation::True;
+        let b = MathRelation::False;
+        let c = MathRelation::equal(
+            MathExpression::Number(Number::int(1)),
+            MathExpression::Number(Number::int(1)),
+        );
+        // ¬¬(A ∧ (B ∧ C))
+        let statement = Located::new_concrete(MathRelation::Not(Located::new_concrete(
+            MathRelation::Not(Located::new_concrete(MathRelation::And(vec![
+                Located::new_concrete(a.clone()),
+                Located::new_concrete(MathRelation::And(vec![
+                    Located::new_concrete(b.clone()),
+                    Located::new_concrete(c.clone()),
+                ])),
+            ]))),
+        )));
+        let goal = ProofGoal {
+            context: vec![],
+            quantifiers: vec![],
+            statement,
+        };
+
+        let normalize = |goal: &ProofGoal| {
+            Tactic::Normalize {
+                target: Target::new(ContextOrStatement::Statement, goal.statement.id.clone()),
+            }
+            .apply_to_goal(goal)
+        };
+
+        let normalized = match normalize(&goal) {
+            TacticApplicationResult::SingleGoal(new_goal) => new_goal,
+            other => panic!("Expected SingleGoal, got {:?}", other),
+        };
+        match normalized.statement.concrete_value().map(|r| r.as_ref()) {
+            Some(MathRelation::And(conjuncts)) => {
+                let conjuncts: Vec<MathRelation> = conjuncts
+                    .iter()
+                    .map(|conjunct| conjunct.concrete_value().unwrap().as_ref().clone())
+                    .collect();
+                assert_eq!(conjuncts, vec![a, b, c]);
+            }
+            other => panic!("Expected A ∧ B ∧ C, got {:?}", other),
+        }
+
+        // Idempotent: a second pass finds nothing left to do
+        assert!(matches!(
+            normalize(&normalized),
+            TacticApplicationResult::NoChange
+        ));
+    }
+
+    #[test]
+    fn test_check_invariants_reports_corrupted_links() {
+        use crate::subjects::math::formalism::relations::MathRelation;
+        use crate::subjects::math::theories::number_theory::definitions::Number;
+
+        let part = || {
+            Located::new_concrete(MathRelation::equal(
+                MathExpression::Number(Number::int(1)),
+                MathExpression::Number(Number::int(1)),
+            ))
+        };
+        let goal = ProofGoal {
+            context: vec![],
+            quantifiers: vec![],
+            statement: Located::new_concrete(MathRelation::And(vec![part(), part()])),
+        };
+
+        let mut forest = ProofForest::new_from_goal(goal.clone());
+        let root = ProofNode {
+            id: "root".to_string(),
+            parent: None,
+            children: vec![],
+            role: NodeRole::Goal(goal),
+            tactic: Tactic::SearchAssumptions,
+            description: None,
+            hint: None,
+        };
+        forest.add_node(root.clone()).unwrap();
+        forest.roots.push(root.id.clone());
+        let outcome = root.apply_tactic(Tactic::SplitGoalConjunction, &mut forest);
+        assert_eq!(forest.check_invariants(), Ok(()));
+
+        // Detach one subgoal from its parent and drop the other entirely
+        let detached = outcome.sub_nodes[0].id.clone();
+        let dropped = outcome.sub_nodes[1].id.clone();
+        forest.get_node_mut(&detached).unwrap().parent = Some("elsewhere".to_string());
+        forest.nodes.remove(&dropped);
+
+        let problems = forest.check_invariants().unwrap_err();
+        assert!(problems.contains(&format!(
+            "Child '{}' of node 'root' has parent Some(\"elsewhere\")",
+            detached
+        )));
+        assert!(problems.contains(&format!("Node 'root' lists missing child '{}'", dropped)));
+        assert!(problems.contains(&format!(
+            "Manager '{}' references missing subgoal '{}'",
+            outcome.manager.id, dropped
+        )));
+        assert!(problems.contains(&format!(
+            "Node '{}' has missing parent 'elsewhere'",
+            detached
+        )));
+    }
+
+    #[test]
+    fn test_close_manager_after_proving_both_conjuncts() {
+        use crate::subjects::math::formalism::relations::MathRelation;
+        use crate::subjects::math::theories::number_theory::definitions::Number;
+
+        let part = || {
+            Located::new_concrete(MathRelation::equal(
+                MathExpression::Number(Number::int(1)),
+                MathExpression::Number(Number::int(1)),
+            ))
+        };
+        let goal = ProofGoal {
+            context: vec![],
+            quantifiers: vec![],
+            statement: Located::new_concrete(MathRelation::And(vec![part(), part()])),
+        };
+
+        let mut forest = ProofForest::new_from_goal(goal.clone());
+        let root = ProofNode {
+            id: "root".to_string(),
+            parent: None,
+            children: vec![],
+            role: NodeRole::Goal(goal),
+            tactic: Tactic::SearchAssumptions,
+            description: None,
+            hint: None,
+        };
+        forest.add_node(root.clone()).unwrap();
+        forest.roots.push(root.id.clone());
+        let outcome = root.apply_tactic(Tactic::SplitGoalConjunction, &mut forest);
+
+        // Closing is refused while a conjunct is still open
+        outcome.sub_nodes[0].apply_tactic(Tactic::ByReflexivity, &mut forest);
+        assert!(matches!(
+            forest.close_manager(&outcome.manager.id),
+            Err(FormalismError::TacticFailed { .. })
+        ));
+
+        outcome.sub_nodes[1].apply_tactic(Tactic::ByReflexivity, &mut forest);
+        assert!(!forest.is_fully_proven());
+
+        forest.close_manager(&outcome.manager.id).unwrap();
+        assert!(matches!(
+            forest.get_node(&outcome.manager.id).unwrap().role,
+            NodeRole::Completed
+        ));
+        assert!(forest.is_fully_proven());
+        assert_eq!(forest.check_invariants(), Ok(()));
+    }
+
+    #[test]
+    fn test_have_yields_lemma_then_extended_goal() {
+        use crate::subjects::math::formalism::relations::MathRelation;
+        use crate::subjects::math::theories::number_theory::definitions::Number;
+        use crate::turn_render::Identifier;
+
+        let lemma = MathRelation::equal(
+            MathExpression::Number(Number::int(1)),
+            MathExpression::Number(Number::int(1)),
+        );
+        let goal = ProofGoal {
+            context: vec![],
+            quantifiers: vec![],
+            statement: Located::new_concrete(MathRelation::True),
+        };
+
+        let mut forest = ProofForest::new_from_goal(goal.clone());
+        let root = ProofNode {
+            id: Uuid::new_v4().to_string(),
+            parent: None,
+            children: vec![],
+            role: NodeRole::Goal(goal.clone()),
+            tactic: Tactic::SearchAssumptions,
+            description: None,
+            hint: None,
+        };
+        forest.add_node(root.clone()).unwrap();
+        forest.roots.push(root.id.clone());
+
+        let name = Identifier::new_simple("h".to_string());
+        let outcome = root.apply_tactic(
+            Tactic::Have {
+                name: name.clone(),
+                statement: lemma.clone(),
+            },
+            &mut forest,
+        );
+        assert_eq!(outcome.sub_nodes.len(), 2);
+
+        // First the lemma, in the unchanged context
+        let lemma_goal = outcome.sub_nodes[0].get_goal();
+        assert_eq!(lemma_goal.statement.concrete_value().unwrap().as_ref(), &lemma);
+        assert!(lemma_goal.context.is_empty());
+
+        // Then the original goal, with the lemma as a hypothesis
+        let continuation = outcome.sub_nodes[1].get_goal();
+        assert_eq!(continuation.statement.id, goal.statement.id);
+        assert_eq!(continuation.context.len(), 1);
+        assert_eq!(continuation.context[0].name, name);
+        match continuation.context[0].ty.concrete_value().unwrap().as_ref() {
+            MathExpression::Relation(relation) => assert_eq!(relation.as_ref(), &lemma),
+            other => panic!("Expected the lemma as hypothesis, got {:?}", other),
+        }
+
+        match &outcome.manager.role {
+            NodeRole::SubgoalManager {
+                combination_type, ..
+            } => assert_eq!(combination_type, &SubgoalCombination::Sequential),
+            other => panic!("Expected SubgoalManager, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_have_continuation_waits_for_the_lemma() {
+        use crate::subjects::math::formalism::relations::MathRelation;
+        use crate::subjects::math::theories::number_theory::definitions::Number;
+        use crate::turn_render::Identifier;
+
+        let goal = ProofGoal {
+            context: vec![],
+            quantifiers: vec![],
+            statement: Located::new_concrete(MathRelation::True),
+        };
+        let mut forest = ProofForest::new_from_goal(goal.clone());
+        let root = ProofNode {
+            id: Uuid::new_v4().to_string(),
+            parent: None,
+            children: vec![],
+            role: NodeRole::Goal(goal),
+            tactic: Tactic::SearchAssumptions,
+            description: None,
+            hint: None,
+        };
+        forest.add_node(root.clone()).unwrap();
+        forest.roots.push(root.id.clone());
+
+        let outcome = root.apply_tactic(
+            Tactic::Have {
+                name: Identifier::new_simple("h".to_string()),
+                statement: MathRelation::equal(
+                    MathExpression::Number(Number::int(1)),
+                    MathExpression::Number(Number::int(1)),
+                ),
+            },
+            &mut forest,
+        );
+        let (lemma, continuation) = (&outcome.sub_nodes[0], &outcome.sub_nodes[1]);
+
+        // The continuation is refused, and the forest left as it was, until the lemma is proven
+        let nodes = forest.len();
+        assert_eq!(
+            forest.blocking_subgoal(&continuation.id),
+            Some(lemma.id.clone())
+        );
+        assert!(matches!(
+            continuation.try_apply_tactic(Tactic::Trivial, &mut forest),
+            Err(FormalismError::TacticFailed { .. })
+        ));
+        assert_eq!(forest.len(), nodes);
+        assert_eq!(forest.blocking_subgoal(&lemma.id), None);
+
+        lemma
+            .try_apply_tactic(Tactic::Trivial, &mut forest)
+            .unwrap();
+        assert_eq!(forest.blocking_subgoal(&continuation.id), None);
+        continuation
+            .try_apply_tactic(Tactic::Trivial, &mut forest)
+            .unwrap();
+        forest.close_manager(&outcome.manager.id).unwrap();
+        assert!(forest.is_fully_proven());
+    }
+
+    #[test]
+    fn test_suffices_yields_implication_and_new_goal() {
+        use crate::subjects::math::formalism::proof::tactics::{Tactic, TacticApplicationResult};
+        use crate::subjects::math::formalism::relations::MathRelation;
+        use crate::subjects::math::theories::number_theory::definitions::Number;
+
+        let sufficient = MathRelation::equal(
+            MathExpression::Number(Number::int(1)),
+            MathExpression::Number(Number::int(1)),
+        );
+        let goal = ProofGoal {
+            context: vec![],
+            quantifiers: vec![],
+            statement: Located::new_concrete(MathRelation::True),
+        };
+
+        let tactic = Tactic::Suffices {
+            statement: sufficient.clone(),
+        };
+        match tactic.apply_to_goal(&goal) {
+            TacticApplicationResult::MultiGoal(goals) => {
+                assert_eq!(goals.len(), 2);
+
+                // Side-goal: statement → original goal
+                match goals[0].statement.concrete_value().unwrap().as_ref() {
+                    MathRelation::Implies(antecedent, consequent) => {
+                        assert_eq!(antecedent.concrete_value().unwrap().as_ref(), &sufficient);
+                        assert_eq!(consequent.id, goal.statement.id);
+                    }
+                    other => panic!("Expected an implication side-goal, got {:?}", other),
+                }
+
+                // Main goal: the sufficient statement itself
+                assert_eq!(
+                    goals[1].statement.concrete_value().unwrap().as_ref(),
+                    &sufficient
+                );
+                assert_eq!(goals[1].context.len(), goal.context.len());
+            }
+            other => panic!("Expected MultiGoal, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_alpha_eq_up_to_bound_variable_names() {
+        use crate::subjects::math::formalism::relations::MathRelation;
+        use crate::subjects::math::theories::number_theory::definitions::Number;
+        use crate::turn_render::Identifier;
+
+        // ∀v, P(v) with P(v) := v = n, and Q(v) := n = v
+        let forall = |name: &str, flipped: bool| {
+            let v = Identifier::new_simple(name.to_string());
+            let variable = Located::new_variable(v.clone());
+            let number = Located::new_concrete(MathExpression::Number(Number::int(1)));
+            let (left, right) = if flipped {
+                (number, variable)
+            } else {
+                (variable, number)
+            };
+            ProofGoal {
+                context: vec![ContextEntry {
+                    name: v.clone(),
+                    ty: Located::new_concrete(MathExpression::Number(Number::int(1))),
+                    definition: DefinitionState::Abstract,
+                    description: None,
+                    is_internal: false,
+                }],
+                quantifiers: vec![Quantifier {
+                    variable_name: v,
+                    quantification: Quantification::Universal,
+                }],
+                statement: Located::new_concrete(MathRelation::Equal { left, right }),
+            }
+        };
+
+        let p_x = forall("x", false);
+        assert!(p_x.alpha_eq(&forall("y", false)));
+        assert!(!p_x.alpha_eq(&forall("x", true)));
+        // Plain content equality still sees the different names
+        assert!(!p_x.semantically_eq(&forall("y", false)));
+    }
+
+    #[test]
+    fn test_theorem_library_search_matches_up_to_bound_variable_names() {
+        use crate::subjects::math::formalism::automation::registry::register_fn;
+        use crate::subjects::math::formalism::proof::tactics::{Tactic, TacticApplicationResult};
+        use crate::subjects::math::formalism::relations::MathRelation;
+        use crate::subjects::math::formalism::theorem::Theorem;
+        use crate::subjects::math::theories::number_theory::definitions::Number;
+        use crate::turn_render::Identifier;
+
+        // ∀v, v = v
+        fn forall(name: &str) -> ProofGoal {
+            let v = Identifier::new_simple(name.to_string());
+            ProofGoal {
+                context: vec![ContextEntry {
+                    name: v.clone(),
+                    ty: Located::new_concrete(MathExpression::Number(Number::int(2))),
+                    definition: DefinitionState::Abstract,
+                    description: None,
+                    is_internal: false,
+                }],
+                quantifiers: vec![Quantifier {
+                    variable_name: v.clone(),
+                    quantification: Quantification::Universal,
+                }],
+                statement: Located::new_concrete(MathRelation::Equal {
+                    left: Located::new_variable(v.clone()),
+                    right: Located::new_variable(v),
+                }),
+            }
+        }
+        fn theorem(id: &str, proven: bool) -> Theorem {
+            let goal = forall("x");
+            let mut proofs = ProofForest::new_from_goal(goal.clone());
+            let root = ProofNode {
+                id: format!("{}-root", id),
+                parent: None,
+                children: vec![],
+                role: NodeRole::Goal(goal),
+                tactic: Tactic::SearchAssumptions,
+                description: None,
+                hint: None,
+            };
+            proofs.add_node(root.clone()).unwrap();
+            proofs.roots.push(root.id.clone());
+            if proven {
+                root.try_apply_tactic(Tactic::ByReflexivity, &mut proofs)
+                    .unwrap();
+            }
+            Theorem {
+                id: id.to_string(),
+                name: "Every x equals itself".to_string(),
+                description: "∀x, x = x".to_string(),
+                proofs,
+            }
+        }
+
+        // A registered theorem without a proof is not trusted
+        fn unproven() -> Theorem {
+            theorem("library_search_unproven_reflexivity", false)
+        }
+        let category = "test_theorem_library_search";
+        register_fn("library_search_unproven_reflexivity", unproven, &[category]).unwrap();
+        assert!(matches!(
+            Tactic::SearchTheoremLibrary.apply_to_goal(&forall("y")),
+            TacticApplicationResult::Error(_)
+        ));
+
+        fn proven() -> Theorem {
+            theorem("library_search_proven_reflexivity", true)
+        }
+        register_fn("library_search_proven_reflexivity", proven, &[category]).unwrap();
+        assert!(matches!(
+            Tactic::SearchTheoremLibrary.apply_to_goal(&forall("y")),
+            TacticApplicationResult::ProofComplete
+        ));
+
+        // A different statement is still not found
+        let mut other = forall("y");
+        other.quantifiers[0].quantification = Quantification::Existential;
+        assert!(matches!(
+            Tactic::SearchTheoremLibrary.apply_to_goal(&other),
+            TacticApplicationResult::Error(_)
+        ));
+    }
+
+    #[test]
+    fn test_auto_closes_conjunction_of_reflexive_equalities() {
+        use crate::subjects::math::formalism::proof::tactics::{Tactic, TacticApplicationResult};
+        use crate::subjects::math::formalism::relations::MathRelation;
+        use crate::subjects::math::theories::number_theory::definitions::Number;
+
+        let reflexive = || {
+            Located::new_concrete(MathRelation::equal(
+                MathExpression::Number(Number::int(1)),
+                MathExpression::Number(Number::int(1)),
+            ))
+        };
+        let goal = ProofGoal {
+            context: vec![],
+            quantifiers: vec![],
+            statement: Located::new_concrete(MathRelation::And(vec![reflexive(), reflexive()])),
+        };
+
+        let auto = Tactic::Auto {
+            depth: None,
+            with_tactics: vec![],
+        };
+        match auto.apply_to_goal(&goal) {
+            TacticApplicationResult::ProofComplete => {}
+            other => panic!("Expected ProofComplete, got {:?}", other),
+        }
+
+        let no_split = Tactic::Auto {
+            depth: Some(2),
+            with_tactics: vec![Tactic::ByReflexivity],
+        };
+        assert!(matches!(
+            no_split.apply_to_goal(&goal),
+            TacticApplicationResult::Error(_)
+        ));
+    }
+
+    #[test]
+    fn test_assumption_closes_goal_with_unique_matching_hypothesis() {
+        use crate::subjects::math::formalism::proof::tactics::{Tactic, TacticApplicationResult};
+        use crate::subjects::math::formalism::relations::MathRelation;
+        use crate::subjects::math::theories::number_theory::definitions::Number;
+
+        let equation = |left: i64, right: i64| {
+            MathRelation::equal(
+                MathExpression::Number(Number::int(left)),
+                MathExpression::Number(Number::int(right)),
+            )
+        };
+        let goal = ProofGoal {
+            context: vec![],
+            quantifiers: vec![],
+            statement: Located::new_concrete(equation(1, 2)),
+        }
+        .with_hypothesis("h_other", equation(2, 3), None)
+        .0
+        .with_hypothesis("h", equation(1, 2), None)
+        .0;
+
+        assert!(matches!(
+            Tactic::Assumption.apply_to_goal(&goal),
+            TacticApplicationResult::ProofComplete
+        ));
+
+        let without_match = ProofGoal {
+            statement: Located::new_concrete(equation(3, 4)),
+            ..goal
+        };
+        assert!(matches!(
+            Tactic::Assumption.apply_to_goal(&without_match),
+            TacticApplicationResult::Error(_)
+        ));
+    }
+
+    #[test]
+    fn test_apply_lemma_leaves_its_hypothesis_as_subgoal() {
+        use crate::subjects::math::formalism::proof::tactics::{
+            RelationSource, Tactic, TacticApplicationResult,
+        };
+        use crate::subjects::math::formalism::relations::MathRelation;
+        use crate::subjects::math::theories::number_theory::definitions::Number;
+
+        let equation = |left: i64, right: i64| {
+            MathRelation::equal(
+                MathExpression::Number(Number::int(left)),
+                MathExpression::Number(Number::int(right)),
+            )
+        };
+        // h : P → Q with P := 1 = 2 and Q := 3 = 4
+        let (goal, lemma) = ProofGoal {
+            context: vec![],
+            quantifiers: vec![],
+            statement: Located::new_concrete(equation(3, 4)),
+        }
+        .with_hypothesis(
+            "h",
+            MathRelation::Implies(
+                Located::new_concrete(equation(1, 2)),
+                Located::new_concrete(equation(3, 4)),
+            ),
+            None,
+        );
+        let apply = Tactic::Apply {
+            source: RelationSource::LocalAssumption(lemma),
+        };
+
+        let TacticApplicationResult::MultiGoal(sub_goals) = apply.apply_to_goal(&goal) else {
+            panic!("Expected the lemma's hypothesis as a new goal");
+        };
+        let expected = ProofGoal {
+            statement: Located::new_concrete(equation(1, 2)),
+            ..goal.clone()
+        };
+        assert_eq!(sub_goals.len(), 1);
+        assert!(sub_goals[0].semantically_eq(&expected));
+
+        let unrelated = ProofGoal {
+            statement: Located::new_concrete(equation(5, 6)),
+            ..goal
+        };
+        assert!(matches!(
+            apply.apply_to_goal(&unrelated),
+            TacticApplicationResult::Error(_)
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "groups")]
+    fn test_apply_lemma_names_variables_only_its_hypotheses_mention() {
+        use crate::subjects::math::formalism::automation::registry::register_fn;
+        use crate::subjects::math::formalism::expressions::{MathExpression, TheoryExpression};
+        use crate::subjects::math::formalism::proof::tactics::{
+            RelationSource, Tactic, TacticApplicationResult,
+        };
+        use crate::subjects::math::formalism::relations::MathRelation;
+        use crate::subjects::math::formalism::theorem::Theorem;
+        use crate::subjects::math::theories::groups::definitions::{Group, GroupExpression};
+        use crate::turn_render::Identifier;
+
+        fn element(name: &str) -> ContextEntry {
+            ContextEntry {
+                name: Identifier::new_simple(name.to_string()),
+                ty: Located::new_concrete(MathExpression::Expression(TheoryExpression::Group(
+                    GroupExpression::Element {
+                        group: Located::new_concrete(Group::new_generic()),
+                        element: None,
+                    },
+                ))),
+                definition: DefinitionState::Abstract,
+                description: None,
+                is_internal: false,
+            }
+        }
+        fn equal(left: &str, right: &str) -> Located<MathRelation> {
+            Located::new_concrete(MathRelation::Equal {
+                left: Located::new_variable(Identifier::new_simple(left.to_string())),
+                right: Located::new_variable(Identifier::new_simple(right.to_string())),
+            })
+        }
+        fn theorem(id: &str, statement: MathRelation) -> Theorem {
+            Theorem {
+                id: id.to_string(),
+                name: id.to_string(),
+                description: String::new(),
+                proofs: ProofForest::new_from_goal(ProofGoal {
+                    context: vec![element("a"), element("b"), element("c")],
+                    quantifiers: vec![],
+                    statement: Located::new_concrete(statement),
+                }),
+            }
+        }
+        // a = b → b = c → a = c
+        fn transitivity() -> Theorem {
+            let conclusion = MathRelation::Implies(equal("b", "c"), equal("a", "c"));
+            theorem(
+                "test_lemma_transitivity",
+                MathRelation::Implies(equal("a", "b"), Located::new_concrete(conclusion)),
+            )
+        }
+        // a = b → (a = b ∧ b = a)
+        fn symmetric_pair() -> Theorem {
+            let pair = MathRelation::And(vec![equal("a", "b"), equal("b", "a")]);
+            theorem(
+                "test_lemma_symmetric_pair",
+                MathRelation::Implies(equal("a", "b"), Located::new_concrete(pair)),
+            )
+        }
+        register_fn(
+            "test_lemma_transitivity",
+            transitivity,
+            &["test_apply_lemma"],
+        )
+        .unwrap();
+        register_fn(
+            "test_lemma_symmetric_pair",
+            symmetric_pair,
+            &["test_apply_lemma"],
+        )
+        .unwrap();
+        let apply = |id: &str, conjunct: Option<usize>, goal: &ProofGoal| {
+            Tactic::Apply {
+                source: RelationSource::Theorem(id.to_string(), conjunct),
+            }
+            .apply_to_goal(goal)
+        };
+        let sides = |goal: &ProofGoal| match goal.statement.data.unwrap(&goal.context) {
+            MathRelation::Equal { left, right } => (
+                left.variable_id().map(|id| id.body.clone()),
+                right.variable_id().map(|id| id.body.clone()),
+            ),
+            other => panic!("Expected an equality, got {:?}", other),
+        };
+        let some = |name: &str| Some(name.to_string());
+
+        // Only the hypotheses mention the lemma's b; it may not reuse the goal's b
+        let goal = ProofGoal {
+            context: vec![element("g"), element("b")],
+            quantifiers: vec![],
+            statement: equal("g", "b"),
+        };
+        let TacticApplicationResult::MultiGoal(sub_goals) =
+            apply("test_lemma_transitivity", None, &goal)
+        else {
+            panic!("Expected both hypotheses as sub-goals");
+        };
+        assert_eq!(sub_goals.len(), 2);
+        assert_eq!(sides(&sub_goals[0]), (some("g"), some("b_1")));
+        assert_eq!(sides(&sub_goals[1]), (some("b_1"), some("b")));
+        for sub_goal in &sub_goals {
+            assert!(
+                sub_goal
+                    .context
+                    .iter()
+                    .any(|entry| entry.name.body == "b_1")
+            );
+            assert_eq!(sub_goal.verify(), Ok(()));
+        }
+
+        // The conjunct index picks which part of the conclusion to unify
+        let goal = ProofGoal {
+            context: vec![element("g"), element("h")],
+            quantifiers: vec![],
+            statement: equal("h", "g"),
+        };
+        let TacticApplicationResult::MultiGoal(sub_goals) =
+            apply("test_lemma_symmetric_pair", Some(1), &goal)
+        else {
+            panic!("Expected the hypothesis as a sub-goal");
+        };
+        assert_eq!(sub_goals.len(), 1);
+        assert_eq!(sides(&sub_goals[0]), (some("g"), some("h")));
+        assert!(matches!(
+            apply("test_lemma_symmetric_pair", None, &goal),
+            TacticApplicationResult::Error(_)
+        ));
+        assert!(matches!(
+            apply("test_lemma_symmetric_pair", Some(2), &goal),
+            TacticApplicationResult::Error(_)
+        ));
+    }
+
+    #[test]
+    fn test_verify_goals_flags_tactic_that_corrupts_goal() {
+        use crate::subjects::math::formalism::proof::tactics::Tactic;
+        use crate::subjects::math::formalism::relations::MathRelation;
+        use crate::subjects::math::theories::number_theory::definitions::Number;
+
+        let (goal, _) = ProofGoal {
+            context: vec![],
+            quantifiers: vec![],
+            statement: Located::new_concrete(MathRelation::equal(
+                MathExpression::Number(Number::int(1)),
+                MathExpression::Number(Number::int(1)),
+            )),
+        }
+        .with_hypothesis(
+            "h",
+            MathRelation::equal(
+                MathExpression::Number(Number::int(2)),
+                MathExpression::Number(Number::int(2)),
+            ),
+            None,
+        );
+        // Stands in for a buggy tactic: it re-adds a hypothesis under a taken name
+        let corrupting_tactic = |goal: &ProofGoal| {
+            let mut corrupted = goal.clone();
+            corrupted.context.push(goal.context[0].clone());
+            corrupted
+        };
+        let outcome_with = |goal: ProofGoal| TacticOutcome {
+            manager: ProofNode {
+                id: "step".to_string(),
+                parent: Some("root".to_string()),
+                children: vec![],
+                role: NodeRole::Goal(goal),
+                tactic: Tactic::Assumption,
+                description: None,
+                hint: None,
+            },
+            sub_nodes: vec![],
+            made_progress: true,
+        };
+
+        assert_eq!(outcome_with(goal.clone()).verify_goals(), Ok(()));
+        let error = outcome_with(corrupting_tactic(&goal))
+            .verify_goals()
+            .unwrap_err();
+        let FormalismError::IllFormedGoal { message } = error else {
+            panic!("expected an ill-formed goal, got {:?}", error);
+        };
+        assert!(message.contains("Assumption"));
+        assert!(message.contains("Duplicate names"));
+    }
+
+    /// A root node over `A ∧ A` whose goal carries `hypotheses` as its context
+    fn conjunction_forest(hypotheses: Vec<ContextEntry>) -> (ProofForest, ProofNode) {
+        use crate::subjects::math::formalism::proof::tactics::Tactic;
+        use crate::subjects::math::formalism::relations::MathRelation;
+        use crate::subjects::math::theories::number_theory::definitions::Number;
+
+        let part = || {
+            Located::new_concrete(MathRelation::equal(
+                MathExpression::Number(Number::int(1)),
+                MathExpression::Number(Number::int(1)),
+            ))
+        };
+        let goal = ProofGoal {
+            context: hypotheses,
+            quantifiers: vec![],
+            statement: Located::new_concrete(MathRelation::And(vec![part(), part()])),
+        };
+        let mut forest = ProofForest::new_from_goal(goal.clone());
+        let root = ProofNode {
+            id: "root".to_string(),
+            parent: None,
+            children: vec![],
+            role: NodeRole::Goal(goal),
+            tactic: Tactic::SearchAssumptions,
+            description: None,
+            hint: None,
+        };
+        forest.add_node(root.clone()).unwrap();
+        forest.roots.push(root.id.clone());
+        (forest, root)
+    }
+
+    #[test]
+    fn test_apply_tactic_verifies_the_goals_it_adds() {
+        use crate::subjects::math::formalism::proof::tactics::Tactic;
+
+        let (mut forest, root) = conjunction_forest(vec![]);
+        let outcome = root.apply_tactic(Tactic::SplitGoalConjunction, &mut forest);
+
+        assert_eq!(outcome.verify_goals(), Ok(()));
+        assert_eq!(outcome.sub_nodes.len(), 2);
+        for node in &outcome.sub_nodes {
+            assert!(forest.get_node(&node.id).is_some());
+        }
+    }
+
+    #[test]
+    fn test_try_apply_tactic_rejects_ill_formed_goals() {
+        use crate::subjects::math::formalism::proof::tactics::Tactic;
+        use crate::subjects::math::formalism::relations::MathRelation;
+        use crate::subjects::math::theories::number_theory::definitions::Number;
+
+        let (goal, _) = ProofGoal {
+            context: vec![],
+            quantifiers: vec![],
+            statement: Located::new_concrete(MathRelation::True),
+        }
+        .with_hypothesis(
+            "h",
+            MathRelation::equal(
+                MathExpression::Number(Number::int(2)),
+                MathExpression::Number(Number::int(2)),
+            ),
+            None,
+        );
+        // Splitting copies the duplicated hypothesis into both subgoals
+        let duplicated = vec![goal.context[0].clone(), goal.context[0].clone()];
+        let (mut forest, root) = conjunction_forest(duplicated);
+
+        let error = root
+            .try_apply_tactic(Tactic::SplitGoalConjunction, &mut forest)
+            .unwrap_err();
+        assert!(matches!(error, FormalismError::IllFormedGoal { .. }));
+        assert!(error.to_string().contains("Duplicate names"));
+        // The rejected tactic leaves the forest as it was
+        assert_eq!(forest.len(), 1);
+        assert!(forest.get_node("root").unwrap().children.is_empty());
+        assert_eq!(forest.check_invariants(), Ok(()));
+    }
+
+    #[test]
+    fn test_write_tree_indents_children_and_truncates_at_depth() {
+        use crate::subjects::math::formalism::proof::tactics::Tactic;
+        use crate::subjects::math::formalism::relations::MathRelation;
+        use crate::subjects::math::theories::number_theory::definitions::Number;
+
+        let part = || {
+            Located::new_concrete(MathRelation::equal(
+                MathExpression::Number(Number::int(1)),
+                MathExpression::Number(Number::int(1)),
+            ))
+        };
+        let goal = ProofGoal {
+            context: vec![],
+            quantifiers: vec![],
+            statement: Located::new_concrete(MathRelation::And(vec![part(), part()])),
+        };
+        let mut forest = ProofForest::new_from_goal(goal.clone());
+        let root = ProofNode {
+            id: "root".to_string(),
+            parent: None,
+            children: vec![],
+            role: NodeRole::Goal(goal),
+            tactic: Tactic::SearchAssumptions,
+            description: None,
+            hint: None,
+        };
+        forest.add_node(root.clone()).unwrap();
+        forest.roots.push("root".to_string());
+
+        let outcome = root.apply_tactic(Tactic::SplitGoalConjunction, &mut forest);
+        outcome.sub_nodes[0].apply_tactic(Tactic::ByReflexivity, &mut forest);
+
+        let render = |max_depth| {
+            let mut buffer = Vec::new();
+            forest.write_tree(&mut buffer, max_depth).unwrap();
+            String::from_utf8(buffer).unwrap()
+        };
+
+        // The manager and both conjuncts hang off the root; the first conjunct is closed
+        assert_eq!(
+            render(None),
+            "○ SearchAssumptions\n\
+             ├── ◇ SplitGoalConjunction\n\
+             ├── ○ SplitGoalConjunction\n\
+             │   └── ✓ ByReflexivity\n\
+             └── ○ SplitGoalConjunction\n"
+        );
+        assert_eq!(
+            render(Some(1)),
+            "○ SearchAssumptions\n\
+             ├── ◇ SplitGoalConjunction\n\
+             ├── ○ SplitGoalConjunction\n\
+             │   └── …\n\
+             └── ○ SplitGoalConjunction\n"
+        );
+        assert_eq!(render(Some(0)), "○ SearchAssumptions\n└── …\n");
+    }
+
+    #[test]
+    fn test_from_implication_puts_hypotheses_in_context() {
+        use crate::subjects::math::formalism::relations::MathRelation;
+        use crate::subjects::math::theories::number_theory::definitions::Number;
+
+        let equation = |left: i64, right: i64| {
+            MathRelation::equal(
+                MathExpression::Number(Number::int(left)),
+                MathExpression::Number(Number::int(right)),
+            )
+        };
+        let conclusion = equation(1, 3);
+        let goal = ProofGoal::from_implication(
+            vec![("h1", equation(1, 2)), ("h2", equation(2, 3))],
+            conclusion.clone(),
+        );
+
+        let names: Vec<&str> = goal
+            .context
+            .iter()
+            .map(|entry| entry.name.body.as_str())
+            .collect();
+        assert_eq!(names, vec!["h1", "h2"]);
+        for name in names {
+            let name = Identifier::new_simple(name.to_string());
+            assert!(goal.find_relation_by_name(&name).is_some());
+        }
+        assert!(goal.quantifiers.is_empty());
+        assert_eq!(
+            goal.statement.concrete_value().map(|s| s.as_ref()),
+            Some(&conclusion)
+        );
+        assert_eq!(goal.verify(), Ok(()));
+    }
+
+    #[test]
+    fn test_case_on_membership_adds_membership_and_non_membership() {
+        use crate::subjects::math::formalism::proof::tactics::{Tactic, TacticApplicationResult};
+        use crate::subjects::math::formalism::relations::MathRelation;
+        use crate::subjects::math::theories::number_theory::definitions::Number;
+        use crate::subjects::math::theories::zfc::definitions::{Set, SetRelation};
+
+        let goal = ProofGoal {
+            context: vec![],
+            quantifiers: vec![],
+            statement: Located::new_concrete(MathRelation::equal(
+                MathExpression::Number(Number::int(1)),
+                MathExpression::Number(Number::int(1)),
+            )),
+        };
+        let tactic = Tactic::CaseOnMembership {
+            element: MathExpression::Number(Number::Variable(Identifier::new_simple(
+                "x".to_string(),
+            ))),
+            set: MathExpression::Object(Arc::new(MathObject::Set(Set::empty()))),
+        };
+
+        let TacticApplicationResult::MultiGoal(cases) = tactic.apply_to_goal(&goal) else {
+            panic!("Expected one subgoal per case");
+        };
+        assert_eq!(cases.len(), 2);
+        let case_hypothesis = |case: &ProofGoal| {
+            let entry = case.context.last().unwrap();
+            assert_eq!(entry.name.body, "H");
+            case.find_relation_by_name(&entry.name).unwrap()
+        };
+        let is_membership = |relation: &MathRelation| {
+            matches!(relation, MathRelation::SetTheory(SetRelation::ElementOf { element, .. })
+                if matches!(element, Parametrizable::Variable(name) if name.body == "x"))
+        };
+
+        let in_case = case_hypothesis(&cases[0]);
+        assert!(is_membership(in_case.concrete_value().unwrap().as_ref()));
+
+        let out_case = case_hypothesis(&cases[1]);
+        match out_case.concrete_value().unwrap().as_ref() {
+            MathRelation::Not(inner) => {
+                assert!(is_membership(inner.concrete_value().unwrap().as_ref()))
+            }
+            other => panic!("Expected a negated membership, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_fresh_name_skips_names_in_use() {
+        use crate::subjects::math::formalism::relations::MathRelation;
+
+        let goal = ProofGoal {
+            context: vec![],
+            quantifiers: vec![],
+            statement: Located::new_concrete(MathRelation::True),
+        };
+        let h = Identifier::new_simple("h".to_string());
+        let first = goal.fresh_name(&h);
+        assert_eq!(first, h);
+
+        let goal = goal
+            .with_hypothesis(&first.body, MathRelation::True, None)
+            .0;
+        assert_eq!(goal.fresh_name(&h).body, "h_1");
+
+        let goal = goal.with_hypothesis("h_1", MathRelation::True, None).0;
+        assert_eq!(goal.fresh_name(&h).body, "h_2");
+
+        // Free names keep their scripts and primes; a taken one only has its body renamed
+        let x_1 = Identifier::with_subscript("x".to_string(), "1".to_string());
+        assert_eq!(goal.fresh_name(&x_1), x_1);
+        let h_prime = Identifier::with_primes("h".to_string(), 1);
+        assert_eq!(goal.fresh_name(&h_prime), h_prime);
+
+        let mut goal = goal;
+        goal.quantifiers.push(Quantifier {
+            variable_name: h_prime.clone(),
+            quantification: Quantification::Universal,
+        });
+        let renamed = goal.fresh_name(&h_prime);
+        assert_eq!(renamed.body, "h_1");
+        assert_eq!(renamed.primes, 1);
+    }
+
+    #[test]
+    fn test_have_rejects_a_name_in_use() {
+        use crate::subjects::math::formalism::proof::tactics::{Tactic, TacticApplicationResult};
+        use crate::subjects::math::formalism::relations::MathRelation;
+
+        let goal = ProofGoal {
+            context: vec![],
+            quantifiers: vec![],
+            statement: Located::new_concrete(MathRelation::True),
+        }
+        .with_hypothesis("h", MathRelation::True, None)
+        .0;
+        let have = Tactic::Have {
+            name: Identifier::new_simple("h".to_string()),
+            statement: MathRelation::True,
+        };
+        assert!(matches!(
+            have.apply_to_goal(&goal),
+            TacticApplicationResult::Error(_)
+        ));
+    }
+
+    #[test]
+    fn test_preview_tactic_leaves_forest_untouched() {
+        use crate::subjects::math::formalism::proof::tactics::{Tactic, TacticApplicationResult};
+        use crate::subjects::math::formalism::relations::MathRelation;
+        use crate::subjects::math::theories::number_theory::definitions::Number;
+
+        let one_equals_one = || {
+            Located::new_concrete(MathRelation::equal(
+                MathExpression::Number(Number::int(1)),
+                MathExpression::Number(Number::int(1)),
+            ))
+        };
+        let goal = ProofGoal {
+            context: vec![],
+            quantifiers: vec![],
+            statement: Located::new_concrete(MathRelation::Implies(
+                one_equals_one(),
+                one_equals_one(),
+            )),
+        };
+        let mut forest = ProofForest::new_from_goal(goal.clone());
+        let root = ProofNode {
+            id: "root".to_string(),
+            parent: None,
+            children: vec![],
+            role: NodeRole::Goal(goal),
+            tactic: Tactic::SearchAssumptions,
+            description: None,
+            hint: None,
+        };
+        forest.add_node(root.clone()).unwrap();
+        forest.roots.push("root".to_string());
+
+        let tactic = Tactic::AssumeImplicationAntecedent {
+            with_name: Identifier::new_simple("h".to_string()),
+        };
+        let TacticApplicationResult::SingleGoal(previewed) = root.preview_tactic(&tactic) else {
+            panic!("Expected a single goal");
+        };
+        assert_eq!(forest.len(), 1);
+        assert!(forest.get_node("root").unwrap().children.is_empty());
+
+        let stored = root.apply_tactic(tactic, &mut forest).first_goal_node();
+        assert_eq!(forest.len(), 2);
+        assert!(previewed.semantically_eq(stored.get_goal()));
+    }
+
+    #[test]
+    #[cfg(feature = "groups")]
+    fn test_assumption_rejects_ambiguous_hypotheses() {
+        use crate::subjects::math::formalism::proof::tactics::{Tactic, TacticApplicationResult};
+        use crate::subjects::math::formalism::relations::MathRelation;
+        use crate::subjects::math::theories::number_theory::definitions::Number;
+
+        let one_equals_two = || {
+            MathRelation::equal(
+                MathExpression::Number(Number::int(1)),
+                MathExpression::Number(Number::int(2)),
+            )
+        };
+        let goal = ProofGoal {
+            context: vec![],
+            quantifiers: vec![],
+            statement: Located::new_concrete(one_equals_two()),
+        }
+        .with_hypothesis("h1", one_equals_two(), None)
+        .0
+        .with_hypothesis("h2", one_equals_two(), None)
+        .0;
+
+        match Tactic::Assumption.apply_to_goal(&goal) {
+            TacticApplicationResult::Error(message) => {
+                assert!(message.contains("h1") && message.contains("h2"));
+            }
+            other => panic!("Expected Error, got {:?}", other),
+        }
+    }
+
+    fn trivial_test_goal(statement: MathRelation) -> ProofGoal {
+        use crate::subjects::math::theories::zfc::definitions::{GenericSet, Set};
+
+        let element = |name: &str| ContextEntry {
+            name: Identifier::new_simple(name.to_string()),
+            ty: Located::new_concrete(MathExpression::Object(Arc::new(MathObject::Set(
+                Set::Generic(GenericSet::new()),
+            )))),
+            definition: DefinitionState::Abstract,
+            description: None,
+            is_internal: false,
+        };
+        ProofGoal {
+            context: vec![element("a"), element("b")],
+            quantifiers: vec![],
+            statement: Located::new_concrete(statement),
+        }
+    }
+
+    fn trivial_test_equal(left: &str, right: &str) -> MathRelation {
+        MathRelation::Equal {
+            left: Located::new_variable(Identifier::new_simple(left.to_string())),
+            right: Located::new_variable(Identifier::new_simple(right.to_string())),
+        }
+    }
+
+    #[test]
+    fn test_trivial_closes_true_and_reflexive_goals() {
+        use crate::subjects::math::formalism::proof::tactics::TacticApplicationResult;
+
+        assert!(matches!(
+            Tactic::Trivial.apply_to_goal(&trivial_test_goal(MathRelation::True)),
+            TacticApplicationResult::ProofComplete
+        ));
+        assert!(matches!(
+            Tactic::Trivial.apply_to_goal(&trivial_test_goal(trivial_test_equal("a", "a"))),
+            TacticApplicationResult::ProofComplete
+        ));
+    }
+
+    #[test]
+    fn test_trivial_leaves_nontrivial_goal_unchanged() {
+        use crate::subjects::math::formalism::proof::tactics::TacticApplicationResult;
+
+        let goal = trivial_test_goal(trivial_test_equal("a", "b"));
+        assert!(matches!(
+            Tactic::Trivial.apply_to_goal(&goal),
+            TacticApplicationResult::NoChange
+        ));
+
+        // The same goal is trivial once a hypothesis states it
+        let (goal, _) = goal.with_hypothesis("h", trivial_test_equal("a", "b"), None);
+        assert!(matches!(
+            Tactic::Trivial.apply_to_goal(&goal),
+            TacticApplicationResult::ProofComplete
+        ));
+    }
+
+    #[test]
+    fn test_capped_forest_refuses_oversized_split() {
+        use crate::subjects::math::formalism::relations::MathRelation;
+
+        let goal = ProofGoal {
+            context: vec![],
+            quantifiers: vec![],
+            statement: Located::new_concrete(MathRelation::And(vec![
+                Located::new_concrete(MathRelation::True),
+                Located::new_concrete(MathRelation::True),
+            ])),
+        };
+
+        // Room for the root and one more node, but the split needs a manager and two subgoals
+        let mut forest = ProofForest::with_max_nodes(goal.clone(), 2);
+        let root = ProofNode {
+            id: Uuid::new_v4().to_string(),
+            parent: None,
+            children: vec![],
+            role: NodeRole::Goal(goal),
+            tactic: Tactic::SearchAssumptions,
+            description: None,
+            hint: None,
+        };
+        forest.add_node(root.clone()).unwrap();
+        forest.roots.push(root.id.clone());
+
+        let result = root.try_apply_tactic(Tactic::SplitGoalConjunction, &mut forest);
+        assert_eq!(
+            result.unwrap_err(),
+            FormalismError::ForestTooLarge { max_nodes: 2 }
+        );
+        // Nothing was added on the way to the error
+        assert_eq!(forest.len(), 1);
+        assert!(forest.get_node(&root.id).unwrap().children.is_empty());
+    }
 }
 
 // Backward compatibility types for existing code
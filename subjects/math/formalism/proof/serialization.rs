@@ -0,0 +1,246 @@
+//! Versioned serialization of proof forests: JSON for interchange and, with the
+//! `binary` feature, compact CBOR for storing many proof searches.
+//!
+//! Both formats wrap the forest in the same envelope, tagged with
+//! `PROOF_FOREST_FORMAT_VERSION`, and carry exactly the same data.
+//! `to_readable_json` is a separate, one-way view for human review.
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use super::{NodeRole, ProofForest, ProofNode, SubgoalCombination};
+
+/// Layout version written with every serialized forest. Bump it when the
+/// serialized shape of `ProofForest` changes incompatibly.
+pub const PROOF_FOREST_FORMAT_VERSION: u32 = 1;
+
+/// Errors from writing or reading a serialized proof forest.
+#[derive(Debug, Error)]
+pub enum ForestSerializationError {
+    /// The JSON encoder or parser failed
+    #[error("Proof forest JSON error: {0}")]
+    Json(#[from] serde_json::Error),
+
+    /// The CBOR encoder failed
+    #[cfg(feature = "binary")]
+    #[error("Failed to serialize proof forest: {0}")]
+    CborSerialize(#[from] ciborium::ser::Error<std::io::Error>),
+
+    /// The CBOR parser failed
+    #[cfg(feature = "binary")]
+    #[error("Failed to parse proof forest: {0}")]
+    CborParse(#[from] ciborium::de::Error<std::io::Error>),
+
+    /// The forest was written with another layout version
+    #[error("Unsupported proof forest format version {found} (expected {expected})")]
+    UnsupportedVersion { found: u32, expected: u32 },
+}
+
+#[derive(Serialize)]
+struct VersionedForestRef<'a> {
+    version: u32,
+    forest: &'a ProofForest,
+}
+
+#[derive(Deserialize)]
+struct VersionedForest {
+    version: u32,
+    forest: ProofForest,
+}
+
+impl VersionedForest {
+    fn into_forest(self) -> Result<ProofForest, ForestSerializationError> {
+        if self.version != PROOF_FOREST_FORMAT_VERSION {
+            return Err(ForestSerializationError::UnsupportedVersion {
+                found: self.version,
+                expected: PROOF_FOREST_FORMAT_VERSION,
+            });
+        }
+        Ok(self.forest)
+    }
+}
+
+/// A node of the readable view, with its children nested inline.
+#[derive(Serialize)]
+struct ReadableNode {
+    role: String,
+    tactic: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    children: Vec<ReadableNode>,
+}
+
+fn role_summary(role: &NodeRole) -> String {
+    match role {
+        NodeRole::Goal(_) => "goal".to_string(),
+        NodeRole::SubgoalManager {
+            subgoal_ids,
+            combination_type,
+        } => {
+            let combination = match combination_type {
+                SubgoalCombination::And => "and",
+                SubgoalCombination::Or => "or",
+                SubgoalCombination::Sequential => "sequential",
+                SubgoalCombination::Custom(name) => name,
+            };
+            format!("{} of {} subgoals", combination, subgoal_ids.len())
+        }
+        NodeRole::AutomatedTacticStep { .. } => "automated step".to_string(),
+        NodeRole::Disproved(theorem_id) => format!("disproved by {}", theorem_id),
+        NodeRole::RewriteStep { .. } => "rewrite step".to_string(),
+        NodeRole::Completed => "completed".to_string(),
+    }
+}
+
+impl ProofForest {
+    fn versioned(&self) -> VersionedForestRef<'_> {
+        VersionedForestRef {
+            version: PROOF_FOREST_FORMAT_VERSION,
+            forest: self,
+        }
+    }
+
+    /// Serializes the forest as version-tagged JSON.
+    pub fn to_json(&self) -> Result<String, ForestSerializationError> {
+        Ok(serde_json::to_string(&self.versioned())?)
+    }
+
+    /// Reads a forest written by `to_json`, refusing other format versions.
+    pub fn from_json(json: &str) -> Result<Self, ForestSerializationError> {
+        serde_json::from_str::<VersionedForest>(json)?.into_forest()
+    }
+
+    /// Pretty JSON of the forest as nested trees, for reading and diffing by
+    /// hand. Each node shows its role and tactic in place of its id, with its
+    /// children nested under it. One-way: `from_json` cannot read it back.
+    pub fn to_readable_json(&self) -> Result<String, ForestSerializationError> {
+        let roots: Vec<ReadableNode> = self
+            .roots
+            .iter()
+            .filter_map(|root_id| self.get_node(root_id))
+            .map(|root| self.readable_node(root))
+            .collect();
+        Ok(serde_json::to_string_pretty(&roots)?)
+    }
+
+    fn readable_node(&self, node: &ProofNode) -> ReadableNode {
+        ReadableNode {
+            role: role_summary(&node.role),
+            tactic: node.tactic.to_string(),
+            children: node
+                .children
+                .iter()
+                .filter_map(|child_id| self.get_node(child_id))
+                .map(|child| self.readable_node(child))
+                .collect(),
+        }
+    }
+
+    /// Serializes the forest as version-tagged CBOR, the same data as `to_json`
+    /// in a fraction of the space.
+    #[cfg(feature = "binary")]
+    pub fn to_bytes(&self) -> Result<Vec<u8>, ForestSerializationError> {
+        let mut bytes = Vec::new();
+        ciborium::ser::into_writer(&self.versioned(), &mut bytes)?;
+        Ok(bytes)
+    }
+
+    /// Reads a forest written by `to_bytes`, refusing other format versions.
+    #[cfg(feature = "binary")]
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, ForestSerializationError> {
+        ciborium::de::from_reader::<VersionedForest, _>(bytes)?.into_forest()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::subjects::math::formalism::proof::tactics::Tactic;
+    use crate::subjects::math::formalism::proof::test_support::make_nested_implication_goal;
+    use crate::turn_render::Identifier;
+
+    #[test]
+    #[cfg(feature = "binary")]
+    fn test_binary_round_trip_matches_json_round_trip() {
+        let assume = |name: &str| Tactic::AssumeImplicationAntecedent {
+            with_name: Identifier::new_simple(name.to_string()),
+        };
+        let mut forest = ProofForest::new_from_goal(make_nested_implication_goal(3));
+        let first = forest.apply_initial_tactic(assume("h1")).clone();
+        first.apply_tactic(assume("h2"), &mut forest);
+
+        let bytes = forest.to_bytes().unwrap();
+        let json = forest.to_json().unwrap();
+        assert!(bytes.len() < json.len());
+
+        let from_bytes = ProofForest::from_bytes(&bytes).unwrap();
+        let from_json = ProofForest::from_json(&json).unwrap();
+        assert_eq!(
+            serde_json::to_value(&from_bytes).unwrap(),
+            serde_json::to_value(&from_json).unwrap()
+        );
+        assert_eq!(
+            serde_json::to_value(&from_bytes).unwrap(),
+            serde_json::to_value(&forest).unwrap()
+        );
+
+        // A forest from another format version is refused
+        let mut future = Vec::new();
+        let envelope = VersionedForestRef {
+            version: PROOF_FOREST_FORMAT_VERSION + 1,
+            forest: &forest,
+        };
+        ciborium::ser::into_writer(&envelope, &mut future).unwrap();
+        assert!(matches!(
+            ProofForest::from_bytes(&future),
+            Err(ForestSerializationError::UnsupportedVersion { found, .. })
+                if found == PROOF_FOREST_FORMAT_VERSION + 1
+        ));
+    }
+
+    #[test]
+    fn test_from_json_reports_the_parser_error_and_foreign_versions() {
+        use std::error::Error as _;
+
+        let error = ProofForest::from_json("{ not json").unwrap_err();
+        assert!(matches!(error, ForestSerializationError::Json(_)));
+        assert!(error.source().is_some());
+
+        let forest = ProofForest::new_from_goal(make_nested_implication_goal(1));
+        let envelope = VersionedForestRef {
+            version: PROOF_FOREST_FORMAT_VERSION + 1,
+            forest: &forest,
+        };
+        let future = serde_json::to_string(&envelope).unwrap();
+        assert!(matches!(
+            ProofForest::from_json(&future),
+            Err(ForestSerializationError::UnsupportedVersion { found, .. })
+                if found == PROOF_FOREST_FORMAT_VERSION + 1
+        ));
+    }
+
+    #[test]
+    fn test_readable_json_nests_children_under_their_parent() {
+        let mut forest = ProofForest::new_from_goal(make_nested_implication_goal(2));
+        let root = forest
+            .apply_initial_tactic(Tactic::AssumeImplicationAntecedent {
+                with_name: Identifier::new_simple("h1".to_string()),
+            })
+            .clone();
+        root.apply_tactic(
+            Tactic::AssumeImplicationAntecedent {
+                with_name: Identifier::new_simple("h2".to_string()),
+            },
+            &mut forest,
+        );
+
+        let readable: serde_json::Value =
+            serde_json::from_str(&forest.to_readable_json().unwrap()).unwrap();
+        let roots = readable.as_array().unwrap();
+        assert_eq!(roots.len(), 1);
+        let children = roots[0]["children"].as_array().unwrap();
+        assert_eq!(children.len(), 1);
+        assert!(children[0]["tactic"].as_str().unwrap().contains("h2"));
+        assert_eq!(children[0]["role"], "goal");
+        // No node ids leak into the readable view
+        assert!(!forest.to_readable_json().unwrap().contains(&root.id));
+    }
+}
@@ -1,31 +1,40 @@
 use crate::subjects::math::formalism::automation::registry::get_theorem_registry;
 use crate::subjects::math::formalism::traits::debug::ShortDebug;
+use crate::subjects::math::formalism::traits::collect_identifier::CollectIdentifier;
 use crate::subjects::math::formalism::expressions::{MathExpression, TheoryExpression};
 use crate::subjects::math::formalism::extract::Parametrizable;
-use crate::subjects::math::formalism::location::Located;
+use crate::subjects::math::formalism::location::{Located, content_eq, content_json};
 use crate::subjects::math::formalism::objects::MathObject;
 use crate::subjects::math::formalism::proof::{
-    ContextEntry, DefinitionState, ProofGoal, Tactic,
+    ContextEntry, DefinitionState, ProofGoal, Quantifier, Tactic,
 };
 use crate::subjects::math::formalism::relations::{MathRelation, Quantification};
 use crate::subjects::math::formalism::traits::instantiable::{Instantiable, InstantiationType};
+use crate::subjects::math::formalism::traits::normalize::Normalize;
 use crate::subjects::math::formalism::traits::replace::Replace;
 use crate::subjects::math::formalism::traits::substitutable::Substitutable;
 use crate::subjects::math::formalism::traits::search::Search;
 use crate::subjects::math::formalism::theorem::Theorem;
-use crate::subjects::math::theories::groups::definitions::{Group, GroupElement, GroupExpression};
+#[cfg(feature = "groups")]
+use crate::subjects::math::theories::groups::definitions::{
+    GenericGroup, Group, GroupElement, GroupExpression, GroupHomomorphism, GroupProperty,
+    GroupRelation, ImageGroup, KernelGroup, QuotientGroup,
+};
 use crate::subjects::math::theories::VariantSet;
 use crate::subjects::math::theories::number_theory::definitions::Number as TTNumber;
 use crate::subjects::math::theories::zfc::definitions::{Set, SetProperty};
 use crate::turn_render::{Identifier, MathNode, RichText, RichTextSegment};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
 use super::{ContextOrStatement, RelationSource, RewriteDirection, Target};
-use crate::subjects::math::formalism::traits::is_compatible::SameRole;
+use crate::subjects::math::formalism::traits::is_compatible::{ExplainMismatch, SameRole};
 
 use std::thread;
 
+/// Search depth used by `Auto` when none is given.
+const DEFAULT_AUTO_DEPTH: u8 = 3;
+
 #[derive(Clone)]
 pub enum TacticApplicationResult {
     SingleGoal(ProofGoal),
@@ -78,6 +87,23 @@ impl ProofGoal {
     pub fn is_name_used(&self, name: &Identifier) -> bool {
         self.context.iter().any(|entry| &entry.name == name)
     }
+
+    /// `base` itself when no context entry or quantifier uses it, otherwise the
+    /// first of `base_1`, `base_2`, ... that is free. Only the body is renamed, so
+    /// scripts and primes carry over. Tactics that introduce names take the name
+    /// the caller asked for as the base.
+    pub fn fresh_name(&self, base: &Identifier) -> Identifier {
+        let is_taken = |name: &Identifier| {
+            self.is_name_used(name) || self.quantifiers.iter().any(|q| &q.variable_name == name)
+        };
+        std::iter::once(base.clone())
+            .chain((1..).map(|i| Identifier {
+                body: format!("{}_{}", base.body, i),
+                ..base.clone()
+            }))
+            .find(|name| !is_taken(name))
+            .unwrap()
+    }
 }
 
 /// Core tactic application system - implements all tactic rules
@@ -131,11 +157,14 @@ impl Tactic {
             Tactic::CaseAnalysis { .. } => {
                 TacticApplicationResult::Error("CaseAnalysis not implemented".to_string())
             }
+            Tactic::CaseOnMembership { element, set } => {
+                Self::apply_case_on_membership(goal, element, set)
+            }
             Tactic::Induction {
                 variable_name,
                 hypothesis_name,
             } => {
-                let base_case_value = MathExpression::Number(TTNumber {});
+                let base_case_value = MathExpression::Number(TTNumber::int(0));
                 Self::apply_induction(
                     goal,
                     // Since we target the whole statement for induction, we pass the statement's ID
@@ -149,6 +178,8 @@ impl Tactic {
                 target_quantifier,
                 witness,
             } => Self::apply_provide_witness(goal, target_quantifier, witness),
+            Tactic::Have { name, statement } => Self::apply_have(goal, name, statement),
+            Tactic::Suffices { statement } => Self::apply_suffices(goal, statement),
             Tactic::SplitAssumptionConjunction {
                 target_hypothesis,
                 with_names,
@@ -174,6 +205,7 @@ impl Tactic {
                                     "From splitting {}",
                                     target_hypothesis.to_string()
                                 ))),
+                                is_internal: false,
                             };
                             new_goal.context.push(new_entry);
                                 }
@@ -196,8 +228,11 @@ impl Tactic {
             ),
 
             Tactic::ByRelation(source) => Self::apply_exact_with(goal, source),
+            Tactic::Assumption => Self::apply_assumption(goal),
+            Tactic::Apply { source } => Self::apply_lemma(goal, source),
 
             Tactic::ByReflexivity => Self::apply_reflexivity(goal),
+            Tactic::Trivial => Self::apply_trivial(goal),
             Tactic::ByContradiction {
                 hypothesis1,
                 hypothesis2,
@@ -232,6 +267,7 @@ impl Tactic {
                         ty: goal_relation_as_expr,
                         definition: DefinitionState::Abstract,
                         description: None,
+                        is_internal: false,
                     };
                     if Self::entries_contradict(entry, &contradictory_entry) {
                         TacticApplicationResult::ProofComplete
@@ -260,6 +296,11 @@ impl Tactic {
                     Self::apply_rewrite_with_theorem(goal, target, id, *node_index, direction, &instantiations)
                 }
             },
+            Tactic::RewriteSides {
+                left_rule,
+                right_rule,
+            } => Self::apply_rewrite_sides(goal, left_rule.as_ref(), right_rule.as_ref()),
+            Tactic::RewriteChain { equalities } => Self::apply_rewrite_chain(goal, equalities),
             Tactic::UnfoldDefinition {
                 definition_to_unfold,
                 target,
@@ -279,14 +320,29 @@ impl Tactic {
             Tactic::Revert {
                 hypothesis_to_revert,
             } => todo!(),
+            #[cfg(feature = "groups")]
+            Tactic::ApplyFirstIsomorphism {
+                homomorphism,
+                with_name,
+            } => Self::apply_first_isomorphism(goal, homomorphism, with_name),
+            #[cfg(feature = "groups")]
+            Tactic::UnfoldGroupConstruction { on_variable } => {
+                Self::apply_unfold_group_construction(goal, on_variable)
+            }
+            #[cfg(feature = "groups")]
+            Tactic::AssertProperty {
+                on_variable,
+                property,
+            } => Self::apply_assert_property(goal, on_variable, property),
             Tactic::SearchAssumptions => todo!(),
-            Tactic::SearchTheoremLibrary => todo!(),
+            Tactic::SearchTheoremLibrary => Self::apply_search_theorem_library(goal),
             Tactic::Search => todo!(),
-            Tactic::Simplify { target } => todo!(),
+            Tactic::Simplify { target } => Self::apply_simplify(goal, target),
+            Tactic::Normalize { target } => Self::apply_normalize(goal, target),
             Tactic::Auto {
                 depth,
                 with_tactics,
-            } => todo!(),
+            } => Self::apply_auto(goal, *depth, with_tactics),
             Tactic::DisproveByTheorem { theorem_id } => todo!(),
         }
     }
@@ -370,6 +426,7 @@ impl Tactic {
                     parameters: HashMap::new(),
                     description: entry.name.to_string(),
                     membership_condition: "".to_string(),
+                    membership_predicate: None,
                     properties: VariantSet::new(),
                 })));
 
@@ -386,6 +443,7 @@ impl Tactic {
                         parameters: HashMap::new(),
                         description: entry.name.to_string(),
                         membership_condition: "".to_string(),
+                        membership_predicate: None,
                         properties: VariantSet::new(),
                     })));
 
@@ -454,6 +512,7 @@ impl Tactic {
                     parameters: HashMap::new(),
             description: with_name.to_string(),
                     membership_condition: String::new(),
+                    membership_predicate: None,
                     properties: VariantSet::new(),
         })));
 
@@ -521,6 +580,7 @@ impl Tactic {
                 description: Some(RichText::text(format!(
                     "Witness provided for existential quantifier"
                 ))),
+                is_internal: true,
             };
             new_goal.context.push(witness_entry);
             
@@ -596,9 +656,15 @@ impl Tactic {
             if instantiated_arc == goal_arc {
             TacticApplicationResult::ProofComplete
         } else {
-            TacticApplicationResult::Error(
-                "Provided statement does not exactly match goal.".to_string(),
-            )
+            match goal_arc.explain_mismatch(&goal.context, instantiated_arc, &theorem_context) {
+                Some(mismatch) => TacticApplicationResult::Error(format!(
+                    "Provided statement does not exactly match goal: {}",
+                    mismatch
+                )),
+                None => TacticApplicationResult::Error(
+                    "Provided statement does not exactly match goal.".to_string(),
+                ),
+            }
             }
         } else {
             TacticApplicationResult::Error("Cannot compare non-concrete statements".to_string())
@@ -638,27 +704,288 @@ impl Tactic {
         instantiations: &HashMap<Identifier, Identifier>,
     ) -> TacticApplicationResult {
         let registry = get_theorem_registry();
-        if let Some(theorem) = registry.get(theorem_id) {
-            let theorem_statement = &theorem.proofs.initial_goal.statement;
-            let theorem_context = &theorem.proofs.initial_goal.context;
-            if let Some(theorem_arc) = theorem_statement.concrete_value() {
-            Self::find_and_apply_rewrite(
+        let Some(theorem) = registry.get(theorem_id) else {
+            return TacticApplicationResult::Error(format!("Theorem {} not found", theorem_id));
+        };
+        let theorem_context = &theorem.proofs.initial_goal.context;
+        let Some(theorem_arc) = theorem.proofs.initial_goal.statement.concrete_value() else {
+            return TacticApplicationResult::Error("Theorem statement is not concrete".to_string());
+        };
+
+        // The theorem's universals live in its context as abstract entries, so matching
+        // the conclusion against the target binds them to the goal's subexpressions.
+        let (hypotheses, conclusion) =
+            Self::rewrite_conclusion(theorem_arc.as_ref(), theorem_context);
+        if hypotheses.is_empty() {
+            return Self::find_and_apply_rewrite(
                 goal,
                 target,
                 direction,
-                    theorem_arc.as_ref(),
+                &conclusion,
                 theorem_context,
                 node_index,
                 instantiations,
-            )
-            } else {
-                TacticApplicationResult::Error("Theorem statement is not concrete".to_string())
+            );
+        }
+
+        // A conditional rule only holds where its hypotheses do, so they are
+        // instantiated at the rewritten subterm and returned as side goals.
+        let Some(pattern) =
+            Self::rewrite_pattern(&conclusion, direction, node_index, theorem_context)
+        else {
+            return TacticApplicationResult::Error(format!(
+                "Theorem {} is conditional; rewriting with it needs a single equation \
+                 (select a conjunct of its conclusion)",
+                theorem_id
+            ));
+        };
+        let new_goal = match Self::find_and_apply_rewrite(
+            goal,
+            target,
+            direction,
+            &conclusion,
+            theorem_context,
+            node_index,
+            instantiations,
+        ) {
+            TacticApplicationResult::SingleGoal(new_goal) => new_goal,
+            other => return other,
+        };
+        let Some(matched) = Self::select_rewrite_match(goal, target, &pattern, theorem_context)
+            .and_then(|id| {
+                let statement = goal.statement.data.unwrap(&goal.context);
+                statement.get_located::<MathExpression>(id)
+            })
+        else {
+            return TacticApplicationResult::Error(format!(
+                "Cannot instantiate the hypotheses of {} at the rewritten subterm",
+                theorem_id
+            ));
+        };
+        let mut bindings = matched.instantiate(&goal.context, &pattern, theorem_context);
+        for (theorem_var, goal_var) in instantiations {
+            bindings.insert(
+                theorem_var.clone(),
+                InstantiationType::Identifier(goal_var.clone()),
+            );
+        }
+        match Self::hypothesis_goals(goal, &hypotheses, &bindings, theorem_context) {
+            Ok(side_goals) => TacticApplicationResult::MultiGoal(
+                std::iter::once(new_goal).chain(side_goals).collect(),
+            ),
+            Err(message) => TacticApplicationResult::Error(message),
+        }
+    }
+
+    /// Instantiates a lemma's hypotheses with the bindings found by matching its
+    /// conclusion, giving one goal per hypothesis over the context of `goal`.
+    fn hypothesis_goals(
+        goal: &ProofGoal,
+        hypotheses: &[Located<MathRelation>],
+        bindings: &HashMap<Identifier, InstantiationType>,
+        lemma_context: &Vec<ContextEntry>,
+    ) -> Result<Vec<ProofGoal>, String> {
+        let Some(goal_arc) = goal.statement.concrete_value() else {
+            return Err("Goal statement is not concrete".to_string());
+        };
+        let goal_located = Located::new_concrete(MathExpression::Relation(goal_arc.clone()));
+        hypotheses
+            .iter()
+            .map(|hypothesis| {
+                let Some(arc) = hypothesis.concrete_value() else {
+                    return Err("Lemma hypothesis is not concrete".to_string());
+                };
+                match MathExpression::Relation(arc.clone()).substitute(
+                    bindings,
+                    &goal_located,
+                    lemma_context,
+                ) {
+                    MathExpression::Relation(instantiated) => {
+                        let mut sub_goal = goal.clone();
+                        sub_goal.statement = Located::from_arc(instantiated);
+                        Ok(sub_goal)
+                    }
+                    _ => Err("Lemma hypothesis did not instantiate to a relation".to_string()),
+                }
+            })
+            .collect()
+    }
+
+    /// Rewrites each side of an equality goal with its own rule, in one step.
+    /// The sides are targeted by id so that a rule cannot reach across to the
+    /// other side; the goal closes if the result is reflexive.
+    fn apply_rewrite_sides(
+        goal: &ProofGoal,
+        left_rule: Option<&RelationSource>,
+        right_rule: Option<&RelationSource>,
+    ) -> TacticApplicationResult {
+        let side_id = |goal: &ProofGoal, left_side: bool| match goal.statement.concrete_value() {
+            Some(statement) => match statement.as_ref() {
+                MathRelation::Equal { left, right } => {
+                    Some(if left_side { left.id.clone() } else { right.id.clone() })
+                }
+                _ => None,
+            },
+            None => None,
+        };
+
+        let mut current = goal.clone();
+        for (rule, left_side) in [(left_rule, true), (right_rule, false)] {
+            let Some(rule) = rule else {
+                continue;
+            };
+            let Some(target_id) = side_id(&current, left_side) else {
+                return TacticApplicationResult::Error("Goal is not an equality.".to_string());
+            };
+            let rewrite = Tactic::Rewrite {
+                using_rule: rule.clone(),
+                target: Target::new(ContextOrStatement::Statement, target_id),
+                direction: RewriteDirection::Forward,
+                instantiations: HashMap::new(),
+            };
+            current = match rewrite.apply_to_goal(&current) {
+                TacticApplicationResult::SingleGoal(new_goal) => new_goal,
+                TacticApplicationResult::Error(message) => {
+                    let side = if left_side { "left" } else { "right" };
+                    return TacticApplicationResult::Error(format!(
+                        "Rewriting the {} side failed: {}",
+                        side, message
+                    ));
+                }
+                other => {
+                    return TacticApplicationResult::Error(format!(
+                        "Unexpected rewrite result: {:?}",
+                        other
+                    ));
+                }
+            };
+        }
+        if side_id(&current, true).is_none() {
+            return TacticApplicationResult::Error("Goal is not an equality.".to_string());
+        }
+
+        match Self::apply_reflexivity(&current) {
+            TacticApplicationResult::ProofComplete => TacticApplicationResult::ProofComplete,
+            _ => TacticApplicationResult::SingleGoal(current),
+        }
+    }
+
+    fn apply_rewrite_chain(goal: &ProofGoal, equalities: &[Identifier]) -> TacticApplicationResult {
+        let left_id = |goal: &ProofGoal| match goal.statement.concrete_value() {
+            Some(statement) => match statement.as_ref() {
+                MathRelation::Equal { left, .. } => Some(left.id.clone()),
+                _ => None,
+            },
+            None => None,
+        };
+        if equalities.is_empty() {
+            return TacticApplicationResult::Error("No equalities to chain.".to_string());
+        }
+
+        let mut current = goal.clone();
+        for (step, equality) in equalities.iter().enumerate() {
+            let Some(target_id) = left_id(&current) else {
+                return TacticApplicationResult::Error("Goal is not an equality.".to_string());
+            };
+            let rewrite = Tactic::Rewrite {
+                using_rule: RelationSource::LocalAssumption(equality.clone()),
+                target: Target::new(ContextOrStatement::Statement, target_id),
+                direction: RewriteDirection::Forward,
+                instantiations: HashMap::new(),
+            };
+            current = match rewrite.apply_to_goal(&current) {
+                TacticApplicationResult::SingleGoal(new_goal) => new_goal,
+                TacticApplicationResult::Error(message) => {
+                    return TacticApplicationResult::Error(format!(
+                        "Step {} of the chain ({}) failed: {}",
+                        step + 1,
+                        equality,
+                        message
+                    ));
+                }
+                other => {
+                    return TacticApplicationResult::Error(format!(
+                        "Unexpected rewrite result: {:?}",
+                        other
+                    ));
+                }
+            };
+        }
+
+        match Self::apply_reflexivity(&current) {
+            TacticApplicationResult::ProofComplete => TacticApplicationResult::ProofComplete,
+            _ => TacticApplicationResult::SingleGoal(current),
+        }
+    }
+
+    /// Splits a theorem statement into the hypotheses and the part that acts as a
+    /// rewrite rule. For `H → (a = b)` or `H → (P ↔ Q)` the rule is the equation in
+    /// the conclusion and `H` is returned as a hypothesis; any other statement is used
+    /// as-is (an implication whose conclusion is not an equation still rewrites its
+    /// antecedent into its consequent).
+    fn rewrite_conclusion(
+        statement: &MathRelation,
+        context: &Vec<ContextEntry>,
+    ) -> (Vec<Located<MathRelation>>, MathRelation) {
+        if let MathRelation::Implies(antecedent, consequent) = statement {
+            let conclusion = consequent.data.clone().unwrap(context);
+            if matches!(
+                conclusion,
+                MathRelation::Equal { .. } | MathRelation::Equivalent(..) | MathRelation::And(_)
+            ) {
+                let (mut hypotheses, rule) = Self::rewrite_conclusion(&conclusion, context);
+                hypotheses.insert(0, antecedent.clone());
+                return (hypotheses, rule);
+            }
+        }
+        (vec![], statement.clone())
+    }
+
+    /// The side of an equational rule that is matched against the target, looking
+    /// into the selected conjunct of a conjunction.
+    fn rewrite_pattern(
+        rule: &MathRelation,
+        direction: &RewriteDirection,
+        node_index: Option<usize>,
+        context: &Vec<ContextEntry>,
+    ) -> Option<Located<MathExpression>> {
+        match rule {
+            MathRelation::Equal { left, right } => {
+                Some(if *direction == RewriteDirection::Forward {
+                    left.clone()
+                } else {
+                    right.clone()
+                })
+            }
+            MathRelation::And(conjuncts) => {
+                let conjunct = conjuncts.get(node_index?)?.data.clone().unwrap(context);
+                Self::rewrite_pattern(&conjunct, direction, None, context)
             }
+            _ => None,
+        }
+    }
+
+    /// The subterm an equational rewrite applies to: the target itself when the
+    /// pattern matches it, otherwise the match with the smallest id, so that
+    /// repeated lookups agree.
+    fn select_rewrite_match(
+        goal: &ProofGoal,
+        target: &Target,
+        pattern: &Located<MathExpression>,
+        rule_context: &Vec<ContextEntry>,
+    ) -> Option<String> {
+        let matches = goal.statement.data.unwrap(&goal.context).find_matches(
+            target.clone(),
+            goal.statement.id.clone(),
+            &goal.context,
+            pattern,
+            rule_context,
+            false,
+        );
+        if matches.contains(&target.id) {
+            Some(target.id.clone())
         } else {
-            TacticApplicationResult::Error(format!(
-                "Theorem {} not found or not an equality.",
-                theorem_id
-            ))
+            matches.into_iter().min()
         }
     }
 
@@ -688,35 +1015,24 @@ impl Tactic {
                 // let replacement = replacement_loc.data.unwrap(rule_context);
 
                 // search if the pattern match the target expression
-                
-                let matches = goal.statement.data.unwrap(&goal.context).find_matches(
-                    target.clone(),
-                    goal.statement.id.clone(),
-                    &goal.context,
-                        pattern_loc,  // ✅ Pass Located<MathExpression>
-                    rule_context,
-                    false,
-                );
-                
-                // ✅ FIXED: Allow multiple matches, only reject when no matches found
-                if matches.len() == 0 {
-                    return TacticApplicationResult::Error(format!(
-                        "Pattern does not match target expression in goal. Found {} matches.",
-                        matches.len()
+                // Select the match: the target itself if it matches, otherwise the
+                // match with the smallest id, so the choice is deterministic
+                let Some(selected_match) =
+                    Self::select_rewrite_match(goal, target, pattern_loc, rule_context)
+                else {
+                    return TacticApplicationResult::Error(Self::rewrite_mismatch_error(
+                        goal,
+                        target,
+                        pattern_loc,
+                        rule_context,
                     ));
-                }
-                // Select the best match: prefer target.id if available, otherwise first match
-                let selected_match = if matches.contains(&target.id) {
-                    &target.id
-                } else {
-                    matches.iter().next().unwrap()
                 };
 
                 // replace the target expression with the replacement expression
                 let mut new_goal = goal.clone();
                 let new_statement = goal.statement.replace(
                     &new_goal.statement.id,
-                        selected_match,
+                        &selected_match,
                     &new_goal.context,
                         pattern_loc,      // ✅ Pass Located<MathExpression>
                         replacement_loc,  // ✅ Pass Located<MathExpression>
@@ -764,9 +1080,11 @@ impl Tactic {
 
                 // ✅ FIXED: Allow multiple matches, only reject when no matches found
                 if matches.len() == 0 {
-                    return TacticApplicationResult::Error(format!(
-                        "Pattern does not match target expression in goal. Found {} matches.",
-                        matches.len()
+                    return TacticApplicationResult::Error(Self::rewrite_mismatch_error(
+                        goal,
+                        target,
+                        &pattern_loc,
+                        rule_context,
                     ));
                 }
 
@@ -869,9 +1187,11 @@ impl Tactic {
                 );
 
                 if matches.len() == 0 {
-                    return TacticApplicationResult::Error(format!(
-                        "Pattern does not match target expression in goal. Found {} matches.",
-                        matches.len()
+                    return TacticApplicationResult::Error(Self::rewrite_mismatch_error(
+                        goal,
+                        target,
+                        &pattern_located,
+                        rule_context,
                     ));
                 }
 
@@ -900,18 +1220,54 @@ impl Tactic {
         }
     }
 
+    /// The error for a rewrite whose pattern matches nowhere in its target,
+    /// explaining where the pattern first diverges from the targeted expression.
+    fn rewrite_mismatch_error(
+        goal: &ProofGoal,
+        target: &Target,
+        pattern: &Located<MathExpression>,
+        pattern_context: &Vec<ContextEntry>,
+    ) -> String {
+        let Some(statement) = goal.statement.concrete_value() else {
+            return "Pattern does not match target expression in goal.".to_string();
+        };
+        // The target is either one side of an equality goal or the whole statement
+        let targeted = match statement.as_ref() {
+            MathRelation::Equal { left, .. } if left.id == target.id => {
+                left.data.unwrap(&goal.context)
+            }
+            MathRelation::Equal { right, .. } if right.id == target.id => {
+                right.data.unwrap(&goal.context)
+            }
+            _ => MathExpression::Relation(statement.clone()),
+        };
+        match targeted.explain_mismatch(
+            &goal.context,
+            &pattern.data.unwrap(pattern_context),
+            pattern_context,
+        ) {
+            Some(mismatch) => format!(
+                "Pattern does not match target expression in goal: {}",
+                mismatch
+            ),
+            None => "Pattern does not match target expression in goal.".to_string(),
+        }
+    }
+
     fn apply_assume_implication_antecedent(
         goal: &ProofGoal,
         hypothesis_name: &Identifier,
     ) -> TacticApplicationResult {
+        let hypothesis_name = goal.fresh_name(hypothesis_name);
         if let Some(statement_arc) = goal.statement.concrete_value() {
             if let MathRelation::Implies(antecedent, consequent) = statement_arc.as_ref() {
             let antecedent_ty = antecedent.data.clone().unwrap(&goal.context);
             let new_entry = ContextEntry {
-                name: hypothesis_name.clone(),
+                name: hypothesis_name,
                     ty: Located::new_concrete(MathExpression::Relation(Arc::new(antecedent_ty))),
                 definition: DefinitionState::Abstract,
                 description: Some(RichText::text("Result of assuming antecedent".to_string())),
+                is_internal: false,
             };
             let mut new_context = goal.context.clone();
             new_context.push(new_entry);
@@ -930,16 +1286,826 @@ impl Tactic {
         }
     }
 
+    #[cfg(feature = "groups")]
+    fn apply_first_isomorphism(
+        goal: &ProofGoal,
+        homomorphism: &Identifier,
+        with_name: &Identifier,
+    ) -> TacticApplicationResult {
+        if goal.is_name_used(with_name) {
+            return TacticApplicationResult::Error(format!(
+                "Name '{}' is already used in the context.",
+                with_name
+            ));
+        }
+        let Some(entry) = goal.context.iter().find(|e| &e.name == homomorphism) else {
+            return TacticApplicationResult::Error(format!(
+                "'{}' is not in the context.",
+                homomorphism
+            ));
+        };
+        let hom = match entry.ty.data.unwrap(&goal.context) {
+            MathExpression::Expression(TheoryExpression::Group(GroupExpression::Homomorphism(
+                hom,
+            ))) => hom.data.unwrap(&goal.context),
+            _ => {
+                return TacticApplicationResult::Error(format!(
+                    "'{}' is not a group homomorphism.",
+                    homomorphism
+                ));
+            }
+        };
+
+        // G/ker(φ) ≅ im(φ), with φ referring back to the context entry
+        let phi = || Located::new_variable(homomorphism.clone());
+        let quotient = Group::Quotient(QuotientGroup {
+            core: GenericGroup::default(),
+            group: hom.domain.clone(),
+            normal_subgroup: Located::new_concrete(Group::Kernel(KernelGroup {
+                core: GenericGroup::default(),
+                defining_homomorphism: phi(),
+            })),
+            quotient_props: VariantSet::new(),
+        });
+        let image = Group::Image(ImageGroup {
+            core: GenericGroup::default(),
+            defining_homomorphism: phi(),
+        });
+        let isomorphism = MathRelation::GroupTheory(GroupRelation::IsIsomorphicTo {
+            first: Located::new_concrete(quotient),
+            second: Located::new_concrete(image),
+        });
+
+        let mut new_goal = goal.clone();
+        new_goal.context.push(ContextEntry {
+            name: with_name.clone(),
+            ty: Located::new_concrete(MathExpression::Relation(Arc::new(isomorphism))),
+            definition: DefinitionState::Abstract,
+            description: Some(RichText::text(format!(
+                "First isomorphism theorem applied to {}",
+                homomorphism
+            ))),
+            is_internal: false,
+        });
+        TacticApplicationResult::SingleGoal(new_goal)
+    }
+
+    #[cfg(feature = "groups")]
+    fn apply_unfold_group_construction(
+        goal: &ProofGoal,
+        on_variable: &Identifier,
+    ) -> TacticApplicationResult {
+        let Some(index) = goal.context.iter().position(|e| &e.name == on_variable) else {
+            return TacticApplicationResult::Error(format!(
+                "'{}' is not in the context.",
+                on_variable
+            ));
+        };
+        let group = match goal.context[index].ty.data.unwrap(&goal.context) {
+            MathExpression::Object(object) => match object.as_ref() {
+                MathObject::Group(group) => Some(group.clone()),
+                _ => None,
+            },
+            _ => None,
+        };
+        let Some(group) = group else {
+            return TacticApplicationResult::Error(format!("'{}' is not a group.", on_variable));
+        };
+        if !matches!(
+            group,
+            Group::Kernel(_) | Group::Image(_) | Group::Center(_) | Group::Normalizer(_)
+        ) {
+            return TacticApplicationResult::Error(format!(
+                "'{}' is a {} group, which has no definition to unfold.",
+                on_variable,
+                group.get_variant_name()
+            ));
+        }
+
+        // The construction stays in the context; only memberships `g ∈ K` in the
+        // statement and hypotheses are replaced by the condition defining K.
+        let mut new_goal = goal.clone();
+        let mut changed = false;
+
+        if let Some(statement) = goal.statement.concrete_value() {
+            // `g ∈ im φ` needs a preimage, so the goal becomes `∃w. φ(w) = g`
+            let unfolded = match Self::image_membership(statement, on_variable, &group) {
+                Some(element) => {
+                    let witness = goal.fresh_name(&Identifier::new_simple("w".to_string()));
+                    let Some(entry) = Self::image_witness_entry(&group, &witness, &goal.context)
+                    else {
+                        return TacticApplicationResult::Error(
+                            Self::unresolved_homomorphism_message(on_variable),
+                        );
+                    };
+                    new_goal.context.push(entry);
+                    new_goal.quantifiers.push(Quantifier {
+                        variable_name: witness.clone(),
+                        quantification: Quantification::Existential,
+                    });
+                    group.membership_condition(element, &witness, &goal.context)
+                }
+                None => {
+                    match Self::unfold_memberships(statement, on_variable, &group, &goal.context) {
+                        Ok(unfolded) => unfolded,
+                        Err(message) => return TacticApplicationResult::Error(message),
+                    }
+                }
+            };
+            if let Some(unfolded) = unfolded {
+                new_goal.statement = Located {
+                    id: goal.statement.id.clone(),
+                    data: Parametrizable::Concrete(Arc::new(unfolded)),
+                };
+                changed = true;
+            }
+        }
+
+        let mut index = 0;
+        while index < new_goal.context.len() {
+            let ty = new_goal.context[index].ty.concrete_value().map(Arc::as_ref);
+            let Some(MathExpression::Relation(hypothesis)) = ty else {
+                index += 1;
+                continue;
+            };
+            let hypothesis = hypothesis.clone();
+            // A hypothesis `g ∈ im φ` is eliminated into a preimage `w` with `φ(w) = g`
+            let unfolded = match Self::image_membership(&hypothesis, on_variable, &group) {
+                Some(element) => {
+                    let witness = new_goal.fresh_name(&Identifier::new_simple("w".to_string()));
+                    let Some(entry) =
+                        Self::image_witness_entry(&group, &witness, &new_goal.context[..index])
+                    else {
+                        return TacticApplicationResult::Error(
+                            Self::unresolved_homomorphism_message(on_variable),
+                        );
+                    };
+                    new_goal.context.insert(index, entry);
+                    index += 1;
+                    group.membership_condition(element, &witness, &new_goal.context[..index])
+                }
+                None => match Self::unfold_memberships(
+                    &hypothesis,
+                    on_variable,
+                    &group,
+                    &new_goal.context[..index],
+                ) {
+                    Ok(unfolded) => unfolded,
+                    Err(message) => return TacticApplicationResult::Error(message),
+                },
+            };
+            if let Some(unfolded) = unfolded {
+                new_goal.context[index].ty = Located {
+                    id: new_goal.context[index].ty.id.clone(),
+                    data: Parametrizable::Concrete(Arc::new(MathExpression::Relation(Arc::new(
+                        unfolded,
+                    )))),
+                };
+                changed = true;
+            }
+            index += 1;
+        }
+
+        if !changed {
+            return TacticApplicationResult::NoChange;
+        }
+        TacticApplicationResult::SingleGoal(new_goal)
+    }
+    /// The element `g` when `relation` is exactly `g ∈ on_variable` and the
+    /// group is an image, whose membership needs a preimage to unfold.
+    #[cfg(feature = "groups")]
+    fn image_membership<'a>(
+        relation: &'a MathRelation,
+        on_variable: &Identifier,
+        group: &Group,
+    ) -> Option<&'a Located<GroupExpression>> {
+        match (relation, group) {
+            (
+                MathRelation::GroupTheory(GroupRelation::ElementOf { element, group: of }),
+                Group::Image(_),
+            ) if of.variable_id() == Some(on_variable) => Some(element),
+            _ => None,
+        }
+    }
+
+    /// Declares `witness` as an element of the domain of the homomorphism whose
+    /// image is `group`.
+    #[cfg(feature = "groups")]
+    fn image_witness_entry(
+        group: &Group,
+        witness: &Identifier,
+        context: &[ContextEntry],
+    ) -> Option<ContextEntry> {
+        let Group::Image(image) = group else {
+            return None;
+        };
+        let homomorphism = GroupHomomorphism::resolve(&image.defining_homomorphism, context)?;
+        Some(ContextEntry {
+            name: witness.clone(),
+            ty: Located::new_concrete(MathExpression::Expression(TheoryExpression::Group(
+                GroupExpression::Element {
+                    group: homomorphism.domain,
+                    element: None,
+                },
+            ))),
+            definition: DefinitionState::Abstract,
+            description: Some(RichText::text(
+                "A preimage chosen by unfolding an image".to_string(),
+            )),
+            is_internal: false,
+        })
+    }
+
+    /// Replaces every `g ∈ on_variable` inside `relation`, looking through the
+    /// logical connectives, with the condition defining the construction `group`.
+    /// Returns `Ok(None)` when `relation` has no such membership.
+    #[cfg(feature = "groups")]
+    fn unfold_memberships(
+        relation: &MathRelation,
+        on_variable: &Identifier,
+        group: &Group,
+        context: &[ContextEntry],
+    ) -> Result<Option<MathRelation>, String> {
+        let unfold =
+            |located: &Located<MathRelation>| -> Result<Option<Located<MathRelation>>, String> {
+                let Some(inner) = located.concrete_value() else {
+                    return Ok(None);
+                };
+                Ok(
+                    Self::unfold_memberships(inner, on_variable, group, context)?.map(|unfolded| {
+                        Located {
+                            id: located.id.clone(),
+                            data: Parametrizable::Concrete(Arc::new(unfolded)),
+                        }
+                    }),
+                )
+            };
+        match relation {
+            MathRelation::GroupTheory(GroupRelation::ElementOf { element, group: of })
+                if of.variable_id() == Some(on_variable) =>
+            {
+                if let Group::Image(_) = group {
+                    return Err(format!(
+                        "Membership in the image '{}' can only be unfolded when it is the whole statement or hypothesis.",
+                        on_variable
+                    ));
+                }
+                // Only images use the witness
+                match group.membership_condition(element, on_variable, context) {
+                    Some(condition) => Ok(Some(condition)),
+                    None => Err(Self::unresolved_homomorphism_message(on_variable)),
+                }
+            }
+            MathRelation::And(parts) | MathRelation::Or(parts) => {
+                let mut changed = false;
+                let mut new_parts = Vec::with_capacity(parts.len());
+                for part in parts {
+                    match unfold(part)? {
+                        Some(unfolded) => {
+                            changed = true;
+                            new_parts.push(unfolded);
+                        }
+                        None => new_parts.push(part.clone()),
+                    }
+                }
+                if !changed {
+                    return Ok(None);
+                }
+                Ok(Some(match relation {
+                    MathRelation::And(_) => MathRelation::And(new_parts),
+                    _ => MathRelation::Or(new_parts),
+                }))
+            }
+            MathRelation::Not(inner) => Ok(unfold(inner)?.map(MathRelation::Not)),
+            MathRelation::Implies(left, right) | MathRelation::Equivalent(left, right) => {
+                let (new_left, new_right) = (unfold(left)?, unfold(right)?);
+                if new_left.is_none() && new_right.is_none() {
+                    return Ok(None);
+                }
+                let new_left = new_left.unwrap_or_else(|| left.clone());
+                let new_right = new_right.unwrap_or_else(|| right.clone());
+                Ok(Some(match relation {
+                    MathRelation::Implies(..) => MathRelation::Implies(new_left, new_right),
+                    _ => MathRelation::Equivalent(new_left, new_right),
+                }))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    #[cfg(feature = "groups")]
+    fn unresolved_homomorphism_message(on_variable: &Identifier) -> String {
+        format!(
+            "The homomorphism defining '{}' is not declared in the context.",
+            on_variable
+        )
+    }
+
     fn apply_simplify(goal: &ProofGoal, target: &Target) -> TacticApplicationResult {
-        // This is a placeholder for a much more complex simplification engine.
-        // let mut new_goal = goal.clone();
-        // if let Some(located_relation) = target.locate_in_goal_mut(&mut new_goal) {
-        //     located_relation.data = Self::apply_basic_simplifications(&located_relation.data);
-        //     TacticApplicationResult::SingleGoal(new_goal)
-        // } else {
-        //     TacticApplicationResult::Error("Target for simplification not found.".to_string())
-        // }
-        todo!()
+        // The context entry whose type holds the target, or None for the statement
+        let entry_index = match &target.scope {
+            ContextOrStatement::Statement => None,
+            ContextOrStatement::Context(name, None) => {
+                match goal.context.iter().position(|entry| &entry.name == name) {
+                    Some(index) => Some(index),
+                    None => {
+                        return TacticApplicationResult::Error(format!(
+                            "Context entry '{}' for simplification not found.",
+                            name.body
+                        ));
+                    }
+                }
+            }
+            ContextOrStatement::Context(name, Some(_)) => {
+                return TacticApplicationResult::Error(format!(
+                    "Simplify cannot target a secondary type of context entry '{}'.",
+                    name.body
+                ));
+            }
+            ContextOrStatement::Both => {
+                return TacticApplicationResult::Error(
+                    "Simplify needs a single scope: the statement or one context entry."
+                        .to_string(),
+                );
+            }
+        };
+
+        let found = match entry_index {
+            Some(index) => goal.context[index]
+                .ty
+                .get_located::<MathExpression>(target.id.clone()),
+            None => goal
+                .statement
+                .get_located::<MathExpression>(target.id.clone()),
+        };
+        let Some(located) = found else {
+            return TacticApplicationResult::Error(format!(
+                "Target '{}' for simplification not found.",
+                target.id
+            ));
+        };
+
+        let expr = located.data.unwrap(&goal.context);
+        let set = match &expr {
+            MathExpression::Object(obj) => match obj.as_ref() {
+                MathObject::Set(set) => set,
+                _ => {
+                    return TacticApplicationResult::Error(
+                        "Simplify currently only handles set expressions.".to_string(),
+                    );
+                }
+            },
+            _ => {
+                return TacticApplicationResult::Error(
+                    "Simplify currently only handles set expressions.".to_string(),
+                );
+            }
+        };
+
+        let simplified = set.simplify();
+        if simplified == *set {
+            return TacticApplicationResult::NoChange;
+        }
+
+        // The pattern is a bare meta-variable so the target is swapped out wholesale.
+        let pattern = Located::new_variable(Identifier::new_simple("S".to_string()));
+        let replacement = Located {
+            id: located.id.clone(),
+            data: Parametrizable::Concrete(Arc::new(MathExpression::Object(Arc::new(
+                MathObject::Set(simplified),
+            )))),
+        };
+
+        let mut new_goal = goal.clone();
+        match entry_index {
+            Some(index) => {
+                let ty = &goal.context[index].ty;
+                new_goal.context[index].ty = ty.replace(
+                    &ty.id,
+                    &located.id,
+                    &goal.context,
+                    &pattern,
+                    &replacement,
+                    &vec![],
+                    &HashMap::new(),
+                );
+            }
+            None => {
+                new_goal.statement = goal.statement.replace(
+                    &goal.statement.id,
+                    &located.id,
+                    &goal.context,
+                    &pattern,
+                    &replacement,
+                    &vec![],
+                    &HashMap::new(),
+                );
+            }
+        }
+        TacticApplicationResult::SingleGoal(new_goal)
+    }
+
+    /// Splits the goal into the lemma `statement` and the original goal with the
+    /// lemma available as the hypothesis `name`.
+    fn apply_have(
+        goal: &ProofGoal,
+        name: &Identifier,
+        statement: &MathRelation,
+    ) -> TacticApplicationResult {
+        if goal.is_name_used(name) {
+            return TacticApplicationResult::Error(format!(
+                "Name '{}' is already used in the context.",
+                name
+            ));
+        }
+
+        let mut lemma = goal.clone();
+        lemma.statement = Located::new_concrete(statement.clone());
+
+        let mut continuation = goal.clone();
+        continuation.context.push(ContextEntry {
+            name: name.clone(),
+            ty: Located::new_concrete(MathExpression::Relation(Arc::new(statement.clone()))),
+            definition: DefinitionState::Abstract,
+            description: Some(RichText::text(format!("Lemma {} established by have", name))),
+            is_internal: false,
+        });
+
+        TacticApplicationResult::MultiGoal(vec![lemma, continuation])
+    }
+
+    /// Splits the goal into a justification of `G has property` and the original
+    /// goal with that fact added to the context.
+    #[cfg(feature = "groups")]
+    fn apply_assert_property(
+        goal: &ProofGoal,
+        on_variable: &Identifier,
+        property: &GroupProperty,
+    ) -> TacticApplicationResult {
+        let Some(entry) = goal.context.iter().find(|e| &e.name == on_variable) else {
+            return TacticApplicationResult::Error(format!(
+                "'{}' is not in the context.",
+                on_variable
+            ));
+        };
+        let is_group = match entry.ty.data.unwrap(&goal.context) {
+            MathExpression::Object(object) => matches!(object.as_ref(), MathObject::Group(_)),
+            _ => false,
+        };
+        if !is_group {
+            return TacticApplicationResult::Error(format!("'{}' is not a group.", on_variable));
+        }
+
+        let has_property = MathRelation::GroupTheory(GroupRelation::HasBasicProperty {
+            target: Located::new_variable(on_variable.clone()),
+            property: property.clone(),
+        });
+
+        let mut justification = goal.clone();
+        justification.statement = Located::new_concrete(has_property.clone());
+
+        let name = goal.fresh_name(&Identifier::new_simple("h".to_string()));
+        let mut continuation = goal.clone();
+        continuation.context.push(ContextEntry {
+            name: name.clone(),
+            ty: Located::new_concrete(MathExpression::Relation(Arc::new(has_property))),
+            definition: DefinitionState::Abstract,
+            description: Some(RichText::text(format!(
+                "Property of {} asserted as {}",
+                on_variable, name
+            ))),
+            is_internal: false,
+        });
+
+        TacticApplicationResult::MultiGoal(vec![justification, continuation])
+    }
+
+    /// Replaces the goal `G` by `statement`, leaving the side-goal `statement → G`.
+    fn apply_suffices(goal: &ProofGoal, statement: &MathRelation) -> TacticApplicationResult {
+        let sufficient = Located::new_concrete(statement.clone());
+
+        let mut side_goal = goal.clone();
+        side_goal.statement = Located::new_concrete(MathRelation::Implies(
+            sufficient.clone(),
+            goal.statement.clone(),
+        ));
+
+        let mut main_goal = goal.clone();
+        main_goal.statement = sufficient;
+
+        TacticApplicationResult::MultiGoal(vec![side_goal, main_goal])
+    }
+
+    fn apply_normalize(goal: &ProofGoal, target: &Target) -> TacticApplicationResult {
+        let statement = if target.id == goal.statement.id {
+            goal.statement.normalize()
+        } else {
+            let replacement = if let Some(located) = goal
+                .statement
+                .get_located::<MathRelation>(target.id.clone())
+            {
+                located.normalize().concrete_value().map(|relation| Located {
+                    id: located.id.clone(),
+                    data: Parametrizable::Concrete(Arc::new(MathExpression::Relation(
+                        relation.clone(),
+                    ))),
+                })
+            } else if let Some(located) = goal
+                .statement
+                .get_located::<MathExpression>(target.id.clone())
+            {
+                Some(located.normalize())
+            } else {
+                return TacticApplicationResult::Error(format!(
+                    "Target '{}' for normalization not found.",
+                    target.id
+                ));
+            };
+            let Some(replacement) = replacement else {
+                return TacticApplicationResult::NoChange;
+            };
+
+            // As in Simplify, a bare meta-variable pattern swaps the target out wholesale.
+            let pattern = Located::new_variable(Identifier::new_simple("N".to_string()));
+            goal.statement.replace(
+                &goal.statement.id,
+                &target.id,
+                &goal.context,
+                &pattern,
+                &replacement,
+                &vec![],
+                &HashMap::new(),
+            )
+        };
+
+        let mut new_goal = goal.clone();
+        new_goal.statement = statement;
+        if new_goal.semantically_eq(goal) {
+            TacticApplicationResult::NoChange
+        } else {
+            TacticApplicationResult::SingleGoal(new_goal)
+        }
+    }
+
+    /// Closes the goal if some theorem in the library states it. Goals are
+    /// compared up to ids and the names of quantified variables, so `∀y, P(y)`
+    /// is closed by a theorem stated as `∀x, P(x)`.
+    fn apply_search_theorem_library(goal: &ProofGoal) -> TacticApplicationResult {
+        let registry = get_theorem_registry();
+        // Built-in theorems are trusted; runtime registrations need a complete proof
+        let found = registry
+            .list_ids()
+            .into_iter()
+            .filter_map(|id| {
+                let theorem = registry.get(&id)?;
+                (!registry.is_registered(&id) || theorem.proofs.is_fully_proven())
+                    .then_some(theorem)
+            })
+            .any(|theorem| theorem.proofs.initial_goal.alpha_eq(goal));
+        if found {
+            TacticApplicationResult::ProofComplete
+        } else {
+            TacticApplicationResult::Error("No theorem in the library states the goal.".to_string())
+        }
+    }
+
+    /// Searches depth-first for a sequence of tactics that closes the goal.
+    /// Goals that are alpha-equivalent to one already explored at the same or a
+    /// greater remaining depth are not explored again.
+    fn apply_auto(
+        goal: &ProofGoal,
+        depth: Option<u8>,
+        with_tactics: &[Tactic],
+    ) -> TacticApplicationResult {
+        let depth = depth.unwrap_or(DEFAULT_AUTO_DEPTH);
+        let tactics = if with_tactics.is_empty() {
+            vec![Tactic::ByReflexivity, Tactic::SplitGoalConjunction]
+        } else {
+            with_tactics.to_vec()
+        };
+
+        let mut explored = Vec::new();
+        if Self::auto_search(goal, depth, &tactics, &mut explored) {
+            TacticApplicationResult::ProofComplete
+        } else {
+            TacticApplicationResult::Error(format!(
+                "Auto could not close the goal within depth {}.",
+                depth
+            ))
+        }
+    }
+
+    /// One step of `Auto`. `explored` records each visited goal with the depth it
+    /// was explored at and whether it was proven; a goal still on the search path
+    /// counts as unproven, which stops cycles.
+    fn auto_search(
+        goal: &ProofGoal,
+        depth: u8,
+        tactics: &[Tactic],
+        explored: &mut Vec<(ProofGoal, u8, bool)>,
+    ) -> bool {
+        if let Some((_, explored_depth, proven)) = explored
+            .iter()
+            .rev()
+            .find(|(seen, _, _)| seen.alpha_eq(goal))
+        {
+            if *proven || *explored_depth >= depth {
+                return *proven;
+            }
+        }
+        explored.push((goal.clone(), depth, false));
+        let index = explored.len() - 1;
+
+        let proven = tactics.iter().any(|tactic| match tactic.apply_to_goal(goal) {
+            TacticApplicationResult::ProofComplete => true,
+            TacticApplicationResult::SingleGoal(next) if depth > 0 => {
+                Self::auto_search(&next, depth - 1, tactics, explored)
+            }
+            TacticApplicationResult::MultiGoal(goals) if depth > 0 => goals
+                .iter()
+                .all(|next| Self::auto_search(next, depth - 1, tactics, explored)),
+            _ => false,
+        });
+
+        explored[index].2 = proven;
+        proven
+    }
+
+    /// Splits the goal on `element ∈ set`: the first subgoal assumes membership,
+    /// the second its negation. Both hypotheses get the same fresh name based on `H`.
+    fn apply_case_on_membership(
+        goal: &ProofGoal,
+        element: &MathExpression,
+        set: &MathExpression,
+    ) -> TacticApplicationResult {
+        let membership = match MathRelation::try_element_of(element, set) {
+            Ok(relation) => relation,
+            Err(message) => return TacticApplicationResult::Error(message),
+        };
+        let non_membership = MathRelation::Not(Located::new_concrete(membership.clone()));
+
+        let name = goal.fresh_name(&Identifier::new_simple("H".to_string())).body;
+        let in_case = goal
+            .clone()
+            .with_hypothesis(&name, membership, Some("Case: element of the set".to_string()))
+            .0;
+        let out_case = goal
+            .clone()
+            .with_hypothesis(
+                &name,
+                non_membership,
+                Some("Case: not an element of the set".to_string()),
+            )
+            .0;
+        TacticApplicationResult::MultiGoal(vec![in_case, out_case])
+    }
+
+    /// Closes the goal by the one hypothesis whose relation equals the goal
+    /// statement. Relations are compared by content, ignoring located ids.
+    fn apply_assumption(goal: &ProofGoal) -> TacticApplicationResult {
+        let Some(statement) = goal.statement.concrete_value() else {
+            return TacticApplicationResult::Error("Goal statement is not concrete".to_string());
+        };
+        let Some(target) = content_json(statement.as_ref()) else {
+            return TacticApplicationResult::Error("Goal statement cannot be compared".to_string());
+        };
+
+        let matching: Vec<String> = goal
+            .context
+            .iter()
+            .filter(
+                |entry| match entry.ty.concrete_value().map(|ty| ty.as_ref()) {
+                    Some(MathExpression::Relation(relation)) => {
+                        content_json(relation.as_ref()).as_ref() == Some(&target)
+                    }
+                    _ => false,
+                },
+            )
+            .map(|entry| entry.name.body.clone())
+            .collect();
+
+        match matching.as_slice() {
+            [] => TacticApplicationResult::Error("No hypothesis matches the goal".to_string()),
+            [_] => TacticApplicationResult::ProofComplete,
+            names => TacticApplicationResult::Error(format!(
+                "Ambiguous assumption: hypotheses {} all match the goal",
+                names.join(", ")
+            )),
+        }
+    }
+
+    /// Backward reasoning with a lemma `P₁ → … → Pₙ → C`: once `C` unifies with
+    /// the goal, each instantiated `Pᵢ` becomes a sub-goal in the goal's context.
+    /// A conjunct index on a theorem unifies only that conjunct of `C`. Theorem
+    /// variables that no unification fixes, because only the `Pᵢ` mention them,
+    /// join the sub-goals' context under fresh names.
+    fn apply_lemma(goal: &ProofGoal, source: &RelationSource) -> TacticApplicationResult {
+        let (lemma, lemma_context, conjunct_index) = match source {
+            RelationSource::LocalAssumption(id) => match goal.find_relation_by_name(id) {
+                Some(relation) => (relation, goal.context.clone(), None),
+                None => {
+                    return TacticApplicationResult::Error(format!("Assumption {} not found", id));
+                }
+            },
+            RelationSource::Theorem(id, conjunct_index) => match get_theorem_registry().get(id) {
+                Some(theorem) => (
+                    theorem.proofs.initial_goal.statement.clone(),
+                    theorem.proofs.initial_goal.context.clone(),
+                    *conjunct_index,
+                ),
+                None => return TacticApplicationResult::Error(format!("Theorem {} not found", id)),
+            },
+        };
+
+        let mut hypotheses = Vec::new();
+        let mut conclusion = lemma;
+        loop {
+            let Some(relation) = conclusion.concrete_value().cloned() else {
+                return TacticApplicationResult::Error("Lemma statement is not concrete".to_string());
+            };
+            match relation.as_ref() {
+                MathRelation::Implies(antecedent, consequent) => {
+                    hypotheses.push(antecedent.clone());
+                    conclusion = consequent.clone();
+                }
+                _ => break,
+            }
+        }
+        if let Some(index) = conjunct_index {
+            let conjunct = match conclusion.concrete_value().map(|c| c.as_ref()) {
+                Some(MathRelation::And(conjuncts)) => conjuncts.get(index).cloned(),
+                _ => None,
+            };
+            let Some(conjunct) = conjunct else {
+                return TacticApplicationResult::Error(format!(
+                    "Lemma conclusion has no conjunct {}",
+                    index
+                ));
+            };
+            conclusion = conjunct;
+        }
+
+        let Some(goal_arc) = goal.statement.concrete_value() else {
+            return TacticApplicationResult::Error("Goal statement is not concrete".to_string());
+        };
+        let Some(conclusion_arc) = conclusion.concrete_value() else {
+            return TacticApplicationResult::Error("Lemma conclusion is not concrete".to_string());
+        };
+        let goal_expr = MathExpression::Relation(goal_arc.clone());
+        let mut instantiations = goal_expr.instantiate(
+            &goal.context,
+            &MathExpression::Relation(conclusion_arc.clone()),
+            &lemma_context,
+        );
+        let goal_located = Located::new_concrete(goal_expr);
+        let unifies = match MathExpression::Relation(conclusion_arc.clone()).substitute(
+            &instantiations,
+            &goal_located,
+            &lemma_context,
+        ) {
+            MathExpression::Relation(instantiated) => {
+                content_eq(instantiated.as_ref(), goal_arc.as_ref())
+            }
+            _ => false,
+        };
+        if !unifies {
+            return TacticApplicationResult::Error(
+                "Lemma conclusion does not unify with the goal".to_string(),
+            );
+        }
+
+        if hypotheses.is_empty() {
+            return TacticApplicationResult::ProofComplete;
+        }
+
+        // A local assumption is stated over the goal's own context, so only a
+        // theorem brings variables of its own
+        let mut sub_goal_base = goal.clone();
+        if matches!(source, RelationSource::Theorem(..)) {
+            let mentioned: HashSet<Identifier> = hypotheses
+                .iter()
+                .flat_map(|hypothesis| hypothesis.collect_identifier())
+                .collect();
+            for entry in &lemma_context {
+                if instantiations.contains_key(&entry.name) || !mentioned.contains(&entry.name) {
+                    continue;
+                }
+                let name = sub_goal_base.fresh_name(&entry.name);
+                let ty = entry
+                    .ty
+                    .substitute(&instantiations, &goal_located, &lemma_context);
+                sub_goal_base.context.push(ContextEntry {
+                    name: name.clone(),
+                    ty,
+                    ..entry.clone()
+                });
+                instantiations.insert(entry.name.clone(), InstantiationType::Identifier(name));
+            }
+        }
+        match Self::hypothesis_goals(&sub_goal_base, &hypotheses, &instantiations, &lemma_context) {
+            Ok(sub_goals) => TacticApplicationResult::MultiGoal(sub_goals),
+            Err(message) => TacticApplicationResult::Error(message),
+        }
     }
 
     fn apply_reflexivity(goal: &ProofGoal) -> TacticApplicationResult {
@@ -958,6 +2124,36 @@ impl Tactic {
         }
     }
 
+    /// Closes `True`, reflexive equalities and goals that some hypothesis states
+    /// exactly; `NoChange` for anything else.
+    fn apply_trivial(goal: &ProofGoal) -> TacticApplicationResult {
+        let Some(statement) = goal.statement.concrete_value() else {
+            return TacticApplicationResult::NoChange;
+        };
+        if matches!(statement.as_ref(), MathRelation::True)
+            || matches!(
+                Self::apply_reflexivity(goal),
+                TacticApplicationResult::ProofComplete
+            )
+        {
+            return TacticApplicationResult::ProofComplete;
+        }
+
+        let matches_hypothesis = goal.context.iter().any(|entry| {
+            match entry.ty.concrete_value().map(|ty| ty.as_ref()) {
+                Some(MathExpression::Relation(relation)) => {
+                    content_eq(relation.as_ref(), statement.as_ref())
+                }
+                _ => false,
+            }
+        });
+        if matches_hypothesis {
+            TacticApplicationResult::ProofComplete
+        } else {
+            TacticApplicationResult::NoChange
+        }
+    }
+
     fn apply_induction(
         goal: &ProofGoal,
         _target_relation_id: &String,
@@ -985,8 +2181,10 @@ impl Tactic {
             parameters: HashMap::new(),
             description: "k".to_string(),
             membership_condition: String::new(),
+            membership_predicate: None,
             properties: VariantSet::new(),
         })));
+        #[cfg(feature = "groups")]
         let k_plus_one_expr = MathExpression::Expression(TheoryExpression::Group(
             GroupExpression::Operation {
                 group: Located::new_concrete(Group::new_generic()),
@@ -1000,6 +2198,15 @@ impl Tactic {
                 }),
             },
         ));
+        // Without group theory there is no addition to build k + 1 from
+        #[cfg(not(feature = "groups"))]
+        let k_plus_one_expr = MathExpression::Object(Arc::new(MathObject::Set(Set::Parametric {
+            parameters: HashMap::new(),
+            description: "k + 1".to_string(),
+            membership_condition: String::new(),
+            membership_predicate: None,
+            properties: VariantSet::new(),
+        })));
         let located_k_var = Located::new_concrete(k_var.clone());
         let substitution_map_k = HashMap::from([(induction_variable_name.clone(), InstantiationType::LocatedId(located_k_var.id.clone()))]);
         if let Some(goal_statement_arc) = goal.statement.concrete_value() {
@@ -1018,11 +2225,13 @@ impl Tactic {
 
         // Add the induction hypothesis to the context of the inductive step goal
             if let MathExpression::Relation(hyp_rel) = induction_hypothesis_result {
+            let name = inductive_step_goal.fresh_name(induction_hypothesis_name);
             inductive_step_goal.context.push(ContextEntry {
-                name: induction_hypothesis_name.clone(),
+                name,
                     ty: Located::new_concrete(MathExpression::Relation(hyp_rel)),
                 definition: DefinitionState::Abstract,
                 description: Some(RichText::text("Induction Hypothesis".to_string())),
+                is_internal: false,
             });
         }
 
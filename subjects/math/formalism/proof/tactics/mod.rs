@@ -8,6 +8,9 @@ pub use super::{ContextEntry, ProofForest, ProofNode};
 use crate::subjects::math::formalism::expressions::MathExpression;
 use crate::subjects::math::formalism::extract::Parametrizable;
 use crate::subjects::math::formalism::relations::MathRelation;
+use crate::subjects::math::formalism::traits::debug::ShortDebug;
+#[cfg(feature = "groups")]
+use crate::subjects::math::theories::groups::definitions::GroupProperty;
 use crate::turn_render::Identifier;
 use crate::turn_render::RichText;
 use crate::turn_render::Section;
@@ -18,22 +21,27 @@ use std::collections::HashMap;
 /// This enum includes both primitive, single-step rules of inference and
 /// higher-level automated tactics that execute complex procedures.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", content = "content")]
 pub enum Tactic {
     //=================================================================//
     // I.   PRIMITIVE: GOAL-DIRECTED TACTICS (Introduction Rules)
     //=================================================================//
     /// To prove `A → B`, adds `A` to the context and changes the goal to `B`.
+    #[serde(rename = "assume_implication_antecedent")]
     AssumeImplicationAntecedent { with_name: Identifier },
 
     /// To prove `A ∧ B`, splits the goal into two sub-goals: `A` and `B`.
+    #[serde(rename = "split_goal_conjunction")]
     SplitGoalConjunction,
 
     /// To prove `A ∨ B`, requires the user to choose which disjunct to prove.
+    #[serde(rename = "split_goal_disjunction")]
     SplitGoalDisjunction { disjunct_index: usize },
 
     /// Performs a case analysis on a variable by replacing it with a
     /// specific new version of the object in each new subgoal.
     /// This is the most direct and type-safe way to perform a case split.
+    #[serde(rename = "case_analysis")]
     CaseAnalysis {
         /// The identifier of the variable in the context to replace.
         on_variable: Identifier,
@@ -42,8 +50,17 @@ pub enum Tactic {
         cases: Vec<Case>,
     },
 
+    /// Splits the goal into the cases `x ∈ A` and `x ∉ A`, adding the case as a
+    /// hypothesis to each subgoal. The two cases are always exhaustive.
+    #[serde(rename = "case_on_membership")]
+    CaseOnMembership {
+        element: MathExpression,
+        set: MathExpression,
+    },
+
     /// To prove `∀x:T, P(x)`, splits the proof into base case and inductive step.
     /// induction is a special case of case analysis on a quantifier.
+    #[serde(rename = "induction")]
     Induction {
         variable_name: Identifier,
         hypothesis_name: Identifier,
@@ -54,6 +71,7 @@ pub enum Tactic {
     /// This tactic consumes the specified existential quantifier in the goal's
     /// quantifier list and substitutes the witness for the bound variable
     /// in the goal's matrix.
+    #[serde(rename = "provide_witness")]
     ProvideWitness {
         /// The variable of the quantifier to target (e.g., `x` in `∃x`).
         target_quantifier: Identifier,
@@ -61,16 +79,32 @@ pub enum Tactic {
         witness: MathExpression,
     },
 
+    /// Asserts an intermediate lemma `statement` (Lean's `have`). Produces two
+    /// sub-goals: first prove `statement`, then prove the original goal with
+    /// `name: statement` added to the context.
+    #[serde(rename = "have")]
+    Have {
+        name: Identifier,
+        statement: MathRelation,
+    },
+
+    /// Backward reasoning: "it suffices to show `statement`". Produces two
+    /// sub-goals: the side-goal `statement → G` and the new main goal `statement`.
+    #[serde(rename = "suffices")]
+    Suffices { statement: MathRelation },
+
     //=================================================================//
     // II.  PRIMITIVE: CONTEXT-DIRECTED TACTICS (Elimination Rules)
     //=================================================================//
     /// From `H: A ∧ B`, adds `A` and `B` as new hypotheses.
+    #[serde(rename = "split_assumption_conjunction")]
     SplitAssumptionConjunction {
         target_hypothesis: Identifier,
         with_names: Vec<Identifier>,
     },
 
     /// From `H: A ∨ B`, splits the proof into two cases.
+    #[serde(rename = "split_assumption_disjunction")]
     SplitAssumptionDisjunction {
         target_hypothesis: Identifier,
         with_names: Vec<CaseCondition>,
@@ -81,24 +115,45 @@ pub enum Tactic {
     //=================================================================//
     /// Solves goal `G` by pointing to the exact hypothesis `H: G`.
     /// this is ExactWith tactics but
+    #[serde(rename = "by_relation")]
     ByRelation(RelationSource),
 
+    /// Solves goal `G` by the unique hypothesis `H: G`, without naming it.
+    /// Fails if no hypothesis or more than one hypothesis matches.
+    #[serde(rename = "assumption")]
+    Assumption,
+
+    /// Solves goal `G` with a lemma `P₁ → … → Pₙ → C` whose conclusion `C`
+    /// unifies with `G`, leaving the instantiated `P₁, …, Pₙ` as new sub-goals.
+    /// The index of a `RelationSource::Theorem` selects one conjunct of `C`.
+    #[serde(rename = "apply")]
+    Apply { source: RelationSource },
+
     /// Solves goal `t = t`.
+    #[serde(rename = "by_reflexivity")]
     ByReflexivity,
 
+    /// Solves goals that are obviously true: `True`, `t = t`, or a statement
+    /// found verbatim among the hypotheses. Leaves any other goal unchanged.
+    #[serde(rename = "trivial")]
+    Trivial,
+
     /// Solves any goal by citing two contradictory hypotheses `H1: A` and `H2: ¬A`.
+    #[serde(rename = "by_contradiction")]
     ByContradiction {
         hypothesis1: Identifier,
         hypothesis2: Identifier,
     },
 
     /// Solves goal `G` by citing a hypothesis `H: ¬G`.
+    #[serde(rename = "by_goal_contradiction")]
     ByGoalContradiction { conflicting_hypothesis: Identifier },
 
     //=================================================================//
     // IV.  PRIMITIVE: REWRITING & STRUCTURAL TACTICS
     //=================================================================//
     /// The primary workhorse. Rewrites a target using an equality or implication.
+    #[serde(rename = "rewrite")]
     Rewrite {
         using_rule: RelationSource,
         target: Target,
@@ -106,19 +161,36 @@ pub enum Tactic {
         instantiations: HashMap<Identifier, Identifier>, // meta-variable in theorem vs variable in current goal
     },
 
+    /// Rewrites the two sides of an equality goal `L = R` forward, each with its
+    /// own rule, then closes the goal if both sides have become identical.
+    #[serde(rename = "rewrite_sides")]
+    RewriteSides {
+        left_rule: Option<RelationSource>,
+        right_rule: Option<RelationSource>,
+    },
+
+    /// Rewrites the left side of an equality goal `a = d` forward with each of the
+    /// named equality hypotheses in order (`a = b`, `b = c`, `c = d`), closing the
+    /// goal once both sides are identical.
+    #[serde(rename = "rewrite_chain")]
+    RewriteChain { equalities: Vec<Identifier> },
+
     /// Replaces a defined term with its definition.
+    #[serde(rename = "unfold_definition")]
     UnfoldDefinition {
         definition_to_unfold: Identifier,
         target: Target,
     },
 
     /// Gives a name to a sub-expression for clarity.
+    #[serde(rename = "introduce_let_binding")]
     IntroduceLetBinding {
         target_expression: Target,
         with_name: Identifier,
     },
 
     /// Renames a bound variable for clarity (α-conversion).
+    #[serde(rename = "rename_bound_variable")]
     RenameBoundVariable {
         target: Target,
         from_name: Identifier,
@@ -126,14 +198,45 @@ pub enum Tactic {
     },
 
     /// Moves a hypothesis back into the goal as an implication.
+    #[serde(rename = "revert")]
     Revert { hypothesis_to_revert: Identifier },
 
+    /// Given a homomorphism `φ: G → H` in the context, adds the hypothesis
+    /// `G/ker(φ) ≅ im(φ)` (the first isomorphism theorem) under `with_name`.
+    #[cfg(feature = "groups")]
+    #[serde(rename = "apply_first_isomorphism")]
+    ApplyFirstIsomorphism {
+        homomorphism: Identifier,
+        with_name: Identifier,
+    },
+
+    /// When the group variable `on_variable` is a kernel, image, center or
+    /// normalizer, replaces each membership `g ∈ on_variable` in the goal and its
+    /// hypotheses with the condition defining the construction, e.g. `φ(g) = e`
+    /// for `ker φ`. A goal `g ∈ im φ` becomes `∃w. φ(w) = g`, and such a
+    /// hypothesis introduces the preimage `w`. The construction itself stays in
+    /// the context.
+    #[cfg(feature = "groups")]
+    #[serde(rename = "unfold_group_construction")]
+    UnfoldGroupConstruction { on_variable: Identifier },
+
+    /// Asserts that the group variable `on_variable` has `property`. Produces two
+    /// sub-goals: first justify `on_variable has property`, then prove the original
+    /// goal with that fact added to the context.
+    #[cfg(feature = "groups")]
+    #[serde(rename = "assert_property")]
+    AssertProperty {
+        on_variable: Identifier,
+        property: GroupProperty,
+    },
+
     //=================================================================//
     // VI.  VARIABLE VIEW MANAGEMENT (Type Roles)
     //=================================================================//
     /// Refines (strengthens) a variable's primary type using an equality theorem whose two sides
     /// are of the exact same role (SameRole). Only collection-like fields may be extended.
     /// Fails otherwise. This never introduces multi-views.
+    #[serde(rename = "refine_variable")]
     RefineVariable {
         variable: Identifier,
         theorem_id: String,
@@ -144,22 +247,34 @@ pub enum Tactic {
     //=================================================================//
     /// **Automated**: Searches the context to find a hypothesis that exactly
     /// matches the goal. A convenient version of `ByAssumption`.
+    #[serde(rename = "search_assumptions")]
     SearchAssumptions,
 
     /// **Automated**: Searches the theorem library for a single theorem that
-    /// can directly prove the current goal.
+    /// can directly prove the current goal. Built-in theorems and axioms are
+    /// trusted; theorems registered at runtime only count once fully proven.
+    #[serde(rename = "search_theorem_library")]
     SearchTheoremLibrary,
 
     /// **Automated**: A convenient combination of the above. It first calls
     /// `SearchAssumptions` and, if that fails, calls `SearchTheoremLibrary`.
+    #[serde(rename = "search")]
     Search,
 
     /// **Automated**: Attempts to simplify a target expression by repeatedly
     /// applying a pre-defined set of rewrite rules (`x+0=x`, etc.).
+    #[serde(rename = "simplify")]
     Simplify { target: Target },
 
+    /// **Automated**: Applies light structural normalization to a target before
+    /// matching: strips double negations, flattens nested `∧`/`∨` and collapses
+    /// repeated identical views. Applying it twice is the same as applying it once.
+    #[serde(rename = "normalize")]
+    Normalize { target: Target },
+
     /// **Automated**: A general-purpose "sledgehammer" tactic that tries a
     /// sequence of other tactics to solve the goal.
+    #[serde(rename = "auto")]
     Auto {
         /// Maximum search depth.
         depth: Option<u8>,
@@ -172,6 +287,7 @@ pub enum Tactic {
     //=================================================================//
     /// Closes a goal `G` by pointing to a theorem that proves `¬G`.
     /// This marks the current proof branch as disproven.
+    #[serde(rename = "disprove_by_theorem")]
     DisproveByTheorem {
         /// The ID of the theorem proving the negation of the goal.
         theorem_id: String,
@@ -253,31 +369,12 @@ pub enum RewriteDirection {
     Backward, // RHS -> LHS
 }
 
-/*
+/// One-line form of the tactic, as used in debug output and tree printouts.
 impl std::fmt::Display for Tactic {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Tactic::Introduce { .. } => write!(f, "Introduce"),
-            Tactic::IntroduceLetBinding { .. } => write!(f, "IntroduceLetBinding"),
-            Tactic::IntroduceFreshVariable { .. } => write!(f, "IntroduceFreshVariable"),
-            Tactic::ProvideWitness { .. } => write!(f, "ProvideWitness"),
-            Tactic::ReorderQuantifiers { .. } => write!(f, "ReorderQuantifiers"),
-            Tactic::UniversalCaseAnalysis { .. } => write!(f, "UniversalCaseAnalysis"),
-            Tactic::ExactWith { .. } => write!(f, "ExactWith"),
-            Tactic::Rewrite { .. } => write!(f, "Rewrite"),
-            Tactic::AssumeImplicationAntecedent { .. } => write!(f, "AssumeImplicationAntecedent"),
-            Tactic::SplitConjunction { .. } => write!(f, "SplitConjunction"),
-            Tactic::SplitDisjunction { .. } => write!(f, "SplitDisjunction"),
-            Tactic::StatementCaseAnalysis { .. } => write!(f, "StatementCaseAnalysis"),
-            Tactic::Simplify { .. } => write!(f, "Simplify"),
-            Tactic::Auto(_) => write!(f, "Auto"),
-            Tactic::Reflexivity => write!(f, "Reflexivity"),
-            Tactic::Transitivity { .. } => write!(f, "Transitivity"),
-            Tactic::Induction { .. } => write!(f, "Induction"),
-        }
+        write!(f, "{}", self.short_debug())
     }
 }
-*/
 
 /*
 #[cfg(test)]
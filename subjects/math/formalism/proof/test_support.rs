@@ -0,0 +1,59 @@
+//! Builders for large synthetic proof goals, for stress testing and profiling
+//! the tactic engine.
+use super::ProofGoal;
+use crate::subjects::math::formalism::expressions::MathExpression;
+use crate::subjects::math::formalism::location::Located;
+use crate::subjects::math::formalism::relations::MathRelation;
+use crate::subjects::math::theories::number_theory::definitions::Number;
+
+/// The trivial equality `Aᵢ := i = i`; distinct for distinct `i`.
+fn trivial_equality(i: usize) -> Located<MathRelation> {
+    Located::new_concrete(MathRelation::equal(
+        MathExpression::Number(Number::int(i as i64)),
+        MathExpression::Number(Number::int(i as i64)),
+    ))
+}
+
+/// The goal `A₁ ∧ A₂ ∧ … ∧ Aₙ`, a single flat conjunction of `n` conjuncts.
+pub fn make_conjunction_goal(n: usize) -> ProofGoal {
+    ProofGoal {
+        context: vec![],
+        quantifiers: vec![],
+        statement: Located::new_concrete(MathRelation::And(
+            (1..=n).map(trivial_equality).collect(),
+        )),
+    }
+}
+
+/// The goal `A₁ → (A₂ → (… → (Aₙ → A₀)))`, whose antecedents can be assumed
+/// `n` times in a row.
+pub fn make_nested_implication_goal(n: usize) -> ProofGoal {
+    let statement = (1..=n).rev().fold(trivial_equality(0), |consequent, i| {
+        Located::new_concrete(MathRelation::Implies(trivial_equality(i), consequent))
+    });
+    ProofGoal {
+        context: vec![],
+        quantifiers: vec![],
+        statement,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::subjects::math::formalism::proof::tactics::{Tactic, TacticApplicationResult};
+
+    #[test]
+    fn test_conjunction_goal_splits_into_n_subgoals() {
+        let goal = make_conjunction_goal(50);
+        match goal.statement.concrete_value().unwrap().as_ref() {
+            MathRelation::And(conjuncts) => assert_eq!(conjuncts.len(), 50),
+            other => panic!("Expected a conjunction, got {:?}", other),
+        }
+
+        match Tactic::SplitGoalConjunction.apply_to_goal(&goal) {
+            TacticApplicationResult::MultiGoal(goals) => assert_eq!(goals.len(), 50),
+            other => panic!("Expected MultiGoal, got {:?}", other),
+        }
+    }
+}
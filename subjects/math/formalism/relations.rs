@@ -9,15 +9,19 @@ use super::{objects::MathObject, traits::complexity::Complexity};
 
 // Import domain-specific relations from their respective modules
 use super::super::super::super::foundational_theories::category_theory::definitions::CategoryRelation;
+#[cfg(feature = "groups")]
 use super::super::theories::groups::definitions::GroupRelation;
 use super::super::theories::number_theory::definitions::NumberTheoryRelation;
+#[cfg(feature = "probability")]
 use super::super::theories::probability::definitions::ProbabilityRelation;
+#[cfg(feature = "rings")]
 use super::super::theories::rings::definitions::RingRelation;
 use super::super::theories::topology::definitions::TopologyRelation;
 use super::super::theories::zfc::definitions::SetRelation;
 
 use super::location::Located;
 use crate::subjects::math::formalism::extract::Parametrizable;
+#[cfg(feature = "groups")]
 use crate::subjects::math::theories::groups::definitions::{Group, GroupExpression};
 use crate::turn_render::Identifier;
 
@@ -36,27 +40,46 @@ pub enum Quantification {
 
 /// A mathematical relation between objects
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(tag = "type", content = "content")]
 pub enum MathRelation {
     // Core logical connectives only
     // Quantifier are encoded into theorem so that it is PNF directly.
+    #[serde(rename = "and")]
     And(Vec<Located<MathRelation>>),
+    #[serde(rename = "or")]
     Or(Vec<Located<MathRelation>>),
+    #[serde(rename = "not")]
     Not(Located<MathRelation>),
+    #[serde(rename = "implies")]
     Implies(Located<MathRelation>, Located<MathRelation>), // ->
+    #[serde(rename = "equivalent")]
     Equivalent(Located<MathRelation>, Located<MathRelation>), // <=>
+    #[serde(rename = "true")]
     True,
+    #[serde(rename = "false")]
     False,
 
     // Domain-specific relations organized by theory
+    #[serde(rename = "number_theory")]
     NumberTheory(NumberTheoryRelation),
+    #[serde(rename = "set_theory")]
     SetTheory(SetRelation),
+    #[cfg(feature = "groups")]
+    #[serde(rename = "group_theory")]
     GroupTheory(GroupRelation),
+    #[cfg(feature = "rings")]
+    #[serde(rename = "ring_theory")]
     RingTheory(RingRelation),
+    #[serde(rename = "topology_theory")]
     TopologyTheory(TopologyRelation),
+    #[serde(rename = "category_theory")]
     CategoryTheory(CategoryRelation),
+    #[cfg(feature = "probability")]
+    #[serde(rename = "probability_theory")]
     ProbabilityTheory(ProbabilityRelation),
 
     // For basic equality that crosses domains
+    #[serde(rename = "equal")]
     Equal {
         left: Located<MathExpression>,
         right: Located<MathExpression>,
@@ -94,8 +117,53 @@ impl MathRelation {
     }
 
     /// Creates a set theory ElementOf relation
+    /// Panics if the operands are not an element and a set, see [`MathRelation::try_element_of`]
     pub fn element_of(element: MathExpression, set: MathExpression) -> Self {
-        todo!()
+        Self::try_element_of(&element, &set).unwrap_or_else(|message| panic!("{}", message))
+    }
+
+    /// Creates a set theory ElementOf relation if the operands can be read as one.
+    /// Integers, numeric variables and sets are elements; only set objects are sets.
+    pub fn try_element_of(element: &MathExpression, set: &MathExpression) -> Result<Self, String> {
+        use super::super::theories::number_theory::definitions::Number;
+        use super::super::theories::zfc::definitions::SetElement;
+
+        let element = match element {
+            MathExpression::Number(Number::Integer(value)) => {
+                Parametrizable::Concrete(SetElement::Integer(*value))
+            }
+            MathExpression::Number(Number::Variable(name)) => {
+                Parametrizable::Variable(name.clone())
+            }
+            MathExpression::Object(object) => match object.as_ref() {
+                MathObject::Set(set) => {
+                    Parametrizable::Concrete(SetElement::Set(Box::new(set.clone())))
+                }
+                _ => return Err("Only sets can be used as set elements among objects".to_string()),
+            },
+            other => {
+                return Err(format!(
+                    "{} expression cannot be used as a set element",
+                    other.get_variant_name()
+                ));
+            }
+        };
+        let set = match set {
+            MathExpression::Object(object) => match object.as_ref() {
+                MathObject::Set(set) => Parametrizable::Concrete(set.clone()),
+                _ => return Err("Membership requires a set object".to_string()),
+            },
+            other => {
+                return Err(format!(
+                    "{} expression cannot be used as a set",
+                    other.get_variant_name()
+                ));
+            }
+        };
+        Ok(MathRelation::SetTheory(SetRelation::ElementOf {
+            element,
+            set,
+        }))
     }
 
     /// Creates a set theory SubsetOf relation
@@ -7,16 +7,41 @@ use super::super::relations::MathRelation;
 use crate::turn_render::math_node::ToTurnMath;
 use crate::turn_render::{BracketSize, BracketStyle, MathNode, MathNodeContent};
 use crate::turn_render::{RichText, RichTextSegment, ToRichText};
-use crate::{
-    subjects::math::theories::groups::definitions::GroupExpression, turn_render::ScriptNode,
-};
-use crate::{
-    subjects::math::theories::rings::definitions::{FieldExpression, RingExpression},
-    turn_render::Identifier,
-};
+#[cfg(feature = "groups")]
+use crate::subjects::math::theories::groups::definitions::GroupExpression;
+#[cfg(feature = "rings")]
+use crate::subjects::math::theories::rings::definitions::{FieldExpression, RingExpression};
+use crate::turn_render::{Identifier, ScriptNode};
 use serde::{Deserialize, Serialize};
 use std::{string::String, sync::Arc};
 
+impl Identifier {
+    /// A name with a subscript, as in `x₁` or `G_ab`.
+    pub fn with_subscript(body: String, subscript: String) -> Self {
+        let subscript_node = MathNode {
+            id: format!("{}_{}", body, subscript),
+            content: Arc::new(MathNodeContent::Identifier(Identifier::new_simple(
+                subscript,
+            ))),
+        };
+        Identifier {
+            post_script: Some(ScriptNode {
+                subscripts: vec![subscript_node],
+                superscripts: vec![],
+            }),
+            ..Identifier::new_simple(body)
+        }
+    }
+
+    /// A name followed by `primes` prime marks, as in `f'` or `f''`.
+    pub fn with_primes(body: String, primes: usize) -> Self {
+        Identifier {
+            primes,
+            ..Identifier::new_simple(body)
+        }
+    }
+}
+
 impl ToTurnMath for Identifier {
     fn to_turn_math(&self, master_id: String) -> MathNode {
         MathNode {
@@ -30,17 +55,7 @@ impl ToTurnMath for MathExpression {
     fn to_turn_math(&self, master_id: String) -> MathNode {
         match self {
             // MathExpression::Var(id) => id.to_turn_math(master_id),
-            MathExpression::Number(_num) => {
-                // Number is a struct with no members, just render it as a generic number
-                MathNode {
-                    id: master_id,
-                    content: Arc::new(MathNodeContent::Quantity {
-                        number: "0".to_string(), // Default representation
-                        scientific_notation: None,
-                        unit: None,
-                    }),
-                }
-            }
+            MathExpression::Number(num) => num.to_turn_math(master_id),
             MathExpression::Object(obj) => obj.to_turn_math(master_id),
             MathExpression::Expression(theory_expr) => {
                 // For now, just display the expression as text
@@ -61,7 +76,9 @@ impl ToTurnMath for MathExpression {
 impl ToTurnMath for TheoryExpression {
     fn to_turn_math(&self, master_id: String) -> MathNode {
         match self {
+            #[cfg(feature = "groups")]
             TheoryExpression::Group(group_expr) => group_expr.to_turn_math(master_id),
+            #[cfg(feature = "rings")]
             TheoryExpression::Ring(_ring_expr) => {
                 // TODO: Implement ToTurnMath for RingExpression or provide better placeholder
                 MathNode {
@@ -69,6 +86,7 @@ impl ToTurnMath for TheoryExpression {
                     content: Arc::new(MathNodeContent::Text("RingExpression (TODO)".to_string())),
                 }
             }
+            #[cfg(feature = "rings")]
             TheoryExpression::Field(_field_expr) => {
                 // TODO: Implement ToTurnMath for FieldExpression or provide better placeholder
                 MathNode {
@@ -91,10 +109,12 @@ impl ToRichText for MathExpression {
     fn to_rich_text(&self) -> RichText {
         match self {
             MathExpression::Object(obj) => match obj.as_ref() {
+                #[cfg(feature = "groups")]
                 MathObject::Group(g) => RichText {
                     segments: vec![RichTextSegment::Math(g.to_turn_math("".to_string()))],
                     alignment: None,
                 },
+                #[cfg(feature = "rings")]
                 MathObject::Ring(_) => RichText {
                     segments: vec![RichTextSegment::Text("Ring".to_string())],
                     alignment: None,
@@ -115,11 +135,14 @@ impl ToRichText for MathExpression {
             MathExpression::Expression(theory_expr) => {
                 // Delegate to theory-specific implementations
                 match theory_expr {
+                    #[cfg(feature = "groups")]
                     TheoryExpression::Group(group_expr) => group_expr.to_rich_text(),
+                    #[cfg(feature = "rings")]
                     TheoryExpression::Ring(_ring_expr) => RichText {
                         segments: vec![RichTextSegment::Text("ring element".to_string())],
                         alignment: None,
                     },
+                    #[cfg(feature = "rings")]
                     TheoryExpression::Field(_field_expr) => RichText {
                         segments: vec![RichTextSegment::Text("field element".to_string())],
                         alignment: None,
@@ -131,8 +154,8 @@ impl ToRichText for MathExpression {
                 segments: vec![RichTextSegment::Math(rel.to_turn_math("".to_string()))],
                 alignment: None,
             },
-            MathExpression::Number(_) => RichText {
-                segments: vec![RichTextSegment::Text("number".to_string())],
+            MathExpression::Number(num) => RichText {
+                segments: vec![RichTextSegment::Text(num.to_string())],
                 alignment: None,
             },
             MathExpression::ViewAs { .. } => RichText {
@@ -142,3 +165,47 @@ impl ToRichText for MathExpression {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::subjects::math::formalism::traits::debug::ShortDebug;
+
+    fn rendered_identifier(identifier: &Identifier) -> Identifier {
+        match identifier.to_turn_math("id".to_string()).content.as_ref() {
+            MathNodeContent::Identifier(rendered) => rendered.clone(),
+            other => panic!("Expected an identifier, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_subscripted_identifier_renders_its_subscript() {
+        let x1 = Identifier::with_subscript("x".to_string(), "1".to_string());
+        let rendered = rendered_identifier(&x1);
+        assert_eq!(rendered.body, "x");
+        let script = rendered.post_script.expect("Expected a subscript");
+        assert!(script.superscripts.is_empty());
+        match script.subscripts.as_slice() {
+            [subscript] => match subscript.content.as_ref() {
+                MathNodeContent::Identifier(index) => assert_eq!(index.body, "1"),
+                other => panic!("Expected the subscript 1, got {:?}", other),
+            },
+            other => panic!("Expected one subscript, got {:?}", other),
+        }
+        assert_eq!(x1.short_debug(), "Identifier(x_1)");
+    }
+
+    #[test]
+    fn test_primed_identifier_renders_its_primes() {
+        let f2 = Identifier::with_primes("f".to_string(), 2);
+        let rendered = rendered_identifier(&f2);
+        assert_eq!(rendered.body, "f");
+        assert_eq!(rendered.primes, 2);
+        assert!(rendered.post_script.is_none());
+        assert_eq!(f2.short_debug(), "Identifier(f'')");
+        assert_ne!(
+            f2.short_debug(),
+            Identifier::new_simple("f".to_string()).short_debug()
+        );
+    }
+}
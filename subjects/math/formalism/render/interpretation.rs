@@ -1,4 +1,5 @@
 use super::super::interpretation::TypeViewOperator;
+#[cfg(feature = "groups")]
 use crate::subjects::math::theories::groups::definitions::Group;
 use crate::turn_render::*;
 
@@ -8,9 +8,12 @@ use crate::{
     },
 };
 
+/// Renders the located value under `<master_id>-<id>`. The caller's id keeps
+/// clones of one `Located` apart, and the stored id at the end is what
+/// path-based targeting looks for.
 impl<T: ToTurnMath> ToTurnMath for Located<T> {
     fn to_turn_math(&self, master_id: String) -> MathNode {
-        self.data.to_turn_math(master_id)
+        self.data.to_turn_math(format!("{}-{}", master_id, self.id))
     }
 }
 
@@ -56,3 +59,45 @@ impl<T: ToLogicalNode> ToLogicalNode for Arc<T> {
         self.to_logical_node()
     }
 }
+
+#[cfg(all(test, feature = "groups"))]
+mod tests {
+    use super::*;
+    use crate::subjects::math::theories::groups::definitions::{Group, GroupExpression};
+    use crate::turn_render::Identifier;
+
+    #[test]
+    fn test_located_render_id_ends_with_its_own_id() {
+        let element = Located::new_variable(Identifier::new_simple("g".to_string()));
+        let inverse = Located::new_concrete(GroupExpression::Inverse {
+            group: Located::new_concrete(Group::new_generic()),
+            element: element.clone(),
+        });
+
+        let node = inverse.to_turn_math("inv".to_string());
+        assert_eq!(node.id, format!("inv-{}", inverse.id));
+
+        // Group renderers pass a role-derived id to located children
+        let MathNodeContent::Power { base, .. } = node.content.as_ref() else {
+            panic!("Expected g⁻¹ as a power, got {:?}", node.content);
+        };
+        assert_eq!(base.id, format!("{}-element-{}", node.id, element.id));
+    }
+
+    #[test]
+    fn test_clones_of_a_located_render_with_distinct_ids() {
+        let element = Located::new_variable(Identifier::new_simple("g".to_string()));
+        let square = Located::new_concrete(GroupExpression::Operation {
+            group: Located::new_concrete(Group::new_generic()),
+            left: element.clone(),
+            right: element.clone(),
+        });
+
+        let node = square.to_turn_math("square".to_string());
+        let MathNodeContent::Multiplications { terms } = node.content.as_ref() else {
+            panic!("Expected g·g as a product, got {:?}", node.content);
+        };
+        assert_ne!(terms[0].1.id, terms[1].1.id);
+        assert!(terms.iter().all(|(_, term)| term.id.ends_with(&element.id)));
+    }
+}
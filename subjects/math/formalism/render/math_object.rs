@@ -1,18 +1,165 @@
 use super::super::objects::MathObject;
+use crate::subjects::math::theories::analysis::definition::functions::{
+    ConcreteFunction, Function,
+};
+use crate::subjects::math::theories::common::spaces::Space;
+#[cfg(feature = "groups")]
 use crate::subjects::math::theories::groups::definitions::Group;
 use crate::turn_render::math_node::ToTurnMath;
-use crate::turn_render::{BracketStyle, MathNode, MathNodeContent, RelationOperatorNode};
+use crate::turn_render::{
+    BracketStyle, Identifier, MathNode, MathNodeContent, RelationOperatorNode,
+};
 use std::sync::Arc;
 
 impl ToTurnMath for MathObject {
     fn to_turn_math(&self, master_id: String) -> MathNode {
-        let content = match self {
+        // Theories with their own renderer take over; the rest show the
+        // conventional symbol for their kind of structure.
+        let symbol = match self {
+            #[cfg(feature = "groups")]
             MathObject::Group(group) => return group.to_turn_math(master_id),
-            _ => todo!(),
+            MathObject::Field(field) => return field.to_turn_math(master_id),
+            MathObject::Set(set) => return set.to_turn_math(master_id),
+            #[cfg(feature = "rings")]
+            MathObject::Ring(_) => "R",
+            #[cfg(feature = "rings")]
+            MathObject::Module(_) => "M",
+            #[cfg(feature = "rings")]
+            MathObject::Algebra(_) => "A",
+            MathObject::TopologicalSpace(_) => "X",
+            MathObject::VectorSpace(_) => "V",
+            MathObject::Function(function) => return function.to_turn_math(master_id),
         };
         MathNode {
             id: master_id,
-            content: Arc::new(content),
+            content: Arc::new(MathNodeContent::Identifier(Identifier::new_simple(
+                symbol.to_string(),
+            ))),
         }
     }
 }
+
+impl ToTurnMath for Function {
+    /// Renders the signature `f: A → B`. Named concrete functions such as `sin`
+    /// use their own name; any other function is called `f`.
+    fn to_turn_math(&self, master_id: String) -> MathNode {
+        let name = match &self.concrete {
+            Some(ConcreteFunction::Exp { .. }) => "exp",
+            Some(ConcreteFunction::Log { .. }) => "log",
+            Some(ConcreteFunction::Sin { .. }) => "sin",
+            Some(ConcreteFunction::Cos { .. }) => "cos",
+            Some(ConcreteFunction::Tan { .. }) => "tan",
+            Some(ConcreteFunction::Gamma { .. }) => "Γ",
+            Some(ConcreteFunction::Zeta { .. }) => "ζ",
+            Some(ConcreteFunction::ErrorFunction { .. }) => "erf",
+            _ => "f",
+        };
+        let space = |space: &Space, fallback: &str, role: &str| {
+            let id = format!("{}-{}", master_id, role);
+            match space {
+                Space::Set(set) => set.to_turn_math(id),
+                _ => MathNode {
+                    id,
+                    content: Arc::new(MathNodeContent::Identifier(Identifier::new_simple(
+                        fallback.to_string(),
+                    ))),
+                },
+            }
+        };
+
+        let mapping = MathNode {
+            id: format!("{}-mapping", master_id),
+            content: Arc::new(MathNodeContent::Relationship {
+                lhs: Arc::new(space(&self.domain, "X", "domain")),
+                operator: RelationOperatorNode::Custom("→".to_string()),
+                rhs: Arc::new(space(&self.codomain, "Y", "codomain")),
+            }),
+        };
+        MathNode {
+            id: master_id.clone(),
+            content: Arc::new(MathNodeContent::Relationship {
+                lhs: Arc::new(MathNode {
+                    id: format!("{}-name", master_id),
+                    content: Arc::new(MathNodeContent::Identifier(Identifier::new_simple(
+                        name.to_string(),
+                    ))),
+                }),
+                operator: RelationOperatorNode::Custom(":".to_string()),
+                rhs: Arc::new(mapping),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "groups")]
+    use crate::subjects::math::theories::groups::definitions::{
+        CyclicGroup, GenericGroup, GroupElement,
+    };
+    use crate::subjects::math::theories::zfc::definitions::Set;
+
+    #[test]
+    #[cfg(feature = "groups")]
+    fn test_group_object_uses_group_renderer() {
+        let group = Group::Cyclic(CyclicGroup {
+            core: GenericGroup::default(),
+            generator: GroupElement::Integer(1),
+            order: Some(5),
+        });
+        let object = MathObject::Group(group.clone());
+
+        let via_object = serde_json::to_string(&object.to_turn_math("obj".to_string())).unwrap();
+        let via_group = serde_json::to_string(&group.to_turn_math("obj".to_string())).unwrap();
+        assert_eq!(via_object, via_group);
+    }
+
+    #[test]
+    fn test_set_object_uses_set_renderer() {
+        let object = MathObject::Set(Set::reals());
+        let node = object.to_turn_math("obj".to_string());
+
+        assert_eq!(node.id, "obj");
+        match node.content.as_ref() {
+            MathNodeContent::Identifier(identifier) => assert_eq!(identifier.body, "ℝ"),
+            other => panic!("Expected ℝ identifier, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_function_renders_domain_and_codomain() {
+        use crate::subjects::math::theories::VariantSet;
+
+        let unit_interval = Set::interval(Some(0.into()), Some(1.into()), true, true);
+        let object = MathObject::Function(Function {
+            domain: Space::Set(unit_interval.clone()),
+            codomain: Space::Set(Set::reals()),
+            properties: VariantSet::new(),
+            concrete: None,
+        });
+        let node = object.to_turn_math("fn".to_string());
+
+        let MathNodeContent::Relationship { lhs, operator, rhs } = node.content.as_ref() else {
+            panic!("Expected f: A → B, got {:?}", node.content);
+        };
+        assert!(matches!(operator, RelationOperatorNode::Custom(symbol) if symbol == ":"));
+        assert!(matches!(
+            lhs.content.as_ref(),
+            MathNodeContent::Identifier(identifier) if identifier.body == "f"
+        ));
+
+        let MathNodeContent::Relationship { lhs, operator, rhs } = rhs.content.as_ref() else {
+            panic!("Expected A → B, got {:?}", rhs.content);
+        };
+        assert!(matches!(operator, RelationOperatorNode::Custom(symbol) if symbol == "→"));
+        assert_eq!(
+            serde_json::to_value(lhs.as_ref()).unwrap(),
+            serde_json::to_value(unit_interval.to_turn_math("fn-domain".to_string())).unwrap()
+        );
+        assert!(matches!(
+            rhs.content.as_ref(),
+            MathNodeContent::Identifier(identifier) if identifier.body == "ℝ"
+        ));
+    }
+}
@@ -6,6 +6,7 @@ pub mod extract;
 pub mod interpretation;
 pub mod location;
 pub mod math_object;
+pub mod options;
 pub mod proof;
 pub mod relations;
 pub mod tactics;
@@ -17,4 +18,5 @@ pub mod theorem;
 
 // Diagnostic: Explicitly bring the module containing ToTurnMath impls into scope here.
 // This shouldn't typically be necessary for trait impls to be found if types/traits are in scope.
+#[cfg(feature = "groups")]
 pub use crate::subjects::math::theories::groups::render as _; // Underscore to avoid unused warning if not directly used
@@ -0,0 +1,66 @@
+use crate::turn_render::{MathNode, ToTurnMath};
+
+/// How an object with a standard notation is introduced.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NotationStyle {
+    /// Name the kind of object before its notation, as in "Cyclic group C₅"
+    #[default]
+    Prose,
+    /// The notation alone, as in "C₅"
+    Symbolic,
+}
+
+/// Options controlling how much detail `to_turn_math_with` renders.
+///
+/// The defaults reproduce `to_turn_math`, so consumers only set what they need.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct RenderOptions {
+    /// Leave out generic cores where the object's type already determines it,
+    /// rendering its standard notation instead.
+    pub compact_cores: bool,
+    /// How objects rendered with their notation are introduced.
+    pub notation: NotationStyle,
+    /// Follow the rendering with a summary of the object's known properties.
+    pub include_properties: bool,
+    /// How many levels of nested objects to render; deeper ones render as `…`.
+    /// `None` renders every level.
+    pub max_depth: Option<usize>,
+    /// Write right-nested ordered pairs `(a, (b, c))` as the tuple `(a, b, c)`.
+    pub flatten_tuple: bool,
+    /// Leave internal bookkeeping entries out of displayed proof contexts.
+    pub hide_internal_context: bool,
+}
+
+impl RenderOptions {
+    /// Symbolic notation without cores or properties, for tight spaces.
+    pub fn compact() -> Self {
+        RenderOptions {
+            compact_cores: true,
+            notation: NotationStyle::Symbolic,
+            include_properties: false,
+            max_depth: None,
+            flatten_tuple: true,
+            hide_internal_context: true,
+        }
+    }
+
+    /// The options for an object nested one level deeper.
+    pub fn nested(&self) -> Self {
+        RenderOptions {
+            max_depth: self.max_depth.map(|depth| depth.saturating_sub(1)),
+            ..*self
+        }
+    }
+
+    /// Whether the depth limit has been used up.
+    pub fn depth_exhausted(&self) -> bool {
+        self.max_depth == Some(0)
+    }
+}
+
+/// Math rendering that can be tuned with `RenderOptions`.
+///
+/// Implementors render `to_turn_math` as `to_turn_math_with` the default options.
+pub trait ToTurnMathConfigurable: ToTurnMath {
+    fn to_turn_math_with(&self, master_id: String, options: &RenderOptions) -> MathNode;
+}
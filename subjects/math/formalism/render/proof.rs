@@ -1,4 +1,5 @@
-use super::super::proof::{ProofForest, ProofNode};
+use super::super::proof::{ContextEntry, ProofForest, ProofNode};
+use super::options::RenderOptions;
 use crate::{
     subjects::math::formalism::proof::{NodeRole, SubgoalCombination, tactics::Tactic},
     turn_render::second_order_math_node::{
@@ -17,9 +18,40 @@ use crate::{
         LayoutDirection, LayoutType, MathNode, NodeState, NodeType, RichText, RichTextSegment,
         SecondOrderMathNode, Section, SectionContentNode, ToRichText, ToSectionNode, ToTurnMath,
         TransformationFlow,
+        section_node::{ProofDisplayNode, ProofStepNode},
     },
 };
 
+impl ToRichText for ContextEntry {
+    /// Renders the entry as `name : type`, followed by its description if any.
+    fn to_rich_text(&self) -> RichText {
+        let type_id = format!("context-{}-type", self.name);
+        let type_node = match &self.ty.data {
+            crate::subjects::math::formalism::extract::Parametrizable::Concrete(expr) => {
+                expr.to_turn_math(type_id)
+            }
+            crate::subjects::math::formalism::extract::Parametrizable::Variable(id) => {
+                id.to_turn_math(type_id)
+            }
+        };
+
+        let mut segments = vec![
+            RichTextSegment::Math(self.name.to_turn_math(format!("context-name-{}", self.name))),
+            RichTextSegment::Text(" : ".to_string()),
+            RichTextSegment::Math(type_node),
+        ];
+        if let Some(description) = &self.description {
+            segments.push(RichTextSegment::Text(" — ".to_string()));
+            segments.extend(description.segments.iter().cloned());
+        }
+
+        RichText {
+            segments,
+            alignment: None,
+        }
+    }
+}
+
 impl ProofForest {
     // build_proof_tree method removed since proof types are not exported from section_node
 
@@ -113,6 +145,13 @@ impl ProofForest {
                                 .iter()
                                 .any(|subgoal_id| self.is_branch_complete_for_display(subgoal_id))
                         }
+                        SubgoalCombination::Sequential => {
+                            // Checked in order, stopping at the first open subgoal,
+                            // so a continuation never counts before its lemma
+                            subgoal_ids
+                                .iter()
+                                .all(|subgoal_id| self.is_branch_complete_for_display(subgoal_id))
+                        }
                         _ => false,
                     };
                     result
@@ -139,6 +178,20 @@ impl ProofForest {
 }
 
 impl ProofNode {
+    /// Render the author's hint as "Hint: …", if the node has one
+    pub fn render_hint(&self) -> Option<RichText> {
+        let hint = self.hint.as_ref()?;
+        let mut segments = vec![RichTextSegment::StyledText {
+            text: "Hint: ".to_string(),
+            styles: vec![crate::turn_render::TextStyle::Italic],
+        }];
+        segments.extend(hint.segments.iter().cloned());
+        Some(RichText {
+            segments,
+            alignment: None,
+        })
+    }
+
     /// Render tactic information as RichText
     pub fn render_tactic_info(&self, id_prefix: &str) -> RichText {
         use crate::turn_render::{RichTextSegment, TextStyle};
@@ -156,12 +209,21 @@ impl ProofNode {
             Tactic::CaseAnalysis { on_variable, cases } => {
                 ("Case Analysis".to_string(), vec![on_variable.body.clone(), format!("{} cases", cases.len())])
             }
+            Tactic::CaseOnMembership { .. } => {
+                ("Case On Membership".to_string(), vec![])
+            }
             Tactic::Induction { variable_name, hypothesis_name } => {
                 ("Induction".to_string(), vec![variable_name.body.clone(), hypothesis_name.body.clone()])
             }
             Tactic::ProvideWitness { target_quantifier, witness: _ } => {
                 ("Provide Witness".to_string(), vec![target_quantifier.body.clone()])
             }
+            Tactic::Have { name, statement: _ } => {
+                ("Have".to_string(), vec![name.body.clone()])
+            }
+            Tactic::Suffices { statement: _ } => {
+                ("Suffices".to_string(), vec![])
+            }
             Tactic::SplitAssumptionConjunction { target_hypothesis, with_names } => {
                 let names: Vec<String> = with_names.iter().map(|id| id.body.clone()).collect();
                 ("Split Assumption Conjunction".to_string(), vec![target_hypothesis.body.clone()])
@@ -179,9 +241,25 @@ impl ProofNode {
                     }
                 }
             }
+            Tactic::Assumption => {
+                ("By Assumption".to_string(), vec![])
+            }
+            Tactic::Apply { source } => {
+                match source {
+                    crate::subjects::math::formalism::proof::tactics::RelationSource::LocalAssumption(id) => {
+                        ("Apply".to_string(), vec![id.body.clone()])
+                    }
+                    crate::subjects::math::formalism::proof::tactics::RelationSource::Theorem(theorem_id, _) => {
+                        ("Apply".to_string(), vec![theorem_id.clone()])
+                    }
+                }
+            }
             Tactic::ByReflexivity => {
                 ("By Reflexivity".to_string(), vec![])
             }
+            Tactic::Trivial => {
+                ("Trivially".to_string(), vec![])
+            }
             Tactic::ByContradiction { hypothesis1, hypothesis2 } => {
                 ("By Contradiction".to_string(), vec![hypothesis1.body.clone(), hypothesis2.body.clone()])
             }
@@ -199,6 +277,17 @@ impl ProofNode {
                 };
                 ("Rewrite".to_string(), vec![rule_name, direction_str.to_string(), format!("{} instantiations", instantiations.len())])
             }
+            Tactic::RewriteSides { left_rule, right_rule } => {
+                let rule_name = |rule: &Option<crate::subjects::math::formalism::proof::tactics::RelationSource>| match rule {
+                    Some(crate::subjects::math::formalism::proof::tactics::RelationSource::LocalAssumption(id)) => id.body.clone(),
+                    Some(crate::subjects::math::formalism::proof::tactics::RelationSource::Theorem(theorem_id, _)) => theorem_id.clone(),
+                    None => "-".to_string(),
+                };
+                ("Rewrite Sides".to_string(), vec![rule_name(left_rule), rule_name(right_rule)])
+            }
+            Tactic::RewriteChain { equalities } => {
+                ("Rewrite Chain".to_string(), equalities.iter().map(|id| id.body.clone()).collect())
+            }
             Tactic::UnfoldDefinition { definition_to_unfold, target: _ } => {
                 ("Unfold Definition".to_string(), vec![definition_to_unfold.body.clone()])
             }
@@ -211,6 +300,18 @@ impl ProofNode {
             Tactic::Revert { hypothesis_to_revert } => {
                 ("Revert".to_string(), vec![hypothesis_to_revert.body.clone()])
             }
+            #[cfg(feature = "groups")]
+            Tactic::ApplyFirstIsomorphism { homomorphism, with_name } => {
+                ("Apply First Isomorphism".to_string(), vec![homomorphism.body.clone(), with_name.body.clone()])
+            }
+            #[cfg(feature = "groups")]
+            Tactic::UnfoldGroupConstruction { on_variable } => {
+                ("Unfold Group Construction".to_string(), vec![on_variable.body.clone()])
+            }
+            #[cfg(feature = "groups")]
+            Tactic::AssertProperty { on_variable, property } => {
+                ("Assert Property".to_string(), vec![on_variable.body.clone(), format!("{:?}", property)])
+            }
             Tactic::SearchAssumptions => {
                 ("Search Assumptions".to_string(), vec![])
             }
@@ -223,6 +324,9 @@ impl ProofNode {
             Tactic::Simplify { target: _ } => {
                 ("Simplify".to_string(), vec![])
             }
+            Tactic::Normalize { target: _ } => {
+                ("Normalize".to_string(), vec![])
+            }
             Tactic::Auto { depth, with_tactics } => {
                 let depth_str = depth.map(|d| d.to_string()).unwrap_or_else(|| "unlimited".to_string());
                 ("Auto".to_string(), vec![depth_str, format!("{} tactics", with_tactics.len())])
@@ -285,23 +389,38 @@ impl ProofNode {
             Tactic::SplitGoalConjunction => "Split Goal Conjunction".to_string(),
             Tactic::SplitGoalDisjunction { .. } => "Split Goal Disjunction".to_string(),
             Tactic::CaseAnalysis { .. } => "Case Analysis".to_string(),
+            Tactic::CaseOnMembership { .. } => "Case On Membership".to_string(),
             Tactic::Induction { .. } => "Induction".to_string(),
             Tactic::ProvideWitness { .. } => "Provide Witness".to_string(),
+            Tactic::Have { .. } => "Have".to_string(),
+            Tactic::Suffices { .. } => "Suffices".to_string(),
             Tactic::SplitAssumptionConjunction { .. } => "Split Assumption Conjunction".to_string(),
             Tactic::SplitAssumptionDisjunction { .. } => "Split Assumption Disjunction".to_string(),
             Tactic::ByRelation(..) => "By Relation".to_string(),
+            Tactic::Assumption => "By Assumption".to_string(),
+            Tactic::Apply { .. } => "Apply".to_string(),
             Tactic::ByReflexivity => "By Reflexivity".to_string(),
+            Tactic::Trivial => "Trivially".to_string(),
             Tactic::ByContradiction { .. } => "By Contradiction".to_string(),
             Tactic::ByGoalContradiction { .. } => "By Goal Contradiction".to_string(),
             Tactic::Rewrite { .. } => "Rewrite".to_string(),
+            Tactic::RewriteSides { .. } => "Rewrite Sides".to_string(),
+            Tactic::RewriteChain { .. } => "Rewrite Chain".to_string(),
             Tactic::UnfoldDefinition { .. } => "Unfold Definition".to_string(),
             Tactic::IntroduceLetBinding { .. } => "Introduce Let Binding".to_string(),
             Tactic::RenameBoundVariable { .. } => "Rename Bound Variable".to_string(),
             Tactic::Revert { .. } => "Revert".to_string(),
+            #[cfg(feature = "groups")]
+            Tactic::ApplyFirstIsomorphism { .. } => "Apply First Isomorphism".to_string(),
+            #[cfg(feature = "groups")]
+            Tactic::UnfoldGroupConstruction { .. } => "Unfold Group Construction".to_string(),
+            #[cfg(feature = "groups")]
+            Tactic::AssertProperty { .. } => "Assert Property".to_string(),
             Tactic::SearchAssumptions => "Search Assumptions".to_string(),
             Tactic::SearchTheoremLibrary => "Search Theorem Library".to_string(),
             Tactic::Search => "Search".to_string(),
             Tactic::Simplify { .. } => "Simplify".to_string(),
+            Tactic::Normalize { .. } => "Normalize".to_string(),
             Tactic::Auto { .. } => "Auto".to_string(),
             Tactic::DisproveByTheorem { .. } => "Disprove By Theorem".to_string(),
             Tactic::RefineVariable { .. } => "Refine Variable".to_string(),
@@ -325,12 +444,21 @@ impl ProofNode {
                 elements.push(format!("{} cases", cases.len()));
                 elements
             }
+            Tactic::CaseOnMembership { .. } => {
+                vec!["∈".to_string(), "∉".to_string()]
+            }
             Tactic::Induction { variable_name, hypothesis_name } => {
                 vec![variable_name.body.clone(), hypothesis_name.body.clone()]
             }
             Tactic::ProvideWitness { target_quantifier, witness: _ } => {
                 vec![target_quantifier.body.clone()]
             }
+            Tactic::Have { name, statement: _ } => {
+                vec![name.body.clone()]
+            }
+            Tactic::Suffices { statement: _ } => {
+                vec![]
+            }
             Tactic::SplitAssumptionConjunction { target_hypothesis, with_names } => {
                 let mut elements = vec![target_hypothesis.body.clone()];
                 elements.extend(with_names.iter().map(|id| id.body.clone()));
@@ -351,9 +479,25 @@ impl ProofNode {
                     }
                 }
             }
+            Tactic::Assumption => {
+                vec![]
+            }
+            Tactic::Apply { source } => {
+                match source {
+                    crate::subjects::math::formalism::proof::tactics::RelationSource::LocalAssumption(id) => {
+                        vec![id.body.clone()]
+                    }
+                    crate::subjects::math::formalism::proof::tactics::RelationSource::Theorem(theorem_id, _) => {
+                        vec![theorem_id.clone()]
+                    }
+                }
+            }
             Tactic::ByReflexivity => {
                 vec![]
             }
+            Tactic::Trivial => {
+                vec![]
+            }
             Tactic::ByContradiction { hypothesis1, hypothesis2 } => {
                 vec![hypothesis1.body.clone(), hypothesis2.body.clone()]
             }
@@ -371,6 +515,17 @@ impl ProofNode {
                 };
                 vec![rule_name, direction_str.to_string(), format!("{} instantiations", instantiations.len())]
             }
+            Tactic::RewriteSides { left_rule, right_rule } => {
+                let rule_name = |rule: &Option<crate::subjects::math::formalism::proof::tactics::RelationSource>| match rule {
+                    Some(crate::subjects::math::formalism::proof::tactics::RelationSource::LocalAssumption(id)) => id.body.clone(),
+                    Some(crate::subjects::math::formalism::proof::tactics::RelationSource::Theorem(theorem_id, _)) => theorem_id.clone(),
+                    None => "-".to_string(),
+                };
+                vec![rule_name(left_rule), rule_name(right_rule)]
+            }
+            Tactic::RewriteChain { equalities } => {
+                equalities.iter().map(|id| id.body.clone()).collect()
+            }
             Tactic::UnfoldDefinition { definition_to_unfold, target: _ } => {
                 vec![definition_to_unfold.body.clone()]
             }
@@ -383,6 +538,18 @@ impl ProofNode {
             Tactic::Revert { hypothesis_to_revert } => {
                 vec![hypothesis_to_revert.body.clone()]
             }
+            #[cfg(feature = "groups")]
+            Tactic::ApplyFirstIsomorphism { homomorphism, with_name } => {
+                vec![homomorphism.body.clone(), with_name.body.clone()]
+            }
+            #[cfg(feature = "groups")]
+            Tactic::UnfoldGroupConstruction { on_variable } => {
+                vec![on_variable.body.clone()]
+            }
+            #[cfg(feature = "groups")]
+            Tactic::AssertProperty { on_variable, property } => {
+                vec![on_variable.body.clone(), format!("{:?}", property)]
+            }
             Tactic::SearchAssumptions => {
                 vec![]
             }
@@ -395,6 +562,9 @@ impl ProofNode {
             Tactic::Simplify { target: _ } => {
                 vec![]
             }
+            Tactic::Normalize { target: _ } => {
+                vec![]
+            }
             Tactic::Auto { depth, with_tactics } => {
                 let depth_str = depth.map(|d| d.to_string()).unwrap_or_else(|| "unlimited".to_string());
                 vec![depth_str, format!("{} tactics", with_tactics.len())]
@@ -646,10 +816,12 @@ impl ProofNode {
     /// Get the workflow stage for this transformation
     fn get_workflow_stage(&self) -> TransformationWorkflowStage {
         match &self.tactic {
-            Tactic::Rewrite { .. } => TransformationWorkflowStage::Replace,
+            Tactic::Rewrite { .. } | Tactic::RewriteSides { .. } | Tactic::RewriteChain { .. } => {
+                TransformationWorkflowStage::Replace
+            }
             Tactic::AssumeImplicationAntecedent { .. } => TransformationWorkflowStage::Prescribe,
             Tactic::SplitAssumptionConjunction { .. } => TransformationWorkflowStage::Search,
-            Tactic::ByReflexivity => TransformationWorkflowStage::Verify,
+            Tactic::ByReflexivity | Tactic::Trivial => TransformationWorkflowStage::Verify,
             _ => TransformationWorkflowStage::Search,
         }
     }
@@ -761,23 +933,36 @@ impl ProofNode {
             | Tactic::SplitGoalConjunction
             | Tactic::SplitGoalDisjunction { .. }
             | Tactic::CaseAnalysis { .. }
+            | Tactic::CaseOnMembership { .. }
             | Tactic::Induction { .. }
-            | Tactic::ProvideWitness { .. } => "introduction".to_string(),
+            | Tactic::ProvideWitness { .. }
+            | Tactic::Have { .. }
+            | Tactic::Suffices { .. } => "introduction".to_string(),
             Tactic::SplitAssumptionConjunction { .. }
             | Tactic::SplitAssumptionDisjunction { .. } => "elimination".to_string(),
             Tactic::ByRelation(..)
+            | Tactic::Assumption
+            | Tactic::Apply { .. }
             | Tactic::ByReflexivity
+            | Tactic::Trivial
             | Tactic::ByContradiction { .. }
             | Tactic::ByGoalContradiction { .. } => "completion".to_string(),
             Tactic::Rewrite { .. }
+            | Tactic::RewriteSides { .. }
+            | Tactic::RewriteChain { .. }
             | Tactic::UnfoldDefinition { .. }
             | Tactic::IntroduceLetBinding { .. }
             | Tactic::RenameBoundVariable { .. }
             | Tactic::Revert { .. } => "structural".to_string(),
+            #[cfg(feature = "groups")]
+            Tactic::ApplyFirstIsomorphism { .. }
+            | Tactic::UnfoldGroupConstruction { .. }
+            | Tactic::AssertProperty { .. } => "structural".to_string(),
             Tactic::SearchAssumptions
             | Tactic::SearchTheoremLibrary
             | Tactic::Search
             | Tactic::Simplify { .. }
+            | Tactic::Normalize { .. }
             | Tactic::Auto { .. } => "automated".to_string(),
             Tactic::DisproveByTheorem { .. } => "meta-logical".to_string(),
             Tactic::RefineVariable { .. } => "type-roles".to_string(),
@@ -805,6 +990,9 @@ impl ProofNode {
                     cases.len()
                 )
             }
+            Tactic::CaseOnMembership { .. } => {
+                "Splits into the cases where the element is and is not in the set".to_string()
+            }
             Tactic::Induction {
                 variable_name,
                 hypothesis_name,
@@ -822,6 +1010,12 @@ impl ProofNode {
                     target_quantifier
                 )
             }
+            Tactic::Have { name, .. } => {
+                format!("Proves an intermediate lemma {} and adds it to the context", name)
+            }
+            Tactic::Suffices { .. } => {
+                "Reduces the goal to a sufficient statement that implies it".to_string()
+            }
             Tactic::SplitAssumptionConjunction {
                 target_hypothesis,
                 with_names,
@@ -845,7 +1039,12 @@ impl ProofNode {
             Tactic::ByRelation(relation_source) => {
                 format!("Applies relation: {:?}", relation_source)
             }
+            Tactic::Assumption => "Solves goal by the matching hypothesis".to_string(),
+            Tactic::Apply { source } => {
+                format!("Applies lemma {:?}, leaving its hypotheses as goals", source)
+            }
             Tactic::ByReflexivity => "Solves equality t = t by reflexivity".to_string(),
+            Tactic::Trivial => "Solves an obviously true goal".to_string(),
             Tactic::ByContradiction {
                 hypothesis1,
                 hypothesis2,
@@ -881,6 +1080,19 @@ impl ProofNode {
                     instantiations.len()
                 )
             }
+            Tactic::RewriteSides {
+                left_rule,
+                right_rule,
+            } => {
+                format!(
+                    "Rewrites the left side using {:?} and the right side using {:?}",
+                    left_rule, right_rule
+                )
+            }
+            Tactic::RewriteChain { equalities } => {
+                let names: Vec<&str> = equalities.iter().map(|id| id.body.as_str()).collect();
+                format!("Rewrites the left side along the chain {}", names.join(", "))
+            }
             Tactic::UnfoldDefinition {
                 definition_to_unfold,
                 target,
@@ -917,6 +1129,30 @@ impl ProofNode {
                     hypothesis_to_revert
                 )
             }
+            #[cfg(feature = "groups")]
+            Tactic::ApplyFirstIsomorphism {
+                homomorphism,
+                with_name,
+            } => {
+                format!(
+                    "Adds {}: G/ker({}) ≅ im({}) to context",
+                    with_name, homomorphism, homomorphism
+                )
+            }
+            #[cfg(feature = "groups")]
+            Tactic::UnfoldGroupConstruction { on_variable } => {
+                format!("Replaces {} by its defining set of elements", on_variable)
+            }
+            #[cfg(feature = "groups")]
+            Tactic::AssertProperty {
+                on_variable,
+                property,
+            } => {
+                format!(
+                    "Asserts {} has {:?}, leaving its justification as a subgoal",
+                    on_variable, property
+                )
+            }
             Tactic::SearchAssumptions => "Searches context for matching hypothesis".to_string(),
             Tactic::SearchTheoremLibrary => {
                 "Searches theorem library for applicable theorem".to_string()
@@ -925,6 +1161,9 @@ impl ProofNode {
             Tactic::Simplify { target } => {
                 format!("Simplifies expression at target {:?}", target)
             }
+            Tactic::Normalize { target } => {
+                format!("Normalizes the structure at target {:?}", target)
+            }
             Tactic::Auto {
                 depth,
                 with_tactics,
@@ -949,6 +1188,16 @@ impl ProofNode {
 
     /// Convert ProofNode to BranchingNode for export
     pub fn to_branching_node(&self, id_prefix: &str) -> BranchingNode {
+        self.to_branching_node_with(id_prefix, &RenderOptions::default())
+    }
+
+    /// Convert ProofNode to BranchingNode, leaving internal context entries out
+    /// when `options.hide_internal_context` is set.
+    pub fn to_branching_node_with(
+        &self,
+        id_prefix: &str,
+        options: &RenderOptions,
+    ) -> BranchingNode {
         let node_type = match &self.role {
             NodeRole::Goal(_) => NodeType::ProofGoal,
             NodeRole::SubgoalManager { .. } => NodeType::ProofManager,
@@ -970,10 +1219,20 @@ impl ProofNode {
         // Add tactic information with detailed description
         let tactic_info = self.render_tactic_info(id_prefix);
         content.push(SectionContentNode::RichText(tactic_info));
+        if let Some(hint) = self.render_hint() {
+            content.push(SectionContentNode::RichText(hint));
+        }
 
         // Add node-specific content based on role
         match &self.role {
             NodeRole::Goal(proof_goal) => {
+                // Show the local context, one entry per line
+                for entry in &proof_goal.context {
+                    if options.hide_internal_context && entry.is_internal {
+                        continue;
+                    }
+                    content.push(SectionContentNode::RichText(entry.to_rich_text()));
+                }
                 // Render the proof goal as a Judgement
                 content.push(SectionContentNode::SecondOrderMath(
                     SecondOrderMathNode::Judgement(
@@ -1349,6 +1608,307 @@ impl ToInteractiveProofDisplay for ProofForest {
     }
 }
 
+impl ProofForest {
+    /// Render the proof as nested prose steps.
+    ///
+    /// Subgoal managers are expanded in place: an `And` manager reads
+    /// "To prove A ∧ B, we show:" followed by one labeled block per subgoal,
+    /// each holding that subgoal's own proof steps.
+    pub fn to_proof_steps_section(&self, id_prefix: &str) -> Section {
+        let mut steps = Vec::new();
+        for root_id in &self.roots {
+            self.collect_proof_steps(root_id, id_prefix, &mut steps);
+        }
+
+        let mut title_segments = vec![RichTextSegment::Text("Proof".to_string())];
+        let strategy = self.strategy_summary();
+        if !strategy.is_empty() {
+            title_segments.push(RichTextSegment::Text(". ".to_string()));
+            title_segments.extend(strategy);
+        }
+
+        Section {
+            id: format!("{}-proof-steps", id_prefix),
+            title: Some(RichText {
+                segments: title_segments,
+                alignment: None,
+            }),
+            content: SectionContentNode::SubSection(steps),
+            metadata: vec![],
+            display_options: None,
+        }
+    }
+
+    /// One-line overview of the proof, e.g. "By induction on n".
+    ///
+    /// The strategy is read off the first tactic applied to each root goal;
+    /// roots that have not been worked on yet contribute nothing.
+    pub fn strategy_summary(&self) -> Vec<RichTextSegment> {
+        let mut summaries: Vec<String> = Vec::new();
+        for root_id in &self.roots {
+            let Some(first_step) = self
+                .get_node(root_id)
+                .and_then(|root| root.children.first())
+                .and_then(|child_id| self.get_node(child_id))
+            else {
+                continue;
+            };
+
+            let summary = match &first_step.tactic {
+                Tactic::Induction { variable_name, .. } => {
+                    format!("By induction on {}", variable_name)
+                }
+                Tactic::CaseAnalysis { on_variable, .. } => {
+                    format!("By case analysis on {}", on_variable)
+                }
+                Tactic::CaseOnMembership { .. } => "By case analysis on membership".to_string(),
+                Tactic::ByContradiction { .. } | Tactic::ByGoalContradiction { .. } => {
+                    "By contradiction".to_string()
+                }
+                Tactic::Rewrite { .. }
+                | Tactic::RewriteSides { .. }
+                | Tactic::RewriteChain { .. }
+                | Tactic::Simplify { .. }
+                | Tactic::Normalize { .. }
+                | Tactic::UnfoldDefinition { .. }
+                | Tactic::ByReflexivity => "Direct computation".to_string(),
+                _ => "Direct proof".to_string(),
+            };
+            if !summaries.contains(&summary) {
+                summaries.push(summary);
+            }
+        }
+
+        if summaries.is_empty() {
+            vec![]
+        } else {
+            vec![RichTextSegment::Text(format!("{}.", summaries.join("; ")))]
+        }
+    }
+
+    /// The proof skeleton: its strategy and key steps, without the micro-steps.
+    ///
+    /// Only major steps (`Have`, `Apply`, `Induction`, `CaseAnalysis`,
+    /// `DisproveByTheorem`) are listed. Each run of consecutive rewriting steps
+    /// is collapsed into a single "Simplify/rewrite" step, other steps are left
+    /// out, and the QED symbol is set once the proof is complete.
+    pub fn to_outline(&self) -> ProofDisplayNode {
+        let outline_prefix = "outline";
+        // Subgoals carry their manager's tactic; count each split once, at the manager.
+        let managed: std::collections::HashSet<&String> = self
+            .node_values()
+            .filter_map(|node| match &node.role {
+                NodeRole::SubgoalManager { subgoal_ids, .. } => Some(subgoal_ids.iter()),
+                _ => None,
+            })
+            .flatten()
+            .collect();
+
+        let mut steps = Vec::new();
+        let mut rewrites = 0;
+        let mut pending = self
+            .roots
+            .iter()
+            .rev()
+            .filter_map(|root_id| self.get_node(root_id))
+            .flat_map(|root| root.children.iter().rev())
+            .collect::<Vec<_>>();
+        while let Some(node_id) = pending.pop() {
+            let Some(node) = self.get_node(node_id) else {
+                continue;
+            };
+            pending.extend(node.children.iter().rev());
+            if managed.contains(node_id) {
+                continue;
+            }
+
+            match &node.tactic {
+                Tactic::Rewrite { .. }
+                | Tactic::RewriteSides { .. }
+                | Tactic::RewriteChain { .. }
+                | Tactic::Simplify { .. }
+                | Tactic::Normalize { .. }
+                | Tactic::UnfoldDefinition { .. } => {
+                    rewrites += 1;
+                    continue;
+                }
+                _ => {}
+            }
+            Self::push_rewrite_summary(&mut rewrites, &mut steps);
+            if matches!(
+                node.tactic,
+                Tactic::Have { .. }
+                    | Tactic::Apply { .. }
+                    | Tactic::Induction { .. }
+                    | Tactic::CaseAnalysis { .. }
+                    | Tactic::DisproveByTheorem { .. }
+            ) {
+                steps.push(ProofStepNode::Statement {
+                    claim: node.render_tactic_info(outline_prefix).segments,
+                    justification: vec![],
+                });
+            }
+        }
+        Self::push_rewrite_summary(&mut rewrites, &mut steps);
+
+        ProofDisplayNode {
+            title: Some(RichText {
+                segments: vec![RichTextSegment::Text("Proof outline".to_string())],
+                alignment: None,
+            }),
+            strategy: self.strategy_summary(),
+            steps,
+            qed_symbol: (!self.roots.is_empty() && self.is_fully_proven()).then(|| "∎".to_string()),
+        }
+    }
+
+    /// Ends a run of rewriting steps in the outline with one summary step.
+    fn push_rewrite_summary(rewrites: &mut usize, steps: &mut Vec<ProofStepNode>) {
+        if *rewrites == 0 {
+            return;
+        }
+        let text = match *rewrites {
+            1 => "Simplify/rewrite (1 step)".to_string(),
+            count => format!("Simplify/rewrite ({} steps)", count),
+        };
+        steps.push(ProofStepNode::Statement {
+            claim: vec![RichTextSegment::Text(text)],
+            justification: vec![],
+        });
+        *rewrites = 0;
+    }
+
+    fn collect_proof_steps(&self, node_id: &str, id_prefix: &str, steps: &mut Vec<Section>) {
+        let Some(node) = self.get_node(node_id) else {
+            return;
+        };
+
+        if let NodeRole::SubgoalManager {
+            subgoal_ids,
+            combination_type,
+        } = &node.role
+        {
+            steps.push(self.subgoal_manager_section(node, subgoal_ids, combination_type, id_prefix));
+            return;
+        }
+
+        let mut step_text = node.render_tactic_info(id_prefix);
+        if let Some(hint) = node.render_hint() {
+            step_text.segments.push(RichTextSegment::Text(" — ".to_string()));
+            step_text.segments.extend(hint.segments);
+        }
+        steps.push(Section {
+            id: format!("{}-step-{}", id_prefix, node.id),
+            title: None,
+            content: SectionContentNode::RichText(step_text),
+            metadata: vec![],
+            display_options: None,
+        });
+
+        // Subgoals are listed as siblings of their manager; render them only inside it.
+        let managed: std::collections::HashSet<&String> = node
+            .children
+            .iter()
+            .filter_map(|child_id| self.get_node(child_id))
+            .filter_map(|child| match &child.role {
+                NodeRole::SubgoalManager { subgoal_ids, .. } => Some(subgoal_ids.iter()),
+                _ => None,
+            })
+            .flatten()
+            .collect();
+
+        for child_id in &node.children {
+            if !managed.contains(child_id) {
+                self.collect_proof_steps(child_id, id_prefix, steps);
+            }
+        }
+    }
+
+    fn subgoal_manager_section(
+        &self,
+        manager: &ProofNode,
+        subgoal_ids: &[String],
+        combination_type: &SubgoalCombination,
+        id_prefix: &str,
+    ) -> Section {
+        let manager_prefix = format!("{}-manager-{}", id_prefix, manager.id);
+
+        let (lead_in, phrase) = match combination_type {
+            SubgoalCombination::And => ("To prove ", ", we show:".to_string()),
+            SubgoalCombination::Or => ("To prove ", ", it suffices to show one of:".to_string()),
+            SubgoalCombination::Sequential => ("To prove ", ", we show in order:".to_string()),
+            SubgoalCombination::Custom(how) => ("To prove ", format!(", we combine ({}):", how)),
+        };
+
+        let mut title_segments = vec![RichTextSegment::Text(lead_in.to_string())];
+        match manager
+            .parent
+            .as_ref()
+            .and_then(|parent_id| self.get_node(parent_id))
+            .filter(|parent| parent.has_goal())
+        {
+            Some(parent) => title_segments.push(RichTextSegment::Math(
+                parent
+                    .get_goal()
+                    .statement
+                    .to_turn_math(format!("{}-statement", manager_prefix)),
+            )),
+            None => title_segments.push(RichTextSegment::Text("the goal".to_string())),
+        }
+        title_segments.push(RichTextSegment::Text(phrase));
+
+        let blocks = subgoal_ids
+            .iter()
+            .enumerate()
+            .filter_map(|(index, subgoal_id)| {
+                let subgoal = self.get_node(subgoal_id)?;
+                let label = index + 1;
+
+                let mut block_title = vec![RichTextSegment::StyledText {
+                    text: format!("({}) ", label),
+                    styles: vec![crate::turn_render::TextStyle::Bold],
+                }];
+                if let NodeRole::Goal(goal) = &subgoal.role {
+                    block_title.push(RichTextSegment::Math(
+                        goal.statement
+                            .to_turn_math(format!("{}-subgoal-{}-statement", manager_prefix, label)),
+                    ));
+                }
+
+                let mut block_steps = Vec::new();
+                for child_id in &subgoal.children {
+                    self.collect_proof_steps(child_id, id_prefix, &mut block_steps);
+                }
+
+                Some(Section {
+                    id: format!("{}-subgoal-{}", manager_prefix, label),
+                    title: Some(RichText {
+                        segments: block_title,
+                        alignment: None,
+                    }),
+                    content: SectionContentNode::SubSection(block_steps),
+                    metadata: vec![("subgoal_id".to_string(), subgoal_id.clone())],
+                    display_options: None,
+                })
+            })
+            .collect();
+
+        Section {
+            id: manager_prefix,
+            title: Some(RichText {
+                segments: title_segments,
+                alignment: None,
+            }),
+            content: SectionContentNode::SubSection(blocks),
+            metadata: vec![(
+                "combination".to_string(),
+                format!("{:?}", combination_type),
+            )],
+            display_options: None,
+        }
+    }
+}
+
 // Add a simple export function for proof forests
 impl ProofForest {
     /// Export proof forest as a SecondOrderMathNode for rendering
@@ -1395,9 +1955,10 @@ mod tests {
             role: NodeRole::Goal(proof_goal),
             tactic: Tactic::ByReflexivity,
             description: None,
+            hint: None,
         };
 
-        forest.add_node(proof_node);
+        forest.add_node(proof_node).unwrap();
         forest.roots.push("test-node".to_string());
 
         // Test the export function
@@ -1411,4 +1972,336 @@ mod tests {
             _ => panic!("Expected InteractiveProof variant"),
         }
     }
+
+    #[test]
+    fn test_context_entry_renders_name_and_type() {
+        use crate::subjects::math::formalism::proof::DefinitionState;
+        use crate::subjects::math::theories::number_theory::definitions::Number;
+        use crate::turn_render::{Identifier, MathNodeContent};
+
+        let hypothesis = ContextEntry {
+            name: Identifier::new_simple("H1".to_string()),
+            ty: Located::new_concrete(MathExpression::Relation(std::sync::Arc::new(
+                MathRelation::equal(
+                    MathExpression::Number(Number::int(1)),
+                    MathExpression::Number(Number::int(1)),
+                ),
+            ))),
+            definition: DefinitionState::Abstract,
+            description: Some(RichText {
+                segments: vec![RichTextSegment::Text("assumed".to_string())],
+                alignment: None,
+            }),
+            is_internal: false,
+        };
+
+        let rich_text = hypothesis.to_rich_text();
+        match &rich_text.segments[0] {
+            RichTextSegment::Math(node) => match node.content.as_ref() {
+                MathNodeContent::Identifier(identifier) => assert_eq!(identifier.body, "H1"),
+                other => panic!("Expected the entry name, got {:?}", other),
+            },
+            other => panic!("Expected a math segment for the name, got {:?}", other),
+        }
+        assert!(matches!(rich_text.segments[2], RichTextSegment::Math(_)));
+        assert!(matches!(
+            rich_text.segments.last(),
+            Some(RichTextSegment::Text(text)) if text == "assumed"
+        ));
+    }
+
+    #[test]
+    fn test_conjunction_split_renders_labeled_subproofs() {
+        use crate::subjects::math::theories::number_theory::definitions::Number;
+
+        let part = || {
+            Located::new_concrete(MathRelation::equal(
+                MathExpression::Number(Number::int(1)),
+                MathExpression::Number(Number::int(1)),
+            ))
+        };
+        let goal = ProofGoal {
+            context: vec![],
+            quantifiers: vec![],
+            statement: Located::new_concrete(MathRelation::And(vec![part(), part()])),
+        };
+
+        let mut forest = ProofForest::new_from_goal(goal.clone());
+        forest
+            .add_node(ProofNode {
+                id: "root".to_string(),
+                parent: None,
+                children: vec![],
+                role: NodeRole::Goal(goal),
+                tactic: Tactic::ByReflexivity,
+                description: None,
+                hint: None,
+            })
+            .unwrap();
+        forest.roots.push("root".to_string());
+
+        let root = forest.get_node("root").unwrap().clone();
+        root.apply_tactic(Tactic::SplitGoalConjunction, &mut forest);
+
+        let section = forest.to_proof_steps_section("test");
+        let SectionContentNode::SubSection(steps) = &section.content else {
+            panic!("Expected proof steps");
+        };
+        let manager_block = steps
+            .iter()
+            .find(|step| step.id.starts_with("test-manager-"))
+            .expect("manager block rendered");
+        let SectionContentNode::SubSection(blocks) = &manager_block.content else {
+            panic!("Expected labeled subproof blocks");
+        };
+
+        assert_eq!(blocks.len(), 2);
+        assert!(blocks[0].id.ends_with("-subgoal-1"));
+        assert!(blocks[1].id.ends_with("-subgoal-2"));
+        // Subgoals appear only inside the manager block, not as top-level steps
+        assert_eq!(steps.len(), 2);
+    }
+
+    #[test]
+    fn test_induction_rooted_proof_summarizes_as_induction() {
+        use crate::turn_render::Identifier;
+
+        let goal = ProofGoal {
+            context: vec![],
+            quantifiers: vec![],
+            statement: Located::new_concrete(MathRelation::True),
+        };
+
+        let mut forest = ProofForest::new_from_goal(goal.clone());
+        forest
+            .add_node(ProofNode {
+                id: "root".to_string(),
+                parent: None,
+                children: vec!["base".to_string()],
+                role: NodeRole::Goal(goal.clone()),
+                tactic: Tactic::ByReflexivity,
+                description: None,
+                hint: None,
+            })
+            .unwrap();
+        forest
+            .add_node(ProofNode {
+                id: "base".to_string(),
+                parent: Some("root".to_string()),
+                children: vec![],
+                role: NodeRole::Goal(goal),
+                tactic: Tactic::Induction {
+                    variable_name: Identifier::new_simple("n".to_string()),
+                    hypothesis_name: Identifier::new_simple("IH".to_string()),
+                },
+                description: None,
+                hint: None,
+            })
+            .unwrap();
+        forest.roots.push("root".to_string());
+
+        let summary = forest.strategy_summary();
+        assert!(matches!(
+            summary.as_slice(),
+            [RichTextSegment::Text(text)] if text.starts_with("By induction")
+        ));
+
+        let title = forest.to_proof_steps_section("test").title.unwrap();
+        assert!(title.segments.iter().any(|segment| matches!(
+            segment,
+            RichTextSegment::Text(text) if text.starts_with("By induction on n")
+        )));
+    }
+
+    #[test]
+    fn test_hinted_node_renders_hint_alongside_step() {
+        let goal = ProofGoal {
+            context: vec![],
+            quantifiers: vec![],
+            statement: Located::new_concrete(MathRelation::True),
+        };
+
+        let mut forest = ProofForest::new_from_goal(goal.clone());
+        forest
+            .add_node(ProofNode {
+                id: "root".to_string(),
+                parent: None,
+                children: vec![],
+                role: NodeRole::Goal(goal),
+                tactic: Tactic::ByReflexivity,
+                description: None,
+                hint: Some(RichText {
+                    segments: vec![RichTextSegment::Text("both sides are equal".to_string())],
+                    alignment: None,
+                }),
+            })
+            .unwrap();
+        forest.roots.push("root".to_string());
+
+        let has_hint = |text: &RichText| {
+            text.segments.iter().any(|segment| {
+                matches!(segment, RichTextSegment::Text(text) if text == "both sides are equal")
+            })
+        };
+
+        let section = forest.to_proof_steps_section("test");
+        let SectionContentNode::SubSection(steps) = &section.content else {
+            panic!("Expected proof steps");
+        };
+        let SectionContentNode::RichText(step_text) = &steps[0].content else {
+            panic!("Expected the step as rich text");
+        };
+        assert!(has_hint(step_text));
+        assert!(step_text.segments.iter().any(|segment| matches!(
+            segment,
+            RichTextSegment::StyledText { text, .. } if text == "Hint: "
+        )));
+
+        let tree_node = forest.get_node("root").unwrap().to_branching_node("test");
+        assert!(tree_node.content.iter().any(|content| matches!(
+            content,
+            SectionContentNode::RichText(text) if has_hint(text)
+        )));
+    }
+
+    #[test]
+    fn test_internal_context_entries_hidden_from_display_but_verified() {
+        use crate::subjects::math::formalism::proof::DefinitionState;
+        use crate::subjects::math::theories::number_theory::definitions::Number;
+        use crate::turn_render::{Identifier, MathNodeContent};
+
+        let entry = |name: &str, is_internal: bool| ContextEntry {
+            name: Identifier::new_simple(name.to_string()),
+            ty: Located::new_concrete(MathExpression::Number(Number::int(1))),
+            definition: DefinitionState::Abstract,
+            description: None,
+            is_internal,
+        };
+        let goal = ProofGoal {
+            context: vec![entry("x", false), entry("witness", true)],
+            quantifiers: vec![],
+            statement: Located::new_concrete(MathRelation::True),
+        };
+        let node = ProofNode {
+            id: "root".to_string(),
+            parent: None,
+            children: vec![],
+            role: NodeRole::Goal(goal.clone()),
+            tactic: Tactic::ByReflexivity,
+            description: None,
+            hint: None,
+        };
+
+        let shown_context = |options: &RenderOptions| {
+            node.to_branching_node_with("test", options)
+                .content
+                .iter()
+                .filter_map(|content| match content {
+                    SectionContentNode::RichText(text) => text.segments.first().cloned(),
+                    _ => None,
+                })
+                .filter_map(|segment| match segment {
+                    RichTextSegment::Math(math) => match math.content.as_ref() {
+                        MathNodeContent::Identifier(identifier) => Some(identifier.body.clone()),
+                        _ => None,
+                    },
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            shown_context(&RenderOptions::default()),
+            vec!["x", "witness"]
+        );
+        let hidden = RenderOptions {
+            hide_internal_context: true,
+            ..RenderOptions::default()
+        };
+        assert_eq!(shown_context(&hidden), vec!["x"]);
+
+        // Verification still sees the hidden entry
+        let mut duplicated = goal.clone();
+        duplicated.context.push(entry("witness", false));
+        assert!(goal.verify().is_ok());
+        assert!(duplicated.verify().is_err());
+    }
+
+    #[test]
+    fn test_outline_keeps_induction_and_collapses_rewrites() {
+        use crate::subjects::math::formalism::proof::tactics::{
+            ContextOrStatement, RelationSource, RewriteDirection, Target,
+        };
+        use crate::turn_render::Identifier;
+
+        let goal = ProofGoal {
+            context: vec![],
+            quantifiers: vec![],
+            statement: Located::new_concrete(MathRelation::True),
+        };
+        let rewrite = || Tactic::Rewrite {
+            using_rule: RelationSource::LocalAssumption(Identifier::new_simple("h".to_string())),
+            target: Target::new(ContextOrStatement::Statement, goal.statement.id.clone()),
+            direction: RewriteDirection::Forward,
+            instantiations: std::collections::HashMap::new(),
+        };
+        let induction = Tactic::Induction {
+            variable_name: Identifier::new_simple("n".to_string()),
+            hypothesis_name: Identifier::new_simple("IH".to_string()),
+        };
+
+        // root -> rewrite -> rewrite -> induction -> done
+        let chain = [
+            ("root", Tactic::ByReflexivity, NodeRole::Goal(goal.clone())),
+            ("r1", rewrite(), NodeRole::Goal(goal.clone())),
+            ("r2", rewrite(), NodeRole::Goal(goal.clone())),
+            ("ind", induction, NodeRole::Goal(goal.clone())),
+            ("done", Tactic::ByReflexivity, NodeRole::Completed),
+        ];
+        let mut forest = ProofForest::new_from_goal(goal.clone());
+        for (index, (id, tactic, role)) in chain.iter().enumerate() {
+            forest
+                .add_node(ProofNode {
+                    id: id.to_string(),
+                    parent: index
+                        .checked_sub(1)
+                        .map(|parent| chain[parent].0.to_string()),
+                    children: chain
+                        .get(index + 1)
+                        .map(|(child, _, _)| vec![child.to_string()])
+                        .unwrap_or_default(),
+                    role: role.clone(),
+                    tactic: tactic.clone(),
+                    description: None,
+                    hint: None,
+                })
+                .unwrap();
+        }
+        forest.roots.push("root".to_string());
+
+        let outline = forest.to_outline();
+        let claims: Vec<String> = outline
+            .steps
+            .iter()
+            .map(|step| match step {
+                ProofStepNode::Statement { claim, .. } => claim
+                    .iter()
+                    .filter_map(|segment| match segment {
+                        RichTextSegment::Text(text) | RichTextSegment::StyledText { text, .. } => {
+                            Some(text.as_str())
+                        }
+                        _ => None,
+                    })
+                    .collect(),
+                other => panic!("Expected outline statements, got {:?}", other),
+            })
+            .collect();
+        assert_eq!(claims.len(), 2, "{:?}", claims);
+        assert_eq!(claims[0], "Simplify/rewrite (2 steps)");
+        assert!(claims[1].contains("Induction"), "{:?}", claims[1]);
+        assert_eq!(outline.qed_symbol.as_deref(), Some("∎"));
+        assert_eq!(
+            outline.strategy,
+            vec![RichTextSegment::Text("Direct computation.".to_string())]
+        );
+    }
 }
@@ -104,10 +104,13 @@ impl ToTurnMath for MathRelation {
             }
             MathRelation::NumberTheory(nr) => nr.to_turn_math(master_id),
             MathRelation::SetTheory(sr) => todo!(),
+            #[cfg(feature = "groups")]
             MathRelation::GroupTheory(gr) => gr.to_turn_math(master_id),
+            #[cfg(feature = "rings")]
             MathRelation::RingTheory(rr) => todo!(),
             MathRelation::TopologyTheory(tr) => todo!(),
             MathRelation::CategoryTheory(cr) => todo!(),
+            #[cfg(feature = "probability")]
             MathRelation::ProbabilityTheory(pr) => pr.to_turn_math(master_id),
 
             MathRelation::True => MathNode {
@@ -132,6 +135,7 @@ impl ToTurnMath for MathRelation {
                     is_function: false,
                 })),
             },
+            #[cfg(feature = "probability")]
             MathRelation::ProbabilityTheory(prob_rel) => MathNode {
                 id: "prob_rel".to_string(),
                 content: Arc::new(MathNodeContent::Text("Probability Relation".to_string())),
@@ -24,6 +24,7 @@ use crate::turn_render::*;
 
 use crate::subjects::math::formalism::automation::registry::TheoremRegistry;
 
+#[cfg(feature = "groups")]
 use crate::subjects::math::theories::groups::definitions::GroupExpression;
 
 // use crate::subjects::math::theories::groups::theorems::{
@@ -126,6 +127,85 @@ impl ToSectionNode for ProofGoal {
     }
 }
 
+impl ProofGoal {
+    /// Render the goal as a worked problem: a "Given" subsection with one row
+    /// per unquantified context entry, and a "Prove" subsection holding the
+    /// statement behind its quantifier prefix, e.g. `∀ x, ∃ y. P(x, y)`.
+    pub fn to_sequent_section(&self, id_prefix: &str) -> Section {
+        let is_quantified = |entry: &ContextEntry| {
+            self.quantifiers
+                .iter()
+                .any(|quantifier| quantifier.variable_name == entry.name)
+        };
+        let given_rows = self
+            .context
+            .iter()
+            .filter(|entry| !is_quantified(entry))
+            .map(|entry| Section {
+                id: format!("{}-given-{}", id_prefix, entry.name),
+                title: None,
+                content: SectionContentNode::RichText(entry.to_rich_text()),
+                metadata: vec![],
+                display_options: None,
+            })
+            .collect();
+
+        let mut prove_segments = Vec::new();
+        for quantifier in &self.quantifiers {
+            let symbol = match quantifier.quantification {
+                Quantification::Universal => "∀ ",
+                Quantification::Existential => "∃ ",
+                Quantification::UniqueExistential => "∃! ",
+            };
+            if !prove_segments.is_empty() {
+                prove_segments.push(RichTextSegment::Text(", ".to_string()));
+            }
+            prove_segments.push(RichTextSegment::Text(symbol.to_string()));
+            prove_segments.push(RichTextSegment::Math(
+                quantifier.variable_name.to_turn_math(format!(
+                    "{}-quantifier-{}",
+                    id_prefix, quantifier.variable_name
+                )),
+            ));
+        }
+        if !prove_segments.is_empty() {
+            prove_segments.push(RichTextSegment::Text(". ".to_string()));
+        }
+        prove_segments.push(RichTextSegment::Math(
+            self.statement
+                .to_turn_math(format!("{}-statement", id_prefix)),
+        ));
+
+        let subsection = |suffix: &str, title: &str, content: SectionContentNode| Section {
+            id: format!("{}-{}", id_prefix, suffix),
+            title: Some(RichText {
+                segments: vec![RichTextSegment::Text(title.to_string())],
+                alignment: None,
+            }),
+            content,
+            metadata: vec![],
+            display_options: None,
+        };
+        Section {
+            id: format!("{}-sequent", id_prefix),
+            title: None,
+            content: SectionContentNode::SubSection(vec![
+                subsection("given", "Given", SectionContentNode::SubSection(given_rows)),
+                subsection(
+                    "prove",
+                    "Prove",
+                    SectionContentNode::RichText(RichText {
+                        segments: prove_segments,
+                        alignment: None,
+                    }),
+                ),
+            ]),
+            metadata: vec![],
+            display_options: None,
+        }
+    }
+}
+
 impl ToSectionNode for Theorem {
     fn to_section_node(&self, id_prefix: &str) -> Section {
         let mut content = vec![
@@ -155,21 +235,31 @@ impl ToSectionNode for Theorem {
             },
         ];
 
-        // Add proof structure if available
+        // Add the proof, as prose steps and as the full proof tree, if available
         if self.proofs.node_values().next().is_some() {
-            content.push(self.proofs.to_section_node(&format!("{}-proof", id_prefix)));
+            let proof_prefix = format!("{}-proof", id_prefix);
+            content.push(self.proofs.to_proof_steps_section(&proof_prefix));
+            content.push(self.proofs.to_section_node(&proof_prefix));
         }
 
+        let status = self.proof_status();
         Section {
             id: format!("{}-main", id_prefix),
             title: Some(RichText {
-                segments: vec![RichTextSegment::Text(self.name.clone())],
+                segments: vec![
+                    RichTextSegment::Text(self.name.clone()),
+                    RichTextSegment::StyledText {
+                        text: format!(" {}", status.badge()),
+                        styles: vec![TextStyle::Italic],
+                    },
+                ],
                 alignment: None,
             }),
             content: SectionContentNode::SubSection(content),
             metadata: vec![
                 ("type".to_string(), "theorem".to_string()),
                 ("theorem_id".to_string(), self.id.clone()),
+                ("proof_status".to_string(), status.keyword().to_string()),
             ],
             display_options: None,
         }
@@ -200,7 +290,10 @@ impl ToMathDocument for Theorem {
                     date_modified: None,
                     venue: Some("Mathematical Theorems".to_string()),
                     doi: None,
-                    keywords: vec!["theorem".to_string()],
+                    keywords: vec![
+                        "theorem".to_string(),
+                        self.proof_status().keyword().to_string(),
+                    ],
                 },
                 structure: DocumentStructure {
                     abstract_content: Some(Section {
@@ -233,12 +326,15 @@ impl ToMathDocument for Theorem {
 
 #[cfg(test)]
 mod tests {
+    #[cfg(feature = "groups")]
     use crate::subjects::math::formalism::automation::registry::get_theorem_registry;
+    #[cfg(feature = "groups")]
     use crate::subjects::math::theories::groups::theorems::group_inverse_uniqueness;
 
     use super::*;
 
     #[test]
+    #[cfg(feature = "groups")]
     fn test_simple_theorem_creation() {
         // Test just creating the theorem without rendering
         let _ = get_theorem_registry();
@@ -248,6 +344,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "groups")]
     fn test_theorem_to_section_node() {
         // Test just the to_section_node method
         let _ = get_theorem_registry();
@@ -257,6 +354,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "groups")]
     fn test_proof_goal_to_section_node() {
         // Test just the ProofGoal::to_section_node method
         let _ = get_theorem_registry();
@@ -267,6 +365,194 @@ mod tests {
     }
 
     #[test]
+    fn test_proof_goal_sequent_section_has_given_and_prove() {
+        use crate::subjects::math::formalism::location::Located;
+        use crate::subjects::math::formalism::proof::Quantifier;
+
+        let variable = |name: &str| Located::new_variable(Identifier::new_simple(name.to_string()));
+        let entry = |name: &str| ContextEntry {
+            name: Identifier::new_simple(name.to_string()),
+            ty: variable("G"),
+            definition: DefinitionState::Abstract,
+            description: None,
+            is_internal: false,
+        };
+        let goal = ProofGoal {
+            context: vec![entry("g"), entry("h")],
+            quantifiers: vec![Quantifier {
+                variable_name: Identifier::new_simple("g".to_string()),
+                quantification: Quantification::Universal,
+            }],
+            statement: Located::new_concrete(MathRelation::Equal {
+                left: variable("g"),
+                right: variable("h"),
+            }),
+        };
+
+        let section = goal.to_sequent_section("problem");
+        let SectionContentNode::SubSection(parts) = &section.content else {
+            panic!("Expected Given and Prove subsections");
+        };
+        assert_eq!(parts.len(), 2);
+        assert_eq!(parts[0].id, "problem-given");
+        assert_eq!(parts[1].id, "problem-prove");
+
+        // Only the unquantified h is listed as given
+        let SectionContentNode::SubSection(given) = &parts[0].content else {
+            panic!("Expected one row per context entry");
+        };
+        assert_eq!(given.len(), 1);
+        assert_eq!(given[0].id, "problem-given-h");
+
+        let SectionContentNode::RichText(prove) = &parts[1].content else {
+            panic!("Expected the statement as rich text");
+        };
+        assert!(matches!(&prove.segments[0], RichTextSegment::Text(text) if text == "∀ "));
+        assert!(matches!(
+            prove.segments.last(),
+            Some(RichTextSegment::Math(node))
+                if node.id == format!("problem-statement-{}", goal.statement.id)
+        ));
+    }
+
+    #[test]
+    fn test_exported_theorem_carries_proof_status_badge() {
+        use crate::subjects::math::formalism::location::Located;
+        use crate::subjects::math::formalism::proof::tactics::Tactic;
+        use crate::subjects::math::formalism::proof::{NodeRole, ProofNode};
+
+        let goal = ProofGoal {
+            context: vec![],
+            quantifiers: vec![],
+            statement: Located::new_concrete(MathRelation::True),
+        };
+        let node = |id: &str, parent: Option<&str>, children: Vec<String>, role| ProofNode {
+            id: id.to_string(),
+            parent: parent.map(str::to_string),
+            children,
+            role,
+            tactic: Tactic::Trivial,
+            description: None,
+            hint: None,
+        };
+        let theorem = |proofs| Theorem {
+            id: "truth".to_string(),
+            name: "Truth".to_string(),
+            description: "True holds".to_string(),
+            proofs,
+        };
+
+        let mut proved = ProofForest::new_from_goal(goal.clone());
+        proved
+            .add_node(node(
+                "root",
+                None,
+                vec!["done".to_string()],
+                NodeRole::Goal(goal.clone()),
+            ))
+            .unwrap();
+        proved
+            .add_node(node("done", Some("root"), vec![], NodeRole::Completed))
+            .unwrap();
+        proved.roots.push("root".to_string());
+
+        for (theorem, badge, keyword) in [
+            (theorem(proved), "✓ verified", "verified"),
+            (
+                theorem(ProofForest::new_from_goal(goal)),
+                "⚠ proof incomplete",
+                "proof-incomplete",
+            ),
+        ] {
+            let document = theorem.to_math_document("status");
+            let MathDocumentType::ScientificPaper(paper) = &document.content_type else {
+                panic!("Expected a paper");
+            };
+            assert!(
+                paper
+                    .academic_metadata
+                    .keywords
+                    .contains(&keyword.to_string())
+            );
+
+            let section = &paper.structure.body[0];
+            assert!(
+                section
+                    .metadata
+                    .contains(&("proof_status".to_string(), keyword.to_string()))
+            );
+            let title = section.title.as_ref().unwrap();
+            assert!(title.segments.iter().any(|segment| matches!(
+                segment,
+                RichTextSegment::StyledText { text, .. } if text.trim() == badge
+            )));
+        }
+    }
+
+    #[test]
+    fn test_theorem_section_shows_subgoal_blocks_of_its_proof() {
+        use crate::subjects::math::formalism::location::Located;
+        use crate::subjects::math::formalism::proof::tactics::Tactic;
+        use crate::subjects::math::formalism::proof::{NodeRole, ProofNode};
+
+        let goal = ProofGoal {
+            context: vec![],
+            quantifiers: vec![],
+            statement: Located::new_concrete(MathRelation::And(vec![
+                Located::new_concrete(MathRelation::True),
+                Located::new_concrete(MathRelation::True),
+            ])),
+        };
+        let mut forest = ProofForest::new_from_goal(goal.clone());
+        forest
+            .add_node(ProofNode {
+                id: "root".to_string(),
+                parent: None,
+                children: vec![],
+                role: NodeRole::Goal(goal),
+                tactic: Tactic::Trivial,
+                description: None,
+                hint: None,
+            })
+            .unwrap();
+        forest.roots.push("root".to_string());
+        let root = forest.get_node("root").unwrap().clone();
+        root.apply_tactic(Tactic::SplitGoalConjunction, &mut forest);
+
+        let theorem = Theorem {
+            id: "both".to_string(),
+            name: "Both".to_string(),
+            description: "True and true".to_string(),
+            proofs: forest,
+        };
+        let section = theorem.to_section_node("thm");
+        let SectionContentNode::SubSection(content) = &section.content else {
+            panic!("Expected the theorem parts");
+        };
+        let steps = content
+            .iter()
+            .find(|part| part.id == "thm-proof-proof-steps")
+            .expect("proof steps rendered with the theorem");
+        let SectionContentNode::SubSection(steps) = &steps.content else {
+            panic!("Expected proof steps");
+        };
+        let manager = steps
+            .iter()
+            .find(|step| step.id.starts_with("thm-proof-manager-"))
+            .expect("subgoal manager rendered");
+        let SectionContentNode::SubSection(blocks) = &manager.content else {
+            panic!("Expected labeled subproof blocks");
+        };
+        assert_eq!(blocks.len(), 2);
+        assert!(
+            content
+                .iter()
+                .any(|part| part.id == "thm-proof-proof-forest")
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "groups")]
     fn test_theorem_to_math_document() {
         // The call to get_theorem_registry() is enough to ensure axioms are registered.
         let _ = get_theorem_registry();
@@ -11,8 +11,9 @@ use std::rc::Rc;
 use uuid::Uuid;
 
 use crate::subjects::math::formalism::automation::registry::get_theorem_registry;
-use crate::subjects::math::formalism::proof::ProofGoal;
+use crate::subjects::math::formalism::proof::{ContextEntry, ProofGoal};
 
+use super::error::FormalismError;
 use super::expressions::{MathExpression, TheoryExpression};
 use super::proof::tactics::Tactic;
 use super::proof::{NodeRole, ProofForest, ProofNode};
@@ -38,6 +39,33 @@ pub struct Theorem {
     pub proofs: ProofForest,
 }
 
+/// Whether a theorem's proof has been checked all the way through.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ProofStatus {
+    /// Every branch of the proof ends in a completed goal
+    Verified,
+    /// The proof is missing or still has open goals
+    Incomplete,
+}
+
+impl ProofStatus {
+    /// The badge shown next to the theorem's name.
+    pub fn badge(&self) -> &'static str {
+        match self {
+            ProofStatus::Verified => "✓ verified",
+            ProofStatus::Incomplete => "⚠ proof incomplete",
+        }
+    }
+
+    /// The value recorded in exported metadata and keywords.
+    pub fn keyword(&self) -> &'static str {
+        match self {
+            ProofStatus::Verified => "verified",
+            ProofStatus::Incomplete => "proof-incomplete",
+        }
+    }
+}
+
 pub type Axiom = Theorem;
 pub type Lemma = Theorem;
 pub type Corollary = Theorem;
@@ -65,6 +93,22 @@ impl Theorem {
         result
     }
 
+    /// The statement the theorem establishes, i.e. the statement of its goal.
+    ///
+    /// Panics if the statement is a variable rather than a concrete relation.
+    pub fn conclusion(&self) -> &MathRelation {
+        self.proofs
+            .initial_goal
+            .statement
+            .concrete_value()
+            .expect("theorem statements are concrete relations")
+    }
+
+    /// The context the conclusion is stated in: the theorem's variables and assumptions.
+    pub fn hypotheses(&self) -> &[ContextEntry] {
+        &self.proofs.initial_goal.context
+    }
+
     pub fn get_all_goals(&self) -> Vec<&ProofGoal> {
         self.proofs
             .node_values()
@@ -91,6 +135,58 @@ impl Theorem {
             })
     }
 
+    /// `Verified` once the proof has at least one tree and every branch of it is complete.
+    pub fn proof_status(&self) -> ProofStatus {
+        if !self.proofs.roots.is_empty() && self.proofs.is_fully_proven() {
+            ProofStatus::Verified
+        } else {
+            ProofStatus::Incomplete
+        }
+    }
+
+    /// Checks the theorem end to end: its goal is well-formed, its proof forest is
+    /// consistent and replays step by step, and every branch of the proof is complete.
+    ///
+    /// Returns every failure found rather than stopping at the first.
+    pub fn check(&self) -> Result<(), Vec<FormalismError>> {
+        let mut problems = Vec::new();
+
+        if let Err(error) = self.proofs.initial_goal.verify() {
+            problems.push(error);
+        }
+        if let Err(inconsistencies) = self.proofs.check_invariants() {
+            problems.extend(
+                inconsistencies
+                    .into_iter()
+                    .map(|message| FormalismError::InconsistentForest { message }),
+            );
+        }
+        if let Err(mismatches) = self.proofs.validate_replay() {
+            problems.extend(
+                mismatches
+                    .into_iter()
+                    .map(|problem| FormalismError::TacticFailed {
+                        message: format!("Proof does not replay: {}", problem),
+                    }),
+            );
+        }
+        if self.proofs.roots.is_empty() {
+            problems.push(FormalismError::IncompleteProof {
+                message: "it has not been started".to_string(),
+            });
+        } else if !self.proofs.is_fully_proven() {
+            problems.push(FormalismError::IncompleteProof {
+                message: "some branches are still open".to_string(),
+            });
+        }
+
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(problems)
+        }
+    }
+
     /// Check if a proof has been started (i.e., has at least one node).
     pub fn has_proof_started(&self) -> bool {
         !self.proofs.is_empty()
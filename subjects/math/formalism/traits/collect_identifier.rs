@@ -12,6 +12,7 @@ use crate::subjects::math::formalism::{
 };
 
 // Import Group types for child node implementations
+#[cfg(feature = "groups")]
 use crate::subjects::math::theories::groups::definitions::{
     Group, GroupElement, GroupExpression, GroupHomomorphism,
 };
@@ -121,11 +122,14 @@ impl CollectIdentifier for MathExpression {
 impl CollectIdentifier for TheoryExpression {
     fn collect_identifier(&self) -> HashSet<Identifier> {
         match self {
+            #[cfg(feature = "groups")]
             TheoryExpression::Group(group_expr) => group_expr.collect_identifier(),
+            #[cfg(feature = "rings")]
             TheoryExpression::Ring(_ring_expr) => {
                 // TODO: Implement for RingExpression when available
                 HashSet::new()
             }
+            #[cfg(feature = "rings")]
             TheoryExpression::Field(_field_expr) => {
                 // TODO: Implement for FieldExpression when available
                 HashSet::new()
@@ -161,12 +165,15 @@ impl CollectIdentifier for MathRelation {
             MathRelation::True | MathRelation::False => HashSet::new(),
             // For theory-specific relations, we don't traverse into them
             // since they're not part of the core MathExpression tree
+            #[cfg(feature = "groups")]
             MathRelation::GroupTheory(g) => g.collect_identifier(),
             MathRelation::NumberTheory(_) => todo!(),
             MathRelation::SetTheory(_) => todo!(),
+            #[cfg(feature = "rings")]
             MathRelation::RingTheory(_) => todo!(),
             MathRelation::TopologyTheory(_) => todo!(),
             MathRelation::CategoryTheory(_) => todo!(),
+            #[cfg(feature = "probability")]
             MathRelation::ProbabilityTheory(_) => todo!(),
         }
     }
@@ -175,12 +182,16 @@ impl CollectIdentifier for MathRelation {
 impl CollectIdentifier for MathObject {
     fn collect_identifier(&self) -> HashSet<Identifier> {
         match self {
+            #[cfg(feature = "groups")]
             MathObject::Group(group) => group.collect_identifier(),
             // For other math objects, we don't traverse into them
             // since they're not part of the core expression tree
+            #[cfg(feature = "rings")]
             MathObject::Ring(_) => HashSet::new(),
             MathObject::Field(_) => HashSet::new(),
+            #[cfg(feature = "rings")]
             MathObject::Module(_) => HashSet::new(),
+            #[cfg(feature = "rings")]
             MathObject::Algebra(_) => HashSet::new(),
             MathObject::TopologicalSpace(_) => HashSet::new(),
             MathObject::VectorSpace(_) => HashSet::new(),
@@ -193,6 +204,7 @@ impl CollectIdentifier for MathObject {
 impl CollectIdentifier for TypeViewOperator {
     fn collect_identifier(&self) -> HashSet<Identifier> {
         match self {
+            #[cfg(feature = "groups")]
             TypeViewOperator::AsGroupElement { group } => group.collect_identifier(),
             TypeViewOperator::AsGroup { operation } => operation
                 .as_ref()
@@ -208,9 +220,11 @@ impl CollectIdentifier for TypeViewOperator {
                 .map_or(HashSet::new(), |dom| dom.collect_identifier()),
             TypeViewOperator::Custom { parameters, .. } => parameters.collect_identifier(),
             // For other view operators, we don't need to traverse deeper
-            TypeViewOperator::AsRingElement { .. }
-            | TypeViewOperator::AsFieldElement { .. }
-            | TypeViewOperator::AsRing { .. }
+            #[cfg(feature = "rings")]
+            TypeViewOperator::AsRingElement { .. } | TypeViewOperator::AsFieldElement { .. } => {
+                HashSet::new()
+            }
+            TypeViewOperator::AsRing { .. }
             | TypeViewOperator::AsTopologicalSpace { .. }
             | TypeViewOperator::AsCyclicGroup
             | TypeViewOperator::AsPoint
@@ -247,10 +261,12 @@ impl CollectIdentifier for String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    #[cfg(feature = "groups")]
     use crate::subjects::math::theories::groups::definitions::GroupExpression;
     use crate::turn_render::Identifier;
 
     #[test]
+    #[cfg(feature = "groups")]
     fn test_collect_identifier_from_group_expression() {
         // Create some identifiers
         let x_id = Identifier::new_simple("x".to_string());
@@ -294,6 +310,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "groups")]
     fn test_collect_identifier_from_nested_expression() {
         // Create identifiers
         let x_id = Identifier::new_simple("x".to_string());
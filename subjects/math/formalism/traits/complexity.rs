@@ -70,10 +70,14 @@ impl<T: Complexity> Complexity for Parametrizable<T> {
 impl Complexity for MathObject {
     fn complexity(&self) -> usize {
         match self {
+            #[cfg(feature = "groups")]
             MathObject::Group(g) => g.complexity(), // Assuming Group implements Complexity
+            #[cfg(feature = "rings")]
             MathObject::Ring(r) => 1,               // Placeholder
             MathObject::Field(f) => 1,              // Placeholder
+            #[cfg(feature = "rings")]
             MathObject::Module(m) => 1,             // Placeholder
+            #[cfg(feature = "rings")]
             MathObject::Algebra(a) => 1,            // Placeholder
             MathObject::TopologicalSpace(ts) => 1,  // Placeholder
             MathObject::VectorSpace(vs) => 1,       // Placeholder
@@ -92,8 +96,11 @@ impl Complexity for Identifier {
 impl Complexity for TheoryExpression {
     fn complexity(&self) -> usize {
         match self {
+            #[cfg(feature = "groups")]
             TheoryExpression::Group(ge) => 1 + ge.complexity(),
+            #[cfg(feature = "rings")]
             TheoryExpression::Ring(re) => 1 + 0, // Assuming RingExpression complexity will be added
+            #[cfg(feature = "rings")]
             TheoryExpression::Field(fe) => 1 + 0, // Assuming FieldExpression complexity will be added
         }
     }
@@ -131,10 +138,13 @@ impl Complexity for MathRelation {
             MathRelation::False => 1,
             MathRelation::NumberTheory(_) => 1,
             MathRelation::SetTheory(_) => 1,
+            #[cfg(feature = "groups")]
             MathRelation::GroupTheory(_) => 1,
+            #[cfg(feature = "rings")]
             MathRelation::RingTheory(_) => 1,
             MathRelation::TopologyTheory(_) => 1,
             MathRelation::CategoryTheory(_) => 1,
+            #[cfg(feature = "probability")]
             MathRelation::ProbabilityTheory(_) => 1,
         }
     }
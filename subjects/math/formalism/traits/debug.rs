@@ -1,30 +1,26 @@
-use crate::subjects::math::theories::groups::definitions::GroupAction;
-use crate::subjects::math::theories::{
-    groups::definitions::{
-        AlternatingGroup, CyclicGroup, DihedralGroup, FreeGroup, GenericGroup, Group, GroupElement,
-        GroupExpression, GroupHomomorphism, SymmetricGroup,
+use crate::subjects::math::formalism::{
+    expressions::{MathExpression, TheoryExpression},
+    extract::Parametrizable,
+    interpretation::TypeViewOperator,
+    location::Located,
+    objects::MathObject,
+    proof::{
+        ContextEntry, DefinitionState, NodeRole, ProofForest, ProofGoal, ProofNode,
+        QuantifiedMathObject, Quantifier, SubgoalCombination, TacticOutcome, ValueBindedVariable,
+        tactics::{ContextOrStatement, RelationSource, RewriteDirection, Tactic, Target},
     },
+    relations::{MathRelation, Quantification},
+};
+#[cfg(feature = "groups")]
+use crate::subjects::math::theories::groups::definitions::{
+    AlternatingGroup, CyclicGroup, DihedralGroup, FreeGroup, GenericGroup, Group, GroupAction,
+    GroupElement, GroupExpression, GroupHomomorphism, GroupRelation, SymmetricGroup,
+};
+use crate::subjects::math::theories::{
     number_theory::definitions::Number,
     zfc::definitions::{GenericSet, Set, SetElement},
 };
-use crate::subjects::math::{
-    formalism::{
-        expressions::{MathExpression, TheoryExpression},
-        extract::Parametrizable,
-        interpretation::TypeViewOperator,
-        location::Located,
-        objects::MathObject,
-        proof::{
-            ContextEntry, DefinitionState, NodeRole, ProofForest, ProofGoal, ProofNode,
-            QuantifiedMathObject, Quantifier, SubgoalCombination, TacticOutcome,
-            ValueBindedVariable,
-            tactics::{ContextOrStatement, RelationSource, RewriteDirection, Tactic, Target},
-        },
-        relations::{MathRelation, Quantification},
-    },
-    theories::groups::definitions::GroupRelation,
-};
-use crate::turn_render::{Identifier, RichText, RichTextSegment};
+use crate::turn_render::{Identifier, MathNodeContent, RichText, RichTextSegment};
 use std::fmt;
 use std::sync::Arc;
 
@@ -69,21 +65,16 @@ where
         }
         Parametrizable::Concrete(concrete) => {
             // ✅ IMPROVED: Try to extract variable names intelligently for specific cases
-            if let Some(concrete_any) = (concrete.as_ref() as &dyn std::any::Any).downcast_ref::<crate::subjects::math::theories::groups::definitions::GroupExpression>() {
-                match concrete_any {
-                    crate::subjects::math::theories::groups::definitions::GroupExpression::Element { element: Some(el), .. } => {
-                        // This is a GroupExpression::Element - try to extract the variable name from the element
-                        extract_variable_name(el)
-                    }
-                    _ => {
-                        // For other GroupExpression types, use short_debug
+            #[cfg(feature = "groups")]
+            if let Some(GroupExpression::Element {
+                element: Some(el), ..
+            }) = (concrete.as_ref() as &dyn std::any::Any).downcast_ref::<GroupExpression>()
+            {
+                // This is a GroupExpression::Element - try to extract the variable name from the element
+                return extract_variable_name(el);
+            }
+            // For other types, use short_debug
             concrete.short_debug()
-                    }
-                }
-            } else {
-                // For non-GroupExpression types, use short_debug
-                concrete.short_debug()
-            }
         }
     }
 }
@@ -128,6 +119,7 @@ impl ShortDebug for TypeViewOperator {
     }
 }
 
+#[cfg(feature = "groups")]
 impl ShortDebug for CyclicGroup {
     fn short_debug(&self) -> String {
         if let Some(order) = self.order {
@@ -138,24 +130,28 @@ impl ShortDebug for CyclicGroup {
     }
 }
 
+#[cfg(feature = "groups")]
 impl ShortDebug for DihedralGroup {
     fn short_debug(&self) -> String {
         format!("DihedralGroup {{ order: {} }}", self.order)
     }
 }
 
+#[cfg(feature = "groups")]
 impl ShortDebug for FreeGroup {
     fn short_debug(&self) -> String {
         format!("FreeGroup {{ rank: {} }}", self.rank)
     }
 }
 
+#[cfg(feature = "groups")]
 impl ShortDebug for SymmetricGroup {
     fn short_debug(&self) -> String {
         format!("SymmetricGroup {{ degree: {} }}", self.degree)
     }
 }
 
+#[cfg(feature = "groups")]
 impl ShortDebug for AlternatingGroup {
     fn short_debug(&self) -> String {
         format!("AlternatingGroup {{ degree: {} }}", self.degree)
@@ -227,6 +223,7 @@ impl ShortDebug for Tactic {
             Tactic::CaseAnalysis { on_variable, cases } => {
                 format!("CaseAnalysis({}, {} cases)", on_variable.body, cases.len())
             }
+            Tactic::CaseOnMembership { .. } => "CaseOnMembership".to_string(),
             Tactic::Induction {
                 variable_name,
                 hypothesis_name,
@@ -246,6 +243,12 @@ impl ShortDebug for Tactic {
                     witness.short_debug()
                 )
             }
+            Tactic::Have { name, statement } => {
+                format!("Have({} : {})", name.body, statement.short_debug())
+            }
+            Tactic::Suffices { statement } => {
+                format!("Suffices({})", statement.short_debug())
+            }
             Tactic::SplitAssumptionConjunction {
                 target_hypothesis,
                 with_names,
@@ -274,7 +277,10 @@ impl ShortDebug for Tactic {
             Tactic::ByRelation(source) => {
                 format!("ByRelation({})", source.short_debug())
             }
+            Tactic::Assumption => "Assumption".to_string(),
+            Tactic::Apply { source } => format!("Apply({})", source.short_debug()),
             Tactic::ByReflexivity => "ByReflexivity".to_string(),
+            Tactic::Trivial => "Trivial".to_string(),
             Tactic::ByContradiction {
                 hypothesis1,
                 hypothesis2,
@@ -312,6 +318,19 @@ impl ShortDebug for Tactic {
                     inst_info
                 )
             }
+            Tactic::RewriteSides {
+                left_rule,
+                right_rule,
+            } => {
+                let rule = |rule: &Option<RelationSource>| {
+                    rule.as_ref().map_or("_".to_string(), |rule| rule.short_debug())
+                };
+                format!("RewriteSides({} = {})", rule(left_rule), rule(right_rule))
+            }
+            Tactic::RewriteChain { equalities } => {
+                let names: Vec<&str> = equalities.iter().map(|id| id.body.as_str()).collect();
+                format!("RewriteChain({})", names.join(", "))
+            }
             Tactic::UnfoldDefinition {
                 definition_to_unfold,
                 target,
@@ -349,9 +368,30 @@ impl ShortDebug for Tactic {
             } => {
                 format!("Revert({})", hypothesis_to_revert.body)
             }
+            #[cfg(feature = "groups")]
+            Tactic::ApplyFirstIsomorphism {
+                homomorphism,
+                with_name,
+            } => {
+                format!("FirstIso({} as {})", homomorphism.body, with_name.body)
+            }
+            #[cfg(feature = "groups")]
+            Tactic::UnfoldGroupConstruction { on_variable } => {
+                format!("UnfoldGroupConstruction({})", on_variable.body)
+            }
+            #[cfg(feature = "groups")]
+            Tactic::AssertProperty {
+                on_variable,
+                property,
+            } => {
+                format!("AssertProperty({}: {:?})", on_variable.body, property)
+            }
             Tactic::SearchAssumptions => "SearchAssumptions".to_string(),
             Tactic::SearchTheoremLibrary => "SearchTheoremLibrary".to_string(),
             Tactic::Search => "Search".to_string(),
+            Tactic::Normalize { target } => {
+                format!("Normalize({})", target.short_debug())
+            }
             Tactic::Simplify { target } => {
                 format!("Simplify({})", target.short_debug())
             }
@@ -511,7 +551,18 @@ fn rich_text_to_string(rich_text: &RichText) -> String {
 
 impl ShortDebug for Identifier {
     fn short_debug(&self) -> String {
-        format!("Identifier({})", self.body)
+        // x, x₁ and x' are different names, so the scripts are part of the output
+        let subscripts: String = self
+            .post_script
+            .iter()
+            .flat_map(|script| &script.subscripts)
+            .map(|subscript| match subscript.content.as_ref() {
+                MathNodeContent::Identifier(name) => format!("_{}", name.body),
+                _ => "_?".to_string(),
+            })
+            .collect();
+        let primes = "'".repeat(self.primes as usize);
+        format!("Identifier({}{}{})", self.body, subscripts, primes)
     }
 }
 
@@ -525,7 +576,7 @@ impl<T: ShortDebug> ShortDebug for Arc<T> {
 // Add Number support
 impl ShortDebug for Number {
     fn short_debug(&self) -> String {
-        format!("Number({:?})", self)
+        format!("Number({})", self)
     }
 }
 
@@ -567,9 +618,11 @@ impl ShortDebug for MathExpression {
 impl ShortDebug for MathObject {
     fn short_debug(&self) -> String {
         match self {
+            #[cfg(feature = "groups")]
             MathObject::Group(g) => {
                 format!("MathObject::Group(\n{}\n)", indent(&g.short_debug(), 1))
             }
+            #[cfg(feature = "rings")]
             MathObject::Ring(_) => "MathObject::Ring".to_string(),
             MathObject::Field(_) => "MathObject::Field".to_string(),
             MathObject::Set(s) => format!("MathObject::Set(\n{}\n)", indent(&s.short_debug(), 1)),
@@ -631,6 +684,7 @@ impl ShortDebug for MathRelation {
             MathRelation::True => "MathRelation::True".to_string(),
             MathRelation::False => "MathRelation::False".to_string(),
 
+            #[cfg(feature = "groups")]
             MathRelation::GroupTheory(rel) => rel.short_debug(),
             // MathRelation::SetTheory(rel) => rel.short_debug(),
             // MathRelation::NumberTheory(rel) => rel.short_debug(),
@@ -646,14 +700,18 @@ impl ShortDebug for MathRelation {
 impl ShortDebug for TheoryExpression {
     fn short_debug(&self) -> String {
         match self {
+            #[cfg(feature = "groups")]
             TheoryExpression::Group(g) => g.short_debug(),
+            #[cfg(feature = "rings")]
             TheoryExpression::Ring(_) => "Ring".to_string(),
+            #[cfg(feature = "rings")]
             TheoryExpression::Field(_) => "Field".to_string(),
         }
     }
 }
 
 // Show group type structure
+#[cfg(feature = "groups")]
 impl ShortDebug for Group {
     fn short_debug(&self) -> String {
         match self {
@@ -735,36 +793,42 @@ impl ShortDebug for Group {
     }
 }
 
+#[cfg(feature = "groups")]
 impl ShortDebug for GroupAction {
     fn short_debug(&self) -> String {
         "GroupAction".to_string()
     }
 }
 
+#[cfg(feature = "groups")]
 impl ShortDebug for crate::subjects::math::theories::groups::definitions::TopologicalGroup {
     fn short_debug(&self) -> String {
         "TopologicalGroup".to_string()
     }
 }
 
+#[cfg(feature = "groups")]
 impl ShortDebug for crate::subjects::math::theories::groups::definitions::LieGroup {
     fn short_debug(&self) -> String {
         "LieGroup".to_string()
     }
 }
 
+#[cfg(feature = "groups")]
 impl ShortDebug for crate::subjects::math::theories::groups::definitions::ProductGroup {
     fn short_debug(&self) -> String {
         "ProductGroup".to_string()
     }
 }
 
+#[cfg(feature = "groups")]
 impl ShortDebug for crate::subjects::math::theories::groups::definitions::ModularAdditiveGroup {
     fn short_debug(&self) -> String {
         "ModularAdditiveGroup".to_string()
     }
 }
 
+#[cfg(feature = "groups")]
 impl ShortDebug
     for crate::subjects::math::theories::groups::definitions::ModularMultiplicativeGroup
 {
@@ -773,132 +837,154 @@ impl ShortDebug
     }
 }
 
+#[cfg(feature = "groups")]
 impl ShortDebug for crate::subjects::math::theories::groups::definitions::GeneralLinearGroup {
     fn short_debug(&self) -> String {
         "GeneralLinearGroup".to_string()
     }
 }
 
+#[cfg(feature = "groups")]
 impl ShortDebug for crate::subjects::math::theories::groups::definitions::SpecialLinearGroup {
     fn short_debug(&self) -> String {
         "SpecialLinearGroup".to_string()
     }
 }
 
+#[cfg(feature = "groups")]
 impl ShortDebug for crate::subjects::math::theories::groups::definitions::OrthogonalGroup {
     fn short_debug(&self) -> String {
         "OrthogonalGroup".to_string()
     }
 }
 
+#[cfg(feature = "groups")]
 impl ShortDebug for crate::subjects::math::theories::groups::definitions::SpecialOrthogonalGroup {
     fn short_debug(&self) -> String {
         "SpecialOrthogonalGroup".to_string()
     }
 }
 
+#[cfg(feature = "groups")]
 impl ShortDebug for crate::subjects::math::theories::groups::definitions::UnitaryGroup {
     fn short_debug(&self) -> String {
         "UnitaryGroup".to_string()
     }
 }
 
+#[cfg(feature = "groups")]
 impl ShortDebug for crate::subjects::math::theories::groups::definitions::SpecialUnitaryGroup {
     fn short_debug(&self) -> String {
         "SpecialUnitaryGroup".to_string()
     }
 }
 
+#[cfg(feature = "groups")]
 impl ShortDebug for crate::subjects::math::theories::groups::definitions::QuotientGroup {
     fn short_debug(&self) -> String {
         "QuotientGroup".to_string()
     }
 }
 
+#[cfg(feature = "groups")]
 impl ShortDebug for crate::subjects::math::theories::groups::definitions::KernelGroup {
     fn short_debug(&self) -> String {
         "KernelGroup".to_string()
     }
 }
 
+#[cfg(feature = "groups")]
 impl ShortDebug for crate::subjects::math::theories::groups::definitions::ImageGroup {
     fn short_debug(&self) -> String {
         "ImageGroup".to_string()
     }
 }
 
+#[cfg(feature = "groups")]
 impl ShortDebug for crate::subjects::math::theories::groups::definitions::CenterGroup {
     fn short_debug(&self) -> String {
         "CenterGroup".to_string()
     }
 }
 
+#[cfg(feature = "groups")]
 impl ShortDebug for crate::subjects::math::theories::groups::definitions::GeneratedSubgroup {
     fn short_debug(&self) -> String {
         "GeneratedSubgroup".to_string()
     }
 }
 
+#[cfg(feature = "groups")]
 impl ShortDebug for crate::subjects::math::theories::groups::definitions::NormalizerGroup {
     fn short_debug(&self) -> String {
         "NormalizerGroup".to_string()
     }
 }
 
+#[cfg(feature = "groups")]
 impl ShortDebug for crate::subjects::math::theories::groups::definitions::CentralizerGroup {
     fn short_debug(&self) -> String {
         "CentralizerGroup".to_string()
     }
 }
 
+#[cfg(feature = "groups")]
 impl ShortDebug for crate::subjects::math::theories::groups::definitions::CommutatorSubgroup {
     fn short_debug(&self) -> String {
         "CommutatorSubgroup".to_string()
     }
 }
 
+#[cfg(feature = "groups")]
 impl ShortDebug for crate::subjects::math::theories::groups::definitions::WreathProductGroup {
     fn short_debug(&self) -> String {
         "WreathProductGroup".to_string()
     }
 }
 
+#[cfg(feature = "groups")]
 impl ShortDebug for crate::subjects::math::theories::groups::definitions::CentralProductGroup {
     fn short_debug(&self) -> String {
         "CentralProductGroup".to_string()
     }
 }
 
+#[cfg(feature = "groups")]
 impl ShortDebug for crate::subjects::math::theories::groups::definitions::PullbackGroup {
     fn short_debug(&self) -> String {
         "PullbackGroup".to_string()
     }
 }
 
+#[cfg(feature = "groups")]
 impl ShortDebug for crate::subjects::math::theories::groups::definitions::RestrictionGroup {
     fn short_debug(&self) -> String {
         "RestrictionGroup".to_string()
     }
 }
 
+#[cfg(feature = "groups")]
 impl ShortDebug for crate::subjects::math::theories::groups::definitions::InterceptionGroup {
     fn short_debug(&self) -> String {
         "InterceptionGroup".to_string()
     }
 }
 
+#[cfg(feature = "groups")]
 impl ShortDebug for crate::subjects::math::theories::groups::definitions::SubGroup {
     fn short_debug(&self) -> String {
         "SubGroup".to_string()
     }
 }
 
+#[cfg(feature = "groups")]
 impl ShortDebug for crate::subjects::math::theories::groups::definitions::SylowSubgroup {
     fn short_debug(&self) -> String {
         "SylowSubgroup".to_string()
     }
 }
 
+#[cfg(feature = "groups")]
 impl ShortDebug for GenericGroup {
     fn short_debug(&self) -> String {
         format!(
@@ -926,6 +1012,7 @@ impl ShortDebug for Set {
 }
 
 // ✅ CONCISE: Use mathematical notation with short IDs for tracking
+#[cfg(feature = "groups")]
 impl ShortDebug for GroupExpression {
     fn short_debug(&self) -> String {
         match self {
@@ -968,11 +1055,22 @@ impl ShortDebug for GroupExpression {
             GroupExpression::ElementOrder { element, .. } => {
                 format!("ord({})", extract_variable_name(element))
             }
+            GroupExpression::HomomorphismApplication {
+                homomorphism,
+                element,
+            } => {
+                format!(
+                    "{}({})",
+                    extract_variable_name(homomorphism),
+                    extract_variable_name(element)
+                )
+            }
             _ => "GroupExpr".to_string(),
         }
     }
 }
 
+#[cfg(feature = "groups")]
 impl ShortDebug for GroupElement {
     fn short_debug(&self) -> String {
         match self {
@@ -994,6 +1092,7 @@ impl ShortDebug for GroupElement {
     }
 }
 
+#[cfg(feature = "groups")]
 impl ShortDebug for GroupHomomorphism {
     fn short_debug(&self) -> String {
         format!(
@@ -1004,6 +1103,7 @@ impl ShortDebug for GroupHomomorphism {
     }
 }
 
+#[cfg(feature = "groups")]
 impl ShortDebug for GroupRelation {
     fn short_debug(&self) -> String {
         match self {
@@ -1040,6 +1140,13 @@ impl ShortDebug for GroupRelation {
                     extract_variable_name(normal_subgroup)
                 )
             }
+            GroupRelation::ElementOf { element, group } => {
+                format!(
+                    "{} ∈ {}",
+                    extract_variable_name(element),
+                    extract_variable_name(group)
+                )
+            }
             GroupRelation::IsInCenterOf { element, group } => {
                 format!(
                     "{} ∈ Z({})",
@@ -1326,11 +1433,13 @@ impl ShortDebug for ContextEntry {
             Parametrizable::Variable(id) => id.body.clone(),
             Parametrizable::Concrete(expr) => match expr.as_ref() {
                 MathExpression::Object(obj) => match obj.as_ref() {
+                    #[cfg(feature = "groups")]
                     MathObject::Group(_) => "Group".to_string(),
                     MathObject::Set(_) => "Set".to_string(),
                     _ => "Object".to_string(),
                 },
                 MathExpression::Expression(theory_expr) => match theory_expr {
+                    #[cfg(feature = "groups")]
                     TheoryExpression::Group(group_expr) => match group_expr {
                         GroupExpression::Element { .. } => "GroupElement".to_string(),
                         GroupExpression::Identity(_) => "GroupIdentity".to_string(),
@@ -1450,6 +1559,7 @@ impl ShortDebug for ProofGoal {
                     let cons_summary = extract_variable_name(cons);
                     format!("{} → {}", ant_summary, cons_summary)
                 }
+                #[cfg(feature = "groups")]
                 MathRelation::GroupTheory(rel) => rel.short_debug(),
                 _ => "Relation".to_string(),
             },
@@ -1466,6 +1576,7 @@ impl ShortDebug for SubgoalCombination {
         match self {
             SubgoalCombination::And => "SubgoalCombination::And".to_string(),
             SubgoalCombination::Or => "SubgoalCombination::Or".to_string(),
+            SubgoalCombination::Sequential => "SubgoalCombination::Sequential".to_string(),
             SubgoalCombination::Custom(s) => {
                 format!("SubgoalCombination::Custom({})", s.short_debug())
             }
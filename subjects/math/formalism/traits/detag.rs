@@ -8,10 +8,12 @@ use crate::subjects::math::formalism::location::Located;
 use crate::subjects::math::formalism::objects::MathObject;
 use crate::subjects::math::formalism::relations::MathRelation;
 use crate::subjects::math::theories::fields::definitions::Field;
+#[cfg(feature = "groups")]
 use crate::subjects::math::theories::groups::definitions::{
     Group, GroupElement, GroupExpression, GroupHomomorphism,
 };
 use crate::subjects::math::theories::number_theory::definitions::Number;
+#[cfg(feature = "rings")]
 use crate::subjects::math::theories::rings::definitions::{FieldExpression, Ring, RingExpression};
 use crate::subjects::math::theories::zfc::definitions::Set;
 
@@ -158,7 +160,9 @@ where
 impl<T: 'static + Debug> TryDetag<T> for MathObject {
     fn try_detag(&self) -> Result<&T, String> {
         match self {
+            #[cfg(feature = "groups")]
             MathObject::Group(g) => try_detag_as_with_no_digging!(g, T),
+            #[cfg(feature = "rings")]
             MathObject::Ring(r) => try_detag_as_with_no_digging!(r, T),
             MathObject::Field(f) => try_detag_as_with_no_digging!(f, T),
             // The other variants from objects.rs need to be added here if they are used.
@@ -191,12 +195,15 @@ impl<T: 'static + Debug> TryDetag<T> for MathRelation {
             return Ok(res);
         }
         match self {
+            #[cfg(feature = "groups")]
             MathRelation::GroupTheory(rel) => try_detag_as_with_no_digging!(rel, T),
             MathRelation::NumberTheory(rel) => todo!(),
             MathRelation::SetTheory(rel) => todo!(),
+            #[cfg(feature = "rings")]
             MathRelation::RingTheory(ring_relation) => todo!(),
             MathRelation::TopologyTheory(topology_relation) => todo!(),
             MathRelation::CategoryTheory(category_relation) => todo!(),
+            #[cfg(feature = "probability")]
             MathRelation::ProbabilityTheory(probability_relation) => todo!(),
             _ => Err(format!(
                 "TryGet not implemented for this MathRelation variant to find {}",
@@ -212,8 +219,11 @@ impl<T: 'static + Debug> TryDetag<T> for TheoryExpression {
             return Ok(res);
         }
         match self {
+            #[cfg(feature = "groups")]
             TheoryExpression::Group(g) => try_detag_as_with_no_digging!(g, T),
+            #[cfg(feature = "rings")]
             TheoryExpression::Ring(r) => todo!(),
+            #[cfg(feature = "rings")]
             TheoryExpression::Field(f) => todo!(),
             _ => Err(format!(
                 "TryGet not implemented for this TheoryExpression variant to find {}",
@@ -1,7 +1,9 @@
+#[cfg(feature = "groups")]
 use crate::subjects::math::theories::groups::definitions::{
     GenericGroup, Group, GroupElement, GroupExpression, GroupHomomorphism, GroupOperation,
     GroupRelation,
 };
+#[cfg(feature = "rings")]
 use crate::subjects::math::theories::rings::definitions::Ring;
 use crate::subjects::math::theories::zfc::definitions::Set;
 use crate::{
@@ -182,6 +184,7 @@ impl Instantiable for TheoryExpression {
         let mut instantiations = HashMap::new();
 
         match (self, pattern) {
+            #[cfg(feature = "groups")]
             (TheoryExpression::Group(target_group), TheoryExpression::Group(pattern_group)) => {
                 instantiations.extend(target_group.instantiate(
                     target_context,
@@ -189,9 +192,11 @@ impl Instantiable for TheoryExpression {
                     pattern_context,
                 ));
             }
+            #[cfg(feature = "rings")]
             (TheoryExpression::Ring(target_ring), TheoryExpression::Ring(pattern_ring)) => {
                 // TODO: Implement when RingExpression has Instantiable
             }
+            #[cfg(feature = "rings")]
             (TheoryExpression::Field(target_field), TheoryExpression::Field(pattern_field)) => {
                 // TODO: Implement when FieldExpression has Instantiable
             }
@@ -296,6 +301,7 @@ impl Instantiable for MathRelation {
                 // No variables to instantiate
             }
             // Theory-specific relations
+            #[cfg(feature = "groups")]
             (MathRelation::GroupTheory(target_rel), MathRelation::GroupTheory(pattern_rel)) => {
                 instantiations.extend(target_rel.instantiate(
                     target_context,
@@ -324,6 +330,7 @@ impl Instantiable for MathObject {
         let mut instantiations = HashMap::new();
 
         match (self, pattern) {
+            #[cfg(feature = "groups")]
             (MathObject::Group(target_group), MathObject::Group(pattern_group)) => {
                 instantiations.extend(target_group.instantiate(
                     target_context,
@@ -352,6 +359,7 @@ impl Instantiable for TypeViewOperator {
         let mut instantiations = HashMap::new();
 
         match (self, pattern) {
+            #[cfg(feature = "groups")]
             (
                 TypeViewOperator::AsGroupElement {
                     group: target_group,
@@ -509,6 +517,7 @@ impl<T: Instantiable> Instantiable for Vec<T> {
 }
 
 // Implementation for GroupElement
+#[cfg(feature = "groups")]
 impl Instantiable for crate::subjects::math::theories::groups::definitions::GroupElement {
     fn instantiate(
         &self,
@@ -524,6 +533,7 @@ impl Instantiable for crate::subjects::math::theories::groups::definitions::Grou
 // ===== GROUP THEORY IMPLEMENTATIONS =====
 
 // Implementation for Group
+#[cfg(feature = "groups")]
 impl Instantiable for Group {
     fn instantiate(
         &self,
@@ -552,6 +562,7 @@ impl Instantiable for Group {
 }
 
 // Implementation for GroupExpression
+#[cfg(feature = "groups")]
 impl Instantiable for GroupExpression {
     fn instantiate(
         &self,
@@ -769,6 +780,27 @@ impl Instantiable for GroupExpression {
                     pattern_context,
                 ));
             }
+            (
+                GroupExpression::HomomorphismApplication {
+                    homomorphism: target_hom,
+                    element: target_element,
+                },
+                GroupExpression::HomomorphismApplication {
+                    homomorphism: pattern_hom,
+                    element: pattern_element,
+                },
+            ) => {
+                instantiations.extend(target_hom.instantiate(
+                    target_context,
+                    pattern_hom,
+                    pattern_context,
+                ));
+                instantiations.extend(target_element.instantiate(
+                    target_context,
+                    pattern_element,
+                    pattern_context,
+                ));
+            }
             // Skip ActionOnElement for now since GroupAction is complex
             _ => {
                 // Different variants or unsupported cases
@@ -780,6 +812,7 @@ impl Instantiable for GroupExpression {
 }
 
 // Implementation for GroupHomomorphism
+#[cfg(feature = "groups")]
 impl Instantiable for GroupHomomorphism {
     fn instantiate(
         &self,
@@ -807,12 +840,14 @@ impl Instantiable for GroupHomomorphism {
 #[cfg(test)]
 mod tests {
     use super::*;
+    #[cfg(feature = "groups")]
     use crate::subjects::math::theories::groups::definitions::{
         GenericGroup, Group, GroupExpression,
     };
     use crate::turn_render::Identifier;
 
     #[test]
+    #[cfg(feature = "groups")]
     fn test_instantiate_group_expression() {
         // Create a concrete target: G * x where G is concrete, x and y are concrete
         let target_expr = GroupExpression::Operation {
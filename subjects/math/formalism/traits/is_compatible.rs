@@ -6,7 +6,8 @@ use crate::subjects::math::formalism::proof::ContextEntry;
 use crate::subjects::math::formalism::proof::tactics::Target;
 use crate::subjects::math::formalism::relations::MathRelation;
 use crate::subjects::math::formalism::traits::debug::ShortDebug;
-use std::fmt::Debug;
+use std::fmt::{self, Debug};
+use std::mem::discriminant;
 
 pub trait IsCompatible<P> {
     fn is_compatible(
@@ -17,6 +18,89 @@ pub trait IsCompatible<P> {
     ) -> bool;
 }
 
+/// Why a target failed to match a pattern: the first two sub-nodes that did not
+/// align, and the child positions leading to them from the compared roots.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MatchMismatch {
+    pub path: Vec<usize>,
+    pub target: String,
+    pub pattern: String,
+    pub reason: String,
+}
+
+impl MatchMismatch {
+    /// A mismatch between the compared roots themselves.
+    pub fn new(target: String, pattern: String, reason: &str) -> Self {
+        MatchMismatch {
+            path: vec![],
+            target,
+            pattern,
+            reason: reason.to_string(),
+        }
+    }
+
+    /// A mismatch between two nodes, described by their short debug form.
+    pub fn between<T: ShortDebug, P: ShortDebug>(target: &T, pattern: &P, reason: &str) -> Self {
+        Self::new(one_line(target), one_line(pattern), reason)
+    }
+
+    /// The same mismatch, seen from a parent where it sits under child `position`.
+    pub fn under(mut self, position: usize) -> Self {
+        self.path.insert(0, position);
+        self
+    }
+}
+
+impl fmt::Display for MatchMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "could not match `{}` with `{}` at position {:?}: {}",
+            self.target, self.pattern, self.path, self.reason
+        )
+    }
+}
+
+/// The short debug form of `node` on a single line.
+fn one_line<T: ShortDebug + ?Sized>(node: &T) -> String {
+    node.short_debug()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Explains an `is_compatible` failure by pointing at where the target and
+/// pattern diverge.
+pub trait ExplainMismatch<P>: IsCompatible<P> {
+    /// `None` when `self` is compatible with `pattern`.
+    fn explain_mismatch(
+        &self,
+        target_context: &Vec<ContextEntry>,
+        pattern: &P,
+        pattern_context: &Vec<ContextEntry>,
+    ) -> Option<MatchMismatch>;
+}
+
+/// The first mismatch among a node's child pairs, positioned under its index.
+pub fn first_child_mismatch(
+    children: impl IntoIterator<Item = Option<MatchMismatch>>,
+) -> Option<MatchMismatch> {
+    children
+        .into_iter()
+        .enumerate()
+        .find_map(|(position, mismatch)| mismatch.map(|mismatch| mismatch.under(position)))
+}
+
+/// The reason for a mismatch reported at a node whose children all align:
+/// different variants differ in `what`, equal variants in their arguments.
+pub fn mismatch_reason<T>(target: &T, pattern: &T, what: &'static str) -> &'static str {
+    if discriminant(target) == discriminant(pattern) {
+        "arguments differ"
+    } else {
+        what
+    }
+}
+
 /// Returns true if `self` and `other` have the same enum variant (same type-form).
 /// Implementations can optionally do deeper checks, but should be fast and deterministic.
 pub trait SameRole {
@@ -101,6 +185,70 @@ impl<T: 'static + Clone + IsCompatible<T> + Debug> IsCompatible<Located<T>> for
     }
 }
 
+impl ExplainMismatch<MathExpression> for MathExpression {
+    fn explain_mismatch(
+        &self,
+        target_context: &Vec<ContextEntry>,
+        pattern: &MathExpression,
+        pattern_context: &Vec<ContextEntry>,
+    ) -> Option<MatchMismatch> {
+        if self.is_compatible(target_context, pattern, pattern_context) {
+            return None;
+        }
+        let inner = match (self, pattern) {
+            (MathExpression::Relation(self_rel), MathExpression::Relation(pattern_rel)) => {
+                self_rel.explain_mismatch(target_context, pattern_rel, pattern_context)
+            }
+            (MathExpression::Expression(self_expr), MathExpression::Expression(pattern_expr)) => {
+                self_expr.explain_mismatch(target_context, pattern_expr, pattern_context)
+            }
+            _ => None,
+        };
+        inner.or_else(|| {
+            let reason = mismatch_reason(self, pattern, "kind of expression differs");
+            Some(MatchMismatch::between(self, pattern, reason))
+        })
+    }
+}
+
+impl<T: 'static + Clone + ExplainMismatch<T> + ShortDebug + Debug> ExplainMismatch<Located<T>>
+    for Located<T>
+{
+    fn explain_mismatch(
+        &self,
+        target_context: &Vec<ContextEntry>,
+        pattern: &Located<T>,
+        pattern_context: &Vec<ContextEntry>,
+    ) -> Option<MatchMismatch> {
+        if self.is_compatible(target_context, pattern, pattern_context) {
+            return None;
+        }
+        let name = |data: &Parametrizable<T>| match data {
+            Parametrizable::Variable(id) => id.body.clone(),
+            Parametrizable::Concrete(value) => one_line(value.as_ref()),
+        };
+        let reason = match (&self.data, &pattern.data) {
+            (
+                Parametrizable::Concrete(self_concrete),
+                Parametrizable::Concrete(pattern_concrete),
+            ) => {
+                return self_concrete.explain_mismatch(
+                    target_context,
+                    pattern_concrete,
+                    pattern_context,
+                );
+            }
+            (Parametrizable::Variable(_), Parametrizable::Variable(_)) => "variable types differ",
+            _ => "a variable cannot match a concrete value",
+        };
+        Some(MatchMismatch::new(
+            name(&self.data),
+            name(&pattern.data),
+            reason,
+        ))
+    }
+}
+
 impl IsCompatible<MathRelation> for MathRelation {
     fn is_compatible(
         &self,
@@ -185,6 +333,64 @@ impl IsCompatible<MathRelation> for MathRelation {
     }
 }
 
+impl ExplainMismatch<MathRelation> for MathRelation {
+    fn explain_mismatch(
+        &self,
+        target_context: &Vec<ContextEntry>,
+        pattern: &MathRelation,
+        pattern_context: &Vec<ContextEntry>,
+    ) -> Option<MatchMismatch> {
+        if self.is_compatible(target_context, pattern, pattern_context) {
+            return None;
+        }
+        let explain = |target: &Located<MathRelation>, pattern: &Located<MathRelation>| {
+            target.explain_mismatch(target_context, pattern, pattern_context)
+        };
+        let inner = match (self, pattern) {
+            (
+                MathRelation::Equal { left, right },
+                MathRelation::Equal {
+                    left: pattern_left,
+                    right: pattern_right,
+                },
+            ) => first_child_mismatch([
+                left.explain_mismatch(target_context, pattern_left, pattern_context),
+                right.explain_mismatch(target_context, pattern_right, pattern_context),
+            ]),
+            (MathRelation::And(locateds), MathRelation::And(pattern_locateds))
+            | (MathRelation::Or(locateds), MathRelation::Or(pattern_locateds))
+                if locateds.len() == pattern_locateds.len() =>
+            {
+                first_child_mismatch(
+                    locateds
+                        .iter()
+                        .zip(pattern_locateds)
+                        .map(|(located, pattern_located)| explain(located, pattern_located)),
+                )
+            }
+            (MathRelation::Not(located), MathRelation::Not(pattern_located)) => {
+                explain(located, pattern_located).map(|mismatch| mismatch.under(0))
+            }
+            (
+                MathRelation::Implies(located, located1),
+                MathRelation::Implies(pattern_located, pattern_located1),
+            )
+            | (
+                MathRelation::Equivalent(located, located1),
+                MathRelation::Equivalent(pattern_located, pattern_located1),
+            ) => first_child_mismatch([
+                explain(located, pattern_located),
+                explain(located1, pattern_located1),
+            ]),
+            _ => None,
+        };
+        inner.or_else(|| {
+            let reason = mismatch_reason(self, pattern, "relation differs");
+            Some(MatchMismatch::between(self, pattern, reason))
+        })
+    }
+}
+
 impl IsCompatible<MathObject> for MathObject {
     fn is_compatible(
         &self,
@@ -193,6 +399,7 @@ impl IsCompatible<MathObject> for MathObject {
         pattern_context: &Vec<ContextEntry>,
     ) -> bool {
         match (self, pattern) {
+            #[cfg(feature = "groups")]
             (MathObject::Group(self_group), MathObject::Group(pattern_group)) => {
                 self_group.is_compatible(target_context, &pattern_group, pattern_context)
             }
@@ -211,6 +418,7 @@ impl IsCompatible<TheoryExpression> for TheoryExpression {
         // Deep structural compatibility check using StructurallyEquivalent trait
 
         match (self, pattern) {
+            #[cfg(feature = "groups")]
             (TheoryExpression::Group(self_group), TheoryExpression::Group(pattern_group)) => {
                 self_group.is_compatible(target_context, pattern_group, pattern_context)
             }
@@ -224,3 +432,23 @@ impl IsCompatible<TheoryExpression> for TheoryExpression {
         }
     }
 }
+
+impl ExplainMismatch<TheoryExpression> for TheoryExpression {
+    fn explain_mismatch(
+        &self,
+        target_context: &Vec<ContextEntry>,
+        pattern: &TheoryExpression,
+        pattern_context: &Vec<ContextEntry>,
+    ) -> Option<MatchMismatch> {
+        if self.is_compatible(target_context, pattern, pattern_context) {
+            return None;
+        }
+        match (self, pattern) {
+            #[cfg(feature = "groups")]
+            (TheoryExpression::Group(self_group), TheoryExpression::Group(pattern_group)) => {
+                self_group.explain_mismatch(target_context, pattern_group, pattern_context)
+            }
+            _ => Some(MatchMismatch::between(self, pattern, "theory differs")),
+        }
+    }
+}
@@ -6,6 +6,7 @@ pub mod detag;
 pub mod instantiable;
 pub mod is_compatible;
 pub use is_compatible::SameRole;
+pub mod normalize;
 pub mod replace;
 pub mod search;
 pub mod substitutable;
@@ -16,7 +17,8 @@ pub use complexity::Complexity;
 pub use debug::ShortDebug;
 pub use detag::TryDetag;
 pub use instantiable::Instantiable;
-pub use is_compatible::IsCompatible;
+pub use is_compatible::{ExplainMismatch, IsCompatible};
+pub use normalize::Normalize;
 pub use replace::Replace;
 pub use search::Search;
 pub use substitutable::Substitutable;
@@ -0,0 +1,139 @@
+use std::sync::Arc;
+
+use crate::subjects::math::formalism::{
+    expressions::MathExpression, extract::Parametrizable, location::Located,
+    relations::MathRelation,
+};
+
+/// Light structural normalization applied before matching.
+///
+/// The rules are confluent and the result is a fixed point, so normalizing an
+/// already normalized term returns it unchanged:
+/// - double negations are removed: `¬¬A` becomes `A`
+/// - nested conjunctions and disjunctions are flattened: `A ∧ (B ∧ C)` becomes `A ∧ B ∧ C`,
+///   and a connective with a single operand is replaced by that operand
+/// - a view of a view through the same operator collapses to a single view
+///
+/// Located nodes that are rebuilt keep their id, so targets stay valid.
+pub trait Normalize {
+    fn normalize(&self) -> Self;
+}
+
+impl Normalize for Located<MathRelation> {
+    fn normalize(&self) -> Self {
+        let Some(relation) = self.concrete_value() else {
+            return self.clone();
+        };
+        let rebuilt = match relation.as_ref() {
+            MathRelation::Not(inner) => {
+                let inner = inner.normalize();
+                if let Some(MathRelation::Not(original)) = inner.concrete_value().map(Arc::as_ref) {
+                    // The inner term is normalized, so its operand already is too
+                    return original.clone();
+                }
+                MathRelation::Not(inner)
+            }
+            MathRelation::And(operands) => {
+                let operands = flatten(operands, |relation| match relation {
+                    MathRelation::And(nested) => Some(nested),
+                    _ => None,
+                });
+                if operands.len() == 1 {
+                    return operands[0].clone();
+                }
+                MathRelation::And(operands)
+            }
+            MathRelation::Or(operands) => {
+                let operands = flatten(operands, |relation| match relation {
+                    MathRelation::Or(nested) => Some(nested),
+                    _ => None,
+                });
+                if operands.len() == 1 {
+                    return operands[0].clone();
+                }
+                MathRelation::Or(operands)
+            }
+            MathRelation::Implies(antecedent, consequent) => {
+                MathRelation::Implies(antecedent.normalize(), consequent.normalize())
+            }
+            MathRelation::Equivalent(left, right) => {
+                MathRelation::Equivalent(left.normalize(), right.normalize())
+            }
+            MathRelation::Equal { left, right } => MathRelation::Equal {
+                left: left.normalize(),
+                right: right.normalize(),
+            },
+            _ => return self.clone(),
+        };
+        Located {
+            id: self.id.clone(),
+            data: Parametrizable::Concrete(Arc::new(rebuilt)),
+        }
+    }
+}
+
+impl Normalize for Located<MathExpression> {
+    fn normalize(&self) -> Self {
+        let Some(expression) = self.concrete_value() else {
+            return self.clone();
+        };
+        let rebuilt = match expression.as_ref() {
+            MathExpression::ViewAs { expression, view } => {
+                let expression = expression.normalize();
+                if let Some(MathExpression::ViewAs {
+                    view: inner_view, ..
+                }) = expression.concrete_value().map(Arc::as_ref)
+                {
+                    if inner_view.data == view.data {
+                        return expression;
+                    }
+                }
+                MathExpression::ViewAs {
+                    expression,
+                    view: view.clone(),
+                }
+            }
+            MathExpression::Relation(relation) => {
+                MathExpression::Relation(Arc::new(relation.normalize()))
+            }
+            _ => return self.clone(),
+        };
+        Located {
+            id: self.id.clone(),
+            data: Parametrizable::Concrete(Arc::new(rebuilt)),
+        }
+    }
+}
+
+impl Normalize for MathRelation {
+    fn normalize(&self) -> Self {
+        let normalized = Located::new_concrete(self.clone()).normalize();
+        match normalized.concrete_value() {
+            Some(relation) => relation.as_ref().clone(),
+            // A bare relation can only normalize to a variable if it was `¬¬v`
+            None => self.clone(),
+        }
+    }
+}
+
+/// Normalizes each operand and splices in the operands of any nested
+/// occurrence of the same connective.
+fn flatten(
+    operands: &[Located<MathRelation>],
+    same_connective: impl Fn(&MathRelation) -> Option<&Vec<Located<MathRelation>>>,
+) -> Vec<Located<MathRelation>> {
+    let mut flattened = Vec::with_capacity(operands.len());
+    for operand in operands {
+        let operand = operand.normalize();
+        let nested = operand
+            .concrete_value()
+            .and_then(|relation| same_connective(relation))
+            .cloned();
+        match nested {
+            // Already normalized, hence already flat
+            Some(nested) => flattened.extend(nested),
+            None => flattened.push(operand),
+        }
+    }
+    flattened
+}
@@ -1,7 +1,9 @@
 use crate::subjects::math::formalism::proof::ContextEntryVecExt;
+#[cfg(feature = "groups")]
 use crate::subjects::math::theories::groups::definitions::{
     GenericGroup, Group, GroupExpression, GroupHomomorphism, GroupOperation,
 };
+#[cfg(feature = "rings")]
 use crate::subjects::math::theories::rings::definitions::Ring;
 use crate::subjects::math::theories::zfc::definitions::Set;
 use crate::{
@@ -543,6 +545,7 @@ impl Replace for MathRelation {
             MathRelation::False => MathRelation::False,
             MathRelation::NumberTheory(number_theory_relation) => todo!(),
             MathRelation::SetTheory(set_relation) => todo!(),
+            #[cfg(feature = "groups")]
             MathRelation::GroupTheory(group_relation) => {
                 MathRelation::GroupTheory(group_relation.replace(
                     current_id,
@@ -554,9 +557,11 @@ impl Replace for MathRelation {
                     manual_instantiations,
                 ))
             }
+            #[cfg(feature = "rings")]
             MathRelation::RingTheory(ring_relation) => todo!(),
             MathRelation::TopologyTheory(topology_relation) => todo!(),
             MathRelation::CategoryTheory(category_relation) => todo!(),
+            #[cfg(feature = "probability")]
             MathRelation::ProbabilityTheory(probability_relation) => todo!(),
         }
     }
@@ -575,6 +580,7 @@ impl Replace for TheoryExpression {
         manual_instantiations: &HashMap<Identifier, Identifier>,
     ) -> Self {
         match self {
+            #[cfg(feature = "groups")]
             TheoryExpression::Group(group_expr) => TheoryExpression::Group(group_expr.replace(
                 current_id,
                 target_id,
@@ -10,6 +10,7 @@ use crate::subjects::math::formalism::proof::tactics::{ContextOrStatement, Targe
 use crate::subjects::math::formalism::relations::MathRelation;
 use crate::subjects::math::formalism::traits::debug::ShortDebug;
 use crate::subjects::math::formalism::traits::detag::TryDetag;
+#[cfg(feature = "groups")]
 use crate::subjects::math::theories::groups::definitions::{Group, GroupExpression, GroupRelation};
 use std::fmt::Debug;
 
@@ -228,19 +229,24 @@ impl Search for MathRelation {
         target: String,
     ) -> Option<Located<T>> {
         match self {
-            MathRelation::And(locateds) => todo!(),
-            MathRelation::Or(locateds) => todo!(),
-            MathRelation::Not(located) => todo!(),
-            MathRelation::Implies(located, located1) => todo!(),
-            MathRelation::Equivalent(located, located1) => todo!(),
-            MathRelation::True => todo!(),
-            MathRelation::False => todo!(),
+            MathRelation::And(locateds) | MathRelation::Or(locateds) => locateds
+                .iter()
+                .find_map(|located| located.get_located::<T>(target.clone())),
+            MathRelation::Not(located) => located.get_located::<T>(target.clone()),
+            MathRelation::Implies(located, located1)
+            | MathRelation::Equivalent(located, located1) => located
+                .get_located::<T>(target.clone())
+                .or_else(|| located1.get_located::<T>(target.clone())),
+            MathRelation::True | MathRelation::False => None,
             MathRelation::NumberTheory(number_theory_relation) => todo!(),
             MathRelation::SetTheory(set_relation) => todo!(),
+            #[cfg(feature = "groups")]
             MathRelation::GroupTheory(group_relation) => todo!(),
+            #[cfg(feature = "rings")]
             MathRelation::RingTheory(ring_relation) => todo!(),
             MathRelation::TopologyTheory(topology_relation) => todo!(),
             MathRelation::CategoryTheory(category_relation) => todo!(),
+            #[cfg(feature = "probability")]
             MathRelation::ProbabilityTheory(probability_relation) => todo!(),
             MathRelation::Equal { left, right } => left
                 .get_located::<T>(target.clone())
@@ -308,10 +314,13 @@ impl Search for MathRelation {
             MathRelation::False => todo!(),
             MathRelation::NumberTheory(number_theory_relation) => todo!(),
             MathRelation::SetTheory(set_relation) => todo!(),
+            #[cfg(feature = "groups")]
             MathRelation::GroupTheory(group_relation) => todo!(),
+            #[cfg(feature = "rings")]
             MathRelation::RingTheory(ring_relation) => todo!(),
             MathRelation::TopologyTheory(topology_relation) => todo!(),
             MathRelation::CategoryTheory(category_relation) => todo!(),
+            #[cfg(feature = "probability")]
             MathRelation::ProbabilityTheory(probability_relation) => todo!(),
         };
 
@@ -343,6 +352,7 @@ impl Search for MathObject {
         }
 
         let sub_matches = match self {
+            #[cfg(feature = "groups")]
             MathObject::Group(group) => group.find_matches(
                 target.clone(),
                 current_id.clone(),
@@ -351,9 +361,12 @@ impl Search for MathObject {
                 pattern_context,
                 is_in_scope_now,
             ),
+            #[cfg(feature = "rings")]
             MathObject::Ring(ring) => todo!(),
             MathObject::Field(field) => todo!(),
+            #[cfg(feature = "rings")]
             MathObject::Module(module) => todo!(),
+            #[cfg(feature = "rings")]
             MathObject::Algebra(algebra) => todo!(),
             MathObject::TopologicalSpace(topological_space) => todo!(),
             MathObject::VectorSpace(vector_space) => todo!(),
@@ -370,14 +383,19 @@ impl Search for MathObject {
         target: String,
     ) -> Option<Located<T>> {
         match self {
+            #[cfg(feature = "groups")]
             MathObject::Group(group) => group.get_located(target),
+            #[cfg(feature = "rings")]
             MathObject::Ring(ring) => todo!(),
             MathObject::Field(field) => todo!(),
+            #[cfg(feature = "rings")]
             MathObject::Module(module) => todo!(),
+            #[cfg(feature = "rings")]
             MathObject::Algebra(algebra) => todo!(),
             MathObject::TopologicalSpace(topological_space) => todo!(),
             MathObject::VectorSpace(vector_space) => todo!(),
-            MathObject::Set(set) => todo!(),
+            // Sets carry no located children of their own.
+            MathObject::Set(_) => None,
             MathObject::Function(function) => todo!(),
         }
     }
@@ -406,6 +424,7 @@ impl Search for TheoryExpression {
         }
 
         let sub_matches = match self {
+            #[cfg(feature = "groups")]
             TheoryExpression::Group(group) => group.find_matches(
                 target.clone(),
                 current_id.clone(),
@@ -428,6 +447,7 @@ impl Search for TheoryExpression {
         target: String,
     ) -> Option<Located<T>> {
         match self {
+            #[cfg(feature = "groups")]
             TheoryExpression::Group(group) => group.get_located(target),
             _ => todo!(),
         }
@@ -437,7 +457,7 @@ impl Search for TheoryExpression {
 // Import the IsCompatible trait for use in Search implementations
 use crate::subjects::math::formalism::traits::is_compatible::IsCompatible;
 
-#[cfg(test)]
+#[cfg(all(test, feature = "groups"))]
 mod tests {
     use super::*;
     use crate::subjects::math::theories::groups::definitions::{
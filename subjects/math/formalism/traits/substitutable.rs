@@ -1,6 +1,8 @@
+#[cfg(feature = "groups")]
 use crate::subjects::math::theories::groups::definitions::{
     GenericGroup, Group, GroupExpression, GroupHomomorphism, GroupOperation,
 };
+#[cfg(feature = "rings")]
 use crate::subjects::math::theories::rings::definitions::Ring;
 use crate::subjects::math::theories::zfc::definitions::Set;
 use crate::{
@@ -146,6 +148,7 @@ impl<U: 'static + Clone + Debug + Search> Substitutable<U> for MathObject {
         context: &Vec<ContextEntry>,
     ) -> Self {
         match &self {
+            #[cfg(feature = "groups")]
             MathObject::Group(group) => {
                 MathObject::Group(group.substitute(instantiations, target, context))
             }
@@ -165,88 +168,36 @@ impl<U: 'static + Clone + Debug + Search> Substitutable<U> for MathRelation {
         target: &Located<U>,
         context: &Vec<ContextEntry>,
     ) -> Self {
+        // Operands are substituted as `Located` values, so a variable is replaced by
+        // its instantiation (or kept) rather than unwrapped to its type
         match &self {
-            MathRelation::Equal { left, right } => {
-                // For Arc-wrapped expressions, we need to unwrap, substitute, and re-wrap
-                let new_left_expr =
-                    left.data
-                        .unwrap(context)
-                        .substitute(instantiations, target, context);
-                let new_right_expr =
-                    right
-                        .data
-                        .unwrap(context)
-                        .substitute(instantiations, target, context);
-
-                MathRelation::Equal {
-                    left: Located::new_concrete(new_left_expr),
-                    right: Located::new_concrete(new_right_expr),
-                }
-            }
-            MathRelation::And(relations) => {
-                let new_relations = relations
+            MathRelation::Equal { left, right } => MathRelation::Equal {
+                left: left.substitute(instantiations, target, context),
+                right: right.substitute(instantiations, target, context),
+            },
+            MathRelation::And(relations) => MathRelation::And(
+                relations
                     .iter()
-                    .map(|r| {
-                        let new_rel =
-                            r.data
-                                .unwrap(context)
-                                .substitute(instantiations, target, context);
-                        Located::new_concrete(new_rel)
-                    })
-                    .collect();
-                MathRelation::And(new_relations)
-            }
-            MathRelation::Or(relations) => {
-                let new_relations = relations
+                    .map(|r| r.substitute(instantiations, target, context))
+                    .collect(),
+            ),
+            MathRelation::Or(relations) => MathRelation::Or(
+                relations
                     .iter()
-                    .map(|r| {
-                        let new_rel =
-                            r.data
-                                .unwrap(context)
-                                .substitute(instantiations, target, context);
-                        Located::new_concrete(new_rel)
-                    })
-                    .collect();
-                MathRelation::Or(new_relations)
-            }
+                    .map(|r| r.substitute(instantiations, target, context))
+                    .collect(),
+            ),
             MathRelation::Not(relation) => {
-                let new_rel =
-                    relation
-                        .data
-                        .unwrap(context)
-                        .substitute(instantiations, target, context);
-                MathRelation::Not(Located::new_concrete(new_rel))
-            }
-            MathRelation::Implies(left, right) => {
-                let new_left =
-                    left.data
-                        .unwrap(context)
-                        .substitute(instantiations, target, context);
-                let new_right =
-                    right
-                        .data
-                        .unwrap(context)
-                        .substitute(instantiations, target, context);
-                MathRelation::Implies(
-                    Located::new_concrete(new_left),
-                    Located::new_concrete(new_right),
-                )
-            }
-            MathRelation::Equivalent(left, right) => {
-                let new_left =
-                    left.data
-                        .unwrap(context)
-                        .substitute(instantiations, target, context);
-                let new_right =
-                    right
-                        .data
-                        .unwrap(context)
-                        .substitute(instantiations, target, context);
-                MathRelation::Equivalent(
-                    Located::new_concrete(new_left),
-                    Located::new_concrete(new_right),
-                )
+                MathRelation::Not(relation.substitute(instantiations, target, context))
             }
+            MathRelation::Implies(left, right) => MathRelation::Implies(
+                left.substitute(instantiations, target, context),
+                right.substitute(instantiations, target, context),
+            ),
+            MathRelation::Equivalent(left, right) => MathRelation::Equivalent(
+                left.substitute(instantiations, target, context),
+                right.substitute(instantiations, target, context),
+            ),
             _ => self.clone(),
         }
     }
@@ -266,6 +217,7 @@ impl<U: 'static + Clone + Debug + Search> Substitutable<U> for TheoryExpression
         context: &Vec<ContextEntry>,
     ) -> Self {
         match self {
+            #[cfg(feature = "groups")]
             TheoryExpression::Group(group_expr) => {
                 TheoryExpression::Group(group_expr.substitute(instantiations, target, context))
             }
@@ -1,21 +1,38 @@
+#[cfg(feature = "affine_geometry")]
+use super::super::super::super::math::theories::affine_geometry::definitions::AffineSpace;
+#[cfg(feature = "algebraic_geometry")]
+use super::super::super::super::math::theories::algebraic_geometry::definitions::{
+    Scheme, Variety,
+};
+#[cfg(feature = "differential_geometry")]
+use super::super::super::super::math::theories::differential_geometry::definitions::{
+    FiberBundle, RiemannianManifold, SmoothManifold,
+};
+#[cfg(feature = "groups")]
+use super::super::super::super::math::theories::groups::definitions::{
+    Group, LieGroup, TopologicalGroup,
+};
+#[cfg(feature = "measure")]
+use super::super::super::super::math::theories::measure::definitions::{
+    LpSpace, MeasurableSpace, MeasureSpace,
+};
+#[cfg(feature = "projective_geometry")]
+use super::super::super::super::math::theories::projective_geometry::definitions::ProjectiveSpace;
+#[cfg(feature = "symplectic_geometry")]
+use super::super::super::super::math::theories::symplectic_geometry::definitions::SymplecticManifold;
 use super::super::super::super::math::theories::{
-    affine_geometry::definitions::AffineSpace,
-    algebraic_geometry::definitions::{Scheme, Variety},
     analysis::definition::spaces::{
         BanachSpace, DistributionSpace, FrechetSpace, FunctionSpace, HilbertSpace,
         LocallyConvexSpace, SobolevSpace,
     },
-    differential_geometry::definitions::{FiberBundle, RiemannianManifold, SmoothManifold},
-    groups::definitions::{Group, LieGroup, TopologicalGroup},
     linear_algebra::definitions::{InnerProductSpace, NormedSpace, VectorSpace},
-    measure::definitions::{LpSpace, MeasurableSpace, MeasureSpace},
-    projective_geometry::definitions::ProjectiveSpace,
-    rings::definitions::{Field, Ring},
-    symplectic_geometry::definitions::SymplecticManifold,
     topology::definitions::{MetricSpace, TopologicalSpace},
     zfc::definitions::Set,
 };
 
+#[cfg(feature = "rings")]
+use super::super::super::super::math::theories::rings::definitions::{Field, Ring};
+
 use serde::{Deserialize, Serialize};
 
 /// Registry of all mathematical spaces
@@ -33,15 +50,19 @@ pub enum Space {
     MetricSpace(MetricSpace),
 
     /// Smooth manifold (differential_geometry/definitions.rs)
+    #[cfg(feature = "differential_geometry")]
     SmoothManifold(SmoothManifold),
 
     /// Riemannian manifold (differential_geometry/definitions.rs)
+    #[cfg(feature = "differential_geometry")]
     RiemannianManifold(RiemannianManifold),
 
     /// Fiber bundle (differential_geometry/definitions.rs)
+    #[cfg(feature = "differential_geometry")]
     FiberBundle(FiberBundle),
 
     /// Symplectic manifold (symplectic_geometry/definitions.rs)
+    #[cfg(feature = "symplectic_geometry")]
     SymplecticManifold(SymplecticManifold),
 
     /// Vector space (linear_algebra/definitions.rs)
@@ -57,12 +78,15 @@ pub enum Space {
     FunctionSpace(FunctionSpace),
 
     /// Measurable space (measure/definitions.rs)
+    #[cfg(feature = "measure")]
     MeasurableSpace(MeasurableSpace),
 
     /// Measure space (measure/definitions.rs)
+    #[cfg(feature = "measure")]
     MeasureSpace(MeasureSpace),
 
     /// Lp space (measure/definitions.rs)
+    #[cfg(feature = "measure")]
     LpSpace(LpSpace),
 
     /// Sobolev space (analysis/definitions.rs)
@@ -72,30 +96,39 @@ pub enum Space {
     DistributionSpace(DistributionSpace),
 
     /// Affine space (affine_geometry/definitions.rs)
+    #[cfg(feature = "affine_geometry")]
     AffineSpace(AffineSpace),
 
     /// Projective space (projective_geometry/definitions.rs)
+    #[cfg(feature = "projective_geometry")]
     ProjectiveSpace(ProjectiveSpace),
 
     /// Group (groups/definitions.rs)
+    #[cfg(feature = "groups")]
     Group(Group),
 
     /// Topological group (groups/definitions.rs)
+    #[cfg(feature = "groups")]
     TopologicalGroup(TopologicalGroup),
 
     /// Lie group (groups/definitions.rs)
+    #[cfg(feature = "groups")]
     LieGroup(LieGroup),
 
     /// Ring (rings/definitions.rs)
+    #[cfg(feature = "rings")]
     Ring(Ring),
 
     /// Field (rings/definitions.rs)
+    #[cfg(feature = "rings")]
     Field(Field),
 
     /// Scheme (algebraic_geometry/definitions.rs)
+    #[cfg(feature = "algebraic_geometry")]
     Scheme(Scheme),
 
     /// Variety (algebraic_geometry/definitions.rs)
+    #[cfg(feature = "algebraic_geometry")]
     Variety(Variety),
 
     /// Banach space (analysis/definitions.rs)
@@ -81,6 +81,7 @@ pub fn group_associativity_axiom() -> Axiom {
             )],
             alignment: None,
         }),
+        is_internal: false,
     };
     let y_context_entry = ContextEntry {
         name: y_id.clone(),
@@ -92,6 +93,7 @@ pub fn group_associativity_axiom() -> Axiom {
             )],
             alignment: None,
         }),
+        is_internal: false,
     };
     let z_context_entry = ContextEntry {
         name: z_id.clone(),
@@ -103,6 +105,7 @@ pub fn group_associativity_axiom() -> Axiom {
             )],
             alignment: None,
         }),
+        is_internal: false,
     };
 
     let x_quantifier = Quantifier {
@@ -191,6 +194,7 @@ pub fn group_identity_axiom() -> Axiom {
             ty: Located::new_concrete(group_element_type.clone()),
             definition: DefinitionState::Abstract,
             description: Some(RichText::text("Group element x".to_string())),
+            is_internal: false,
         },
         ContextEntry {
             name: e_id.clone(),
@@ -199,6 +203,7 @@ pub fn group_identity_axiom() -> Axiom {
             ))),
             definition: DefinitionState::Abstract,
             description: Some(RichText::text("Group identity element e".to_string())),
+            is_internal: false,
         },
     ];
     let quantifiers = vec![
@@ -303,6 +308,7 @@ pub fn group_inverse_axiom() -> Axiom {
                 )],
                 alignment: None,
             }),
+            is_internal: false,
         },
         ContextEntry {
             name: e_id.clone(),
@@ -316,6 +322,7 @@ pub fn group_inverse_axiom() -> Axiom {
                 )],
                 alignment: None,
             }),
+            is_internal: false,
         },
     ];
     let quantifiers = vec![
@@ -450,6 +450,11 @@ impl GroupCheckerHelpers {
         if b == 0 { a } else { Self::gcd(b, a % b) }
     }
 
+    /// Check if n is prime, by trial division
+    pub fn is_prime(n: u64) -> bool {
+        n >= 2 && (2..).take_while(|d| d * d <= n).all(|d| n % d != 0)
+    }
+
     /// Check if matrix is singular (simplified)
     pub fn is_matrix_singular(matrix: &[Vec<i64>]) -> bool {
         // Simplified check - in practice would compute actual determinant
@@ -1,4 +1,6 @@
+use crate::subjects::math::formalism::error::FormalismError;
 use crate::subjects::math::formalism::location::Located;
+use crate::subjects::math::formalism::extract::Parametrizable;
 use crate::subjects::math::formalism::{objects::MathObject, traits::complexity::Complexity};
 use crate::subjects::math::theories::zfc::definitions::GenericSet;
 // use crate::turn_render::Identifier; // already imported above; avoid duplicate
@@ -16,6 +18,7 @@ use crate::turn_render::math_node::MathNode;
 use crate::turn_render::section_node::{Section, SectionContentNode};
 use crate::turn_render::{RichText, RichTextSegment};
 use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
 use std::collections::{BTreeMap, HashMap};
 use std::fmt::{self, Display};
 use std::hash::Hash;
@@ -197,6 +200,7 @@ impl Default for GenericGroup {
                 parameters: std::collections::HashMap::new(),
                 description: "Abstract group set".to_string(),
                 membership_condition: "x ∈ G".to_string(),
+                membership_predicate: None,
                 properties: crate::subjects::math::theories::VariantSet::new(),
             },
             operation: GroupOperation::default(),
@@ -645,6 +649,12 @@ pub enum GroupRelation {
         normal_subgroup: Located<Group>,
     },
 
+    /// Element belongs to a group, typically a subgroup given by a construction: g ∈ H
+    ElementOf {
+        element: Located<GroupExpression>,
+        group: Located<Group>,
+    },
+
     /// Element is in the center of a group
     IsInCenterOf {
         element: Located<GroupExpression>,
@@ -959,6 +969,91 @@ pub enum GroupExpression {
     },
     /// A homomorphism between groups: φ : G → H
     Homomorphism(Located<GroupHomomorphism>), // Homomorphism itself can be variable
+    /// A homomorphism applied to an element: φ(g)
+    HomomorphismApplication {
+        homomorphism: Located<GroupHomomorphism>, // Homomorphism can be variable
+        element: Located<GroupExpression>,
+    },
+}
+
+impl GroupExpression {
+    /// The elements of the coset `gH` (or `Hg` when `is_left` is false) in the
+    /// concrete finite `group`.
+    ///
+    /// `g` must be a concrete element or the identity, and `H` the trivial group
+    /// or a subgroup generated by concrete elements; the elements are computed
+    /// from the Cayley table of `group` and listed starting from `g`. Returns
+    /// `None` for anything abstract, and for expressions that are not cosets.
+    pub fn evaluate_coset(&self, group: &Group) -> Option<Vec<GroupElement>> {
+        let GroupExpression::Coset {
+            element,
+            subgroup,
+            is_left,
+            ..
+        } = self
+        else {
+            return None;
+        };
+        let table = group.cayley_table()?;
+        // The first row of the table lists the elements, identity first
+        let index_of =
+            |target: &GroupElement| table[0].iter().position(|candidate| candidate == target);
+
+        let representative = match element.concrete_value()?.as_ref() {
+            GroupExpression::Element {
+                element: Some(value),
+                ..
+            } => index_of(value.concrete_value()?.as_ref())?,
+            GroupExpression::Identity(_) => 0,
+            _ => return None,
+        };
+
+        let members = subgroup_positions(subgroup.concrete_value()?, &table)?;
+
+        Some(
+            members
+                .into_iter()
+                .map(|member| {
+                    if *is_left {
+                        table[representative][member].clone()
+                    } else {
+                        table[member][representative].clone()
+                    }
+                })
+                .collect(),
+        )
+    }
+}
+
+/// The Cayley table positions of the members of `subgroup`, identity first.
+///
+/// `subgroup` must be the trivial group or a subgroup generated by elements of
+/// the table; it is computed by closing `{e}` under the generators, which gives
+/// the whole subgroup as the group is finite.
+fn subgroup_positions(subgroup: &Group, table: &[Vec<GroupElement>]) -> Option<Vec<usize>> {
+    let index_of =
+        |target: &GroupElement| table[0].iter().position(|candidate| candidate == target);
+    let generators = match subgroup {
+        Group::Trivial(_) => vec![],
+        Group::GeneratedSubgroup(generated) => generated
+            .generators
+            .iter()
+            .map(&index_of)
+            .collect::<Option<Vec<_>>>()?,
+        _ => return None,
+    };
+    let mut members = vec![0];
+    let mut frontier = vec![0];
+    while let Some(member) = frontier.pop() {
+        for &generator in &generators {
+            let product = index_of(&table[member][generator])?;
+            if !members.contains(&product) {
+                members.push(product);
+                frontier.push(product);
+            }
+        }
+    }
+    Some(members)
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -967,6 +1062,108 @@ pub struct GroupHomomorphism {
     pub domain: Located<Group>,
     /// The codomain group
     pub codomain: Located<Group>,
+    /// How the map is built, if it is built from other homomorphisms
+    #[serde(default)]
+    pub construction: HomomorphismConstruction,
+}
+
+/// How a group homomorphism is constructed
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub enum HomomorphismConstruction {
+    /// An arbitrary homomorphism, known only through its domain and codomain
+    #[default]
+    Abstract,
+    /// The identity map id_G : G → G
+    Identity,
+    /// The composite outer ∘ inner, applying `inner` first
+    Composite {
+        outer: Box<GroupHomomorphism>,
+        inner: Box<GroupHomomorphism>,
+    },
+}
+
+impl GroupHomomorphism {
+    /// The identity homomorphism id_G : G → G
+    pub fn identity(group: Located<Group>) -> Self {
+        GroupHomomorphism {
+            domain: group.clone(),
+            codomain: group,
+            construction: HomomorphismConstruction::Identity,
+        }
+    }
+
+    /// The composite `self ∘ other`, which applies `other` first.
+    /// Fails unless the domain of `self` is the codomain of `other`.
+    pub fn compose(&self, other: &GroupHomomorphism) -> Result<GroupHomomorphism, FormalismError> {
+        // Located equality only compares ids, so also accept the same variable or value
+        let matches =
+            self.domain.id == other.codomain.id || self.domain.data == other.codomain.data;
+        if !matches {
+            let describe = |group: &Located<Group>| match group.variable_id() {
+                Some(name) => name.body.clone(),
+                None => "a concrete group".to_string(),
+            };
+            return Err(FormalismError::TypeMismatch {
+                expected: format!("inner homomorphism ending at {}", describe(&self.domain)),
+                found: format!("one ending at {}", describe(&other.codomain)),
+            });
+        }
+        Ok(GroupHomomorphism {
+            domain: other.domain.clone(),
+            codomain: self.codomain.clone(),
+            construction: HomomorphismConstruction::Composite {
+                outer: Box::new(self.clone()),
+                inner: Box::new(other.clone()),
+            },
+        })
+    }
+
+    /// The homomorphism `located` stands for, looking a variable up in `context`.
+    /// Returns `None` when the variable is missing or is not a homomorphism.
+    pub fn resolve(
+        located: &Located<GroupHomomorphism>,
+        context: &[ContextEntry],
+    ) -> Option<GroupHomomorphism> {
+        if let Some(hom) = located.concrete_value() {
+            return Some(hom.as_ref().clone());
+        }
+        let name = located.variable_id()?;
+        let entry = context.iter().rev().find(|entry| &entry.name == name)?;
+        match entry.ty.concrete_value()?.as_ref() {
+            MathExpression::Expression(TheoryExpression::Group(GroupExpression::Homomorphism(
+                inner,
+            ))) => GroupHomomorphism::resolve(inner, context),
+            _ => None,
+        }
+    }
+}
+
+/// All permutations of `0..degree` in lexicographic order, identity first.
+fn lexicographic_permutations(degree: usize) -> Vec<Vec<usize>> {
+    let mut current: Vec<usize> = (0..degree).collect();
+    let mut permutations = vec![current.clone()];
+    // Standard next-permutation step: find the last ascent, swap, reverse the tail
+    while let Some(pivot) = (1..degree).rev().find(|&i| current[i - 1] < current[i]) {
+        let successor = (pivot..degree)
+            .rev()
+            .find(|&j| current[j] > current[pivot - 1])
+            .unwrap();
+        current.swap(pivot - 1, successor);
+        current[pivot..].reverse();
+        permutations.push(current.clone());
+    }
+    permutations
+}
+
+/// The number of pairs `i < j` with `p[i] > p[j]`; even exactly for even permutations.
+fn inversion_count(permutation: &[usize]) -> usize {
+    (0..permutation.len())
+        .map(|i| {
+            (i + 1..permutation.len())
+                .filter(|&j| permutation[i] > permutation[j])
+                .count()
+        })
+        .sum()
 }
 
 /// Different types of element values depending on the group structure
@@ -993,6 +1190,87 @@ impl Display for GroupElement {
     }
 }
 
+impl GroupElement {
+    /// The product `a · b` of two permutations or two square integer matrices.
+    ///
+    /// Permutations map `i` to `p[i]` and compose right to left, so
+    /// `(a · b)[i] = a[b[i]]`. Returns `None` for mismatched or symbolic
+    /// elements, and when a matrix entry overflows.
+    pub fn compose(a: &GroupElement, b: &GroupElement) -> Option<GroupElement> {
+        match (a, b) {
+            (GroupElement::Permutation(a), GroupElement::Permutation(b)) if a.len() == b.len() => {
+                let product = b
+                    .iter()
+                    .map(|&i| a.get(i).copied())
+                    .collect::<Option<_>>()?;
+                Some(GroupElement::Permutation(product))
+            }
+            (GroupElement::Matrix(a), GroupElement::Matrix(b)) if a.len() == b.len() => {
+                let n = a.len();
+                if a.iter().chain(b).any(|row| row.len() != n) {
+                    return None;
+                }
+                let product = (0..n)
+                    .map(|i| {
+                        (0..n)
+                            .map(|j| {
+                                (0..n).try_fold(0i64, |sum, k| {
+                                    sum.checked_add(a[i][k].checked_mul(b[k][j])?)
+                                })
+                            })
+                            .collect::<Option<Vec<_>>>()
+                    })
+                    .collect::<Option<_>>()?;
+                Some(GroupElement::Matrix(product))
+            }
+            _ => None,
+        }
+    }
+
+    /// The identity permutation or matrix of the same size as `self`.
+    fn identity_like(&self) -> Option<GroupElement> {
+        match self {
+            GroupElement::Permutation(p) => Some(GroupElement::Permutation((0..p.len()).collect())),
+            GroupElement::Matrix(m) => Some(GroupElement::Matrix(
+                (0..m.len())
+                    .map(|i| (0..m.len()).map(|j| i64::from(i == j)).collect())
+                    .collect(),
+            )),
+            _ => None,
+        }
+    }
+
+    /// Position of the variant in the canonical order.
+    fn kind_rank(&self) -> u8 {
+        match self {
+            GroupElement::Integer(_) => 0,
+            GroupElement::Symbol(_) => 1,
+            GroupElement::Permutation(_) => 2,
+            GroupElement::Matrix(_) => 3,
+        }
+    }
+}
+
+/// Canonical order used for deterministic rendering:
+/// integers, then symbols, then permutations, then matrices, each compared by content.
+impl Ord for GroupElement {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (GroupElement::Integer(a), GroupElement::Integer(b)) => a.cmp(b),
+            (GroupElement::Symbol(a), GroupElement::Symbol(b)) => a.cmp(b),
+            (GroupElement::Permutation(a), GroupElement::Permutation(b)) => a.cmp(b),
+            (GroupElement::Matrix(a), GroupElement::Matrix(b)) => a.cmp(b),
+            _ => self.kind_rank().cmp(&other.kind_rank()),
+        }
+    }
+}
+
+impl PartialOrd for GroupElement {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 /// Error type for group expression evaluation
 #[derive(Debug, Clone, PartialEq, Hash)]
 pub enum GroupExpressionError {
@@ -1780,6 +2058,48 @@ pub struct QuotientGroup {
     pub quotient_props: VariantSet<QuotientProperty>,
 }
 
+impl QuotientGroup {
+    /// The cosets `gN` of a concrete finite quotient `G/N`, each listed starting
+    /// from its representative, in the order the representatives appear in the
+    /// Cayley table of `G`. The first coset is `N` itself.
+    ///
+    /// `G` must have a Cayley table and `N` must be the trivial group or a
+    /// subgroup generated by elements of `G`. Returns `None` otherwise.
+    pub fn cosets(&self) -> Option<Vec<Vec<GroupElement>>> {
+        let table = self.group.concrete_value()?.cayley_table()?;
+        let members = subgroup_positions(self.normal_subgroup.concrete_value()?, &table)?;
+
+        let mut covered = vec![false; table.len()];
+        let mut cosets = Vec::new();
+        for representative in 0..table.len() {
+            if covered[representative] {
+                continue;
+            }
+            let coset: Vec<GroupElement> = members
+                .iter()
+                .map(|&member| table[representative][member].clone())
+                .collect();
+            for element in &coset {
+                let position = table[0].iter().position(|candidate| candidate == element)?;
+                covered[position] = true;
+            }
+            cosets.push(coset);
+        }
+        Some(cosets)
+    }
+
+    /// One representative per coset of a concrete finite quotient, starting with
+    /// the identity for `N` itself. Returns `None` whenever `cosets` does.
+    pub fn coset_representatives(&self) -> Option<Vec<GroupElement>> {
+        Some(
+            self.cosets()?
+                .into_iter()
+                .map(|coset| coset[0].clone())
+                .collect(),
+        )
+    }
+}
+
 /// The trivial group
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub struct TrivialGroup {
@@ -1999,6 +2319,352 @@ impl Group {
             props: VariantSet::new(),
         })
     }
+
+    /// The condition for `element` to belong to this construction group:
+    /// `φ(g) = e` for `ker φ`, `φ(w) = g` for `im φ` (with `witness` as the
+    /// preimage), `g ∈ Z(G)` as a center relation and `g ∈ N_G(H)` as a
+    /// normalizer relation.
+    ///
+    /// Returns `None` for groups that are not one of these constructions, and
+    /// when the defining homomorphism cannot be resolved in `context`.
+    pub fn membership_condition(
+        &self,
+        element: &Located<GroupExpression>,
+        witness: &Identifier,
+        context: &[ContextEntry],
+    ) -> Option<MathRelation> {
+        let group_expr = |expr: GroupExpression| {
+            Located::new_concrete(MathExpression::Expression(TheoryExpression::Group(expr)))
+        };
+        let apply = |map: &Located<GroupHomomorphism>, argument| {
+            group_expr(GroupExpression::HomomorphismApplication {
+                homomorphism: map.clone(),
+                element: argument,
+            })
+        };
+        match self {
+            Group::Kernel(kernel) => {
+                let hom = GroupHomomorphism::resolve(&kernel.defining_homomorphism, context)?;
+                Some(MathRelation::Equal {
+                    left: apply(&kernel.defining_homomorphism, element.clone()),
+                    right: group_expr(GroupExpression::Identity(hom.codomain)),
+                })
+            }
+            Group::Image(image) => {
+                GroupHomomorphism::resolve(&image.defining_homomorphism, context)?;
+                Some(MathRelation::Equal {
+                    left: apply(
+                        &image.defining_homomorphism,
+                        Located::new_variable(witness.clone()),
+                    ),
+                    right: match element.variable_id() {
+                        Some(name) => Located::new_variable(name.clone()),
+                        None => group_expr(element.concrete_value()?.as_ref().clone()),
+                    },
+                })
+            }
+            Group::Center(center) => Some(MathRelation::GroupTheory(GroupRelation::IsInCenterOf {
+                element: element.clone(),
+                group: center.parent_group.clone(),
+            })),
+            Group::Normalizer(normalizer) => Some(MathRelation::GroupTheory(
+                GroupRelation::NormalizesSubgroup {
+                    element: element.clone(),
+                    subgroup: normalizer.subgroup_normalized.clone(),
+                    group: normalizer.parent_group.clone(),
+                },
+            )),
+            _ => None,
+        }
+    }
+
+    /// Largest group order for which `cayley_table` builds a table.
+    pub const CAYLEY_TABLE_MAX_ORDER: usize = 64;
+
+    /// The full multiplication table of a concrete finite group.
+    ///
+    /// Row `i`, column `j` holds `g_i · g_j`, with the identity listed first so the
+    /// first row and column repeat the element list. Cyclic and modular additive
+    /// groups use residues `0..n`; dihedral groups use `r^k` for rotations and
+    /// `sr^k` for reflections. Symmetric and alternating groups list their
+    /// permutations in lexicographic order, and subgroups generated by concrete
+    /// permutations or integer matrices list the closure of their generators.
+    /// Returns `None` for abstract or infinite groups, and for groups larger than
+    /// `CAYLEY_TABLE_MAX_ORDER`.
+    pub fn cayley_table(&self) -> Option<Vec<Vec<GroupElement>>> {
+        let (order, multiply): (usize, Box<dyn Fn(usize, usize) -> usize>) = match self {
+            Group::Symmetric(_) | Group::Alternating(_) | Group::GeneratedSubgroup(_) => {
+                let elements = self.composable_elements()?;
+                return elements
+                    .iter()
+                    .map(|a| {
+                        elements
+                            .iter()
+                            .map(|b| GroupElement::compose(a, b))
+                            .collect()
+                    })
+                    .collect();
+            }
+            Group::Cyclic(cyclic) => {
+                let n = cyclic.order?;
+                (n, Box::new(move |a, b| (a + b) % n))
+            }
+            Group::ModularAdditive(modular) => {
+                let n = modular.modulus as usize;
+                (n, Box::new(move |a, b| (a + b) % n))
+            }
+            Group::Dihedral(dihedral) => {
+                // Index k < n is r^k, index n + k is sr^k; r^k s = s r^(-k).
+                let n = dihedral.order / 2;
+                (
+                    dihedral.order,
+                    Box::new(move |a, b| {
+                        let (a_flip, a_rot) = (a >= n, a % n);
+                        let (b_flip, b_rot) = (b >= n, b % n);
+                        let rotation = if b_flip { (n - a_rot) % n } else { a_rot };
+                        let rotation = (rotation + b_rot) % n;
+                        if a_flip != b_flip { n + rotation } else { rotation }
+                    }),
+                )
+            }
+            _ => return None,
+        };
+        if order == 0 || order > Self::CAYLEY_TABLE_MAX_ORDER {
+            return None;
+        }
+
+        let element = |index: usize| match self {
+            Group::Dihedral(dihedral) => {
+                let n = dihedral.order / 2;
+                let (flip, rotation) = (index >= n, index % n);
+                let name = match (flip, rotation) {
+                    (false, 0) => "e".to_string(),
+                    (false, 1) => "r".to_string(),
+                    (false, k) => format!("r^{}", k),
+                    (true, 0) => "s".to_string(),
+                    (true, 1) => "sr".to_string(),
+                    (true, k) => format!("sr^{}", k),
+                };
+                GroupElement::Symbol(name)
+            }
+            _ => GroupElement::Integer(index as i64),
+        };
+
+        Some(
+            (0..order)
+                .map(|a| (0..order).map(|b| element(multiply(a, b))).collect())
+                .collect(),
+        )
+    }
+
+    /// The elements of a concrete permutation or matrix group, identity first.
+    fn composable_elements(&self) -> Option<Vec<GroupElement>> {
+        let permutations = |degree: usize| {
+            let order = (1..=degree).try_fold(1usize, |acc, k| acc.checked_mul(k))?;
+            (order <= Self::CAYLEY_TABLE_MAX_ORDER).then(|| lexicographic_permutations(degree))
+        };
+        let elements = match self {
+            Group::Symmetric(symmetric) => permutations(symmetric.degree)?,
+            Group::Alternating(alternating) => permutations(alternating.degree as usize)?
+                .into_iter()
+                .filter(|permutation| inversion_count(permutation) % 2 == 0)
+                .collect(),
+            Group::GeneratedSubgroup(generated) => {
+                let first = generated.generators.first()?;
+                let mut elements = vec![first.identity_like()?];
+                let mut frontier = elements.clone();
+                while let Some(element) = frontier.pop() {
+                    for generator in &generated.generators {
+                        let product = GroupElement::compose(&element, generator)?;
+                        if !elements.contains(&product) {
+                            // Infinite groups never close, so give up at the size limit
+                            if elements.len() == Self::CAYLEY_TABLE_MAX_ORDER {
+                                return None;
+                            }
+                            elements.push(product.clone());
+                            frontier.push(product);
+                        }
+                    }
+                }
+                return Some(elements);
+            }
+            _ => return None,
+        };
+        Some(
+            elements
+                .into_iter()
+                .map(GroupElement::Permutation)
+                .collect(),
+        )
+    }
+
+    /// The center `Z(G)` of a concrete finite group: the elements commuting with
+    /// every element, listed in Cayley table order.
+    ///
+    /// Returns `None` whenever `cayley_table` does.
+    pub fn compute_center(&self) -> Option<Vec<GroupElement>> {
+        let table = self.cayley_table()?;
+        Some(
+            (0..table.len())
+                .filter(|&z| (0..table.len()).all(|g| table[z][g] == table[g][z]))
+                .map(|z| table[0][z].clone())
+                .collect(),
+        )
+    }
+
+    /// The commutator subgroup `[G, G]` of a concrete finite group, listed in
+    /// Cayley table order.
+    ///
+    /// Collects every commutator `a⁻¹b⁻¹ab` and closes the set under the group
+    /// operation. Returns `None` whenever `cayley_table` does.
+    pub fn compute_commutator_subgroup(&self) -> Option<Vec<GroupElement>> {
+        let table = self.cayley_table()?;
+        let order = table.len();
+        let index_of =
+            |target: &GroupElement| table[0].iter().position(|candidate| candidate == target);
+        let inverse = (0..order)
+            .map(|a| (0..order).find(|&b| table[a][b] == table[0][0]))
+            .collect::<Option<Vec<_>>>()?;
+        let product = |a: usize, b: usize| index_of(&table[a][b]);
+
+        let mut members = vec![false; order];
+        for a in 0..order {
+            for b in 0..order {
+                let left = product(inverse[a], inverse[b])?;
+                let right = product(a, b)?;
+                members[product(left, right)?] = true;
+            }
+        }
+        // A nonempty subset of a finite group closed under products is a subgroup
+        let mut frontier: Vec<usize> = (0..order).filter(|&m| members[m]).collect();
+        let generators = frontier.clone();
+        while let Some(member) = frontier.pop() {
+            for &generator in &generators {
+                let next = product(member, generator)?;
+                if !members[next] {
+                    members[next] = true;
+                    frontier.push(next);
+                }
+            }
+        }
+
+        Some(
+            (0..order)
+                .filter(|&m| members[m])
+                .map(|m| table[0][m].clone())
+                .collect(),
+        )
+    }
+
+    /// The order of `element` in a concrete finite group: the smallest `k > 0`
+    /// with `elementᵏ = e`.
+    ///
+    /// Multiplies by `element` until the identity comes back. Returns `None` when
+    /// `cayley_table` does, or when `element` is not an element of the group.
+    pub fn element_order(&self, element: &GroupElement) -> Option<u64> {
+        let table = self.cayley_table()?;
+        let index_of =
+            |target: &GroupElement| table[0].iter().position(|candidate| candidate == target);
+        let start = index_of(element)?;
+
+        let mut power = start;
+        let mut order = 1;
+        while power != 0 {
+            power = index_of(&table[power][start])?;
+            order += 1;
+        }
+        Some(order)
+    }
+
+    /// Largest group order for which `subgroups` enumerates subgroups.
+    pub const SUBGROUPS_MAX_ORDER: usize = 32;
+
+    /// Every subgroup of a concrete finite group, each listed in Cayley table
+    /// order, sorted by order and then by the table positions of their elements.
+    ///
+    /// Starts from the cyclic subgroups `⟨g⟩` and keeps adding the subgroup
+    /// generated by each pair found so far until no new subgroup appears. Closing
+    /// under the operation alone is enough, as inverses are powers in a finite
+    /// group. Returns `None` when `cayley_table` does, and for groups larger than
+    /// `SUBGROUPS_MAX_ORDER`.
+    pub fn subgroups(&self) -> Option<Vec<Vec<GroupElement>>> {
+        let table = self.cayley_table()?;
+        let order = table.len();
+        if order > Self::SUBGROUPS_MAX_ORDER {
+            return None;
+        }
+        let index_of =
+            |target: &GroupElement| table[0].iter().position(|candidate| candidate == target);
+        let products = table
+            .iter()
+            .map(|row| row.iter().map(&index_of).collect::<Option<Vec<_>>>())
+            .collect::<Option<Vec<_>>>()?;
+
+        // Subsets of the group as bitmasks over the Cayley table indices
+        let members = |set: u64| (0..order).filter(move |&k| (set >> k) & 1 == 1);
+        let close = |mut set: u64| loop {
+            let mut next = set;
+            for a in members(set) {
+                for b in members(set) {
+                    next |= 1 << products[a][b];
+                }
+            }
+            if next == set {
+                break set;
+            }
+            set = next;
+        };
+
+        let mut found: Vec<u64> = Vec::new();
+        for element in 0..order {
+            let cyclic = close(1 | (1 << element));
+            if !found.contains(&cyclic) {
+                found.push(cyclic);
+            }
+        }
+        let mut next = 0;
+        while next < found.len() {
+            for earlier in 0..next {
+                let joined = close(found[next] | found[earlier]);
+                if !found.contains(&joined) {
+                    found.push(joined);
+                }
+            }
+            next += 1;
+        }
+
+        found.sort_by_key(|&set| (set.count_ones(), members(set).collect::<Vec<_>>()));
+        Some(
+            found
+                .into_iter()
+                .map(|set| members(set).map(|k| table[0][k].clone()).collect())
+                .collect(),
+        )
+    }
+
+    /// Whether the group is commutative, when that is known.
+    ///
+    /// Cyclic, modular and trivial groups are always abelian, while `S_n` and
+    /// `D_n` are non-abelian once `n ≥ 3`. Other groups, and `S_n` or `D_n`
+    /// with a placeholder size of 0, fall back to an explicit `Abelian` property
+    /// on the core, and return `None` if there is none.
+    pub fn is_abelian(&self) -> Option<bool> {
+        match self {
+            Group::Cyclic(_)
+            | Group::Trivial(_)
+            | Group::ModularAdditive(_)
+            | Group::ModularMultiplicative(_) => Some(true),
+            Group::Symmetric(symmetric) if symmetric.degree > 0 => Some(symmetric.degree < 3),
+            // `order` counts 2n elements for the n-gon
+            Group::Dihedral(dihedral) if dihedral.order > 0 => Some(dihedral.order < 6),
+            _ => self.get_core().props.iter().find_map(|prop| match prop {
+                GroupProperty::Abelian(variant) => {
+                    Some(matches!(variant, AbelianPropertyVariant::Abelian))
+                }
+                _ => None,
+            }),
+        }
+    }
 }
 
 // SameRole for Group encodes refinement: self can replace baseline iff same variant
@@ -10,6 +10,15 @@ use crate::turn_render::math_node::{MathNode, MathNodeContent, ToTurnMath};
 use crate::turn_render::*;
 use crate::turn_render::*;
 
+/// The subscript of `A_n`: the degree, or `n` for the abstract family (degree 0).
+fn degree_label(group: &AlternatingGroup) -> String {
+    if group.degree == 0 {
+        "n".to_string()
+    } else {
+        group.degree.to_string()
+    }
+}
+
 impl ToTurnMath for AlternatingGroup {
     fn to_turn_math(&self, master_id: String) -> MathNode {
         // Use proper mathematical notation A_n with subscript
@@ -22,7 +31,7 @@ impl ToTurnMath for AlternatingGroup {
                 post_script: Some(ScriptNode {
                     subscripts: vec![MathNode {
                         id: format!("{}_subscript", master_id),
-                        content: Arc::new(MathNodeContent::String(self.degree.to_string())),
+                        content: Arc::new(MathNodeContent::String(degree_label(self))),
                     }],
                     superscripts: vec![],
                 }),
@@ -49,7 +58,7 @@ impl ToSectionNode for AlternatingGroup {
                     post_script: Some(ScriptNode {
                         subscripts: vec![MathNode {
                             id: format!("{}-title-math-subscript", id_prefix),
-                            content: Arc::new(MathNodeContent::String("n".to_string())),
+                            content: Arc::new(MathNodeContent::String(degree_label(self))),
                         }],
                         superscripts: vec![],
                     }),
@@ -191,11 +200,11 @@ impl ToSectionNode for AlternatingGroup {
 
         // Add properties from core group if any
         if !self.core.props.inner.is_empty() {
-            for prop in self.core.props.inner.iter() {
+            for prop in self.core.props.iter() {
                 selectable_props.push(SelectableProperty {
-                    name: format!("{:?}", prop.0),
-                    current_variant: format!("{:?}", prop.0),
-                    all_variants: vec![format!("{:?}", prop.0)],
+                    name: format!("{:?}", prop),
+                    current_variant: format!("{:?}", prop),
+                    all_variants: vec![format!("{:?}", prop)],
                     description: Some("Group property".to_string()),
                     variant_descriptions: None,
                     property_type_def_id: None,
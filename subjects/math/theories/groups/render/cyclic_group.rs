@@ -186,7 +186,7 @@ impl ToSectionNode for CyclicGroup {
 
         // Add properties from core group if any
         if !self.core.props.inner.is_empty() {
-            for prop in self.core.props.inner.iter() {
+            for prop in self.core.props.iter() {
                 selectable_props.push(SelectableProperty {
                     name: format!("{:?}", prop),
                     current_variant: format!("{:?}", prop),
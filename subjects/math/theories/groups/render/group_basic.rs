@@ -303,11 +303,11 @@ impl ToSectionNode for GenericGroup {
                         segments: vec![RichTextSegment::Text("Properties:".to_string())],
                         alignment: None,
                     }));
-                    for prop in self.props.inner.iter() {
+                    for prop in self.props.iter() {
                         content_nodes.push(SectionContentNode::RichText(RichText {
                             segments: vec![
                                 RichTextSegment::Text("- ".to_string()),
-                                RichTextSegment::Text(format!("{:?}", prop.0)),
+                                RichTextSegment::Text(format!("{:?}", prop)),
                             ],
                             alignment: None,
                         }));
@@ -332,8 +332,7 @@ impl ToSectionNode for GenericGroup {
 
                 // Create selectable properties for L2
                 let mut selectable_props = vec![];
-                for variant_wrapper in self.props.inner.iter() {
-                    let prop = &variant_wrapper.0;
+                for prop in self.props.iter() {
                     match prop {
                         GroupProperty::Finite(fv) => selectable_props.push(SelectableProperty {
                             name: "Order".to_string(),
@@ -553,11 +552,11 @@ impl ToSectionNode for GenericGroup {
                         )],
                         alignment: None,
                     }));
-                    for prop in self.props.inner.iter() {
+                    for prop in self.props.iter() {
                         content_nodes.push(SectionContentNode::RichText(RichText {
                             segments: vec![
                                 RichTextSegment::Text("- ".to_string()),
-                                RichTextSegment::Text(format!("{:?}", prop.0)),
+                                RichTextSegment::Text(format!("{:?}", prop)),
                             ],
                             alignment: None,
                         }));
@@ -592,8 +591,7 @@ impl ToSectionNode for GenericGroup {
 
                 // Create selectable properties for L4 - same as L2 but could include more concrete details
                 let mut selectable_props = vec![];
-                for variant_wrapper in self.props.inner.iter() {
-                    let prop = &variant_wrapper.0;
+                for prop in self.props.iter() {
                     match prop {
                         GroupProperty::Finite(fv) => selectable_props.push(SelectableProperty {
                             name: "Order".to_string(),
@@ -5,12 +5,16 @@ use std::sync::Arc;
 use crate::subjects::math::export::unified_exporter::TheoryExporter;
 use crate::subjects::math::formalism::extract::Parametrizable;
 use crate::subjects::math::formalism::location::Located;
+use crate::subjects::math::formalism::render::options::{
+    NotationStyle, RenderOptions, ToTurnMathConfigurable,
+};
 use crate::subjects::math::formalism::theorem::Theorem;
+use crate::subjects::math::theories::groups::checker::GroupCheckerHelpers;
 use crate::subjects::math::theories::groups::definitions::{
     AbelianPropertyVariant, AlternatingGroup, CenterGroup, CentralProductGroup, CentralizerGroup, CommutatorSubgroup,
     CompactPropertyVariant, ConnectedPropertyVariant, CyclicGroup, DihedralGroup, FinitePropertyVariant, FreeGroup, GeneralLinearGroup, GeneratedSubgroup, GenericGroup,
-    Group, GroupElement, GroupExpression, GroupHomomorphism, GroupOperation, GroupProperty,
-    GroupRelation, ImageGroup, KernelGroup, LieGroup, MetrizablePropertyVariant, ModularAdditiveGroup,
+    Group, GroupElement, GroupExpression, GroupHomomorphism, GroupOperation, GroupOperationVariant, GroupProperty,
+    GroupRelation, HomomorphismConstruction, ImageGroup, KernelGroup, LieGroup, MetrizablePropertyVariant, ModularAdditiveGroup,
     ModularMultiplicativeGroup, NilpotentPropertyVariant, NormalizerGroup, OrthogonalGroup, ProductGroup, ProductOperation,
     PullbackGroup, QuotientGroup, RestrictionGroup, SimplePropertyVariant, SolvablePropertyVariant, SpecialLinearGroup, SpecialOrthogonalGroup,
     SpecialUnitaryGroup, SubGroup, SylowSubgroup, SymmetricGroup, TopologicalGroup,
@@ -53,6 +57,10 @@ pub mod group_basic;
 pub mod lie_group;
 pub mod product_group;
 pub mod symmetric_group;
+#[cfg(test)]
+mod render_audit;
+#[cfg(test)]
+mod render_snapshots;
 pub mod tests;
 pub mod topological_group;
 
@@ -75,6 +83,13 @@ pub mod subgroup_constructions;
 
 impl ToTurnMath for Group {
     fn to_turn_math(&self, master_id: String) -> MathNode {
+        self.to_turn_math_with(master_id, &RenderOptions::default())
+    }
+}
+
+impl Group {
+    /// The full rendering: each group's name followed by its core.
+    fn to_full_turn_math(&self, master_id: String) -> MathNode {
         match self {
             Group::Generic(g) => g.to_turn_math(master_id),
             Group::Trivial(g) => {
@@ -224,16 +239,7 @@ impl ToTurnMath for Group {
                     ])),
                 }
             },
-            Group::Free(g) => {
-                let id = master_id.clone();
-                MathNode {
-                    id: id.clone(),
-                    content: Arc::new(MathNodeContent::RichTextContent(vec![
-                        MathTextSegment::Text("Free group ".to_string()),
-                        MathTextSegment::Math(g.core.to_turn_math(format!("{}-core", id))),
-                    ])),
-                }
-            },
+            Group::Free(g) => g.to_turn_math(master_id),
             Group::Quotient(g) => {
                 let id = master_id.clone();
                 MathNode {
@@ -338,13 +344,42 @@ impl ToTurnMath for Group {
                 }
             },
             Group::SylowSubgroup(g) => {
-                let id = master_id.clone();
+                // A prime of 0 stands for an unspecified p
+                let id = master_id.clone();
+                let prime = if g.prime == 0 { "p".to_string() } else { g.prime.to_string() };
+                let mut segments = vec![
+                    MathTextSegment::Text(format!("Sylow {}-subgroup ", prime)),
+                    MathTextSegment::Math(g.core.to_turn_math(format!("{}-core", id))),
+                ];
+                // |P| = p^a where p^a is the largest power of p dividing |G|
+                let parent_order = g.parent_group.concrete_value().and_then(|parent| parent.finite_order());
+                let prime_known = GroupCheckerHelpers::is_prime(g.prime);
+                if let (true, Some(mut order)) = (prime_known, parent_order) {
+                    let mut exponent = 0;
+                    while order > 0 && order % g.prime == 0 {
+                        order /= g.prime;
+                        exponent += 1;
+                    }
+                    let quantity = |suffix: &str, number: String| MathNode {
+                        id: format!("{}-order-{}", id, suffix),
+                        content: Arc::new(MathNodeContent::Quantity {
+                            number,
+                            scientific_notation: None,
+                            unit: None,
+                        }),
+                    };
+                    segments.push(MathTextSegment::Text(" of order ".to_string()));
+                    segments.push(MathTextSegment::Math(MathNode {
+                        id: format!("{}-order", id),
+                        content: Arc::new(MathNodeContent::Power {
+                            base: Arc::new(quantity("base", prime)),
+                            exponent: Arc::new(quantity("exp", exponent.to_string())),
+                        }),
+                    }));
+                }
                 MathNode {
                     id: id.clone(),
-                    content: Arc::new(MathNodeContent::RichTextContent(vec![
-                        MathTextSegment::Text("Sylow subgroup ".to_string()),
-                        MathTextSegment::Math(g.core.to_turn_math(format!("{}-core", id))),
-                    ])),
+                    content: Arc::new(MathNodeContent::RichTextContent(segments)),
                 }
             },
             Group::CentralProduct(g) => {
@@ -407,7 +442,7 @@ impl ToTurnMath for Group {
                     id: master_id.clone(),
                     content: Arc::new(MathNodeContent::RichTextContent(vec![
                         MathTextSegment::Text("Subgroup of ".to_string()),
-                        MathTextSegment::Math(g.parent_group.value().to_turn_math(format!("{}-parent", master_id))),
+                        MathTextSegment::Math(g.parent_group.to_turn_math(g.parent_group.id.clone())),
                     ])),
                 }
             },
@@ -417,7 +452,7 @@ impl ToTurnMath for Group {
 
 impl ToSectionNode for Group {
     fn to_section_node(&self, id_prefix: &str) -> Section {
-        match self {
+        let mut section = match self {
             Group::Generic(g) => g.to_section_node(id_prefix),
             Group::Trivial(g) => g.core.to_section_node(id_prefix),
             Group::Symmetric(g) => g.to_section_node(id_prefix),
@@ -451,7 +486,10 @@ impl ToSectionNode for Group {
             Group::Restriction(g) => g.core.to_section_node(id_prefix),
             Group::Interception(g) => g.core.to_section_node(id_prefix),
             Group::SubGroup(g) => g.core.to_section_node(id_prefix),
-        }
+        };
+
+        self.append_property_block(&mut section, id_prefix);
+        section
     }
 }
 
@@ -459,7 +497,7 @@ impl ToMathDocument for Group {
     fn to_math_document(&self, id_prefix: &str) -> MathDocument {
         // For specialized groups, use their own rendering even at L1 level
         // Only use the generic L1 schema for truly generic groups
-        match self {
+        let mut document = match self {
             Group::Generic(g) => {
                 let level = g.level();
                 if level == AbstractionLevel::Level1 {
@@ -523,7 +561,17 @@ impl ToMathDocument for Group {
                 // TODO: Implement proper subgroup rendering as "SubGroup(G)"
                 g.core.to_math_document(&format!("{}.subgroup", id_prefix))
             },
+        };
+
+        // The variant documents render their own sections, so the property
+        // block is added to the main one here
+        if let MathDocumentType::ScientificPaper(paper) = &mut document.content_type {
+            if let Some(main_section) = paper.structure.body.first_mut() {
+                let main_id = main_section.id.clone();
+                self.append_property_block(main_section, &main_id);
+            }
         }
+        document
     }
 }
 
@@ -535,6 +583,137 @@ impl ToTurnMath for Box<Group> {
     }
 }
 
+// === CONFIGURABLE GROUP RENDERING ===
+
+impl ToTurnMathConfigurable for Group {
+    fn to_turn_math_with(&self, master_id: String, options: &RenderOptions) -> MathNode {
+        if options.depth_exhausted() {
+            return MathNode {
+                id: master_id,
+                content: Arc::new(MathNodeContent::Text("…".to_string())),
+            };
+        }
+        if !options.include_properties {
+            return self.to_turn_math_without_properties(master_id, options);
+        }
+
+        let summary = self
+            .property_list()
+            .into_iter()
+            .map(|(label, value)| {
+                let value: String = value
+                    .segments
+                    .iter()
+                    .filter_map(|segment| match segment {
+                        RichTextSegment::Text(text) => Some(text.as_str()),
+                        _ => None,
+                    })
+                    .collect();
+                format!("{}: {}", label, value)
+            })
+            .collect::<Vec<_>>()
+            .join("; ");
+        let group = self.to_turn_math_without_properties(format!("{}-group", master_id), options);
+        MathNode {
+            id: master_id,
+            content: Arc::new(MathNodeContent::RichTextContent(vec![
+                MathTextSegment::Math(group),
+                MathTextSegment::Text(format!(" ({})", summary)),
+            ])),
+        }
+    }
+}
+
+impl Group {
+    /// `to_turn_math_with` minus the property summary.
+    fn to_turn_math_without_properties(
+        &self,
+        master_id: String,
+        options: &RenderOptions,
+    ) -> MathNode {
+        if !options.compact_cores {
+            return self.to_full_turn_math(master_id);
+        }
+        let notation = match self {
+            Group::Product(product) if !product.components.is_empty() => Some((
+                "Product group",
+                Self::product_notation(product, format!("{}-notation", master_id), options),
+            )),
+            _ => self.self_describing_notation(&master_id),
+        };
+        match (notation, options.notation) {
+            (None, _) => self.to_full_turn_math(master_id),
+            (Some((name, notation)), NotationStyle::Prose) => MathNode {
+                id: master_id,
+                content: Arc::new(MathNodeContent::RichTextContent(vec![
+                    MathTextSegment::Text(format!("{} ", name)),
+                    MathTextSegment::Math(notation),
+                ])),
+            },
+            (Some((_, notation)), NotationStyle::Symbolic) => MathNode {
+                id: master_id,
+                content: notation.content,
+            },
+        }
+    }
+
+    /// `G₁ × G₂ × ⋯` from the product's actual components, each rendered one
+    /// level deeper, with the symbol of the product operation between them.
+    fn product_notation(product: &ProductGroup, id: String, options: &RenderOptions) -> MathNode {
+        let symbol = match product.operation {
+            ProductOperation::Direct | ProductOperation::Fibered { .. } => "×",
+            ProductOperation::Semidirect { .. } => "⋊",
+            ProductOperation::Free => "*",
+            ProductOperation::Wreath => "≀",
+            ProductOperation::Central => "∘",
+        };
+        let mut terms = Vec::new();
+        for (index, component) in product.components.iter().enumerate() {
+            if index > 0 {
+                terms.push((
+                    RefinedMulOrDivOperation::None,
+                    MathNode {
+                        id: format!("{}-operator-{}", id, index),
+                        content: Arc::new(MathNodeContent::Identifier(Identifier::new_simple(
+                            symbol.to_string(),
+                        ))),
+                    },
+                ));
+            }
+            terms.push((
+                RefinedMulOrDivOperation::None,
+                component.to_turn_math_with(format!("{}-factor-{}", id, index), &options.nested()),
+            ));
+        }
+        MathNode {
+            id,
+            content: Arc::new(MathNodeContent::Multiplications { terms }),
+        }
+    }
+
+    /// The name and standard notation of a group whose type fixes it up to its
+    /// parameters, or `None` when the generic core carries real information.
+    fn self_describing_notation(&self, master_id: &str) -> Option<(&'static str, MathNode)> {
+        let id = format!("{}-notation", master_id);
+        let rendered = match self {
+            Group::Trivial(g) => ("Trivial group", g.to_turn_math(id)),
+            Group::Cyclic(g) => ("Cyclic group", g.to_turn_math(id)),
+            Group::Dihedral(g) => ("Dihedral group", g.to_turn_math(id)),
+            Group::Free(g) => ("Free group", g.to_turn_math(id)),
+            Group::GeneralLinear(g) => ("General linear group", g.to_turn_math(id)),
+            Group::SpecialLinear(g) => ("Special linear group", g.to_turn_math(id)),
+            Group::Orthogonal(g) => ("Orthogonal group", g.to_turn_math(id)),
+            Group::SpecialOrthogonal(g) => ("Special orthogonal group", g.to_turn_math(id)),
+            Group::Unitary(g) => ("Unitary group", g.to_turn_math(id)),
+            Group::SpecialUnitary(g) => ("Special unitary group", g.to_turn_math(id)),
+            Group::ModularAdditive(g) => ("Modular additive group", g.to_turn_math(id)),
+            Group::ModularMultiplicative(g) => ("Modular multiplicative group", g.to_turn_math(id)),
+            _ => return None,
+        };
+        Some(rendered)
+    }
+}
+
 // === GROUPELEMENT IMPLEMENTATIONS ===
 
 impl ToTurnMath for GroupElement {
@@ -587,20 +766,122 @@ impl ToTurnMath for GroupElement {
 
 // === GROUPEXPRESSION IMPLEMENTATIONS ===
 
+/// How the elements of a group are written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ElementNotation {
+    /// `g·h`, `g⁻¹`, the identity `e` and powers `gⁿ`
+    Multiplicative,
+    /// `g + h`, `-g`, the identity `0` and multiples `n·g`
+    Additive,
+}
+
+impl ElementNotation {
+    /// The notation for elements of `group`: additive for modular additive groups
+    /// and groups whose operation is addition, multiplicative otherwise, including
+    /// when the group is a variable.
+    pub fn of(group: &Located<Group>) -> Self {
+        match group.concrete_value().map(|group| group.as_ref()) {
+            Some(Group::ModularAdditive(_)) => ElementNotation::Additive,
+            Some(group)
+                if matches!(
+                    group.get_core().operation.operation_type,
+                    GroupOperationVariant::Addition
+                ) =>
+            {
+                ElementNotation::Additive
+            }
+            _ => ElementNotation::Multiplicative,
+        }
+    }
+}
+
+impl GroupExpression {
+    /// The additive rendering of an operation, inverse, identity or multiple in an
+    /// additive group, or `None` when the multiplicative rendering applies.
+    fn to_additive_turn_math(&self, master_id: &str) -> Option<MathNode> {
+        let group = match self {
+            GroupExpression::Operation { group, .. }
+            | GroupExpression::Inverse { group, .. }
+            | GroupExpression::Power { group, .. } => group,
+            // Identities used as types still render as their group
+            GroupExpression::Identity(group) if !master_id.contains("-type-") => group,
+            _ => return None,
+        };
+        if ElementNotation::of(group) != ElementNotation::Additive {
+            return None;
+        }
+
+        let content = match self {
+            GroupExpression::Operation { left, right, .. } => MathNodeContent::Relationship {
+                lhs: Arc::new(left.to_turn_math(format!("{}-left", master_id))),
+                operator: RelationOperatorNode::Custom("+".to_string()),
+                rhs: Arc::new(right.to_turn_math(format!("{}-right", master_id))),
+            },
+            GroupExpression::Inverse { element, .. } => MathNodeContent::UnaryPrefixOperation {
+                parameter: Arc::new(element.to_turn_math(format!("{}-element", master_id))),
+                operator: Arc::new(MathNode {
+                    id: format!("{}-neg", master_id),
+                    content: Arc::new(MathNodeContent::Identifier(Identifier::new_simple(
+                        "-".to_string(),
+                    ))),
+                }),
+            },
+            GroupExpression::Identity(_) => MathNodeContent::Quantity {
+                number: "0".to_string(),
+                scientific_notation: None,
+                unit: None,
+            },
+            GroupExpression::Power { base, exponent, .. } => {
+                let multiplier = match &exponent.data {
+                    Parametrizable::Concrete(val) => MathNodeContent::Quantity {
+                        number: val.to_string(),
+                        scientific_notation: None,
+                        unit: None,
+                    },
+                    Parametrizable::Variable(name) => MathNodeContent::Identifier(name.clone()),
+                };
+                MathNodeContent::Multiplications {
+                    terms: vec![
+                        (
+                            RefinedMulOrDivOperation::None,
+                            MathNode {
+                                id: format!("{}-exp", master_id),
+                                content: Arc::new(multiplier),
+                            },
+                        ),
+                        (
+                            RefinedMulOrDivOperation::Multiplication(MulSymbol::Dot),
+                            base.to_turn_math(format!("{}-base", master_id)),
+                        ),
+                    ],
+                }
+            }
+            _ => return None,
+        };
+        Some(MathNode {
+            id: master_id.to_string(),
+            content: Arc::new(content),
+        })
+    }
+}
+
 impl ToTurnMath for GroupExpression {
     fn to_turn_math(&self, master_id: String) -> MathNode {
+        if let Some(node) = self.to_additive_turn_math(&master_id) {
+            return node;
+        }
         match self {
             GroupExpression::Operation { left, right, .. } => MathNode {
-                id: master_id,
+                id: master_id.clone(),
                 content: Arc::new(MathNodeContent::Multiplications {
                     terms: vec![
                         (
                             RefinedMulOrDivOperation::None,
-                            left.data.to_turn_math(left.id.clone()),
+                            left.to_turn_math(format!("{}-left", master_id)),
                         ),
                         (
                             RefinedMulOrDivOperation::Multiplication(MulSymbol::Dot),
-                            right.data.to_turn_math(right.id.clone()),
+                            right.to_turn_math(format!("{}-right", master_id)),
                         ),
                     ],
                 }),
@@ -620,7 +901,7 @@ impl ToTurnMath for GroupExpression {
                                     id: id.clone(),
                                     content: Arc::new(MathNodeContent::RichTextContent(vec![
                                         MathTextSegment::Text("Element of ".to_string()),
-                                        MathTextSegment::Math(group.value().to_turn_math(format!("{}-group", id))),
+                                        MathTextSegment::Math(group.to_turn_math(format!("{}-group", id))),
                                     ])),
                         }
                     }
@@ -639,7 +920,7 @@ impl ToTurnMath for GroupExpression {
                                 id: id.clone(),
                                 content: Arc::new(MathNodeContent::RichTextContent(vec![
                                     MathTextSegment::Text("Identity of ".to_string()),
-                                    MathTextSegment::Math(group.value().to_turn_math(format!("{}-group", id))),
+                                    MathTextSegment::Math(group.to_turn_math(format!("{}-group", id))),
                                 ])),
                             }
                         }
@@ -650,7 +931,7 @@ impl ToTurnMath for GroupExpression {
                         MathNode {
                             id: id.clone(),
                 content: Arc::new(MathNodeContent::Power {
-                                base: Arc::new(element.value().to_turn_math(format!("{}-base", id.clone()))),
+                                base: Arc::new(element.to_turn_math(format!("{}-element", id))),
                     exponent: Arc::new(MathNode {
                                     id: format!("{}-exp", id.clone()),
                         content: Arc::new(MathNodeContent::Quantity {
@@ -668,7 +949,7 @@ impl ToTurnMath for GroupExpression {
                     id: id.clone(),
                     content: Arc::new(MathNodeContent::RichTextContent(vec![
                         MathTextSegment::Text("Commutator in ".to_string()),
-                        MathTextSegment::Math(group.value().to_turn_math(format!("{}-group", id))),
+                        MathTextSegment::Math(group.to_turn_math(format!("{}-group", id))),
                     ])),
                 }
             },
@@ -678,7 +959,7 @@ impl ToTurnMath for GroupExpression {
                     id: id.clone(),
                     content: Arc::new(MathNodeContent::RichTextContent(vec![
                         MathTextSegment::Text(if *is_left { "Left coset in " } else { "Right coset in " }.to_string()),
-                        MathTextSegment::Math(group.value().to_turn_math(format!("{}-group", id))),
+                        MathTextSegment::Math(group.to_turn_math(format!("{}-group", id))),
                     ])),
                 }
             },
@@ -693,21 +974,22 @@ impl ToTurnMath for GroupExpression {
             },
             GroupExpression::Power { group, base, exponent } => {
                 let id = master_id.clone();
-                let exp_value = match &exponent.data {
-                    Parametrizable::Concrete(val) => val.to_string(),
-                    Parametrizable::Variable(_) => "n".to_string(), // Use generic variable name
+                let exp_content = match &exponent.data {
+                    Parametrizable::Concrete(val) => MathNodeContent::Quantity {
+                        number: val.to_string(),
+                        scientific_notation: None,
+                        unit: None,
+                    },
+                    // Show the exponent variable under its own name
+                    Parametrizable::Variable(name) => MathNodeContent::Identifier(name.clone()),
                 };
                 MathNode {
                     id: id.clone(),
                     content: Arc::new(MathNodeContent::Power {
-                        base: Arc::new(base.value().to_turn_math(format!("{}-base", id.clone()))),
+                        base: Arc::new(base.to_turn_math(format!("{}-base", id))),
                         exponent: Arc::new(MathNode {
                             id: format!("{}-exp", id),
-                            content: Arc::new(MathNodeContent::Quantity {
-                                number: exp_value,
-                                scientific_notation: None,
-                                unit: None,
-                            }),
+                            content: Arc::new(exp_content),
                         }),
                     }),
                 }
@@ -718,7 +1000,7 @@ impl ToTurnMath for GroupExpression {
                     id: id.clone(),
                     content: Arc::new(MathNodeContent::RichTextContent(vec![
                         MathTextSegment::Text("Order of ".to_string()),
-                        MathTextSegment::Math(group.value().to_turn_math(format!("{}-group", id))),
+                        MathTextSegment::Math(group.to_turn_math(format!("{}-group", id))),
                     ])),
                 }
             },
@@ -728,7 +1010,7 @@ impl ToTurnMath for GroupExpression {
                     id: id.clone(),
                     content: Arc::new(MathNodeContent::RichTextContent(vec![
                         MathTextSegment::Text("Order of element in ".to_string()),
-                        MathTextSegment::Math(group.value().to_turn_math(format!("{}-group", id))),
+                        MathTextSegment::Math(group.to_turn_math(format!("{}-group", id))),
                     ])),
                 }
             },
@@ -740,6 +1022,15 @@ impl ToTurnMath for GroupExpression {
                         MathTextSegment::Text("Homomorphism".to_string()),
                     ])),
                 }
+            },
+            GroupExpression::HomomorphismApplication { homomorphism, element } => MathNode {
+                id: master_id.clone(),
+                content: Arc::new(MathNodeContent::FunctionCall {
+                    name: Arc::new(
+                        homomorphism.to_turn_math(format!("{}-homomorphism", master_id)),
+                    ),
+                    parameters: vec![element.to_turn_math(format!("{}-element", master_id))],
+                }),
             },
                     }
     }
@@ -864,11 +1155,365 @@ impl ToRichText for GroupExpression {
             },
             GroupExpression::Homomorphism(located) => {
                 located.to_rich_text()
+            },
+            GroupExpression::HomomorphismApplication { .. } => RichText {
+                segments: vec![RichTextSegment::Math(
+                    self.to_turn_math("homomorphism-application".to_string()),
+                )],
+                alignment: None,
             },
                     }
     }
 }
 
+impl Group {
+    /// Renders the Cayley table as a grid: a header row listing the elements,
+    /// then one row per element `g` holding `g` followed by the products `g · h`.
+    /// Returns `None` when `cayley_table` has no table for this group.
+    pub fn cayley_table_section(&self, id_prefix: &str) -> Option<Section> {
+        let table = self.cayley_table()?;
+        let row_node = |row_id: String, label: MathNode, cells: &[GroupElement]| {
+            let mut terms = vec![(RefinedMulOrDivOperation::None, label)];
+            terms.extend(cells.iter().enumerate().map(|(j, cell)| {
+                (
+                    RefinedMulOrDivOperation::None,
+                    cell.to_turn_math(format!("{}-cell-{}", row_id, j)),
+                )
+            }));
+            MathNode {
+                id: row_id,
+                content: Arc::new(MathNodeContent::Multiplications { terms }),
+            }
+        };
+        let row_section = |row_id: String, node: MathNode| Section {
+            id: row_id,
+            title: None,
+            content: SectionContentNode::Math(node),
+            metadata: vec![],
+            display_options: None,
+        };
+
+        // The identity row lists the elements in table order.
+        let elements = &table[0];
+        let header_id = format!("{}-cayley-header", id_prefix);
+        let corner = MathNode {
+            id: format!("{}-corner", header_id),
+            content: Arc::new(MathNodeContent::Identifier(Identifier::new_simple(
+                "·".to_string(),
+            ))),
+        };
+        let mut rows = vec![row_section(
+            header_id.clone(),
+            row_node(header_id, corner, elements),
+        )];
+        for (i, (element, products)) in elements.iter().zip(&table).enumerate() {
+            let row_id = format!("{}-cayley-row-{}", id_prefix, i);
+            let label = element.to_turn_math(format!("{}-label", row_id));
+            rows.push(row_section(row_id.clone(), row_node(row_id, label, products)));
+        }
+
+        Some(Section {
+            id: format!("{}-cayley-table", id_prefix),
+            title: Some(RichText {
+                segments: vec![RichTextSegment::Text(format!(
+                    "Cayley Table ({} elements)",
+                    elements.len()
+                ))],
+                alignment: None,
+            }),
+            content: SectionContentNode::SubSection(rows),
+            metadata: vec![],
+            display_options: None,
+        })
+    }
+
+    /// The order of a finite group, from the concrete fields where the variant
+    /// has them and from a recorded `Finite(n)` property otherwise. `None` for
+    /// infinite groups, placeholder families such as `S_n`, and groups whose
+    /// order is unknown.
+    pub fn finite_order(&self) -> Option<u64> {
+        let factorial = |n: u64| (2..=n).try_fold(1u64, |acc, k| acc.checked_mul(k));
+        match self {
+            // S_n, D_n, ℤ/nℤ, ... recorded with a size of 0
+            _ if self.is_placeholder_family() => None,
+            Group::Trivial(_) => Some(1),
+            Group::Cyclic(cyclic) => cyclic.order.map(|n| n as u64),
+            Group::Dihedral(dihedral) => Some(dihedral.order as u64),
+            Group::Symmetric(symmetric) => factorial(symmetric.degree as u64),
+            // |A_n| = n!/2 once there is an odd permutation to pair off
+            Group::Alternating(alternating) => factorial(alternating.degree as u64)
+                .map(|n| if alternating.degree < 2 { n } else { n / 2 }),
+            Group::ModularAdditive(modular) => Some(modular.modulus as u64),
+            Group::ModularMultiplicative(modular) => {
+                let n = modular.modulus;
+                // |(ℤ/nℤ)×| = φ(n)
+                let units = (1..=n).filter(|k| GroupCheckerHelpers::gcd(*k, n) == 1);
+                Some(units.count() as u64)
+            }
+            _ => None,
+        }
+        .or_else(|| {
+            self.get_core().props.iter().find_map(|prop| match prop {
+                GroupProperty::Finite(FinitePropertyVariant::Finite(n)) => Some(*n as u64),
+                _ => None,
+            })
+        })
+    }
+
+    /// Whether the group stands for a whole family such as `S_n`, recorded with a
+    /// size of 0 (or a cyclic group with no order), rather than one concrete group.
+    fn is_placeholder_family(&self) -> bool {
+        match self {
+            Group::Symmetric(symmetric) => symmetric.degree == 0,
+            Group::Alternating(alternating) => alternating.degree == 0,
+            Group::Dihedral(dihedral) => dihedral.order == 0,
+            Group::ModularAdditive(modular) => modular.modulus == 0,
+            Group::ModularMultiplicative(modular) => modular.modulus == 0,
+            Group::Cyclic(cyclic) => cyclic.order.is_none(),
+            _ => false,
+        }
+    }
+
+    /// The group's defining data as labeled entries: Order, Abelian, Simple and
+    /// Generators. Values come from the concrete fields where the variant has
+    /// them and from the core properties otherwise; unknown values are "—",
+    /// including the order and commutativity of placeholder families like `S_n`.
+    pub fn property_list(&self) -> Vec<(String, RichText)> {
+        let text = |value: String| RichText {
+            segments: vec![RichTextSegment::Text(value)],
+            alignment: None,
+        };
+        let unknown = || "—".to_string();
+        let props = &self.get_core().props;
+        let placeholder = self.is_placeholder_family();
+
+        let order = match self {
+            _ if placeholder => None,
+            Group::Free(free) if free.rank > 0 => Some("∞".to_string()),
+            _ => self.finite_order().map(|n| n.to_string()),
+        }
+        .or_else(|| {
+            props
+                .iter()
+                .any(|prop| matches!(prop, GroupProperty::Finite(FinitePropertyVariant::Infinite)))
+                .then(|| "∞".to_string())
+        })
+        .unwrap_or_else(unknown);
+
+        let abelian = match self.is_abelian() {
+            _ if placeholder => unknown(),
+            Some(true) => "Yes".to_string(),
+            Some(false) => "No".to_string(),
+            None => unknown(),
+        };
+
+        let simple = props
+            .iter()
+            .find_map(|prop| match prop {
+                GroupProperty::Simple(SimplePropertyVariant::Simple) => Some("Yes"),
+                GroupProperty::Simple(SimplePropertyVariant::NonSimple) => Some("No"),
+                GroupProperty::Simple(SimplePropertyVariant::QuasiSimple) => Some("Quasi-simple"),
+                _ => None,
+            })
+            .map_or_else(unknown, str::to_string);
+
+        let element_list = |elements: &[GroupElement]| {
+            let mut segments = Vec::new();
+            for (i, element) in elements.iter().enumerate() {
+                if i > 0 {
+                    segments.push(RichTextSegment::Text(", ".to_string()));
+                }
+                segments.push(RichTextSegment::Math(
+                    element.to_turn_math(format!("property-generator-{}", i)),
+                ));
+            }
+            RichText {
+                segments,
+                alignment: None,
+            }
+        };
+        let generators = match self {
+            Group::Cyclic(cyclic) => element_list(std::slice::from_ref(&cyclic.generator)),
+            Group::GeneratedSubgroup(generated) if !generated.generators.is_empty() => {
+                element_list(&generated.generators)
+            }
+            Group::Free(free) => text(format!("{} free generators", free.rank)),
+            _ => text(unknown()),
+        };
+
+        let mut properties = vec![
+            ("Order".to_string(), text(order)),
+            ("Abelian".to_string(), text(abelian)),
+            ("Simple".to_string(), text(simple)),
+            ("Generators".to_string(), generators),
+        ];
+        if let Group::Free(free) = self {
+            properties.push(("Rank".to_string(), text(free.rank.to_string())));
+        }
+        properties
+    }
+
+    /// Every group ends with the same property block, after its own prose.
+    fn append_property_block(&self, section: &mut Section, id_prefix: &str) {
+        let properties = self.property_list_section(id_prefix);
+        let content =
+            std::mem::replace(&mut section.content, SectionContentNode::SubSection(vec![]));
+        section.content = match content {
+            SectionContentNode::SubSection(mut children) => {
+                children.push(properties);
+                SectionContentNode::SubSection(children)
+            }
+            content => SectionContentNode::SubSection(vec![
+                Section {
+                    id: format!("{}-overview", id_prefix),
+                    title: None,
+                    content,
+                    metadata: vec![],
+                    display_options: None,
+                },
+                properties,
+            ]),
+        };
+    }
+
+    /// Renders `property_list` as a definition list, one "Label: value" row per entry.
+    pub fn property_list_section(&self, id_prefix: &str) -> Section {
+        let rows = self
+            .property_list()
+            .into_iter()
+            .enumerate()
+            .map(|(i, (label, value))| {
+                let mut segments = vec![
+                    RichTextSegment::StyledText {
+                        text: label,
+                        styles: vec![TextStyle::Bold],
+                    },
+                    RichTextSegment::Text(": ".to_string()),
+                ];
+                segments.extend(value.segments);
+                Section {
+                    id: format!("{}-property-{}", id_prefix, i),
+                    title: None,
+                    content: SectionContentNode::RichText(RichText {
+                        segments,
+                        alignment: None,
+                    }),
+                    metadata: vec![],
+                    display_options: None,
+                }
+            })
+            .collect();
+
+        Section {
+            id: format!("{}-properties", id_prefix),
+            title: Some(RichText {
+                segments: vec![RichTextSegment::Text("Properties".to_string())],
+                alignment: None,
+            }),
+            content: SectionContentNode::SubSection(rows),
+            metadata: vec![],
+            display_options: None,
+        }
+    }
+}
+
+impl ToMathDocument for GroupExpression {
+    fn to_math_document(&self, id_prefix: &str) -> MathDocument {
+        let description = self.to_rich_text();
+        let description_text = description
+            .segments
+            .iter()
+            .filter_map(|segment| match segment {
+                RichTextSegment::Text(t) => Some(t.clone()),
+                RichTextSegment::StyledText { text, .. } => Some(text.clone()),
+                _ => None,
+            })
+            .collect::<String>();
+        let title = format!("Group Expression: {}", description_text.trim());
+
+        let main_section = Section {
+            id: format!("{}-expression-section", id_prefix),
+            title: Some(RichText {
+                segments: vec![RichTextSegment::Text(title.clone())],
+                alignment: None,
+            }),
+            content: SectionContentNode::SubSection(vec![
+                Section {
+                    id: format!("{}-definition-text", id_prefix),
+                    title: None,
+                    content: SectionContentNode::RichText(RichText {
+                        segments: vec![RichTextSegment::StyledText {
+                            text: format!("Definition: {}", title),
+                            styles: vec![TextStyle::Bold],
+                        }],
+                        alignment: None,
+                    }),
+                    metadata: vec![],
+                    display_options: None,
+                },
+                Section {
+                    id: format!("{}-formal-term", id_prefix),
+                    title: None,
+                    content: SectionContentNode::Math(
+                        self.to_turn_math(format!("{}-formalTerm", id_prefix)),
+                    ),
+                    metadata: vec![],
+                    display_options: None,
+                },
+                Section {
+                    id: format!("{}-description", id_prefix),
+                    title: None,
+                    content: SectionContentNode::RichText(description),
+                    metadata: vec![],
+                    display_options: None,
+                },
+            ]),
+            metadata: vec![("type".to_string(), "GroupExpressionDefinition".to_string())],
+            display_options: None,
+        };
+
+        MathDocument {
+            id: format!("{}-doc", id_prefix),
+            content_type: MathDocumentType::ScientificPaper(ScientificPaperContent {
+                title,
+                paper_type: PaperType::Research,
+                venue: None,
+                peer_reviewed: false,
+                content_metadata: ContentMetadata {
+                    language: Some("en-US".to_string()),
+                    version: Some("1.0".to_string()),
+                    created_at: None,
+                    last_modified: None,
+                    content_hash: None,
+                },
+                academic_metadata: AcademicMetadata {
+                    authors: vec![],
+                    date_published: None,
+                    date_modified: None,
+                    venue: None,
+                    doi: None,
+                    keywords: vec![],
+                },
+                structure: DocumentStructure {
+                    abstract_content: None,
+                    table_of_contents: None,
+                    body: vec![main_section],
+                    footnotes: vec![],
+                    glossary: vec![],
+                    bibliography: vec![],
+                },
+                relationships: DocumentRelationships {
+                    parent_documents: vec![],
+                    child_documents: vec![],
+                    related_concepts: vec![],
+                    cross_references: vec![],
+                    dependency_graph: None,
+                },
+            }),
+        }
+    }
+}
+
 impl ToRichText for GroupHomomorphism {
     fn to_rich_text(&self) -> RichText {
         RichText {
@@ -885,14 +1530,58 @@ impl ToRichText for GroupHomomorphism {
 
 impl ToTurnMath for GroupHomomorphism {
     fn to_turn_math(&self, master_id: String) -> MathNode {
+        match &self.construction {
+            HomomorphismConstruction::Abstract => MathNode {
+                id: master_id,
+                content: Arc::new(MathNodeContent::RichTextContent(vec![
+                    MathTextSegment::Text("Group homomorphism from ".to_string()),
+                    MathTextSegment::Math(self.domain.to_turn_math("domain".to_string())),
+                    MathTextSegment::Text(" to ".to_string()),
+                    MathTextSegment::Math(self.codomain.to_turn_math("codomain".to_string())),
+                ])),
+            },
+            // Built maps are written symbolically: id, or φ ∘ ψ ∘ ...
+            _ => {
+                let mut abstract_count = 0;
+                self.map_node(master_id, &mut abstract_count)
+            }
+        }
+    }
+}
+
+impl GroupHomomorphism {
+    /// The map as a symbol, or as `outer ∘ inner` for a composite.
+    /// Abstract maps are named φ, ψ, χ, ... in order of appearance.
+    fn map_node(&self, master_id: String, abstract_count: &mut usize) -> MathNode {
+        const MAP_NAMES: [&str; 5] = ["φ", "ψ", "χ", "θ", "η"];
+        let symbol = match &self.construction {
+            HomomorphismConstruction::Abstract => {
+                let name = match MAP_NAMES.get(*abstract_count) {
+                    Some(name) => name.to_string(),
+                    None => format!("φ{}", abstract_count),
+                };
+                *abstract_count += 1;
+                name
+            }
+            HomomorphismConstruction::Identity => "id".to_string(),
+            HomomorphismConstruction::Composite { outer, inner } => {
+                return MathNode {
+                    id: master_id.clone(),
+                    content: Arc::new(MathNodeContent::Relationship {
+                        lhs: Arc::new(
+                            outer.map_node(format!("{}-outer", master_id), abstract_count),
+                        ),
+                        rhs: Arc::new(
+                            inner.map_node(format!("{}-inner", master_id), abstract_count),
+                        ),
+                        operator: RelationOperatorNode::Custom("∘".to_string()),
+                    }),
+                };
+            }
+        };
         MathNode {
             id: master_id,
-            content: Arc::new(MathNodeContent::RichTextContent(vec![
-                MathTextSegment::Text("Group homomorphism from ".to_string()),
-                MathTextSegment::Math(self.domain.to_turn_math("domain".to_string())),
-                MathTextSegment::Text(" to ".to_string()),
-                MathTextSegment::Math(self.codomain.to_turn_math("codomain".to_string())),
-            ])),
+            content: Arc::new(MathNodeContent::Identifier(Identifier::new_simple(symbol))),
         }
     }
 }
@@ -935,7 +1624,8 @@ impl ToTurnMath for GroupRelation {
                         }
                     },
             GroupRelation::IsIsomorphicTo { first, second } => {
-                // Use proper Relationship for G ≅ H
+                // Use proper Relationship for G ≅ H; variable operands render as
+                // their identifier through `Parametrizable::to_turn_math`
                 let id = master_id.clone();
                 MathNode {
                     id: id.clone(),
@@ -959,14 +1649,16 @@ impl ToTurnMath for GroupRelation {
                     operator: RelationOperatorNode::Equal,
                     rhs: Arc::new(MathNode {
                                     id: format!("{}-value", id.clone()),
-                                    content: Arc::new(MathNodeContent::Identifier(Identifier {
-                                        body: "n".to_string(),
-                                        pre_script: None,
-                                        mid_script: None,
-                                        post_script: None,
-                                        primes: 0,
-                                        is_function: false,
-                                    })),
+                                    content: Arc::new(match &order.data {
+                                        Parametrizable::Concrete(value) => MathNodeContent::Quantity {
+                                            number: value.to_string(),
+                                            scientific_notation: None,
+                                            unit: None,
+                                        },
+                                        Parametrizable::Variable(name) => {
+                                            MathNodeContent::Identifier(name.clone())
+                                        }
+                                    }),
                     }),
                 }),
                         }
@@ -977,7 +1669,7 @@ impl ToTurnMath for GroupRelation {
                     id: id.clone(),
                     content: Arc::new(MathNodeContent::RichTextContent(vec![
                         MathTextSegment::Text("Normal subgroup of ".to_string()),
-                        MathTextSegment::Math(group.value().to_turn_math(format!("{}-group", id))),
+                        MathTextSegment::Math(group.to_turn_math(format!("{}-group", id))),
                     ])),
                 }
             },
@@ -987,28 +1679,42 @@ impl ToTurnMath for GroupRelation {
                     id: id.clone(),
                     content: Arc::new(MathNodeContent::RichTextContent(vec![
                         MathTextSegment::Text("Quotient of ".to_string()),
-                        MathTextSegment::Math(group.value().to_turn_math(format!("{}-group", id))),
+                        MathTextSegment::Math(group.to_turn_math(format!("{}-group", id))),
                     ])),
                 }
             },
+            GroupRelation::ElementOf { element, group } => {
+                let id = master_id.clone();
+                MathNode {
+                    id: id.clone(),
+                    content: Arc::new(MathNodeContent::Relationship {
+                        lhs: Arc::new(element.to_turn_math(format!("{}-element", id))),
+                        operator: RelationOperatorNode::ElementOf,
+                        rhs: Arc::new(group.to_turn_math(format!("{}-group", id))),
+                    }),
+                }
+            },
             GroupRelation::IsInCenterOf { element, group } => {
                 let id = master_id.clone();
                 MathNode {
                     id: id.clone(),
                     content: Arc::new(MathNodeContent::RichTextContent(vec![
                         MathTextSegment::Text("Element in center of ".to_string()),
-                        MathTextSegment::Math(group.value().to_turn_math(format!("{}-group", id))),
+                        MathTextSegment::Math(group.to_turn_math(format!("{}-group", id))),
                     ])),
                 }
             },
-            GroupRelation::AreConjugateIn { element1, element2, group } => {
+            GroupRelation::AreConjugateIn { element1, element2, .. } => {
+                // Use proper Relationship for g ~ h; the ambient group is context
+                // carried by the relation rather than part of the notation
                 let id = master_id.clone();
                 MathNode {
                     id: id.clone(),
-                    content: Arc::new(MathNodeContent::RichTextContent(vec![
-                        MathTextSegment::Text("Conjugate elements in ".to_string()),
-                        MathTextSegment::Math(group.value().to_turn_math(format!("{}-group", id))),
-                    ])),
+                    content: Arc::new(MathNodeContent::Relationship {
+                        lhs: Arc::new(element1.to_turn_math(format!("{}-element1", id.clone()))),
+                        operator: RelationOperatorNode::Custom("~".to_string()),
+                        rhs: Arc::new(element2.to_turn_math(format!("{}-element2", id.clone()))),
+                    }),
                 }
             },
             GroupRelation::HasOrderInGroup { element, group, order } => {
@@ -1017,7 +1723,7 @@ impl ToTurnMath for GroupRelation {
                     id: id.clone(),
                     content: Arc::new(MathNodeContent::RichTextContent(vec![
                         MathTextSegment::Text("Element order in ".to_string()),
-                        MathTextSegment::Math(group.value().to_turn_math(format!("{}-group", id))),
+                        MathTextSegment::Math(group.to_turn_math(format!("{}-group", id))),
                     ])),
                 }
             },
@@ -1027,18 +1733,29 @@ impl ToTurnMath for GroupRelation {
                     id: id.clone(),
                     content: Arc::new(MathNodeContent::RichTextContent(vec![
                         MathTextSegment::Text("Subgroup index in ".to_string()),
-                        MathTextSegment::Math(group.value().to_turn_math(format!("{}-group", id))),
+                        MathTextSegment::Math(group.to_turn_math(format!("{}-group", id))),
                     ])),
                 }
             },
             GroupRelation::IsCyclicWithGenerator { group, generator } => {
+                // G = ⟨g⟩
                 let id = master_id.clone();
                 MathNode {
                     id: id.clone(),
-                    content: Arc::new(MathNodeContent::RichTextContent(vec![
-                        MathTextSegment::Text("Cyclic group with generator ".to_string()),
-                        MathTextSegment::Math(generator.value().to_turn_math(format!("{}-gen", id))),
-                    ])),
+                    content: Arc::new(MathNodeContent::Relationship {
+                        lhs: Arc::new(group.to_turn_math(format!("{}-group", id))),
+                        operator: RelationOperatorNode::Equal,
+                        rhs: Arc::new(MathNode {
+                            id: format!("{}-generated", id),
+                            content: Arc::new(MathNodeContent::Bracketed {
+                                inner: Arc::new(
+                                    generator.to_turn_math(format!("{}-generator", id)),
+                                ),
+                                style: BracketStyle::Angle,
+                                size: BracketSize::Auto,
+                            }),
+                        }),
+                    }),
                 }
             },
             GroupRelation::NormalizesSubgroup { element, subgroup, group } => {
@@ -1047,7 +1764,7 @@ impl ToTurnMath for GroupRelation {
                     id: id.clone(),
                     content: Arc::new(MathNodeContent::RichTextContent(vec![
                         MathTextSegment::Text("Element normalizes subgroup in ".to_string()),
-                        MathTextSegment::Math(group.value().to_turn_math(format!("{}-group", id))),
+                        MathTextSegment::Math(group.to_turn_math(format!("{}-group", id))),
                     ])),
                 }
             },
@@ -1057,7 +1774,7 @@ impl ToTurnMath for GroupRelation {
                     id: id.clone(),
                     content: Arc::new(MathNodeContent::RichTextContent(vec![
                         MathTextSegment::Text("Element centralizes subgroup in ".to_string()),
-                        MathTextSegment::Math(group.value().to_turn_math(format!("{}-group", id))),
+                        MathTextSegment::Math(group.to_turn_math(format!("{}-group", id))),
                     ])),
                 }
             },
@@ -1067,20 +1784,44 @@ impl ToTurnMath for GroupRelation {
                     id: id.clone(),
                     content: Arc::new(MathNodeContent::RichTextContent(vec![
                         MathTextSegment::Text("Characteristic subgroup of ".to_string()),
-                        MathTextSegment::Math(group.value().to_turn_math(format!("{}-group", id))),
+                        MathTextSegment::Math(group.to_turn_math(format!("{}-group", id))),
                     ])),
                 }
             },
             GroupRelation::OrderDivides { group1, group2 } => {
+                // |G₁| ∣ |G₂|
                 let id = master_id.clone();
+                let bar = |role: &str| {
+                    (
+                        RefinedMulOrDivOperation::None,
+                        MathNode {
+                            id: format!("{}-{}", id, role),
+                            content: Arc::new(MathNodeContent::Identifier(Identifier::new_simple(
+                                "|".to_string(),
+                            ))),
+                        },
+                    )
+                };
+                let order = |group: &Located<Group>, role: &str| MathNode {
+                    id: format!("{}-{}", id, role),
+                    content: Arc::new(MathNodeContent::Multiplications {
+                        terms: vec![
+                            bar(&format!("{}-open", role)),
+                            (
+                                RefinedMulOrDivOperation::None,
+                                group.to_turn_math(format!("{}-{}-group", id, role)),
+                            ),
+                            bar(&format!("{}-close", role)),
+                        ],
+                    }),
+                };
                 MathNode {
                     id: id.clone(),
-                    content: Arc::new(MathNodeContent::RichTextContent(vec![
-                        MathTextSegment::Text("Order of ".to_string()),
-                        MathTextSegment::Math(group1.value().to_turn_math(format!("{}-group1", id.clone()))),
-                        MathTextSegment::Text(" divides order of ".to_string()),
-                        MathTextSegment::Math(group2.value().to_turn_math(format!("{}-group2", id.clone()))),
-                    ])),
+                    content: Arc::new(MathNodeContent::Relationship {
+                        lhs: Arc::new(order(group1, "order1")),
+                        operator: RelationOperatorNode::Divides,
+                        rhs: Arc::new(order(group2, "order2")),
+                    }),
                 }
             },
             GroupRelation::HasUniqueInverse { element, group } => {
@@ -1089,7 +1830,7 @@ impl ToTurnMath for GroupRelation {
                     id: id.clone(),
                     content: Arc::new(MathNodeContent::RichTextContent(vec![
                         MathTextSegment::Text("Element has unique inverse in ".to_string()),
-                        MathTextSegment::Math(group.value().to_turn_math(format!("{}-group", id))),
+                        MathTextSegment::Math(group.to_turn_math(format!("{}-group", id))),
                     ])),
                 }
             },
@@ -1099,7 +1840,7 @@ impl ToTurnMath for GroupRelation {
                     id: id.clone(),
                     content: Arc::new(MathNodeContent::RichTextContent(vec![
                         MathTextSegment::Text("Sylow subgroup properties in ".to_string()),
-                        MathTextSegment::Math(group.value().to_turn_math(format!("{}-group", id))),
+                        MathTextSegment::Math(group.to_turn_math(format!("{}-group", id))),
                     ])),
                 }
             },
@@ -1109,7 +1850,7 @@ impl ToTurnMath for GroupRelation {
                     id: id.clone(),
                     content: Arc::new(MathNodeContent::RichTextContent(vec![
                         MathTextSegment::Text("Inverse relationship in ".to_string()),
-                        MathTextSegment::Math(group.value().to_turn_math(format!("{}-group", id))),
+                        MathTextSegment::Math(group.to_turn_math(format!("{}-group", id))),
                     ])),
                 }
             },
@@ -1119,9 +1860,9 @@ impl ToTurnMath for GroupRelation {
                     id: id.clone(),
                     content: Arc::new(MathNodeContent::RichTextContent(vec![
                         MathTextSegment::Text("Homomorphism from ".to_string()),
-                        MathTextSegment::Math(domain.value().to_turn_math(format!("{}-domain", id.clone()))),
+                        MathTextSegment::Math(domain.to_turn_math(format!("{}-domain", id))),
                         MathTextSegment::Text(" to ".to_string()),
-                        MathTextSegment::Math(codomain.value().to_turn_math(format!("{}-codomain", id))),
+                        MathTextSegment::Math(codomain.to_turn_math(format!("{}-codomain", id))),
                     ])),
                 }
             },
@@ -1131,9 +1872,9 @@ impl ToTurnMath for GroupRelation {
                     id: id.clone(),
                     content: Arc::new(MathNodeContent::RichTextContent(vec![
                         MathTextSegment::Text("Isomorphic embedding from ".to_string()),
-                        MathTextSegment::Math(source.value().to_turn_math(format!("{}-source", id.clone()))),
+                        MathTextSegment::Math(source.to_turn_math(format!("{}-source", id))),
                         MathTextSegment::Text(" to ".to_string()),
-                        MathTextSegment::Math(target.value().to_turn_math(format!("{}-target", id))),
+                        MathTextSegment::Math(target.to_turn_math(format!("{}-target", id))),
                     ])),
                 }
             },
@@ -1160,7 +1901,7 @@ impl ToTurnMath for GroupRelation {
                         MathTextSegment::Text("Has ".to_string()),
                         MathTextSegment::Text(property_text.to_string()),
                         MathTextSegment::Text(" property in ".to_string()),
-                        MathTextSegment::Math(target.value().to_turn_math(format!("{}-target", id))),
+                        MathTextSegment::Math(target.to_turn_math(format!("{}-target", id))),
                     ])),
                 }
             },
@@ -1184,7 +1925,7 @@ impl ToTurnMath for GroupRelation {
                         MathTextSegment::Text("Has ".to_string()),
                         MathTextSegment::Text(property_text.to_string()),
                         MathTextSegment::Text(" topological property in ".to_string()),
-                        MathTextSegment::Math(target.value().to_turn_math(format!("{}-target", id))),
+                        MathTextSegment::Math(target.to_turn_math(format!("{}-target", id))),
                     ])),
                 }
             },
@@ -1331,6 +2072,43 @@ impl ToTurnMath for GroupRelation {
 /// Group Theory Exporter Implementation
 pub struct GroupTheoryExporter;
 
+impl GroupTheoryExporter {
+    /// The theorems exported for group theory, by registry id.
+    fn exported_theorems() -> Vec<(String, Theorem)> {
+        vec![
+            (
+                "group_inverse_uniqueness".to_string(),
+                group_inverse_uniqueness(),
+            ),
+            (
+                "subgroup_intersection_is_subgroup".to_string(),
+                subgroup_intersection_is_subgroup(),
+            ),
+            (
+                "first_isomorphism_theorem".to_string(),
+                first_isomorphism_theorem(),
+            ),
+            (
+                "element_order_divides_group_order".to_string(),
+                element_order_divides_group_order(),
+            ),
+            ("lagrange_theorem".to_string(), lagrange_theorem()),
+            ("cayley_theorem".to_string(), cayley_theorem()),
+            ("sylow_first_theorem".to_string(), sylow_first_theorem()),
+            (
+                "fundamental_theorem_finite_abelian".to_string(),
+                fundamental_theorem_finite_abelian(),
+            ),
+            ("normal_subgroup_test".to_string(), normal_subgroup_test()),
+            ("cauchy_theorem".to_string(), cauchy_theorem()),
+            (
+                "center_is_normal_subgroup".to_string(),
+                center_is_normal_subgroup(),
+            ),
+        ]
+    }
+}
+
 impl TheoryExporter<Group, GroupExpression, GroupRelation> for GroupTheoryExporter {
     fn theory_id(&self) -> &str {
         "group_theory"
@@ -1730,57 +2508,7 @@ impl TheoryExporter<Group, GroupExpression, GroupRelation> for GroupTheoryExport
     }
 
     fn export_theorems(&self) -> Vec<MathDocument> {
-        // Initializing the registry is now handled automatically by the OnceLock
-        // in get_theorem_registry(). We can call it here to be explicit.
-
-        let theorems: Vec<(String, Theorem)> = vec![
-            (
-                "group_inverse_uniqueness".to_string(),
-                group_inverse_uniqueness(),
-            ),
-            (
-                "subgroup_intersection_is_subgroup".to_string(),
-                subgroup_intersection_is_subgroup(),
-            ),
-            (
-                "first_isomorphism_theorem".to_string(),
-                first_isomorphism_theorem(),
-            ),
-            (
-                "element_order_divides_group_order".to_string(),
-                element_order_divides_group_order(),
-            ),
-            (
-                "lagrange_theorem".to_string(),
-                lagrange_theorem(),
-            ),
-            (
-                "cayley_theorem".to_string(),
-                cayley_theorem(),
-            ),
-            (
-                "sylow_first_theorem".to_string(),
-                sylow_first_theorem(),
-            ),
-            (
-                "fundamental_theorem_finite_abelian".to_string(),
-                fundamental_theorem_finite_abelian(),
-            ),
-            (
-                "normal_subgroup_test".to_string(),
-                normal_subgroup_test(),
-            ),
-            (
-                "cauchy_theorem".to_string(),
-                cauchy_theorem(),
-            ),
-            (
-                "center_is_normal_subgroup".to_string(),
-                center_is_normal_subgroup(),
-            ),
-        ];
-
-        theorems
+        Self::exported_theorems()
             .into_iter()
             .map(|(id, theorem)| theorem.to_math_document(&format!("group_theory.thm.{}", id)))
             .collect()
@@ -1851,6 +2579,7 @@ impl TheoryExporter<Group, GroupExpression, GroupRelation> for GroupTheoryExport
                         parameters: HashMap::new(),
                         description: "Abstract topological space".to_string(),
                         membership_condition: "x ∈ G".to_string(),
+                        membership_predicate: None,
                         properties: VariantSet::new(),
                     },
                     topology: Topology {
@@ -1867,6 +2596,7 @@ impl TheoryExporter<Group, GroupExpression, GroupRelation> for GroupTheoryExport
                         parameters: HashMap::new(),
                         description: "Abstract Lie group manifold".to_string(),
                         membership_condition: "x ∈ G".to_string(),
+                        membership_predicate: None,
                         properties: VariantSet::new(),
                     },
                     topology: Topology {
@@ -1981,6 +2711,7 @@ impl TheoryExporter<Group, GroupExpression, GroupRelation> for GroupTheoryExport
                 defining_homomorphism: Located::new_concrete(GroupHomomorphism {
                     domain: Located::new_variable(Identifier::new_simple("G".to_string())),
                     codomain: Located::new_variable(Identifier::new_simple("H".to_string())),
+                    construction: HomomorphismConstruction::Abstract,
                 }),
             }),
             Group::Image(ImageGroup {
@@ -1988,6 +2719,7 @@ impl TheoryExporter<Group, GroupExpression, GroupRelation> for GroupTheoryExport
                 defining_homomorphism: Located::new_concrete(GroupHomomorphism {
                     domain: Located::new_variable(Identifier::new_simple("G".to_string())),
                     codomain: Located::new_variable(Identifier::new_simple("H".to_string())),
+                    construction: HomomorphismConstruction::Abstract,
                 }),
             }),
             // ===== SUBGROUP CONSTRUCTIONS =====
@@ -2127,4 +2859,24 @@ mod export_tests {
         assert!(theorem_ids.contains(&"group_theory.thm.cauchy_theorem-doc"));
         assert!(theorem_ids.contains(&"group_theory.thm.center_is_normal_subgroup-doc"));
     }
+
+    #[test]
+    fn test_exported_theorems_match_registry() {
+        use crate::subjects::math::formalism::automation::registry::get_theorem;
+
+        let exported = GroupTheoryExporter::exported_theorems();
+        assert_eq!(exported.len(), 11);
+        for (id, theorem) in exported {
+            let registered =
+                get_theorem(&id).unwrap_or_else(|| panic!("{} is not in the registry", id));
+            assert!(
+                registered
+                    .proofs
+                    .initial_goal
+                    .semantically_eq(&theorem.proofs.initial_goal),
+                "Registered statement of {} differs from the exported one",
+                id
+            );
+        }
+    }
 }
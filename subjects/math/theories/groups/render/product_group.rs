@@ -140,11 +140,11 @@ impl ToSectionNode for ProductGroup {
 
         // Add properties from core group if any
         if !self.core.props.inner.is_empty() {
-            for prop in self.core.props.inner.iter() {
+            for prop in self.core.props.iter() {
                 selectable_props.push(SelectableProperty {
-                    name: format!("{:?}", prop.0),
-                    current_variant: format!("{:?}", prop.0),
-                    all_variants: vec![format!("{:?}", prop.0)],
+                    name: format!("{:?}", prop),
+                    current_variant: format!("{:?}", prop),
+                    all_variants: vec![format!("{:?}", prop)],
                     description: Some("Group property".to_string()),
                     variant_descriptions: None,
                     property_type_def_id: None,
@@ -3,7 +3,8 @@ use std::collections::{BTreeMap, HashMap};
 use std::sync::Arc;
 
 use crate::turn_render::math_node::{
-    BracketSize, BracketStyle, MathNode, MathNodeContent, ToTurnMath,
+    BracketSize, BracketStyle, Identifier, MathNode, MathNodeContent, RefinedMulOrDivOperation,
+    RelationOperatorNode, ToTurnMath,
 };
 use crate::turn_render::{
     AbstractionMetadata, CollapsibleBlockNode, LinkTarget, RichText, RichTextSegment,
@@ -16,6 +17,7 @@ use crate::subjects::math::formalism::traits::abstraction_level::{
 use crate::subjects::math::theories::groups::definitions::{
     FreeGroup, GenericGroup, QuotientGroup, TrivialGroup,
 };
+use super::ElementNotation;
 use crate::turn_render::{
     AcademicMetadata, ContentMetadata, DocumentRelationships, DocumentStructure, MathDocument,
     MathDocumentType, PaperType, ScientificPaperContent, ToMathDocument,
@@ -149,6 +151,65 @@ impl ToMathDocument for QuotientGroup {
 }
 
 impl QuotientGroup {
+    /// The quotient written out as its set of cosets, as `{N, 1 + N, 2 + N}` in
+    /// additive groups and `{N, gN, hN}` otherwise. Returns `None` when the
+    /// cosets cannot be computed, see `QuotientGroup::cosets`.
+    pub fn to_expanded_turn_math(&self, master_id: String) -> Option<MathNode> {
+        let symbol = |id: String, text: &str| MathNode {
+            id,
+            content: Arc::new(MathNodeContent::Identifier(Identifier::new_simple(
+                text.to_string(),
+            ))),
+        };
+        let additive = ElementNotation::of(&self.group) == ElementNotation::Additive;
+
+        let mut terms = vec![symbol(format!("{}-open", master_id), "{")];
+        for (index, representative) in self.coset_representatives()?.iter().enumerate() {
+            let coset_id = format!("{}-coset-{}", master_id, index);
+            let normal = symbol(format!("{}-normal", coset_id), "N");
+            let coset = match index {
+                0 => normal,
+                _ => {
+                    let representative =
+                        representative.to_turn_math(format!("{}-representative", coset_id));
+                    let content = if additive {
+                        MathNodeContent::Relationship {
+                            lhs: Arc::new(representative),
+                            rhs: Arc::new(normal),
+                            operator: RelationOperatorNode::Custom("+".to_string()),
+                        }
+                    } else {
+                        MathNodeContent::Multiplications {
+                            terms: vec![
+                                (RefinedMulOrDivOperation::None, representative),
+                                (RefinedMulOrDivOperation::None, normal),
+                            ],
+                        }
+                    };
+                    MathNode {
+                        id: coset_id,
+                        content: Arc::new(content),
+                    }
+                }
+            };
+            if index > 0 {
+                terms.push(symbol(format!("{}-separator-{}", master_id, index), ","));
+            }
+            terms.push(coset);
+        }
+        terms.push(symbol(format!("{}-close", master_id), "}"));
+
+        Some(MathNode {
+            id: master_id,
+            content: Arc::new(MathNodeContent::Multiplications {
+                terms: terms
+                    .into_iter()
+                    .map(|term| (RefinedMulOrDivOperation::None, term))
+                    .collect(),
+            }),
+        })
+    }
+
     fn to_tooltip_node(&self, id_prefix: &str) -> Vec<RichTextSegment> {
         let group_name = "G";
         let normal_name = "N";
@@ -518,7 +579,14 @@ impl ToTurnMath for QuotientGroup {
 }
 
 impl ToTurnMath for FreeGroup {
+    /// `F_n` for rank `n`; the free group of rank 0 is trivial and renders as such.
     fn to_turn_math(&self, master_id: String) -> MathNode {
+        if self.rank == 0 {
+            return TrivialGroup {
+                core: self.core.clone(),
+            }
+            .to_turn_math(master_id);
+        }
         MathNode {
             id: master_id.clone(),
             content: Arc::new(MathNodeContent::Text(format!("F_{}", self.rank))),
@@ -0,0 +1,128 @@
+//! Snapshot tests for the group definition documents.
+//!
+//! Each document from `GroupTheoryExporter::export_definitions()` named in
+//! [`SNAPSHOTS`] is compared against `snapshots/<group_type>.json`. Documents
+//! are compared in their public form (see [`to_public_json`]) so that randomly
+//! generated `Located` ids never make a snapshot fail. A listed snapshot that
+//! is missing, or names no exported document, fails the test.
+//!
+//! To accept rendering changes, run the tests with `UPDATE_SNAPSHOTS=1` and
+//! commit the rewritten files. To snapshot another document, add its name to
+//! [`SNAPSHOTS`] and do the same.
+
+use std::fs;
+use std::path::PathBuf;
+
+use serde_json::Value;
+
+use super::GroupTheoryExporter;
+use crate::subjects::math::export::to_public_json;
+use crate::subjects::math::export::unified_exporter::TheoryExporter;
+
+const UPDATE_ENV_VAR: &str = "UPDATE_SNAPSHOTS";
+const DEFINITION_ID_PREFIX: &str = "group_theory.def.";
+
+/// The documents under snapshot, by snapshot name.
+const SNAPSHOTS: &[&str] = &["cyclic_group"];
+
+fn snapshot_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("subjects/math/theories/groups/render/snapshots")
+}
+
+/// `group_theory.def.cyclic_group.cyclic-doc` is stored as `cyclic_group`.
+fn snapshot_name(document_id: &str) -> &str {
+    match document_id.strip_prefix(DEFINITION_ID_PREFIX) {
+        Some(local) => local.split('.').next().unwrap_or(local),
+        None => document_id,
+    }
+}
+
+/// Compares every listed definition document with its snapshot and returns
+/// one message per mismatch or missing snapshot. Rewrites the snapshots
+/// instead when updating.
+fn check_definition_snapshots() -> Vec<String> {
+    let update = std::env::var_os(UPDATE_ENV_VAR).is_some();
+    let mut mismatches = Vec::new();
+    let documents = GroupTheoryExporter.export_definitions();
+
+    for name in SNAPSHOTS {
+        if !documents
+            .iter()
+            .any(|document| snapshot_name(&document.id) == *name)
+        {
+            mismatches.push(format!("No definition document is named {}", name));
+        }
+    }
+
+    for document in documents {
+        let name = snapshot_name(&document.id).to_string();
+        if !SNAPSHOTS.contains(&name.as_str()) {
+            continue;
+        }
+        let path = snapshot_dir().join(format!("{}.json", name));
+        let actual = to_public_json(&document).unwrap();
+
+        if update {
+            fs::create_dir_all(snapshot_dir()).unwrap();
+            let mut pretty = serde_json::to_string_pretty(&actual).unwrap();
+            pretty.push('\n');
+            fs::write(&path, pretty).unwrap();
+            continue;
+        }
+
+        let Ok(stored) = fs::read_to_string(&path) else {
+            mismatches.push(format!(
+                "{} has no snapshot at {}",
+                document.id,
+                path.display()
+            ));
+            continue;
+        };
+        let expected: Value = serde_json::from_str(&stored).unwrap_or_else(|error| {
+            panic!("Snapshot {} is not valid JSON: {}", path.display(), error)
+        });
+        if actual != expected {
+            mismatches.push(format!(
+                "{} no longer matches {}",
+                document.id,
+                path.display()
+            ));
+        }
+    }
+
+    mismatches
+}
+
+#[test]
+fn test_snapshot_name_from_document_id() {
+    assert_eq!(
+        snapshot_name("group_theory.def.cyclic_group.cyclic-doc"),
+        "cyclic_group"
+    );
+    assert_eq!(
+        snapshot_name("other.def.custom-doc"),
+        "other.def.custom-doc"
+    );
+}
+
+#[test]
+fn test_listed_snapshots_are_committed() {
+    for name in SNAPSHOTS {
+        assert!(
+            snapshot_dir().join(format!("{}.json", name)).is_file(),
+            "Missing snapshot for {}",
+            name
+        );
+    }
+}
+
+#[test]
+fn test_group_definitions_match_snapshots() {
+    let mismatches = check_definition_snapshots();
+    assert!(
+        mismatches.is_empty(),
+        "Rendering changed; rerun with {}=1 to accept:\n{}",
+        UPDATE_ENV_VAR,
+        mismatches.join("\n")
+    );
+}
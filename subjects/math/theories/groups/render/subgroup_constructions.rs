@@ -859,15 +859,24 @@ impl ToMathDocument for CommutatorSubgroup {
     }
 }
 
+impl GeneratedSubgroup {
+    /// Comma-separated generators in canonical order, so the rendering does not
+    /// depend on the order they were supplied in.
+    fn generator_list(&self) -> String {
+        let mut generators = self.generators.clone();
+        generators.sort();
+        generators
+            .iter()
+            .map(|generator| generator.to_string())
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
 impl ToSectionNode for GeneratedSubgroup {
     fn to_section_node(&self, id_prefix: &str) -> Section {
         let formalism_obj_level: AbstractionLevel = self.level();
-        let title = if self.generators.len() == 1 {
-            let gen_name = "g";
-            format!("⟨{}⟩", gen_name)
-        } else {
-            format!("⟨{} gens⟩", self.generators.len())
-        };
+        let title = format!("⟨{}⟩", self.generator_list());
 
         let content_nodes = vec![
             SectionContentNode::RichText(RichText {
@@ -877,6 +886,13 @@ impl ToSectionNode for GeneratedSubgroup {
                 ))],
                 alignment: None,
             }),
+            SectionContentNode::RichText(RichText {
+                segments: vec![RichTextSegment::Text(format!(
+                    "Generators: {}",
+                    self.generator_list()
+                ))],
+                alignment: None,
+            }),
             SectionContentNode::RichText(RichText {
                 segments: vec![RichTextSegment::Text(
                     "The subgroup generated by the given elements.".to_string(),
@@ -941,12 +957,7 @@ impl ToSectionNode for GeneratedSubgroup {
     }
 
     fn render_as_l1_schema(&self, id_prefix: &str) -> Section {
-        let title = if self.generators.len() == 1 {
-            let gen_name = "g";
-            format!("⟨{}⟩", gen_name)
-        } else {
-            format!("⟨{} gens⟩", self.generators.len())
-        };
+        let title = format!("⟨{}⟩", self.generator_list());
 
         Section {
             id: format!("{}-main-generated-section", id_prefix),
@@ -301,10 +301,655 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_group_property_rendering_is_deterministic() {
+        use crate::subjects::math::theories::groups::definitions::{
+            NilpotentPropertyVariant, SimplePropertyVariant, SolvablePropertyVariant,
+        };
+
+        let properties = vec![
+            GroupProperty::Abelian(AbelianPropertyVariant::Abelian),
+            GroupProperty::Finite(FinitePropertyVariant::Finite(12)),
+            GroupProperty::Simple(SimplePropertyVariant::NonSimple),
+            GroupProperty::Solvable(SolvablePropertyVariant::Solvable),
+            GroupProperty::Nilpotent(NilpotentPropertyVariant::Nilpotent(1)),
+        ];
+        let build = |props: Vec<GroupProperty>| {
+            let mut set = VariantSet::new();
+            for prop in props {
+                set.insert(prop);
+            }
+            GenericGroup {
+                base_set: create_named_set("G"),
+                operation: GroupOperation::default(),
+                props: set,
+            }
+        };
+
+        let forward = build(properties.clone());
+        let reversed = build(properties.into_iter().rev().collect());
+
+        let render = |group: &GenericGroup| {
+            serde_json::to_string(&group.to_section_node("deterministic")).unwrap()
+        };
+        let first = render(&forward);
+        assert_eq!(first, render(&forward));
+        // Separately built sets hash with different seeds but must render identically
+        assert_eq!(first, render(&reversed));
+        assert_eq!(
+            serde_json::to_string(&forward.props).unwrap(),
+            serde_json::to_string(&reversed.props).unwrap()
+        );
+
+        // The canonical order is by variant name, which doesn't change between builds
+        let names: Vec<&str> = forward
+            .props
+            .iter()
+            .map(|prop| match prop {
+                GroupProperty::Abelian(_) => "Abelian",
+                GroupProperty::Finite(_) => "Finite",
+                GroupProperty::Simple(_) => "Simple",
+                GroupProperty::Solvable(_) => "Solvable",
+                GroupProperty::Nilpotent(_) => "Nilpotent",
+                other => panic!("Unexpected property: {:?}", other),
+            })
+            .collect();
+        assert_eq!(
+            names,
+            vec!["Abelian", "Finite", "Nilpotent", "Simple", "Solvable"]
+        );
+    }
+
+    #[test]
+    fn test_inverse_expression_to_math_document() {
+        use crate::subjects::math::formalism::location::Located;
+        use crate::subjects::math::theories::groups::definitions::GroupExpression;
+        use crate::turn_render::{Identifier, MathDocumentType, MathNodeContent, ToMathDocument};
+
+        let inverse = GroupExpression::Inverse {
+            group: Located::new_concrete(Group::new_generic()),
+            element: Located::new_variable(Identifier::new_simple("g".to_string())),
+        };
+
+        let document = inverse.to_math_document("inverse");
+        assert_eq!(document.id, "inverse-doc");
+        // The id only depends on the prefix, so exporting twice gives the same page
+        assert_eq!(document.id, inverse.to_math_document("inverse").id);
+
+        let MathDocumentType::ScientificPaper(paper) = &document.content_type else {
+            panic!("Expected a scientific paper document");
+        };
+        assert!(paper.title.contains("inverse"));
+
+        let main_section = &paper.structure.body[0];
+        let SectionContentNode::SubSection(parts) = &main_section.content else {
+            panic!("Expected the expression section to have subsections");
+        };
+        let formal_term = parts
+            .iter()
+            .find_map(|part| match &part.content {
+                SectionContentNode::Math(node) => Some(node),
+                _ => None,
+            })
+            .expect("document should contain the rendered expression");
+
+        // g⁻¹ renders as g raised to -1
+        match formal_term.content.as_ref() {
+            MathNodeContent::Power { base, exponent } => {
+                match base.content.as_ref() {
+                    MathNodeContent::Identifier(id) => assert_eq!(id.body, "g"),
+                    other => panic!("Expected base g, got {:?}", other),
+                }
+                match exponent.content.as_ref() {
+                    MathNodeContent::Quantity { number, .. } => assert_eq!(number, "-1"),
+                    other => panic!("Expected exponent -1, got {:?}", other),
+                }
+            }
+            other => panic!("Expected a power node, got {:?}", other),
+        }
+    }
+
     // Helper function to create a Set with a name
     fn create_named_set(name: &str) -> Set {
         // Create a Set with a symbolic element containing the name
         let element = SetElement::Symbol(name.to_string());
         Set::singleton(element)
     }
+
+    #[test]
+    fn test_power_renders_exponent_variable_name() {
+        use crate::subjects::math::formalism::location::Located;
+        use crate::subjects::math::theories::groups::definitions::GroupExpression;
+        use crate::turn_render::{Identifier, MathNodeContent, ToTurnMath};
+
+        // g^k
+        let power = GroupExpression::Power {
+            group: Located::new_concrete(Group::new_generic()),
+            base: Located::new_variable(Identifier::new_simple("g".to_string())),
+            exponent: Located::new_variable(Identifier::new_simple("k".to_string())),
+        };
+
+        let node = power.to_turn_math("power".to_string());
+        let MathNodeContent::Power { exponent, .. } = node.content.as_ref() else {
+            panic!("Expected a power node, got {:?}", node.content);
+        };
+        match exponent.content.as_ref() {
+            MathNodeContent::Identifier(identifier) => assert_eq!(identifier.body, "k"),
+            other => panic!("Expected the exponent variable k, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_generated_subgroup_rendering_ignores_generator_order() {
+        use crate::subjects::math::formalism::location::Located;
+        use crate::subjects::math::theories::groups::definitions::GeneratedSubgroup;
+
+        let generators = vec![
+            GroupElement::Matrix(vec![vec![0, 1], vec![1, 0]]),
+            GroupElement::Symbol("b".to_string()),
+            GroupElement::Permutation(vec![1, 0, 2]),
+            GroupElement::Integer(3),
+            GroupElement::Symbol("a".to_string()),
+        ];
+        let parent_group = Located::new_concrete(Group::new_generic());
+        let build = |generators: Vec<GroupElement>| GeneratedSubgroup {
+            core: GenericGroup::default(),
+            parent_group: parent_group.clone(),
+            generators,
+        };
+
+        let forward = build(generators.clone());
+        let reversed = build(generators.into_iter().rev().collect());
+
+        let render = |subgroup: &GeneratedSubgroup| {
+            serde_json::to_string(&subgroup.to_section_node("generated")).unwrap()
+        };
+        assert_eq!(render(&forward), render(&reversed));
+
+        let title = forward.to_section_node("generated").title.unwrap();
+        assert!(matches!(
+            title.segments.as_slice(),
+            [crate::turn_render::RichTextSegment::Text(text)]
+                if text == "⟨3, a, b, [1, 0, 2], [[0, 1], [1, 0]]⟩"
+        ));
+    }
+
+    #[test]
+    fn test_isomorphism_renders_variable_operand_names() {
+        use crate::subjects::math::formalism::location::Located;
+        use crate::subjects::math::theories::groups::definitions::GroupRelation;
+        use crate::turn_render::{
+            Identifier, MathNode, MathNodeContent, RelationOperatorNode, ToTurnMath,
+        };
+
+        let relation = GroupRelation::IsIsomorphicTo {
+            first: Located::new_variable(Identifier::new_simple("G".to_string())),
+            second: Located::new_variable(Identifier::new_simple("H".to_string())),
+        };
+
+        let node = relation.to_turn_math("iso".to_string());
+        let MathNodeContent::Relationship { lhs, operator, rhs } = node.content.as_ref() else {
+            panic!("Expected a relationship, got {:?}", node.content);
+        };
+        assert!(matches!(operator, RelationOperatorNode::IsIsomorphicTo));
+        for (operand, name) in [(lhs, "G"), (rhs, "H")] {
+            match operand.content.as_ref() {
+                MathNodeContent::Identifier(identifier) => assert_eq!(identifier.body, name),
+                other => panic!("Expected the group variable {}, got {:?}", name, other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_conjugacy_renders_both_elements_around_operator() {
+        use crate::subjects::math::formalism::location::Located;
+        use crate::subjects::math::theories::groups::definitions::GroupRelation;
+        use crate::turn_render::{MathNodeContent, RelationOperatorNode, ToTurnMath};
+
+        let relation = GroupRelation::AreConjugateIn {
+            element1: Located::new_concrete(GroupElement::Symbol("g".to_string())),
+            element2: Located::new_concrete(GroupElement::Symbol("h".to_string())),
+            group: Located::new_concrete(Group::new_generic()),
+        };
+
+        let node = relation.to_turn_math("conj".to_string());
+        let MathNodeContent::Relationship { lhs, operator, rhs } = node.content.as_ref() else {
+            panic!("Expected a relationship, got {:?}", node.content);
+        };
+        assert!(matches!(operator, RelationOperatorNode::Custom(symbol) if symbol == "~"));
+        for (operand, name) in [(lhs, "g"), (rhs, "h")] {
+            match operand.content.as_ref() {
+                MathNodeContent::Identifier(identifier) => assert_eq!(identifier.body, name),
+                other => panic!("Expected the element {}, got {:?}", name, other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_cyclic_with_generator_renders_group_equal_to_generated_subgroup() {
+        use crate::subjects::math::formalism::location::Located;
+        use crate::subjects::math::theories::groups::definitions::GroupRelation;
+        use crate::turn_render::{
+            BracketStyle, Identifier, MathNodeContent, RelationOperatorNode, ToTurnMath,
+        };
+
+        let relation = GroupRelation::IsCyclicWithGenerator {
+            group: Located::new_variable(Identifier::new_simple("G".to_string())),
+            generator: Located::new_variable(Identifier::new_simple("g".to_string())),
+        };
+
+        let node = relation.to_turn_math("cyclic".to_string());
+        let MathNodeContent::Relationship { lhs, operator, rhs } = node.content.as_ref() else {
+            panic!("Expected a relationship, got {:?}", node.content);
+        };
+        assert!(matches!(operator, RelationOperatorNode::Equal));
+        match lhs.content.as_ref() {
+            MathNodeContent::Identifier(identifier) => assert_eq!(identifier.body, "G"),
+            other => panic!("Expected the group G, got {:?}", other),
+        }
+        let MathNodeContent::Bracketed { inner, style, .. } = rhs.content.as_ref() else {
+            panic!("Expected ⟨g⟩, got {:?}", rhs.content);
+        };
+        assert!(matches!(style, BracketStyle::Angle));
+        match inner.content.as_ref() {
+            MathNodeContent::Identifier(identifier) => assert_eq!(identifier.body, "g"),
+            other => panic!("Expected the generator g, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_order_divides_renders_orders_around_divides_symbol() {
+        use crate::subjects::math::formalism::location::Located;
+        use crate::subjects::math::theories::groups::definitions::GroupRelation;
+        use crate::turn_render::{
+            Identifier, MathNode, MathNodeContent, RelationOperatorNode, ToTurnMath,
+        };
+
+        let relation = GroupRelation::OrderDivides {
+            group1: Located::new_variable(Identifier::new_simple("H".to_string())),
+            group2: Located::new_variable(Identifier::new_simple("G".to_string())),
+        };
+
+        let node = relation.to_turn_math("divides".to_string());
+        let MathNodeContent::Relationship { lhs, operator, rhs } = node.content.as_ref() else {
+            panic!("Expected a relationship, got {:?}", node.content);
+        };
+        assert!(matches!(operator, RelationOperatorNode::Divides));
+
+        // |X| spelled out from the bars and the group identifier
+        let order = |node: &MathNode| -> String {
+            let MathNodeContent::Multiplications { terms } = node.content.as_ref() else {
+                panic!("Expected an order |X|, got {:?}", node.content);
+            };
+            terms
+                .iter()
+                .map(|(_, term)| match term.content.as_ref() {
+                    MathNodeContent::Identifier(identifier) => identifier.body.clone(),
+                    other => panic!("Unexpected order component: {:?}", other),
+                })
+                .collect()
+        };
+        assert_eq!(order(lhs), "|H|");
+        assert_eq!(order(rhs), "|G|");
+    }
+
+    #[test]
+    fn test_free_group_renders_with_its_rank() {
+        use crate::subjects::math::theories::groups::definitions::FreeGroup;
+        use crate::turn_render::{MathNodeContent, RichTextSegment, ToTurnMath};
+
+        let free = |rank: u32| {
+            Group::Free(FreeGroup {
+                core: GenericGroup::default(),
+                rank,
+                free_props: VariantSet::new(),
+            })
+        };
+        let rendered = |group: &Group| match group.to_turn_math("free".to_string()).content.as_ref()
+        {
+            MathNodeContent::Text(text) => text.clone(),
+            other => panic!("Expected the free group notation, got {:?}", other),
+        };
+
+        assert_eq!(rendered(&free(2)), "F_2");
+        // F_0 is the trivial group
+        assert_eq!(rendered(&free(0)), "1");
+
+        let properties = free(2).property_list();
+        let (_, rank) = properties
+            .iter()
+            .find(|(label, _)| label == "Rank")
+            .expect("Missing Rank entry");
+        assert!(matches!(
+            rank.segments.as_slice(),
+            [RichTextSegment::Text(text)] if text == "2"
+        ));
+    }
+
+    #[test]
+    fn test_cyclic_group_property_list_has_order() {
+        let c5 = Group::Cyclic(CyclicGroup {
+            core: GenericGroup::default(),
+            generator: GroupElement::Integer(1),
+            order: Some(5),
+        });
+
+        let properties = c5.property_list();
+        let value = |label: &str| {
+            let (_, value) = properties
+                .iter()
+                .find(|(key, _)| key == label)
+                .unwrap_or_else(|| panic!("Missing {} entry", label));
+            match value.segments.as_slice() {
+                [crate::turn_render::RichTextSegment::Text(text)] => text.clone(),
+                other => panic!("Expected plain text for {}, got {:?}", label, other),
+            }
+        };
+        assert_eq!(value("Order"), "5");
+        assert_eq!(value("Abelian"), "Yes");
+        assert_eq!(value("Simple"), "—");
+
+        let section = c5.to_section_node("c5");
+        let SectionContentNode::SubSection(children) = &section.content else {
+            panic!("Expected the property block after the group's own content");
+        };
+        assert_eq!(children.last().unwrap().id, "c5-properties");
+    }
+
+    #[test]
+    fn test_alternating_group_subscript_is_n_for_the_abstract_family() {
+        use crate::subjects::math::theories::groups::definitions::AlternatingGroup;
+        use crate::turn_render::{MathNodeContent, ToTurnMath};
+
+        let subscript = |degree: u32| {
+            let group = AlternatingGroup {
+                core: GenericGroup::default(),
+                degree,
+                perm_props: VariantSet::new(),
+            };
+            let node = group.to_turn_math("alt".to_string());
+            let MathNodeContent::Identifier(identifier) = node.content.as_ref() else {
+                panic!("Expected A with a subscript, got {:?}", node.content);
+            };
+            let script = identifier.post_script.as_ref().expect("Missing subscript");
+            match script.subscripts[0].content.as_ref() {
+                MathNodeContent::String(text) => text.clone(),
+                other => panic!("Expected a plain subscript, got {:?}", other),
+            }
+        };
+        assert_eq!(subscript(5), "5");
+        assert_eq!(subscript(0), "n");
+    }
+
+    #[test]
+    fn test_placeholder_families_have_unknown_order_and_commutativity() {
+        use crate::subjects::math::theories::groups::definitions::{
+            DihedralGroup, ModularAdditiveGroup,
+        };
+
+        let value = |group: &Group, label: &str| {
+            let properties = group.property_list();
+            let (_, value) = properties
+                .into_iter()
+                .find(|(key, _)| key == label)
+                .unwrap_or_else(|| panic!("Missing {} entry", label));
+            match value.segments.as_slice() {
+                [crate::turn_render::RichTextSegment::Text(text)] => text.clone(),
+                other => panic!("Expected plain text for {}, got {:?}", label, other),
+            }
+        };
+        let placeholders = [
+            Group::Symmetric(SymmetricGroup {
+                core: GenericGroup::default(),
+                degree: 0,
+            }),
+            Group::Dihedral(DihedralGroup {
+                core: GenericGroup::default(),
+                order: 0,
+            }),
+            Group::ModularAdditive(ModularAdditiveGroup {
+                core: GenericGroup::default(),
+                modulus: 0,
+                modular_props: VariantSet::new(),
+            }),
+            Group::Cyclic(CyclicGroup {
+                core: GenericGroup::default(),
+                generator: GroupElement::Symbol("g".to_string()),
+                order: None,
+            }),
+        ];
+        for group in &placeholders {
+            assert_eq!(value(group, "Order"), "—", "{:?}", group);
+            assert_eq!(value(group, "Abelian"), "—", "{:?}", group);
+        }
+    }
+
+    #[test]
+    fn test_additive_group_expressions_render_with_plus_and_zero() {
+        use crate::subjects::math::formalism::location::Located;
+        use crate::subjects::math::theories::groups::definitions::{
+            GroupExpression, ModularAdditiveGroup,
+        };
+        use crate::turn_render::{MathNodeContent, RelationOperatorNode, ToTurnMath};
+
+        let z5 = Located::new_concrete(Group::ModularAdditive(ModularAdditiveGroup {
+            core: GenericGroup::default(),
+            modulus: 5,
+            modular_props: VariantSet::new(),
+        }));
+        let element = |value: i64| {
+            Located::new_concrete(GroupExpression::Element {
+                group: z5.clone(),
+                element: Some(Located::new_concrete(GroupElement::Integer(value))),
+            })
+        };
+
+        let sum = GroupExpression::Operation {
+            group: z5.clone(),
+            left: element(2),
+            right: element(4),
+        };
+        match sum.to_turn_math("sum".to_string()).content.as_ref() {
+            MathNodeContent::Relationship { operator, .. } => {
+                assert!(matches!(operator, RelationOperatorNode::Custom(symbol) if symbol == "+"))
+            }
+            other => panic!("Expected 2 + 4, got {:?}", other),
+        }
+
+        let identity = GroupExpression::Identity(z5.clone());
+        match identity.to_turn_math("zero".to_string()).content.as_ref() {
+            MathNodeContent::Quantity { number, .. } => assert_eq!(number, "0"),
+            other => panic!("Expected the identity as 0, got {:?}", other),
+        }
+
+        // Multiplicative groups keep g·h
+        let generic = Located::new_concrete(Group::new_generic());
+        let product = GroupExpression::Operation {
+            group: generic,
+            left: element(2),
+            right: element(4),
+        };
+        assert!(matches!(
+            product.to_turn_math("product".to_string()).content.as_ref(),
+            MathNodeContent::Multiplications { .. }
+        ));
+    }
+
+    #[test]
+    fn test_cyclic_group_render_without_core() {
+        use crate::subjects::math::formalism::render::options::{
+            RenderOptions, ToTurnMathConfigurable,
+        };
+        use crate::turn_render::{MathNodeContent, MathTextSegment};
+
+        let c5 = Group::Cyclic(CyclicGroup {
+            core: GenericGroup::default(),
+            generator: GroupElement::Integer(1),
+            order: Some(5),
+        });
+        let rendered_id = |options: RenderOptions| {
+            let node = c5.to_turn_math_with("c5".to_string(), &options);
+            match node.content.as_ref() {
+                MathNodeContent::RichTextContent(segments) => match segments.as_slice() {
+                    [MathTextSegment::Text(name), MathTextSegment::Math(inner)] => {
+                        assert_eq!(name, "Cyclic group ");
+                        inner.id.clone()
+                    }
+                    other => panic!("Expected name and notation, got {:?}", other),
+                },
+                other => panic!("Expected rich text, got {:?}", other),
+            }
+        };
+
+        // The default keeps the generic core, as to_turn_math does
+        assert_eq!(rendered_id(RenderOptions::default()), "c5-core");
+        assert_eq!(
+            rendered_id(RenderOptions {
+                compact_cores: true,
+                ..RenderOptions::default()
+            }),
+            "c5-notation"
+        );
+    }
+
+    #[test]
+    fn test_product_group_compact_and_verbose_renderings_differ() {
+        use crate::subjects::math::formalism::render::options::{
+            RenderOptions, ToTurnMathConfigurable,
+        };
+        use crate::subjects::math::theories::groups::definitions::{
+            ProductGroup, ProductOperation,
+        };
+        use crate::turn_render::{MathNodeContent, MathTextSegment};
+        use std::sync::Arc;
+
+        let cyclic = |order| {
+            Arc::new(Group::Cyclic(CyclicGroup {
+                core: GenericGroup::default(),
+                generator: GroupElement::Integer(1),
+                order: Some(order),
+            }))
+        };
+        let product = Group::Product(ProductGroup {
+            core: GenericGroup::default(),
+            operation: ProductOperation::Direct,
+            components: vec![cyclic(2), cyclic(3)],
+            normal_component: None,
+            product_props: VariantSet::new(),
+        });
+
+        let verbose = product.to_turn_math_with("p".to_string(), &RenderOptions::default());
+        let MathNodeContent::RichTextContent(segments) = verbose.content.as_ref() else {
+            panic!(
+                "Expected the verbose name and core, got {:?}",
+                verbose.content
+            );
+        };
+        assert!(matches!(&segments[0], MathTextSegment::Text(name) if name == "Product group "));
+
+        let compact = product.to_turn_math_with("p".to_string(), &RenderOptions::compact());
+        let MathNodeContent::Multiplications { terms } = compact.content.as_ref() else {
+            panic!("Expected C₂ × C₃, got {:?}", compact.content);
+        };
+        assert_eq!(terms.len(), 3);
+        assert!(matches!(
+            terms[1].1.content.as_ref(),
+            MathNodeContent::Identifier(identifier) if identifier.body == "×"
+        ));
+        assert!(matches!(
+            terms[0].1.content.as_ref(),
+            MathNodeContent::Power { .. }
+        ));
+        assert_ne!(
+            serde_json::to_string(&verbose).unwrap(),
+            serde_json::to_string(&compact).unwrap()
+        );
+
+        // Past the depth limit the factors are elided
+        let shallow = RenderOptions {
+            max_depth: Some(1),
+            ..RenderOptions::compact()
+        };
+        let node = product.to_turn_math_with("p".to_string(), &shallow);
+        let MathNodeContent::Multiplications { terms } = node.content.as_ref() else {
+            panic!("Expected the product notation, got {:?}", node.content);
+        };
+        assert!(matches!(
+            terms[0].1.content.as_ref(),
+            MathNodeContent::Text(text) if text == "…"
+        ));
+    }
+
+    #[test]
+    fn test_sylow_subgroup_renders_its_prime_and_order() {
+        use crate::subjects::math::formalism::location::Located;
+        use crate::subjects::math::theories::groups::definitions::{DihedralGroup, SylowSubgroup};
+        use crate::turn_render::{MathNodeContent, MathTextSegment, ToTurnMath};
+
+        let sylow = |parent: Group| {
+            Group::SylowSubgroup(SylowSubgroup {
+                core: GenericGroup::default(),
+                parent_group: Located::new_concrete(parent),
+                prime: 3,
+            })
+        };
+        let quantity = |node: &crate::turn_render::MathNode| match node.content.as_ref() {
+            MathNodeContent::Quantity { number, .. } => number.clone(),
+            other => panic!("Expected a number, got {:?}", other),
+        };
+
+        // |D₉| = 18 = 2 · 3², so its Sylow 3-subgroups have order 3²
+        let d9 = Group::Dihedral(DihedralGroup {
+            core: GenericGroup::default(),
+            order: 18,
+        });
+        let node = sylow(d9).to_turn_math("syl".to_string());
+        let MathNodeContent::RichTextContent(segments) = node.content.as_ref() else {
+            panic!("Expected rich text, got {:?}", node.content);
+        };
+        assert!(matches!(&segments[0], MathTextSegment::Text(name) if name == "Sylow 3-subgroup "));
+        let Some(MathTextSegment::Math(order)) = segments.last() else {
+            panic!("Expected the order annotation, got {:?}", segments);
+        };
+        let MathNodeContent::Power { base, exponent } = order.content.as_ref() else {
+            panic!("Expected p^a, got {:?}", order.content);
+        };
+        assert_eq!(quantity(base), "3");
+        assert_eq!(quantity(exponent), "2");
+
+        // Without a known parent order the prime is still shown, but no order
+        let node = sylow(Group::new_generic()).to_turn_math("syl".to_string());
+        let MathNodeContent::RichTextContent(segments) = node.content.as_ref() else {
+            panic!("Expected rich text, got {:?}", node.content);
+        };
+        assert_eq!(segments.len(), 2);
+        assert!(matches!(&segments[0], MathTextSegment::Text(name) if name == "Sylow 3-subgroup "));
+
+        // The abstract D_n has no order to take the 3-part of
+        let d_n = Group::Dihedral(DihedralGroup {
+            core: GenericGroup::default(),
+            order: 0,
+        });
+        assert_eq!(d_n.finite_order(), None);
+        let node = sylow(d_n).to_turn_math("syl".to_string());
+        let MathNodeContent::RichTextContent(segments) = node.content.as_ref() else {
+            panic!("Expected rich text, got {:?}", node.content);
+        };
+        assert_eq!(segments.len(), 2);
+
+        // 4 is not prime, so no order is claimed even though |D₉| is known
+        let not_prime = Group::SylowSubgroup(SylowSubgroup {
+            core: GenericGroup::default(),
+            parent_group: Located::new_concrete(Group::Dihedral(DihedralGroup {
+                core: GenericGroup::default(),
+                order: 18,
+            })),
+            prime: 4,
+        });
+        let node = not_prime.to_turn_math("syl".to_string());
+        let MathNodeContent::RichTextContent(segments) = node.content.as_ref() else {
+            panic!("Expected rich text, got {:?}", node.content);
+        };
+        assert_eq!(segments.len(), 2);
+    }
 }
@@ -167,8 +167,8 @@ impl ToSectionNode for TopologicalGroup {
                 alignment: None,
             }));
             
-            for prop in self.core.props.inner.iter() {
-                let prop_description = match &prop.0 {
+            for prop in self.core.props.iter() {
+                let prop_description = match prop {
                     GroupProperty::Finite(fv) => {
                         format!("• Finiteness: {:?} — determines cardinality and affects topology (finite groups have discrete topology)", fv)
                     }, 
@@ -200,8 +200,7 @@ impl ToSectionNode for TopologicalGroup {
                 alignment: None,
             }));
             
-            for variant_wrapper in self.props.inner.iter() {
-                let prop = &variant_wrapper.0;
+            for prop in self.props.iter() {
                 let prop_description = match prop {
                     TopologicalGroupProperty::Compact(cv) => {
                         format!("• Compactness: {:?} — every open cover has finite subcover. Enables Haar measure existence and Peter-Weyl theorem applications.", cv)
@@ -340,11 +339,11 @@ impl ToSectionNode for TopologicalGroup {
 
         // Add properties from the core group if any
         if !self.core.props.inner.is_empty() {
-            for prop in self.core.props.inner.iter() {
+            for prop in self.core.props.iter() {
                 selectable_props.push(SelectableProperty {
-                    name: format!("Group: {:?}", prop.0),
-                    current_variant: format!("{:?}", prop.0),
-                    all_variants: vec![format!("{:?}", prop.0)],
+                    name: format!("Group: {:?}", prop),
+                    current_variant: format!("{:?}", prop),
+                    all_variants: vec![format!("{:?}", prop)],
                     description: Some("Core group property".to_string()),
                     variant_descriptions: None,
                     property_type_def_id: None,
@@ -353,8 +352,7 @@ impl ToSectionNode for TopologicalGroup {
         }
 
         // Add topological group properties if any
-        for variant_wrapper in self.props.inner.iter() {
-            let prop = &variant_wrapper.0;
+        for prop in self.props.iter() {
             match prop {
                 TopologicalGroupProperty::Compact(cv) => {
                     selectable_props.push(SelectableProperty {
@@ -516,8 +514,8 @@ impl TopologicalGroup {
         // Add primary topological properties if any
         if !self.props.inner.is_empty() {
             // Pick the most important property to show in tooltip
-            if let Some(prop) = self.props.inner.iter().next() {
-                tooltip_text.push_str(&format!(" ({})", format!("{:?}", prop.0)));
+            if let Some(prop) = self.props.iter().next() {
+                tooltip_text.push_str(&format!(" ({})", format!("{:?}", prop)));
             }
         }
 
@@ -31,6 +31,7 @@ fn create_test_set(name: &str, size: Option<u32>) -> Set {
         parameters: HashMap::new(),
         description: name.to_string(),
         membership_condition: format!("Element of {}", name),
+        membership_predicate: None,
         properties,
     }
 }
@@ -994,3 +995,482 @@ mod property_tests {
         ));
     }
 }
+
+#[cfg(test)]
+mod cayley_table_tests {
+    use super::*;
+    use crate::turn_render::SectionContentNode;
+
+    fn z_mod(modulus: u32) -> Group {
+        Group::ModularAdditive(ModularAdditiveGroup {
+            core: GenericGroup::default(),
+            modulus,
+            modular_props: VariantSet::new(),
+        })
+    }
+
+    #[test]
+    fn test_z3_cayley_table() {
+        let table = z_mod(3).cayley_table().expect("ℤ/3ℤ is concrete and finite");
+
+        assert_eq!(table.len(), 3);
+        assert!(table.iter().all(|row| row.len() == 3));
+        // The identity row repeats the elements in order
+        assert_eq!(
+            table[0],
+            vec![
+                GroupElement::Integer(0),
+                GroupElement::Integer(1),
+                GroupElement::Integer(2)
+            ]
+        );
+        assert_eq!(table[2][2], GroupElement::Integer(1));
+    }
+
+    #[test]
+    fn test_dihedral_cayley_table_is_non_abelian() {
+        let d3 = Group::Dihedral(DihedralGroup {
+            core: GenericGroup::default(),
+            order: 6,
+        });
+        let table = d3.cayley_table().unwrap();
+
+        assert_eq!(table.len(), 6);
+        let r = 1;
+        let s = 3;
+        // r·s = sr² but s·r = sr
+        assert_eq!(table[r][s], GroupElement::Symbol("sr^2".to_string()));
+        assert_eq!(table[s][r], GroupElement::Symbol("sr".to_string()));
+    }
+
+    #[test]
+    fn test_cayley_table_unavailable_for_abstract_or_large_groups() {
+        assert!(Group::new_generic().cayley_table().is_none());
+        assert!(z_mod(Group::CAYLEY_TABLE_MAX_ORDER as u32 + 1)
+            .cayley_table()
+            .is_none());
+    }
+
+    #[test]
+    fn test_cayley_table_section_has_header_and_rows() {
+        let section = z_mod(3).cayley_table_section("z3").unwrap();
+        let SectionContentNode::SubSection(rows) = &section.content else {
+            panic!("Expected one subsection per table row");
+        };
+        assert_eq!(rows.len(), 4);
+        assert!(rows.iter().all(|row| matches!(row.content, SectionContentNode::Math(_))));
+    }
+}
+
+#[cfg(test)]
+mod homomorphism_composition_tests {
+    use super::*;
+    use crate::subjects::math::formalism::error::FormalismError;
+    use crate::subjects::math::formalism::location::Located;
+    use crate::turn_render::{
+        Identifier, MathNode, MathNodeContent, RelationOperatorNode, ToTurnMath,
+    };
+
+    fn group_var(name: &str) -> Located<Group> {
+        Located::new_variable(Identifier::new_simple(name.to_string()))
+    }
+
+    fn hom(domain: &str, codomain: &str) -> GroupHomomorphism {
+        GroupHomomorphism {
+            domain: group_var(domain),
+            codomain: group_var(codomain),
+            construction: HomomorphismConstruction::Abstract,
+        }
+    }
+
+    #[test]
+    fn test_compose_homomorphisms() {
+        // φ : H → K, ψ : G → H, so φ ∘ ψ : G → K
+        let phi = hom("H", "K");
+        let psi = hom("G", "H");
+        let composite = phi.compose(&psi).unwrap();
+
+        assert_eq!(composite.domain.variable_id().unwrap().body, "G");
+        assert_eq!(composite.codomain.variable_id().unwrap().body, "K");
+
+        let node = composite.to_turn_math("composite".to_string());
+        let MathNodeContent::Relationship { lhs, rhs, operator } = node.content.as_ref() else {
+            panic!("Expected φ ∘ ψ, got {:?}", node.content);
+        };
+        assert!(matches!(operator, RelationOperatorNode::Custom(op) if op == "∘"));
+        let symbol = |node: &MathNode| match node.content.as_ref() {
+            MathNodeContent::Identifier(identifier) => identifier.body.clone(),
+            other => panic!("Unexpected composite component: {:?}", other),
+        };
+        assert_eq!(
+            (symbol(lhs), symbol(rhs)),
+            ("φ".to_string(), "ψ".to_string())
+        );
+    }
+
+    #[test]
+    fn test_compose_rejects_mismatched_groups() {
+        let phi = hom("H", "K");
+        let psi = hom("G", "L");
+        let FormalismError::TypeMismatch { expected, found } = phi.compose(&psi).unwrap_err()
+        else {
+            panic!("expected a type mismatch");
+        };
+        assert!(expected.contains("H"));
+        assert!(found.contains("L"));
+    }
+
+    #[test]
+    fn test_identity_is_neutral_for_composition() {
+        let g = group_var("G");
+        let identity = GroupHomomorphism::identity(g.clone());
+        assert_eq!(identity.domain.data, identity.codomain.data);
+
+        let phi = hom("G", "K");
+        let composite = phi.compose(&identity).unwrap();
+        assert_eq!(composite.domain.data, g.data);
+        assert_eq!(composite.codomain.data, phi.codomain.data);
+    }
+}
+
+#[cfg(test)]
+mod abelian_tests {
+    use super::*;
+
+    #[test]
+    fn test_cyclic_group_is_abelian() {
+        let z5 = Group::Cyclic(CyclicGroup {
+            core: GenericGroup::default(),
+            generator: GroupElement::Integer(1),
+            order: Some(5),
+        });
+        assert_eq!(z5.is_abelian(), Some(true));
+    }
+
+    #[test]
+    fn test_s3_is_not_abelian() {
+        let s3 = Group::Symmetric(SymmetricGroup {
+            core: GenericGroup::default(),
+            degree: 3,
+        });
+        assert_eq!(s3.is_abelian(), Some(false));
+    }
+
+    #[test]
+    fn test_placeholder_symmetric_and_dihedral_are_unknown() {
+        let s_n = Group::Symmetric(SymmetricGroup {
+            core: GenericGroup::default(),
+            degree: 0,
+        });
+        assert_eq!(s_n.is_abelian(), None);
+
+        let d_n = Group::Dihedral(DihedralGroup {
+            core: GenericGroup::default(),
+            order: 0,
+        });
+        assert_eq!(d_n.is_abelian(), None);
+    }
+
+    #[test]
+    fn test_generic_group_reads_abelian_property() {
+        let mut group = Group::new_generic();
+        assert_eq!(group.is_abelian(), None);
+
+        group
+            .get_mut_core_properties()
+            .insert(GroupProperty::Abelian(AbelianPropertyVariant::Abelian));
+        assert_eq!(group.is_abelian(), Some(true));
+    }
+}
+
+#[cfg(test)]
+mod coset_tests {
+    use super::*;
+    use crate::subjects::math::formalism::location::Located;
+
+    #[test]
+    fn test_left_coset_in_z6() {
+        let z6 = Group::ModularAdditive(ModularAdditiveGroup {
+            core: GenericGroup::default(),
+            modulus: 6,
+            modular_props: VariantSet::new(),
+        });
+        let subgroup = Group::GeneratedSubgroup(GeneratedSubgroup {
+            core: GenericGroup::default(),
+            parent_group: Located::new_concrete(z6.clone()),
+            generators: vec![GroupElement::Integer(3)],
+        });
+        let coset = GroupExpression::Coset {
+            group: Located::new_concrete(z6.clone()),
+            element: Located::new_concrete(GroupExpression::Element {
+                group: Located::new_concrete(z6.clone()),
+                element: Some(Located::new_concrete(GroupElement::Integer(1))),
+            }),
+            subgroup: Located::new_concrete(subgroup),
+            is_left: true,
+        };
+
+        assert_eq!(
+            coset.evaluate_coset(&z6),
+            Some(vec![GroupElement::Integer(1), GroupElement::Integer(4)])
+        );
+        assert_eq!(coset.evaluate_coset(&Group::new_generic()), None);
+    }
+}
+
+#[cfg(test)]
+mod center_commutator_tests {
+    use super::*;
+    use crate::subjects::math::formalism::location::Located;
+    use crate::subjects::math::theories::fields::definitions::{Field, FieldBasic};
+
+    fn symmetric(degree: usize) -> Group {
+        Group::Symmetric(SymmetricGroup {
+            core: GenericGroup::default(),
+            degree,
+        })
+    }
+
+    /// Q8 as the subgroup of GL(4, ℝ) generated by left multiplication by i and j
+    /// on the basis 1, i, j, k.
+    fn quaternion_group() -> Group {
+        let gl4 = Group::GeneralLinear(GeneralLinearGroup {
+            core: GenericGroup::default(),
+            dimension: 4,
+            field: Field::Basic(FieldBasic::default()),
+            matrix_props: VariantSet::new(),
+            linear_props: VariantSet::new(),
+        });
+        let i = GroupElement::Matrix(vec![
+            vec![0, -1, 0, 0],
+            vec![1, 0, 0, 0],
+            vec![0, 0, 0, -1],
+            vec![0, 0, 1, 0],
+        ]);
+        let j = GroupElement::Matrix(vec![
+            vec![0, 0, -1, 0],
+            vec![0, 0, 0, 1],
+            vec![1, 0, 0, 0],
+            vec![0, -1, 0, 0],
+        ]);
+        Group::GeneratedSubgroup(GeneratedSubgroup {
+            core: GenericGroup::default(),
+            parent_group: Located::new_concrete(gl4),
+            generators: vec![i, j],
+        })
+    }
+
+    #[test]
+    fn test_center_of_q8_is_plus_minus_one() {
+        let q8 = quaternion_group();
+        assert_eq!(q8.cayley_table().map(|table| table.len()), Some(8));
+
+        let center = q8.compute_center().expect("Q8 is concrete and finite");
+        let minus_one = GroupElement::Matrix(
+            (0..4)
+                .map(|row| (0..4).map(|col| if row == col { -1 } else { 0 }).collect())
+                .collect(),
+        );
+        assert_eq!(center.len(), 2);
+        assert!(center.contains(&minus_one));
+    }
+
+    #[test]
+    fn test_commutator_subgroup_of_s3_is_a3() {
+        let a3 = Group::Alternating(AlternatingGroup {
+            core: GenericGroup::default(),
+            degree: 3,
+            perm_props: VariantSet::new(),
+        });
+        let mut commutators = symmetric(3)
+            .compute_commutator_subgroup()
+            .expect("S_3 is concrete and finite");
+        let mut alternating = a3.cayley_table().unwrap()[0].clone();
+        commutators.sort();
+        alternating.sort();
+
+        assert_eq!(alternating.len(), 3);
+        assert_eq!(commutators, alternating);
+        // S_3 has trivial center
+        assert_eq!(
+            symmetric(3).compute_center().map(|center| center.len()),
+            Some(1)
+        );
+    }
+
+    #[test]
+    fn test_center_unavailable_for_abstract_groups() {
+        assert!(Group::new_generic().compute_center().is_none());
+        assert!(Group::new_generic().compute_commutator_subgroup().is_none());
+        // 5! elements is past the Cayley table limit
+        assert!(symmetric(5).compute_center().is_none());
+    }
+}
+
+#[cfg(test)]
+mod subgroup_enumeration_tests {
+    use super::*;
+
+    #[test]
+    fn test_z6_has_four_subgroups() {
+        let z6 = Group::ModularAdditive(ModularAdditiveGroup {
+            core: GenericGroup::default(),
+            modulus: 6,
+            modular_props: VariantSet::new(),
+        });
+        let subgroups = z6.subgroups().expect("ℤ/6ℤ is concrete and finite");
+
+        let orders: Vec<usize> = subgroups.iter().map(|subgroup| subgroup.len()).collect();
+        assert_eq!(orders, vec![1, 2, 3, 6]);
+        assert_eq!(
+            subgroups[1],
+            vec![GroupElement::Integer(0), GroupElement::Integer(3)]
+        );
+    }
+
+    #[test]
+    fn test_subgroups_of_s3_satisfy_lagrange() {
+        let s3 = Group::Symmetric(SymmetricGroup {
+            core: GenericGroup::default(),
+            degree: 3,
+        });
+        let subgroups = s3.subgroups().unwrap();
+
+        // {e}, three subgroups of order 2, A_3 and S_3
+        assert_eq!(subgroups.len(), 6);
+        assert!(subgroups.iter().all(|subgroup| 6 % subgroup.len() == 0));
+    }
+
+    #[test]
+    fn test_subgroups_unavailable_for_abstract_or_large_groups() {
+        assert!(Group::new_generic().subgroups().is_none());
+        let large = Group::ModularAdditive(ModularAdditiveGroup {
+            core: GenericGroup::default(),
+            modulus: Group::SUBGROUPS_MAX_ORDER as u32 + 1,
+            modular_props: VariantSet::new(),
+        });
+        assert!(large.subgroups().is_none());
+    }
+}
+
+#[cfg(test)]
+mod quotient_coset_tests {
+    use super::*;
+    use crate::subjects::math::formalism::location::Located;
+    use crate::turn_render::{MathNode, MathNodeContent, RelationOperatorNode};
+
+    fn z6_modulo(generator: i64) -> QuotientGroup {
+        let z6 = Group::ModularAdditive(ModularAdditiveGroup {
+            core: GenericGroup::default(),
+            modulus: 6,
+            modular_props: VariantSet::new(),
+        });
+        let normal_subgroup = Group::GeneratedSubgroup(GeneratedSubgroup {
+            core: GenericGroup::default(),
+            parent_group: Located::new_concrete(z6.clone()),
+            generators: vec![GroupElement::Integer(generator)],
+        });
+        QuotientGroup {
+            core: GenericGroup::default(),
+            group: Located::new_concrete(z6),
+            normal_subgroup: Located::new_concrete(normal_subgroup),
+            quotient_props: VariantSet::new(),
+        }
+    }
+
+    #[test]
+    fn test_cosets_of_z6() {
+        let integers = |values: &[i64]| -> Vec<GroupElement> {
+            values
+                .iter()
+                .map(|&value| GroupElement::Integer(value))
+                .collect()
+        };
+
+        // {0, 3} has index 3 in ℤ/6ℤ
+        let by_order_two = z6_modulo(3);
+        assert_eq!(
+            by_order_two.cosets(),
+            Some(vec![
+                integers(&[0, 3]),
+                integers(&[1, 4]),
+                integers(&[2, 5])
+            ])
+        );
+        assert_eq!(
+            by_order_two.coset_representatives(),
+            Some(integers(&[0, 1, 2]))
+        );
+
+        // {0, 2, 4} has index 2
+        let by_order_three = z6_modulo(2);
+        assert_eq!(
+            by_order_three.cosets(),
+            Some(vec![integers(&[0, 2, 4]), integers(&[1, 3, 5])])
+        );
+
+        let node = by_order_two
+            .to_expanded_turn_math("quotient".to_string())
+            .unwrap();
+        assert_eq!(spelled(&node), "{N,1+N,2+N}");
+    }
+
+    /// Spell out an expanded quotient, e.g. "{N,1+N}".
+    fn spelled(node: &MathNode) -> String {
+        match node.content.as_ref() {
+            MathNodeContent::Multiplications { terms } => {
+                terms.iter().map(|(_, term)| spelled(term)).collect()
+            }
+            MathNodeContent::Relationship {
+                lhs,
+                rhs,
+                operator: RelationOperatorNode::Custom(operator),
+            } => format!("{}{}{}", spelled(lhs), operator, spelled(rhs)),
+            MathNodeContent::Quantity { number, .. } => number.clone(),
+            MathNodeContent::Identifier(identifier) => identifier.body.clone(),
+            other => panic!("Expected a structured coset, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_cosets_unavailable_for_abstract_quotients() {
+        let mut quotient = z6_modulo(3);
+        quotient.group = Located::new_concrete(Group::new_generic());
+        assert!(quotient.cosets().is_none());
+        assert!(
+            quotient
+                .to_expanded_turn_math("quotient".to_string())
+                .is_none()
+        );
+    }
+}
+
+#[cfg(test)]
+mod element_order_tests {
+    use super::*;
+
+    fn z6() -> Group {
+        Group::ModularAdditive(ModularAdditiveGroup {
+            core: GenericGroup::default(),
+            modulus: 6,
+            modular_props: VariantSet::new(),
+        })
+    }
+
+    #[test]
+    fn test_element_orders_in_z6() {
+        let z6 = z6();
+        assert_eq!(z6.element_order(&GroupElement::Integer(1)), Some(6));
+        assert_eq!(z6.element_order(&GroupElement::Integer(2)), Some(3));
+        assert_eq!(z6.element_order(&GroupElement::Integer(0)), Some(1));
+    }
+
+    #[test]
+    fn test_element_order_unavailable_outside_the_group() {
+        assert_eq!(z6().element_order(&GroupElement::Integer(6)), None);
+        assert_eq!(
+            Group::new_generic().element_order(&GroupElement::Integer(1)),
+            None
+        );
+    }
+}
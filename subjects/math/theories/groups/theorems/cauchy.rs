@@ -37,6 +37,7 @@ pub fn cauchy_theorem() -> Theorem {
             ty: Located::new_concrete(Group::new_generic().to_math_expression()),
             definition: DefinitionState::Abstract,
             description: None,
+            is_internal: false,
         },
         ContextEntry {
             name: p_id.clone(),
@@ -46,6 +47,7 @@ pub fn cauchy_theorem() -> Theorem {
             }))),
             definition: DefinitionState::Abstract,
             description: None,
+            is_internal: false,
         },
     ];
 
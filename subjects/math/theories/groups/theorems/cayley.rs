@@ -31,6 +31,7 @@ pub fn cayley_theorem() -> Theorem {
         ty: Located::new_concrete(Group::new_generic().to_math_expression()),
         definition: DefinitionState::Abstract,
         description: None,
+        is_internal: false,
     }];
 
     // Goal: G is isomorphic to a subgroup of a symmetric group
@@ -31,6 +31,7 @@ pub fn center_is_normal_subgroup() -> Theorem {
             ty: Located::new_concrete(Group::new_generic().to_math_expression()),
             definition: DefinitionState::Abstract,
             description: None,
+            is_internal: false,
         },
     ];
 
@@ -32,6 +32,7 @@ pub fn element_order_divides_group_order() -> Theorem {
             ty: Located::new_concrete(Group::new_generic().to_math_expression()),
             definition: DefinitionState::Abstract,
             description: None,
+            is_internal: false,
         },
         ContextEntry {
             name: g_id.clone(),
@@ -43,6 +44,7 @@ pub fn element_order_divides_group_order() -> Theorem {
             ))),
             definition: DefinitionState::Abstract,
             description: None,
+            is_internal: false,
         },
     ];
 
@@ -35,6 +35,7 @@ pub fn fundamental_theorem_finite_abelian() -> Theorem {
         ty: Located::new_concrete(Group::new_generic().to_math_expression()),
         definition: DefinitionState::Abstract,
         description: None,
+        is_internal: false,
     }];
 
     // Goal: G is isomorphic to a direct product of cyclic groups
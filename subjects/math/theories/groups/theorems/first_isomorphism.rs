@@ -67,6 +67,7 @@ pub fn first_isomorphism_theorem() -> Theorem {
             )))),
             definition: DefinitionState::Abstract,
             description: None,
+            is_internal: false,
         },
         ContextEntry {
             name: h_id.clone(),
@@ -75,6 +76,7 @@ pub fn first_isomorphism_theorem() -> Theorem {
             )))),
             definition: DefinitionState::Abstract,
             description: None,
+            is_internal: false,
         },
         ContextEntry {
             name: phi_id.clone(),
@@ -88,6 +90,7 @@ pub fn first_isomorphism_theorem() -> Theorem {
             ))),
             definition: DefinitionState::Abstract,
             description: None,
+            is_internal: false,
         },
     ];
 
@@ -92,6 +92,7 @@ pub fn group_inverse_uniqueness() -> Theorem {
             )))),
             definition: DefinitionState::Abstract,
             description: None,
+            is_internal: false,
         },
         ContextEntry {
             name: g_id.clone(),
@@ -103,6 +104,7 @@ pub fn group_inverse_uniqueness() -> Theorem {
             ))),
             definition: DefinitionState::Abstract,
             description: None,
+            is_internal: false,
         },
         ContextEntry {
             name: h1_id.clone(),
@@ -114,6 +116,7 @@ pub fn group_inverse_uniqueness() -> Theorem {
             ))),
             definition: DefinitionState::Abstract,
             description: None,
+            is_internal: false,
         },
         ContextEntry {
             name: h2_id.clone(),
@@ -125,6 +128,7 @@ pub fn group_inverse_uniqueness() -> Theorem {
             ))),
             definition: DefinitionState::Abstract,
             description: None,
+            is_internal: false,
         },
         ContextEntry {
             name: e_id.clone(),
@@ -133,6 +137,7 @@ pub fn group_inverse_uniqueness() -> Theorem {
             ))),
             definition: DefinitionState::Abstract,
             description: None,
+            is_internal: false,
         },
     ];
     let goal = ProofGoal {
@@ -438,7 +443,9 @@ pub fn group_inverse_uniqueness() -> Theorem {
 #[cfg(test)]
 mod tests {
     use crate::{
-        subjects::math::formalism::automation::registry::get_theorem_registry,
+        subjects::math::formalism::{
+            automation::registry::get_theorem_registry, error::FormalismError,
+        },
         turn_render::ToMathDocument,
     };
 
@@ -462,4 +469,73 @@ mod tests {
         //     theorem.to_math_document("test_id")
         // );
     }
+
+    #[test]
+    fn test_check_accepts_proof_and_reports_broken_one() {
+        let theorem = group_inverse_uniqueness();
+        assert_eq!(theorem.check(), Ok(()));
+
+        let mut broken = theorem.clone();
+        // A variable declared twice
+        let duplicate = broken.proofs.initial_goal.context[0].clone();
+        broken.proofs.initial_goal.context.push(duplicate);
+        // The closing step no longer closes anything
+        let completed_id = broken
+            .proofs
+            .node_values()
+            .find(|node| matches!(node.role, NodeRole::Completed))
+            .map(|node| node.id.clone())
+            .unwrap();
+        let reopened = broken.proofs.initial_goal.clone();
+        broken.proofs.get_node_mut(&completed_id).unwrap().role = NodeRole::Goal(reopened);
+        // A dangling child
+        let root_id = broken.proofs.roots[0].clone();
+        let root = broken.proofs.get_node_mut(&root_id).unwrap();
+        root.children.push("missing".to_string());
+
+        let problems = broken.check().unwrap_err();
+        assert!(problems.iter().any(|problem| matches!(
+            problem,
+            FormalismError::IllFormedGoal { message } if message.starts_with("Duplicate names")
+        )));
+        assert!(problems.iter().any(|problem| matches!(
+            problem,
+            FormalismError::InconsistentForest { message } if message.starts_with("Node '")
+        )));
+        assert!(problems.contains(&FormalismError::TacticFailed {
+            message: format!(
+                "Proof does not replay: Node '{}' differs from replaying its tactic on its parent's goal",
+                completed_id
+            ),
+        }));
+        assert!(
+            problems
+                .iter()
+                .any(|problem| matches!(problem, FormalismError::IncompleteProof { .. }))
+        );
+    }
+
+    #[test]
+    fn test_conclusion_and_hypotheses() {
+        let theorem = group_inverse_uniqueness();
+
+        let MathRelation::Implies(premise, conclusion) = theorem.conclusion() else {
+            panic!("Expected an implication, got {:?}", theorem.conclusion());
+        };
+        assert!(matches!(
+            premise.concrete_value().map(|relation| relation.as_ref()),
+            Some(MathRelation::And(conjuncts)) if conjuncts.len() == 2
+        ));
+        assert!(matches!(
+            conclusion.concrete_value().map(|relation| relation.as_ref()),
+            Some(MathRelation::Equal { .. })
+        ));
+
+        let names: Vec<String> = theorem
+            .hypotheses()
+            .iter()
+            .map(|entry| entry.name.body.clone())
+            .collect();
+        assert_eq!(names, vec!["G", "g", "h1", "h2", "e"]);
+    }
 }
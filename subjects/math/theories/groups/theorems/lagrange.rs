@@ -29,6 +29,7 @@ pub fn lagrange_theorem() -> Theorem {
             ty: Located::new_concrete(Group::new_generic().to_math_expression()),
             definition: DefinitionState::Abstract,
             description: None,
+            is_internal: false,
         },
         ContextEntry {
             name: h_id.clone(),
@@ -42,6 +43,7 @@ pub fn lagrange_theorem() -> Theorem {
             ),
             definition: DefinitionState::Abstract,
             description: None,
+            is_internal: false,
         },
     ];
 
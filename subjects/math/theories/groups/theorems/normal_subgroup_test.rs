@@ -31,6 +31,7 @@ pub fn normal_subgroup_test() -> Theorem {
             ty: Located::new_concrete(Group::new_generic().to_math_expression()),
             definition: DefinitionState::Abstract,
             description: None,
+            is_internal: false,
         },
         ContextEntry {
             name: h_id.clone(),
@@ -44,6 +45,7 @@ pub fn normal_subgroup_test() -> Theorem {
             ),
             definition: DefinitionState::Abstract,
             description: None,
+            is_internal: false,
         },
     ];
 
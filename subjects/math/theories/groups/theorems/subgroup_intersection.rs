@@ -85,18 +85,21 @@ pub fn subgroup_intersection_is_subgroup() -> Theorem {
 
             definition: DefinitionState::Abstract,
             description: None,
+            is_internal: false,
         },
         ContextEntry {
             name: h_id.clone(),
             ty: h_subgroup,
             definition: DefinitionState::Abstract,
             description: None,
+            is_internal: false,
         },
         ContextEntry {
             name: k_id.clone(),
             ty: k_subgroup,
             definition: DefinitionState::Abstract,
             description: None,
+            is_internal: false,
         },
     ];
 
@@ -37,6 +37,7 @@ pub fn sylow_first_theorem() -> Theorem {
             ty: Located::new_concrete(Group::new_generic().to_math_expression()),
             definition: DefinitionState::Abstract,
             description: None,
+            is_internal: false,
         },
         ContextEntry {
             name: p_id.clone(),
@@ -48,6 +49,7 @@ pub fn sylow_first_theorem() -> Theorem {
             ))),
             definition: DefinitionState::Abstract,
             description: None,
+            is_internal: false,
         },
         ContextEntry {
             name: k_id.clone(),
@@ -59,6 +61,7 @@ pub fn sylow_first_theorem() -> Theorem {
             ))),
             definition: DefinitionState::Abstract,
             description: None,
+            is_internal: false,
         },
     ];
 
@@ -244,6 +244,7 @@ impl GetAbstractionLevel for GroupExpression {
             | GroupExpression::Commutator { .. }
             | GroupExpression::Coset { .. }
             | GroupExpression::ActionOnElement { .. }
+            | GroupExpression::HomomorphismApplication { .. }
             | GroupExpression::Power { .. } => AbstractionLevel::Level3, // These are constructors for new group elements/expressions
 
             GroupExpression::GroupOrder { group } => {
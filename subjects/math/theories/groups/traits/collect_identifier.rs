@@ -6,7 +6,7 @@ use crate::{
 };
 
 use super::super::definitions::{
-    Group, GroupElement, GroupExpression, GroupHomomorphism, GroupRelation,
+    Group, GroupElement, GroupExpression, GroupHomomorphism, GroupRelation, HomomorphismConstruction,
 };
 
 // ===== GROUP THEORY IMPLEMENTATIONS (Child nodes of MathExpression) =====
@@ -31,6 +31,11 @@ impl CollectIdentifier for GroupRelation {
                 group,
                 normal_subgroup,
             } => todo!(),
+            GroupRelation::ElementOf { element, group } => {
+                let mut identifiers = element.collect_identifier();
+                identifiers.extend(group.collect_identifier());
+                identifiers
+            }
             GroupRelation::IsInCenterOf { element, group } => todo!(),
             GroupRelation::AreConjugateIn {
                 element1,
@@ -163,6 +168,15 @@ impl CollectIdentifier for GroupExpression {
                 identifiers
             }
             GroupExpression::Homomorphism(hom) => hom.collect_identifier(),
+            GroupExpression::HomomorphismApplication {
+                homomorphism,
+                element,
+            } => {
+                let mut identifiers = HashSet::new();
+                identifiers.extend(homomorphism.collect_identifier());
+                identifiers.extend(element.collect_identifier());
+                identifiers
+            }
         }
     }
 }
@@ -179,6 +193,11 @@ impl CollectIdentifier for GroupHomomorphism {
         let mut identifiers = HashSet::new();
         identifiers.extend(self.domain.collect_identifier());
         identifiers.extend(self.codomain.collect_identifier());
+        // A composite also refers to the groups it passes through
+        if let HomomorphismConstruction::Composite { outer, inner } = &self.construction {
+            identifiers.extend(outer.collect_identifier());
+            identifiers.extend(inner.collect_identifier());
+        }
         identifiers
     }
 }
@@ -39,6 +39,7 @@ impl Complexity for GroupExpression {
             GroupExpression::Operation { left, right, .. } => {
                 1 + left.complexity() + right.complexity()
             }
+            GroupExpression::HomomorphismApplication { element, .. } => 1 + element.complexity(),
             GroupExpression::Inverse { element, .. } => 1 + element.complexity(),
             // Handle other cases with simple complexity values
             _ => 1,
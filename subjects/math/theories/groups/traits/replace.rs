@@ -361,6 +361,13 @@ impl<U: 'static + Clone + Debug + Search> Substitutable<U> for GroupExpression {
             GroupExpression::Homomorphism(hom) => {
                 GroupExpression::Homomorphism(hom.substitute(instantiations, target, context))
             }
+            GroupExpression::HomomorphismApplication {
+                homomorphism,
+                element,
+            } => GroupExpression::HomomorphismApplication {
+                homomorphism: homomorphism.substitute(instantiations, target, context),
+                element: element.substitute(instantiations, target, context),
+            },
         }
     }
 }
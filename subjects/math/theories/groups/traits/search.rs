@@ -6,7 +6,11 @@ use crate::subjects::math::formalism::{
     extract::Parametrizable,
     location::Located,
     proof::{ContextEntry, tactics::Target},
-    traits::{IsCompatible, Search, detag::TryDetag},
+    traits::{
+        ExplainMismatch, IsCompatible, Search,
+        detag::TryDetag,
+        is_compatible::{MatchMismatch, first_child_mismatch, mismatch_reason},
+    },
 };
 
 use super::super::definitions::{
@@ -354,6 +358,12 @@ impl Search for GroupExpression {
                 .get_located(target.clone())
                 .or_else(|| group.get_located(target)),
             GroupExpression::Homomorphism(hom) => hom.get_located(target),
+            GroupExpression::HomomorphismApplication {
+                homomorphism,
+                element,
+            } => homomorphism
+                .get_located(target.clone())
+                .or_else(|| element.get_located(target)),
             GroupExpression::ActionOnElement { action, element } => action
                 .get_located(target.clone())
                 .or_else(|| element.get_located(target)),
@@ -582,3 +592,55 @@ impl IsCompatible<GroupExpression> for GroupExpression {
         }
     }
 }
+
+impl ExplainMismatch<GroupExpression> for GroupExpression {
+    fn explain_mismatch(
+        &self,
+        target_context: &Vec<ContextEntry>,
+        pattern: &GroupExpression,
+        pattern_context: &Vec<ContextEntry>,
+    ) -> Option<MatchMismatch> {
+        if self.is_compatible(target_context, pattern, pattern_context) {
+            return None;
+        }
+        let explain = |target: &Located<GroupExpression>, pattern: &Located<GroupExpression>| {
+            target.explain_mismatch(target_context, pattern, pattern_context)
+        };
+        let inner = match (self, pattern) {
+            (
+                GroupExpression::Operation { left, right, .. },
+                GroupExpression::Operation {
+                    left: pattern_left,
+                    right: pattern_right,
+                    ..
+                },
+            ) => first_child_mismatch([explain(left, pattern_left), explain(right, pattern_right)]),
+            (
+                GroupExpression::Commutator { a, b, .. },
+                GroupExpression::Commutator {
+                    a: pattern_a,
+                    b: pattern_b,
+                    ..
+                },
+            ) => first_child_mismatch([explain(a, pattern_a), explain(b, pattern_b)]),
+            (
+                GroupExpression::Inverse { element, .. },
+                GroupExpression::Inverse {
+                    element: pattern_element,
+                    ..
+                },
+            ) => explain(element, pattern_element).map(|mismatch| mismatch.under(0)),
+            (
+                GroupExpression::Power { base, .. },
+                GroupExpression::Power {
+                    base: pattern_base, ..
+                },
+            ) => explain(base, pattern_base).map(|mismatch| mismatch.under(0)),
+            _ => None,
+        };
+        inner.or_else(|| {
+            let reason = mismatch_reason(self, pattern, "operator differs");
+            Some(MatchMismatch::between(self, pattern, reason))
+        })
+    }
+}
@@ -1,73 +1,279 @@
 // src/formalize_v2/subjects/math/theories/mod.rs
 
-use serde::{Deserialize, Serialize};
+use serde::ser::{Impossible, SerializeStruct, SerializeStructVariant, SerializeTupleVariant};
+use serde::{Deserialize, Serialize, Serializer};
 use std::collections::HashSet;
+use std::fmt::{self, Display};
 use std::hash::{Hash, Hasher};
 use std::mem::discriminant;
 
 // Core mathematical theories
+#[cfg(feature = "algebraic_geometry")]
 pub mod algebraic_geometry;
 pub mod analysis;
 pub mod common;
+#[cfg(feature = "differential_geometry")]
 pub mod differential_geometry;
+#[cfg(feature = "groups")]
 pub mod groups;
+#[cfg(feature = "order_theory")]
 pub mod order_theory;
+#[cfg(feature = "rings")]
 pub mod rings;
+#[cfg(feature = "symplectic_geometry")]
 pub mod symplectic_geometry;
 pub mod topology;
 pub mod zfc;
 
 // Additional mathematical theories
+#[cfg(feature = "affine_geometry")]
 pub mod affine_geometry;
+#[cfg(feature = "algebra")]
 pub mod algebra;
 pub mod fields;
+#[cfg(feature = "homology")]
 pub mod homology;
+#[cfg(feature = "lie_theory")]
 pub mod lie_theory;
 pub mod linear_algebra;
+#[cfg(feature = "measure")]
 pub mod measure;
 pub mod number_theory;
+#[cfg(feature = "probability")]
 pub mod probability;
+#[cfg(feature = "projective_geometry")]
 pub mod projective_geometry;
+#[cfg(feature = "representation")]
 pub mod representation;
+#[cfg(feature = "riemannian_geometry")]
 pub mod riemannian_geometry;
 
 // VariantSet implementation for property collections
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
 pub struct VariantSet<T> {
     inner: HashSet<VariantWrapper<T>>,
 }
 
-impl<T> Hash for VariantSet<T> {
+impl<T: Serialize> Hash for VariantSet<T> {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        let mut elements: Vec<_> = self.inner.iter().collect();
-        // sort elements to get a consistent order for hashing
-        elements.sort();
-        elements.hash(state);
+        self.sorted().hash(state);
+    }
+}
+
+impl<T: Serialize> Serialize for VariantSet<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        // Same layout as the derived impl, but in canonical order so that the
+        // output does not depend on the HashSet's random seed
+        let mut state = serializer.serialize_struct("VariantSet", 1)?;
+        state.serialize_field("inner", &self.sorted())?;
+        state.end()
     }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct VariantWrapper<T>(T);
 
-impl<T> PartialOrd for VariantWrapper<T> {
+impl<T: Serialize> VariantWrapper<T> {
+    /// The name of the wrapped variant, as serde spells it. Only the variant
+    /// tag is visited, never its fields, so this neither allocates nor fails.
+    fn variant_name(&self) -> &'static str {
+        match self.0.serialize(VariantNameSerializer) {
+            Ok(name) => name,
+            // Not an enum: every value is the same "variant"
+            Err(NotAVariant) => "",
+        }
+    }
+}
+
+/// A serializer that stops at the variant tag of an enum value and returns
+/// its name. Any other kind of value is rejected with `NotAVariant`.
+struct VariantNameSerializer;
+
+#[derive(Debug)]
+struct NotAVariant;
+
+impl Display for NotAVariant {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("value is not an enum variant")
+    }
+}
+
+impl std::error::Error for NotAVariant {}
+
+impl serde::ser::Error for NotAVariant {
+    fn custom<M: Display>(_message: M) -> Self {
+        NotAVariant
+    }
+}
+
+/// Accepts and drops the fields of a tuple or struct variant.
+struct SkipFields(&'static str);
+
+impl SerializeTupleVariant for SkipFields {
+    type Ok = &'static str;
+    type Error = NotAVariant;
+
+    fn serialize_field<V: ?Sized + Serialize>(&mut self, _value: &V) -> Result<(), NotAVariant> {
+        Ok(())
+    }
+
+    fn end(self) -> Result<&'static str, NotAVariant> {
+        Ok(self.0)
+    }
+}
+
+impl SerializeStructVariant for SkipFields {
+    type Ok = &'static str;
+    type Error = NotAVariant;
+
+    fn serialize_field<V: ?Sized + Serialize>(
+        &mut self,
+        _key: &'static str,
+        _value: &V,
+    ) -> Result<(), NotAVariant> {
+        Ok(())
+    }
+
+    fn end(self) -> Result<&'static str, NotAVariant> {
+        Ok(self.0)
+    }
+}
+
+macro_rules! reject_non_variants {
+    ($($method:ident($($arg:ty),*);)*) => {
+        $(
+            fn $method(self, $(_: $arg),*) -> Result<&'static str, NotAVariant> {
+                Err(NotAVariant)
+            }
+        )*
+    };
+}
+
+impl Serializer for VariantNameSerializer {
+    type Ok = &'static str;
+    type Error = NotAVariant;
+    type SerializeSeq = Impossible<&'static str, NotAVariant>;
+    type SerializeTuple = Impossible<&'static str, NotAVariant>;
+    type SerializeTupleStruct = Impossible<&'static str, NotAVariant>;
+    type SerializeTupleVariant = SkipFields;
+    type SerializeMap = Impossible<&'static str, NotAVariant>;
+    type SerializeStruct = Impossible<&'static str, NotAVariant>;
+    type SerializeStructVariant = SkipFields;
+
+    reject_non_variants! {
+        serialize_bool(bool);
+        serialize_i8(i8);
+        serialize_i16(i16);
+        serialize_i32(i32);
+        serialize_i64(i64);
+        serialize_u8(u8);
+        serialize_u16(u16);
+        serialize_u32(u32);
+        serialize_u64(u64);
+        serialize_f32(f32);
+        serialize_f64(f64);
+        serialize_char(char);
+        serialize_str(&str);
+        serialize_bytes(&[u8]);
+        serialize_none();
+        serialize_unit();
+        serialize_unit_struct(&'static str);
+    }
+
+    fn serialize_some<V: ?Sized + Serialize>(
+        self,
+        _value: &V,
+    ) -> Result<&'static str, NotAVariant> {
+        Err(NotAVariant)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<&'static str, NotAVariant> {
+        Ok(variant)
+    }
+
+    fn serialize_newtype_struct<V: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        value: &V,
+    ) -> Result<&'static str, NotAVariant> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<V: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _value: &V,
+    ) -> Result<&'static str, NotAVariant> {
+        Ok(variant)
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, NotAVariant> {
+        Err(NotAVariant)
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, NotAVariant> {
+        Err(NotAVariant)
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct, NotAVariant> {
+        Err(NotAVariant)
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<SkipFields, NotAVariant> {
+        Ok(SkipFields(variant))
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, NotAVariant> {
+        Err(NotAVariant)
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStruct, NotAVariant> {
+        Err(NotAVariant)
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<SkipFields, NotAVariant> {
+        Ok(SkipFields(variant))
+    }
+}
+
+impl<T: Serialize> PartialOrd for VariantWrapper<T> {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
         Some(self.cmp(other))
     }
 }
 
-impl<T> Ord for VariantWrapper<T> {
+impl<T: Serialize> Ord for VariantWrapper<T> {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        // We can't compare discriminants directly, but we can compare their hashes.
-        // This is stable enough for a consistent sort order.
-        let mut s = std::collections::hash_map::DefaultHasher::new();
-        discriminant(&self.0).hash(&mut s);
-        let self_hash = s.finish();
-
-        let mut s = std::collections::hash_map::DefaultHasher::new();
-        discriminant(&other.0).hash(&mut s);
-        let other_hash = s.finish();
-
-        self_hash.cmp(&other_hash)
+        // Discriminants aren't ordered and their hashes aren't stable across
+        // builds, so order by variant name: the same variant, the same name.
+        self.variant_name().cmp(other.variant_name())
     }
 }
 
@@ -116,8 +322,12 @@ impl<T: Clone> VariantSet<T> {
             .map(|wrapper| &wrapper.0)
     }
 
-    pub fn iter(&self) -> impl Iterator<Item = &T> {
-        self.inner.iter().map(|wrapper| &wrapper.0)
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
     }
 
     pub fn is_subset(&self, other: &Self) -> bool {
@@ -125,6 +335,22 @@ impl<T: Clone> VariantSet<T> {
     }
 }
 
+impl<T: Serialize> VariantSet<T> {
+    /// Iterates over the properties in canonical order, sorted by variant name.
+    ///
+    /// The backing `HashSet` is randomly seeded, so its own order changes from
+    /// one set to the next; sorting here keeps rendered output deterministic.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.sorted().into_iter().map(|wrapper| &wrapper.0)
+    }
+
+    fn sorted(&self) -> Vec<&VariantWrapper<T>> {
+        let mut wrappers: Vec<_> = self.inner.iter().collect();
+        wrappers.sort();
+        wrappers
+    }
+}
+
 pub trait HasProperties<T> {
     fn get_properties(&self) -> &VariantSet<T>;
     fn get_properties_mut(&mut self) -> &mut VariantSet<T>;
@@ -153,3 +379,34 @@ macro_rules! variant_set {
         }
     };
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, Serialize)]
+    enum Shape {
+        Point,
+        Circle(u32),
+        Segment(u32, u32),
+        Rectangle { width: u32, height: u32 },
+    }
+
+    #[test]
+    fn test_variant_set_orders_every_variant_kind_by_name() {
+        let set = variant_set![
+            Shape::Segment(1, 2),
+            Shape::Rectangle {
+                width: 3,
+                height: 4
+            },
+            Shape::Point,
+            Shape::Circle(5),
+        ];
+
+        let names: Vec<&str> = set.sorted().iter().map(|w| w.variant_name()).collect();
+        assert_eq!(names, vec!["Circle", "Point", "Rectangle", "Segment"]);
+        // A value that is not an enum has no variant name to read
+        assert_eq!(VariantWrapper(7u32).variant_name(), "");
+    }
+}
@@ -5,14 +5,46 @@ use crate::subjects::math::formalism::location::Located;
 
 use super::super::super::super::math::formalism::expressions::MathExpression;
 use super::super::super::super::math::formalism::extract::Parametrizable;
+use crate::turn_render::Identifier;
 use serde::{Deserialize, Serialize};
+use std::fmt;
 
+/// A number appearing in an expression
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
-pub struct Number {}
+pub enum Number {
+    /// An integer literal
+    Integer(i64),
+    /// A rational literal numerator/denominator, kept as written
+    Rational { numerator: i64, denominator: i64 },
+    /// A number known only by name, e.g. `n`
+    Variable(Identifier),
+}
+
+impl Number {
+    /// Create an integer literal
+    pub fn int(value: i64) -> Self {
+        Number::Integer(value)
+    }
+
+    /// Create a rational literal
+    pub fn rational(numerator: i64, denominator: i64) -> Self {
+        Number::Rational {
+            numerator,
+            denominator,
+        }
+    }
+}
 
-impl ToString for Number {
-    fn to_string(&self) -> String {
-        "Number".to_string()
+impl fmt::Display for Number {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Number::Integer(value) => write!(f, "{}", value),
+            Number::Rational {
+                numerator,
+                denominator,
+            } => write!(f, "{}/{}", numerator, denominator),
+            Number::Variable(name) => write!(f, "{}", name),
+        }
     }
 }
 
@@ -2,7 +2,41 @@ use std::sync::Arc;
 
 use crate::turn_render::*;
 
-use super::NumberTheoryRelation;
+use super::{Number, NumberTheoryRelation};
+
+impl ToTurnMath for Number {
+    fn to_turn_math(&self, master_id: String) -> MathNode {
+        let quantity = |id: String, value: &i64| MathNode {
+            id,
+            content: Arc::new(MathNodeContent::Quantity {
+                number: value.to_string(),
+                scientific_notation: None,
+                unit: None,
+            }),
+        };
+        match self {
+            Number::Integer(value) => quantity(master_id, value),
+            Number::Rational {
+                numerator,
+                denominator,
+            } => MathNode {
+                id: master_id.clone(),
+                content: Arc::new(MathNodeContent::Division {
+                    numerator: Arc::new(quantity(format!("{}:numerator", master_id), numerator)),
+                    denominator: Arc::new(quantity(
+                        format!("{}:denominator", master_id),
+                        denominator,
+                    )),
+                    style: crate::turn_render::math_node::DivisionStyle::Inline,
+                }),
+            },
+            Number::Variable(name) => MathNode {
+                id: master_id,
+                content: Arc::new(MathNodeContent::Identifier(name.clone())),
+            },
+        }
+    }
+}
 
 impl ToTurnMath for NumberTheoryRelation {
     fn to_turn_math(&self, master_id: String) -> MathNode {
@@ -183,3 +217,28 @@ impl ToTurnMath for NumberTheoryRelation {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::subjects::math::formalism::expressions::MathExpression;
+
+    #[test]
+    fn test_integer_renders_as_its_value() {
+        let node = MathExpression::Number(Number::int(42)).to_turn_math("n".to_string());
+        match node.content.as_ref() {
+            MathNodeContent::Quantity { number, .. } => assert_eq!(number, "42"),
+            other => panic!("Expected the quantity 42, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_variable_renders_as_its_name() {
+        let number = Number::Variable(Identifier::new_simple("n".to_string()));
+        assert_eq!(number.to_string(), "n");
+        match number.to_turn_math("n".to_string()).content.as_ref() {
+            MathNodeContent::Identifier(identifier) => assert_eq!(identifier.body, "n"),
+            other => panic!("Expected the identifier n, got {:?}", other),
+        }
+    }
+}
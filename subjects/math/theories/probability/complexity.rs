@@ -193,6 +193,7 @@ impl Complexity for ProbabilityExpression {
             ProbabilityExpression::ConditionalExpectedValue { .. } => 3,
             ProbabilityExpression::Variance { .. } => 2,
             ProbabilityExpression::Covariance { .. } => 3,
+            ProbabilityExpression::Correlation { .. } => 3,
             ProbabilityExpression::Moment { .. } => 2,
             ProbabilityExpression::CharacteristicFunction { .. } => 3,
             ProbabilityExpression::MomentGeneratingFunction { .. } => 3,
@@ -202,9 +203,22 @@ impl Complexity for ProbabilityExpression {
             ProbabilityExpression::EventIntersection { .. } => 1,
             ProbabilityExpression::EventComplement { .. } => 1,
             ProbabilityExpression::IndicatorVariable { .. } => 1,
+            ProbabilityExpression::GeneratedSigmaAlgebra { generators } => 1 + generators.len(),
             ProbabilityExpression::DistributionFunction { .. } => 2,
             ProbabilityExpression::ProbabilityDensityFunction { .. } => 3,
             ProbabilityExpression::ProbabilityMassFunction { .. } => 2,
+            ProbabilityExpression::AffineTransform { .. } => 2,
+            ProbabilityExpression::RandomVariablePower { .. } => 2,
+            ProbabilityExpression::RandomVariableMin { .. } => 2,
+            ProbabilityExpression::RandomVariableMax { .. } => 2,
+            ProbabilityExpression::ExpectedValueOf { expression } => 1 + expression.complexity(),
+            ProbabilityExpression::VarianceOf { expression } => 1 + expression.complexity(),
+            ProbabilityExpression::ScaledExpression { expression, .. } => {
+                2 + expression.complexity()
+            }
+            ProbabilityExpression::ExpressionSum { left, right } => {
+                1 + left.complexity() + right.complexity()
+            }
         }
     }
 }
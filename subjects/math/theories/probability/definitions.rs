@@ -811,6 +811,12 @@ pub enum ProbabilityExpression {
         variable2: Parametrizable<RandomVariable>,
     },
 
+    /// Correlation coefficient: Corr(X,Y) = Cov(X,Y) / √(Var(X)Var(Y))
+    Correlation {
+        variable1: Parametrizable<RandomVariable>,
+        variable2: Parametrizable<RandomVariable>,
+    },
+
     /// Moment: E[X^n]
     Moment {
         variable: Parametrizable<RandomVariable>,
@@ -859,6 +865,11 @@ pub enum ProbabilityExpression {
     /// Indicator random variable: 1_A
     IndicatorVariable { event: Parametrizable<Event> },
 
+    /// σ-algebra generated by a collection of events: σ(𝒜)
+    GeneratedSigmaAlgebra {
+        generators: Vec<Parametrizable<Event>>,
+    },
+
     /// Distribution function: F_X(x) = P(X ≤ x)
     DistributionFunction {
         variable: Parametrizable<RandomVariable>,
@@ -876,6 +887,54 @@ pub enum ProbabilityExpression {
         variable: Parametrizable<RandomVariable>,
         value: String, // Could be numeric or symbolic
     },
+
+    /// Affine transform of a random variable: aX + b
+    AffineTransform {
+        variable: Box<Parametrizable<RandomVariable>>,
+        scale: Number,
+        shift: Number,
+    },
+
+    /// Power of a random variable: Xⁿ
+    RandomVariablePower {
+        variable: Box<Parametrizable<RandomVariable>>,
+        exponent: u32,
+    },
+
+    /// Pointwise minimum: min(X, Y)
+    RandomVariableMin {
+        left: Box<Parametrizable<RandomVariable>>,
+        right: Box<Parametrizable<RandomVariable>>,
+    },
+
+    /// Pointwise maximum: max(X, Y)
+    RandomVariableMax {
+        left: Box<Parametrizable<RandomVariable>>,
+        right: Box<Parametrizable<RandomVariable>>,
+    },
+
+    /// Expected value of a derived random variable: E[aX + b], E[X²]
+    ExpectedValueOf {
+        expression: Box<ProbabilityExpression>,
+    },
+
+    /// Variance of a derived random variable: Var(aX + b)
+    VarianceOf {
+        expression: Box<ProbabilityExpression>,
+    },
+
+    /// Affine combination of a scalar expression: a·E[X] + b
+    ScaledExpression {
+        expression: Box<ProbabilityExpression>,
+        scale: Number,
+        shift: Number,
+    },
+
+    /// Sum of two scalar expressions: E[X] + E[Y]
+    ExpressionSum {
+        left: Box<ProbabilityExpression>,
+        right: Box<ProbabilityExpression>,
+    },
 }
 
 /// Markov chain specific structure
@@ -893,6 +952,9 @@ pub struct MarkovChain {
     pub markov_props: VariantSet<MarkovChainProperty>,
 }
 
+/// A dense numeric matrix, stored row by row
+pub type Matrix = Vec<Vec<f64>>;
+
 /// Transition matrix for Markov chains
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub enum TransitionMatrix {
@@ -1034,6 +1096,7 @@ impl Default for GenericProbabilitySpace {
                 parameters: HashMap::new(),
                 description: "Abstract sample space".to_string(),
                 membership_condition: "ω ∈ Ω".to_string(),
+                membership_predicate: None,
                 properties: VariantSet::new(),
             },
             sigma_algebra: SigmaAlgebra {
@@ -1041,6 +1104,7 @@ impl Default for GenericProbabilitySpace {
                     parameters: HashMap::new(),
                     description: "Abstract σ-algebra".to_string(),
                     membership_condition: "A ∈ F".to_string(),
+                    membership_predicate: None,
                     properties: VariantSet::new(),
                 },
                 algebra_type: SigmaAlgebraType::PowerSet,
@@ -1053,6 +1117,7 @@ impl Default for GenericProbabilitySpace {
                         parameters: HashMap::new(),
                         description: "Abstract σ-algebra".to_string(),
                         membership_condition: "A ∈ F".to_string(),
+                        membership_predicate: None,
                         properties: VariantSet::new(),
                     },
                     algebra_type: SigmaAlgebraType::PowerSet,
@@ -1064,3 +1129,156 @@ impl Default for GenericProbabilitySpace {
         }
     }
 }
+
+impl ProbabilityExpression {
+    /// Rewrite transformed random variables into their simplest equivalent form.
+    ///
+    /// - `E[aX + b]` becomes `a·E[X] + b` (linearity of expectation)
+    /// - `E[X + Y]` becomes `E[X] + E[Y]`
+    /// - `Var(aX + b)` becomes `a²·Var(X)`
+    /// - `E[Xⁿ]` becomes the moment `Moment { order: n }`
+    /// - `X¹` inside an expectation or variance collapses to `X`
+    /// - `Cov(X, X)` becomes `Var(X)`
+    pub fn simplify(&self) -> ProbabilityExpression {
+        match self {
+            ProbabilityExpression::Covariance {
+                variable1,
+                variable2,
+            } if variable1 == variable2 => ProbabilityExpression::Variance {
+                variable: variable1.clone(),
+            },
+            ProbabilityExpression::ExpectedValueOf { expression } => match expression.simplify() {
+                ProbabilityExpression::RandomVariablePower { variable, exponent } => {
+                    if exponent == 1 {
+                        ProbabilityExpression::ExpectedValue {
+                            variable: *variable,
+                        }
+                    } else {
+                        ProbabilityExpression::Moment {
+                            variable: *variable,
+                            order: exponent,
+                        }
+                    }
+                }
+                ProbabilityExpression::AffineTransform {
+                    variable,
+                    scale,
+                    shift,
+                } => scaled(
+                    ProbabilityExpression::ExpectedValue {
+                        variable: *variable,
+                    },
+                    scale,
+                    shift,
+                ),
+                ProbabilityExpression::RandomVariableSum { left, right } => {
+                    ProbabilityExpression::ExpressionSum {
+                        left: Box::new(ProbabilityExpression::ExpectedValue { variable: *left }),
+                        right: Box::new(ProbabilityExpression::ExpectedValue { variable: *right }),
+                    }
+                }
+                inner => ProbabilityExpression::ExpectedValueOf {
+                    expression: Box::new(inner),
+                },
+            },
+            ProbabilityExpression::VarianceOf { expression } => match expression.simplify() {
+                ProbabilityExpression::RandomVariablePower {
+                    variable,
+                    exponent: 1,
+                } => ProbabilityExpression::Variance {
+                    variable: *variable,
+                },
+                ProbabilityExpression::AffineTransform {
+                    variable,
+                    scale,
+                    shift,
+                } => match squared(&scale) {
+                    Some(scale) => scaled(
+                        ProbabilityExpression::Variance {
+                            variable: *variable,
+                        },
+                        scale,
+                        Number::from(0),
+                    ),
+                    None => ProbabilityExpression::VarianceOf {
+                        expression: Box::new(ProbabilityExpression::AffineTransform {
+                            variable,
+                            scale,
+                            shift,
+                        }),
+                    },
+                },
+                inner => ProbabilityExpression::VarianceOf {
+                    expression: Box::new(inner),
+                },
+            },
+            other => other.clone(),
+        }
+    }
+}
+
+/// `a·expression + b`, or `expression` itself when the transform is the identity.
+fn scaled(
+    expression: ProbabilityExpression,
+    scale: Number,
+    shift: Number,
+) -> ProbabilityExpression {
+    if scale.as_f64() == Some(1.0) && shift.as_f64() == Some(0.0) {
+        expression
+    } else {
+        ProbabilityExpression::ScaledExpression {
+            expression: Box::new(expression),
+            scale,
+            shift,
+        }
+    }
+}
+
+/// `a²`, exact for integers; `None` on overflow or a non-finite result.
+fn squared(number: &Number) -> Option<Number> {
+    match number.as_i64() {
+        Some(n) => n.checked_mul(n).map(Number::from),
+        None => number.as_f64().and_then(|x| Number::from_f64(x * x)),
+    }
+}
+
+impl TransitionMatrix {
+    /// The numeric matrix for a finite chain, or `None` for kernels, non-square
+    /// matrices and entries that are not representable as `f64`.
+    pub fn to_matrix(&self) -> Option<Matrix> {
+        let TransitionMatrix::Finite(rows) = self else {
+            return None;
+        };
+        let size = rows.len();
+        rows.iter()
+            .map(|row| {
+                if row.len() != size {
+                    return None;
+                }
+                row.iter().map(|entry| entry.as_f64()).collect()
+            })
+            .collect()
+    }
+}
+
+impl MarkovChain {
+    /// The n-step transition matrix `Pⁿ` of a concrete finite chain.
+    /// `n_step(0)` is the identity matrix.
+    pub fn n_step(&self, n: usize) -> Option<Matrix> {
+        let p = self.transition_matrix.to_matrix()?;
+        let size = p.len();
+        let mut result: Matrix = (0..size)
+            .map(|i| (0..size).map(|j| if i == j { 1.0 } else { 0.0 }).collect())
+            .collect();
+        for _ in 0..n {
+            result = (0..size)
+                .map(|i| {
+                    (0..size)
+                        .map(|j| (0..size).map(|k| result[i][k] * p[k][j]).sum())
+                        .collect()
+                })
+                .collect();
+        }
+        Some(result)
+    }
+}
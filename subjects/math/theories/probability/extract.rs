@@ -131,6 +131,7 @@ pub fn extract_random_variable_from_expression(
         ProbabilityExpression::ConditionalExpectedValue { variable, .. } => Some(variable),
         ProbabilityExpression::Variance { variable } => Some(variable),
         ProbabilityExpression::Covariance { variable1, .. } => Some(variable1),
+        ProbabilityExpression::Correlation { variable1, .. } => Some(variable1),
         ProbabilityExpression::Moment { variable, .. } => Some(variable),
         ProbabilityExpression::CharacteristicFunction { variable, .. } => Some(variable),
         ProbabilityExpression::MomentGeneratingFunction { variable, .. } => Some(variable),
@@ -161,6 +162,10 @@ pub fn extract_all_variables_from_expression(
         ProbabilityExpression::Covariance {
             variable1,
             variable2,
+        }
+        | ProbabilityExpression::Correlation {
+            variable1,
+            variable2,
         } => {
             vec![variable1, variable2]
         }
@@ -170,6 +175,22 @@ pub fn extract_all_variables_from_expression(
         ProbabilityExpression::RandomVariableProduct { left, right } => {
             vec![left.as_ref(), right.as_ref()]
         }
+        ProbabilityExpression::RandomVariableMin { left, right }
+        | ProbabilityExpression::RandomVariableMax { left, right } => {
+            vec![left.as_ref(), right.as_ref()]
+        }
+        ProbabilityExpression::AffineTransform { variable, .. }
+        | ProbabilityExpression::RandomVariablePower { variable, .. } => vec![variable.as_ref()],
+        ProbabilityExpression::ExpectedValueOf { expression }
+        | ProbabilityExpression::VarianceOf { expression }
+        | ProbabilityExpression::ScaledExpression { expression, .. } => {
+            extract_all_variables_from_expression(expression)
+        }
+        ProbabilityExpression::ExpressionSum { left, right } => {
+            let mut variables = extract_all_variables_from_expression(left);
+            variables.extend(extract_all_variables_from_expression(right));
+            variables
+        }
         _ => extract_random_variable_from_expression(expr)
             .into_iter()
             .collect(),
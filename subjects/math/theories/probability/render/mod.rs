@@ -22,14 +22,14 @@ use crate::subjects::math::formalism::traits::abstraction_level::{
 use crate::subjects::math::theories::VariantSet;
 use crate::subjects::math::theories::fields::definitions::Field;
 use crate::subjects::math::theories::topology::definitions::{TopologicalSpace, Topology};
-use crate::subjects::math::theories::zfc::definitions::Set;
 
 //--- Imports from probability definitions ---
 use crate::subjects::math::theories::probability::definitions::{
-    BrownianMotion, ConditionalProbabilitySpace, ContinuousProbabilitySpace,
+    BrownianMotion, ConditionalProbabilitySpace, ContinuousProbabilitySpace, ConvergenceType,
     DiscreteProbabilitySpace, Distribution, Event, GenericProbabilitySpace, MarkovChain,
     Martingale, ProbabilityExpression, ProbabilityMeasure, ProbabilityRelation, ProbabilitySpace,
-    RandomVariable, SigmaAlgebra, StochasticProcess,
+    ProductProbabilitySpace, RandomVariable, SigmaAlgebra, SigmaAlgebraType, StochasticProcess,
+    TransitionMatrix,
 };
 
 use crate::subjects::math::theories::probability::theorems::all_probability_theorems;
@@ -49,10 +49,10 @@ impl ToTurnMath for ProbabilitySpace {
             ProbabilitySpace::Generic(p) => p.to_turn_math(master_id),
             ProbabilitySpace::Discrete(p) => p.to_turn_math(master_id),
             ProbabilitySpace::Continuous(p) => p.core.to_turn_math(master_id),
-            ProbabilitySpace::Product(p) => p.core.to_turn_math(master_id),
-            ProbabilitySpace::Conditional(p) => p.core.to_turn_math(master_id),
+            ProbabilitySpace::Product(p) => p.to_turn_math(master_id),
+            ProbabilitySpace::Conditional(p) => p.to_turn_math(master_id),
             ProbabilitySpace::StochasticProcess(p) => p.core.to_turn_math(master_id),
-            ProbabilitySpace::MarkovChain(p) => p.core.core.to_turn_math(master_id),
+            ProbabilitySpace::MarkovChain(p) => p.to_turn_math(master_id),
             ProbabilitySpace::Martingale(p) => p.core.core.to_turn_math(master_id),
             ProbabilitySpace::BrownianMotion(p) => p.core.core.to_turn_math(master_id),
         }
@@ -120,6 +120,76 @@ impl ToTurnMath for GenericProbabilitySpace {
     }
 }
 
+/// Renders a σ-algebra through its defining collection: `𝒫(Ω)`, `ℬ(Ω)`,
+/// `σ({G₁, …, Gₙ})`, `ℱ₁ ⊗ … ⊗ ℱₙ`, or the trace `S ∩ ℱ`.
+impl ToTurnMath for SigmaAlgebra {
+    fn to_turn_math(&self, master_id: String) -> MathNode {
+        let symbol = |body: &str, role: &str| MathNode {
+            id: format!("{}-{}", master_id, role),
+            content: Arc::new(MathNodeContent::Identifier(Identifier::new_simple(
+                body.to_string(),
+            ))),
+        };
+        let of_base_set = |name: &str| MathNode {
+            id: master_id.clone(),
+            content: Arc::new(MathNodeContent::FunctionCall {
+                name: function_name_node(name, format!("{}-name", master_id)),
+                parameters: vec![self.base_set.to_turn_math(format!("{}-base", master_id))],
+            }),
+        };
+
+        match &self.algebra_type {
+            SigmaAlgebraType::PowerSet => of_base_set("𝒫"),
+            SigmaAlgebraType::Borel => of_base_set("ℬ"),
+            SigmaAlgebraType::Generated { generators } => {
+                let generators = generators
+                    .iter()
+                    .enumerate()
+                    .map(|(index, set)| {
+                        set.to_turn_math(format!("{}-generator-{}", master_id, index))
+                    })
+                    .collect();
+                generated_sigma_algebra_node(generators, master_id)
+            }
+            SigmaAlgebraType::Product { factors } => {
+                let mut terms = Vec::new();
+                for (index, factor) in factors.iter().enumerate() {
+                    if index > 0 {
+                        terms.push((
+                            RefinedMulOrDivOperation::None,
+                            symbol("⊗", &format!("otimes-{}", index)),
+                        ));
+                    }
+                    terms.push((
+                        RefinedMulOrDivOperation::None,
+                        factor.to_turn_math(format!("{}-factor-{}", master_id, index)),
+                    ));
+                }
+                MathNode {
+                    id: master_id.clone(),
+                    content: Arc::new(MathNodeContent::Multiplications { terms }),
+                }
+            }
+            SigmaAlgebraType::Trace { parent, subset } => MathNode {
+                id: master_id.clone(),
+                content: Arc::new(MathNodeContent::Multiplications {
+                    terms: vec![
+                        (
+                            RefinedMulOrDivOperation::None,
+                            subset.to_turn_math(format!("{}-subset", master_id)),
+                        ),
+                        (RefinedMulOrDivOperation::None, symbol("∩", "cap")),
+                        (
+                            RefinedMulOrDivOperation::None,
+                            parent.to_turn_math(format!("{}-parent", master_id)),
+                        ),
+                    ],
+                }),
+            },
+        }
+    }
+}
+
 impl ToTurnMath for DiscreteProbabilitySpace {
     fn to_turn_math(&self, master_id: String) -> MathNode {
         MathNode {
@@ -272,6 +342,177 @@ impl ToTurnMath for ContinuousProbabilitySpace {
     }
 }
 
+/// Renders as `(Ω, ℱ, P(· | B))` with `B` the conditioning event.
+impl ToTurnMath for ConditionalProbabilitySpace {
+    fn to_turn_math(&self, master_id: String) -> MathNode {
+        let symbol = |body: &str, role: &str| MathNode {
+            id: format!("{}-{}", master_id, role),
+            content: Arc::new(MathNodeContent::Identifier(Identifier::new_simple(
+                body.to_string(),
+            ))),
+        };
+        let conditioned_measure = MathNode {
+            id: format!("{}-measure", master_id),
+            content: Arc::new(MathNodeContent::FunctionCall {
+                name: function_name_node("P", format!("{}-measure-name", master_id)),
+                parameters: vec![MathNode {
+                    id: format!("{}-given", master_id),
+                    content: Arc::new(MathNodeContent::Multiplications {
+                        terms: vec![
+                            (RefinedMulOrDivOperation::None, symbol("·", "placeholder")),
+                            (RefinedMulOrDivOperation::None, symbol("|", "bar")),
+                            (
+                                RefinedMulOrDivOperation::None,
+                                self.conditioning_event
+                                    .event_set
+                                    .to_turn_math(format!("{}-event", master_id)),
+                            ),
+                        ],
+                    }),
+                }],
+            }),
+        };
+
+        MathNode {
+            id: master_id.clone(),
+            content: Arc::new(MathNodeContent::Bracketed {
+                inner: Arc::new(MathNode {
+                    id: format!("{}-tuple", master_id),
+                    content: Arc::new(MathNodeContent::Multiplications {
+                        terms: vec![
+                            (RefinedMulOrDivOperation::None, symbol("Ω", "omega")),
+                            (RefinedMulOrDivOperation::None, symbol("ℱ", "sigma")),
+                            (RefinedMulOrDivOperation::None, conditioned_measure),
+                        ],
+                    }),
+                }),
+                style: BracketStyle::Round,
+                size: BracketSize::Normal,
+            }),
+        }
+    }
+}
+
+/// Renders a product space as `(Ω₁ × … × Ωₙ, ℱ₁ ⊗ … ⊗ ℱₙ, P₁ ⊗ … ⊗ Pₙ)` from the sample
+/// spaces and σ-algebras of its factors; the measures are indexed symbols.
+/// A factor that is itself a product contributes its own factors in place.
+impl ToTurnMath for ProductProbabilitySpace {
+    fn to_turn_math(&self, master_id: String) -> MathNode {
+        let factors = self.flattened_factors(&master_id);
+
+        let component = |role: &str, joiner: &str, nodes: Vec<MathNode>| -> MathNode {
+            let mut terms = Vec::new();
+            for (index, node) in nodes.into_iter().enumerate() {
+                if index > 0 {
+                    terms.push((
+                        RefinedMulOrDivOperation::None,
+                        MathNode {
+                            id: format!("{}-{}-joiner-{}", master_id, role, index),
+                            content: Arc::new(MathNodeContent::Identifier(Identifier {
+                                body: joiner.to_string(),
+                                pre_script: None,
+                                mid_script: None,
+                                post_script: None,
+                                primes: 0,
+                                is_function: false,
+                            })),
+                        },
+                    ));
+                }
+                terms.push((RefinedMulOrDivOperation::None, node));
+            }
+            MathNode {
+                id: format!("{}-{}", master_id, role),
+                content: Arc::new(MathNodeContent::Multiplications { terms }),
+            }
+        };
+
+        let sample_spaces = factors
+            .iter()
+            .map(|(factor_id, core)| {
+                core.sample_space
+                    .to_turn_math(format!("{}-omega", factor_id))
+            })
+            .collect();
+        let sigma_algebras = factors
+            .iter()
+            .map(|(factor_id, core)| {
+                core.sigma_algebra
+                    .to_turn_math(format!("{}-sigma", factor_id))
+            })
+            .collect();
+        let measures = factors
+            .iter()
+            .enumerate()
+            .map(|(index, (factor_id, _))| MathNode {
+                id: format!("{}-measure", factor_id),
+                content: Arc::new(MathNodeContent::Identifier(Identifier {
+                    body: "P".to_string(),
+                    pre_script: None,
+                    mid_script: None,
+                    post_script: Some(ScriptNode {
+                        subscripts: vec![MathNode {
+                            id: format!("{}-measure-index", factor_id),
+                            content: Arc::new(MathNodeContent::Quantity {
+                                number: (index + 1).to_string(),
+                                scientific_notation: None,
+                                unit: None,
+                            }),
+                        }],
+                        superscripts: vec![],
+                    }),
+                    primes: 0,
+                    is_function: false,
+                })),
+            })
+            .collect();
+
+        MathNode {
+            id: master_id.clone(),
+            content: Arc::new(MathNodeContent::Bracketed {
+                inner: Arc::new(MathNode {
+                    id: format!("{}-tuple", master_id),
+                    content: Arc::new(MathNodeContent::Multiplications {
+                        terms: vec![
+                            (
+                                RefinedMulOrDivOperation::None,
+                                component("omega", "×", sample_spaces),
+                            ),
+                            (
+                                RefinedMulOrDivOperation::None,
+                                component("sigma", "⊗", sigma_algebras),
+                            ),
+                            (
+                                RefinedMulOrDivOperation::None,
+                                component("measure", "⊗", measures),
+                            ),
+                        ],
+                    }),
+                }),
+                style: BracketStyle::Round,
+                size: BracketSize::Normal,
+            }),
+        }
+    }
+}
+
+impl ProductProbabilitySpace {
+    /// Every non-product factor with its id, descending into nested product factors.
+    fn flattened_factors(&self, master_id: &str) -> Vec<(String, &GenericProbabilitySpace)> {
+        self.factors
+            .iter()
+            .enumerate()
+            .flat_map(|(index, factor)| {
+                let factor_id = format!("{}-factor-{}", master_id, index);
+                match factor.as_ref() {
+                    ProbabilitySpace::Product(inner) => inner.flattened_factors(&factor_id),
+                    other => vec![(factor_id, other.core_space())],
+                }
+            })
+            .collect()
+    }
+}
+
 impl ToTurnMath for StochasticProcess {
     fn to_turn_math(&self, master_id: String) -> MathNode {
         MathNode {
@@ -288,9 +529,62 @@ impl ToTurnMath for StochasticProcess {
     }
 }
 
+/// Renders a Markov chain by its transition matrix: `P = [pᵢⱼ]` for finite chains,
+/// or just `P` when the chain is given by a general kernel.
 impl ToTurnMath for MarkovChain {
     fn to_turn_math(&self, master_id: String) -> MathNode {
-        self.core.core.to_turn_math(master_id)
+        let p_node = MathNode {
+            id: format!("{}-p", master_id),
+            content: Arc::new(MathNodeContent::Identifier(Identifier {
+                body: "P".to_string(),
+                pre_script: None,
+                mid_script: None,
+                post_script: None,
+                primes: 0,
+                is_function: false,
+            })),
+        };
+
+        match &self.transition_matrix {
+            TransitionMatrix::Finite(rows) => MathNode {
+                id: master_id.clone(),
+                content: Arc::new(MathNodeContent::Relationship {
+                    lhs: Arc::new(p_node),
+                    rhs: Arc::new(matrix_node(rows, format!("{}-matrix", master_id))),
+                    operator: RelationOperatorNode::Equal,
+                }),
+            },
+            TransitionMatrix::Kernel(_) => MathNode {
+                id: master_id,
+                content: p_node.content,
+            },
+        }
+    }
+}
+
+/// Render a matrix as a square-bracketed matrix node.
+fn matrix_node(rows: &[Vec<serde_json::Number>], id: String) -> MathNode {
+    let rows = rows
+        .iter()
+        .enumerate()
+        .map(|(i, row)| {
+            row.iter()
+                .enumerate()
+                .map(|(j, entry)| number_node(entry, format!("{}-{}-{}", id, i, j)))
+                .collect()
+        })
+        .collect();
+
+    MathNode {
+        id: id.clone(),
+        content: Arc::new(MathNodeContent::Bracketed {
+            inner: Arc::new(MathNode {
+                id: format!("{}-entries", id),
+                content: Arc::new(MathNodeContent::Matrix { rows }),
+            }),
+            style: BracketStyle::Square,
+            size: BracketSize::Auto,
+        }),
     }
 }
 
@@ -365,6 +659,21 @@ impl ToMathDocument for ProbabilitySpace {
 }
 
 impl ProbabilitySpace {
+    /// The `(Ω, ℱ, P)` triple underlying this space.
+    fn core_space(&self) -> &GenericProbabilitySpace {
+        match self {
+            ProbabilitySpace::Generic(p) => p,
+            ProbabilitySpace::Discrete(p) => &p.core,
+            ProbabilitySpace::Continuous(p) => &p.core,
+            ProbabilitySpace::Product(p) => &p.core,
+            ProbabilitySpace::Conditional(p) => &p.core,
+            ProbabilitySpace::StochasticProcess(p) => &p.core,
+            ProbabilitySpace::MarkovChain(p) => &p.core.core,
+            ProbabilitySpace::Martingale(p) => &p.core.core,
+            ProbabilitySpace::BrownianMotion(p) => &p.core.core,
+        }
+    }
+
     pub fn get_id(&self) -> String {
         match self {
             ProbabilitySpace::Generic(_) => "generic_probability_space".to_string(),
@@ -397,9 +706,14 @@ impl ProbabilitySpace {
                     "Product Probability Space".to_string(),
                 )]
             }
-            ProbabilitySpace::Conditional(_) => vec![RichTextSegment::Text(
-                "Conditional Probability Space".to_string(),
-            )],
+            ProbabilitySpace::Conditional(p) => vec![
+                RichTextSegment::Text("Conditional Probability Space given ".to_string()),
+                RichTextSegment::Math(
+                    p.conditioning_event
+                        .event_set
+                        .to_turn_math(format!("{}-event", id_prefix)),
+                ),
+            ],
             ProbabilitySpace::StochasticProcess(_) => {
                 vec![RichTextSegment::Text("Stochastic Process".to_string())]
             }
@@ -427,9 +741,15 @@ impl ProbabilitySpace {
             ProbabilitySpace::Product(_) => {
                 vec![RichTextSegment::Text("Product Space".to_string())]
             }
-            ProbabilitySpace::Conditional(_) => {
-                vec![RichTextSegment::Text("Conditional Space".to_string())]
-            }
+            ProbabilitySpace::Conditional(p) => vec![
+                RichTextSegment::Text("(Ω, ℱ, P(· | ".to_string()),
+                RichTextSegment::Math(
+                    p.conditioning_event
+                        .event_set
+                        .to_turn_math(format!("{}-event", id_prefix)),
+                ),
+                RichTextSegment::Text("))".to_string()),
+            ],
             ProbabilitySpace::StochasticProcess(_) => {
                 vec![RichTextSegment::Text("Stochastic Process".to_string())]
             }
@@ -456,6 +776,7 @@ impl ProbabilityExpression {
             ProbabilityExpression::ExpectedValue { .. } => "expected_value".to_string(),
             ProbabilityExpression::Variance { .. } => "variance".to_string(),
             ProbabilityExpression::Covariance { .. } => "covariance".to_string(),
+            ProbabilityExpression::Correlation { .. } => "correlation".to_string(),
             ProbabilityExpression::Moment { .. } => "moment".to_string(),
             ProbabilityExpression::CharacteristicFunction { .. } => {
                 "characteristic_function".to_string()
@@ -463,6 +784,9 @@ impl ProbabilityExpression {
             ProbabilityExpression::MomentGeneratingFunction { .. } => {
                 "moment_generating_function".to_string()
             }
+            ProbabilityExpression::GeneratedSigmaAlgebra { .. } => {
+                "generated_sigma_algebra".to_string()
+            }
             _ => "probability_expression".to_string(),
         }
     }
@@ -606,6 +930,81 @@ impl ToTurnMath for ProbabilityExpression {
                     }],
                 }),
             },
+            ProbabilityExpression::AffineTransform {
+                variable,
+                scale,
+                shift,
+            } => affine_node(
+                random_variable_node(variable, format!("{}-rv", master_id)),
+                scale,
+                shift,
+                master_id,
+            ),
+            ProbabilityExpression::RandomVariablePower { variable, exponent } => MathNode {
+                id: master_id.clone(),
+                content: Arc::new(MathNodeContent::Power {
+                    base: Arc::new(random_variable_node(
+                        variable,
+                        format!("{}-base", master_id),
+                    )),
+                    exponent: Arc::new(MathNode {
+                        id: format!("{}-exp", master_id),
+                        content: Arc::new(MathNodeContent::Quantity {
+                            number: exponent.to_string(),
+                            scientific_notation: None,
+                            unit: None,
+                        }),
+                    }),
+                }),
+            },
+            ProbabilityExpression::Covariance {
+                variable1,
+                variable2,
+            } => binary_function_node("Cov", variable1, variable2, master_id),
+            ProbabilityExpression::Correlation {
+                variable1,
+                variable2,
+            } => binary_function_node("Corr", variable1, variable2, master_id),
+            ProbabilityExpression::RandomVariableMin { left, right } => {
+                binary_function_node("min", left, right, master_id)
+            }
+            ProbabilityExpression::RandomVariableMax { left, right } => {
+                binary_function_node("max", left, right, master_id)
+            }
+            ProbabilityExpression::ExpectedValueOf { expression } => {
+                unary_function_node("E", expression, master_id)
+            }
+            ProbabilityExpression::VarianceOf { expression } => {
+                unary_function_node("Var", expression, master_id)
+            }
+            ProbabilityExpression::ScaledExpression {
+                expression,
+                scale,
+                shift,
+            } => affine_node(
+                expression.to_turn_math(format!("{}-inner", master_id)),
+                scale,
+                shift,
+                master_id,
+            ),
+            ProbabilityExpression::ExpressionSum { left, right } => MathNode {
+                id: master_id.clone(),
+                content: Arc::new(MathNodeContent::Relationship {
+                    lhs: Arc::new(left.to_turn_math(format!("{}-left", master_id))),
+                    rhs: Arc::new(right.to_turn_math(format!("{}-right", master_id))),
+                    operator: RelationOperatorNode::Custom("+".to_string()),
+                }),
+            },
+            ProbabilityExpression::GeneratedSigmaAlgebra { generators } => {
+                let generators = generators
+                    .iter()
+                    .enumerate()
+                    .map(|(index, event)| {
+                        event_node(event, format!("{}-generator-{}", master_id, index))
+                    })
+                    .collect();
+                generated_sigma_algebra_node(generators, master_id)
+            }
             _ => MathNode {
                 id: master_id.clone(),
                 content: Arc::new(MathNodeContent::Identifier(Identifier {
@@ -621,6 +1020,243 @@ impl ToTurnMath for ProbabilityExpression {
     }
 }
 
+/// Render a random variable: variables by name, concrete ones as `X`.
+fn random_variable_node(variable: &Parametrizable<RandomVariable>, id: String) -> MathNode {
+    match variable {
+        Parametrizable::Variable(name) => name.to_turn_math(id),
+        Parametrizable::Concrete(_) => MathNode {
+            id,
+            content: Arc::new(MathNodeContent::Identifier(Identifier {
+                body: "X".to_string(),
+                pre_script: None,
+                mid_script: None,
+                post_script: None,
+                primes: 0,
+                is_function: false,
+            })),
+        },
+    }
+}
+
+/// Render an event: variables by name, concrete ones by their underlying set.
+fn event_node(event: &Parametrizable<Event>, id: String) -> MathNode {
+    match event {
+        Parametrizable::Variable(name) => name.to_turn_math(id),
+        Parametrizable::Concrete(event) => event.event_set.to_turn_math(id),
+    }
+}
+
+/// Render `σ({G₁, …, Gₙ})`, the σ-algebra generated by the given sets.
+fn generated_sigma_algebra_node(generators: Vec<MathNode>, master_id: String) -> MathNode {
+    let symbol = |body: &str, role: &str| MathNode {
+        id: format!("{}-{}", master_id, role),
+        content: Arc::new(MathNodeContent::Identifier(Identifier::new_simple(
+            body.to_string(),
+        ))),
+    };
+    let mut terms = vec![(RefinedMulOrDivOperation::None, symbol("{", "open"))];
+    for (index, generator) in generators.into_iter().enumerate() {
+        if index > 0 {
+            terms.push((
+                RefinedMulOrDivOperation::None,
+                symbol(",", &format!("comma-{}", index)),
+            ));
+        }
+        terms.push((RefinedMulOrDivOperation::None, generator));
+    }
+    terms.push((RefinedMulOrDivOperation::None, symbol("}", "close")));
+
+    MathNode {
+        id: master_id.clone(),
+        content: Arc::new(MathNodeContent::FunctionCall {
+            name: function_name_node("σ", format!("{}-sigma-name", master_id)),
+            parameters: vec![MathNode {
+                id: format!("{}-generators", master_id),
+                content: Arc::new(MathNodeContent::Multiplications { terms }),
+            }],
+        }),
+    }
+}
+
+/// Render a sequence of random variables: a single variable as itself, several
+/// as a comma-separated list.
+fn sequence_node(sequence: &[Parametrizable<RandomVariable>], id: String) -> MathNode {
+    if let [variable] = sequence {
+        return random_variable_node(variable, id);
+    }
+    let mut terms = Vec::new();
+    for (index, variable) in sequence.iter().enumerate() {
+        if index > 0 {
+            terms.push((
+                RefinedMulOrDivOperation::None,
+                MathNode {
+                    id: format!("{}-comma-{}", id, index),
+                    content: Arc::new(MathNodeContent::Identifier(Identifier {
+                        body: ",".to_string(),
+                        pre_script: None,
+                        mid_script: None,
+                        post_script: None,
+                        primes: 0,
+                        is_function: false,
+                    })),
+                },
+            ));
+        }
+        terms.push((
+            RefinedMulOrDivOperation::None,
+            random_variable_node(variable, format!("{}-{}", id, index)),
+        ));
+    }
+    MathNode {
+        id,
+        content: Arc::new(MathNodeContent::Multiplications { terms }),
+    }
+}
+
+/// The arrow for a mode of convergence, annotated with the mode: `→ᵃ·ˢ·`, `→ᵖ`,
+/// `→ᵈ`, `→ᴸ¹`, or `⇉` for uniform convergence.
+fn convergence_arrow(convergence_type: &ConvergenceType) -> &'static str {
+    match convergence_type {
+        ConvergenceType::AlmostSure => "→ᵃ·ˢ·",
+        ConvergenceType::InProbability => "→ᵖ",
+        ConvergenceType::InDistribution => "→ᵈ",
+        ConvergenceType::InMean => "→ᴸ¹",
+        ConvergenceType::Uniform => "⇉",
+    }
+}
+
+/// Render `a·body + b`, writing negative constants as `−` and leaving out a
+/// unit scale and a zero shift: `2X + 3`, `−X`, `2X − 3`.
+fn affine_node(
+    body: MathNode,
+    scale: &serde_json::Number,
+    shift: &serde_json::Number,
+    master_id: String,
+) -> MathNode {
+    let is_negative = |number: &serde_json::Number| number.as_f64().is_some_and(|x| x < 0.0);
+    let is_unit = |number: &serde_json::Number| number.as_f64().is_some_and(|x| x.abs() == 1.0);
+    let minus = |id: String| {
+        Arc::new(MathNode {
+            id,
+            content: Arc::new(MathNodeContent::Identifier(Identifier::new_simple(
+                "−".to_string(),
+            ))),
+        })
+    };
+
+    let mut term = if is_unit(scale) {
+        body
+    } else {
+        MathNode {
+            id: format!("{}-term", master_id),
+            content: Arc::new(MathNodeContent::Multiplications {
+                terms: vec![
+                    (
+                        RefinedMulOrDivOperation::None,
+                        magnitude_node(scale, format!("{}-scale", master_id)),
+                    ),
+                    (RefinedMulOrDivOperation::None, body),
+                ],
+            }),
+        }
+    };
+    if is_negative(scale) {
+        term = MathNode {
+            id: format!("{}-negated", master_id),
+            content: Arc::new(MathNodeContent::UnaryPrefixOperation {
+                parameter: Arc::new(term),
+                operator: minus(format!("{}-scale-sign", master_id)),
+            }),
+        };
+    }
+
+    if shift.as_f64() == Some(0.0) {
+        return MathNode {
+            id: master_id,
+            ..term
+        };
+    }
+    let operator = if is_negative(shift) { "−" } else { "+" };
+    MathNode {
+        id: master_id.clone(),
+        content: Arc::new(MathNodeContent::Relationship {
+            lhs: Arc::new(term),
+            rhs: Arc::new(magnitude_node(shift, format!("{}-shift", master_id))),
+            operator: RelationOperatorNode::Custom(operator.to_string()),
+        }),
+    }
+}
+
+fn number_node(number: &serde_json::Number, id: String) -> MathNode {
+    MathNode {
+        id,
+        content: Arc::new(MathNodeContent::Quantity {
+            number: number.to_string(),
+            scientific_notation: None,
+            unit: None,
+        }),
+    }
+}
+
+/// The absolute value of `number`, the sign being rendered by the caller.
+fn magnitude_node(number: &serde_json::Number, id: String) -> MathNode {
+    let number = number.to_string();
+    MathNode {
+        id,
+        content: Arc::new(MathNodeContent::Quantity {
+            number: number.trim_start_matches('-').to_string(),
+            scientific_notation: None,
+            unit: None,
+        }),
+    }
+}
+
+fn function_name_node(name: &str, id: String) -> Arc<MathNode> {
+    Arc::new(MathNode {
+        id,
+        content: Arc::new(MathNodeContent::Identifier(Identifier {
+            body: name.to_string(),
+            pre_script: None,
+            mid_script: None,
+            post_script: None,
+            primes: 0,
+            is_function: true,
+        })),
+    })
+}
+
+fn binary_function_node(
+    name: &str,
+    left: &Parametrizable<RandomVariable>,
+    right: &Parametrizable<RandomVariable>,
+    master_id: String,
+) -> MathNode {
+    MathNode {
+        id: master_id.clone(),
+        content: Arc::new(MathNodeContent::FunctionCall {
+            name: function_name_node(name, format!("{}-{}-name", master_id, name)),
+            parameters: vec![
+                random_variable_node(left, format!("{}-left", master_id)),
+                random_variable_node(right, format!("{}-right", master_id)),
+            ],
+        }),
+    }
+}
+
+fn unary_function_node(
+    name: &str,
+    expression: &ProbabilityExpression,
+    master_id: String,
+) -> MathNode {
+    MathNode {
+        id: master_id.clone(),
+        content: Arc::new(MathNodeContent::FunctionCall {
+            name: function_name_node(name, format!("{}-{}-name", master_id, name)),
+            parameters: vec![expression.to_turn_math(format!("{}-inner", master_id))],
+        }),
+    }
+}
+
 impl ToTurnMath for ProbabilityRelation {
     fn to_turn_math(&self, master_id: String) -> MathNode {
         match self {
@@ -659,6 +1295,20 @@ impl ToTurnMath for ProbabilityRelation {
                     variables.len()
                 ))),
             },
+            ProbabilityRelation::ConvergesTo {
+                sequence,
+                limit,
+                convergence_type,
+            } => MathNode {
+                id: master_id.clone(),
+                content: Arc::new(MathNodeContent::Relationship {
+                    lhs: Arc::new(sequence_node(sequence, format!("{}-sequence", master_id))),
+                    operator: RelationOperatorNode::Custom(
+                        convergence_arrow(convergence_type).to_string(),
+                    ),
+                    rhs: Arc::new(random_variable_node(limit, format!("{}-limit", master_id))),
+                }),
+            },
             _ => MathNode {
                 id: master_id.clone(),
                 content: Arc::new(MathNodeContent::Identifier(Identifier {
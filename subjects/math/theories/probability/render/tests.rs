@@ -19,4 +19,477 @@ mod tests {
         assert_eq!(exporter.theory_id(), "probability_theory");
         assert_eq!(exporter.theory_name(), "Probability Theory");
     }
+
+    #[test]
+    fn test_product_probability_space_renders_factors() {
+        use crate::subjects::math::theories::VariantSet;
+        use crate::subjects::math::theories::zfc::definitions::Set;
+        use crate::turn_render::math_node::{MathNodeContent, ToTurnMath};
+
+        // ([0, 1], ℬ([0, 1]), P) × (ℝ, ℬ(ℝ), P)
+        let borel_space = |sample_space: Set| {
+            let mut space = GenericProbabilitySpace::default();
+            space.sigma_algebra.base_set = sample_space.clone();
+            space.sigma_algebra.algebra_type = SigmaAlgebraType::Borel;
+            space.sample_space = sample_space;
+            ProbabilitySpace::Generic(space)
+        };
+        let interval = |lower: Option<i64>, upper: Option<i64>| Set::Interval {
+            lower: lower.map(Into::into),
+            upper: upper.map(Into::into),
+            lower_closed: true,
+            upper_closed: true,
+            properties: VariantSet::new(),
+        };
+        let product = ProbabilitySpace::Product(ProductProbabilitySpace {
+            core: GenericProbabilitySpace::default(),
+            factors: vec![
+                Box::new(borel_space(interval(Some(0), Some(1)))),
+                Box::new(borel_space(interval(None, None))),
+            ],
+            product_props: VariantSet::new(),
+        });
+
+        let node = product.to_turn_math("product".to_string());
+        let MathNodeContent::Bracketed { inner, .. } = node.content.as_ref() else {
+            panic!("Expected a bracketed tuple, got {:?}", node.content);
+        };
+        let MathNodeContent::Multiplications { terms } = inner.content.as_ref() else {
+            panic!("Expected the tuple components, got {:?}", inner.content);
+        };
+
+        assert_eq!(spelled(&terms[0].1), "[0,1]×ℝ");
+        assert_eq!(spelled(&terms[1].1), "ℬ([0,1])⊗ℬ(ℝ)");
+        assert_eq!(spelled(&terms[2].1), "P⊗P");
+        let MathNodeContent::Multiplications {
+            terms: sample_spaces,
+        } = terms[0].1.content.as_ref()
+        else {
+            panic!(
+                "Expected the sample space product, got {:?}",
+                terms[0].1.content
+            );
+        };
+        assert_eq!(sample_spaces[0].1.id, "product-factor-0-omega");
+        assert_eq!(sample_spaces[2].1.id, "product-factor-1-omega");
+    }
+
+    /// Flatten an arithmetic node into the text a reader would see.
+    fn spelled(node: &crate::turn_render::MathNode) -> String {
+        use crate::turn_render::math_node::{MathNodeContent, RelationOperatorNode};
+
+        match node.content.as_ref() {
+            MathNodeContent::Multiplications { terms } => {
+                terms.iter().map(|(_, term)| spelled(term)).collect()
+            }
+            MathNodeContent::Relationship {
+                lhs,
+                rhs,
+                operator: RelationOperatorNode::Custom(operator),
+            } => format!("{} {} {}", spelled(lhs), operator, spelled(rhs)),
+            MathNodeContent::UnaryPrefixOperation {
+                parameter,
+                operator,
+            } => format!("{}{}", spelled(operator), spelled(parameter)),
+            MathNodeContent::FunctionCall { name, parameters } => format!(
+                "{}({})",
+                spelled(name),
+                parameters
+                    .iter()
+                    .map(spelled)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            MathNodeContent::Quantity { number, .. } => number.clone(),
+            MathNodeContent::Identifier(identifier) => identifier.body.clone(),
+            other => panic!("Unexpected arithmetic component: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_affine_transform_rendering() {
+        use crate::subjects::math::formalism::extract::Parametrizable;
+        use crate::turn_render::Identifier;
+        use crate::turn_render::math_node::ToTurnMath;
+
+        let affine = |scale: i64, shift: i64| ProbabilityExpression::AffineTransform {
+            variable: Box::new(Parametrizable::Variable(Identifier::new_simple(
+                "X".to_string(),
+            ))),
+            scale: serde_json::Number::from(scale),
+            shift: serde_json::Number::from(shift),
+        };
+        let rendered = |expr: ProbabilityExpression| spelled(&expr.to_turn_math("affine".into()));
+
+        assert_eq!(rendered(affine(2, 3)), "2X + 3");
+        assert_eq!(rendered(affine(2, -3)), "2X − 3");
+        assert_eq!(rendered(affine(-1, 0)), "−X");
+        assert_eq!(rendered(affine(1, 4)), "X + 4");
+        assert_eq!(affine(2, -3).to_turn_math("affine".into()).id, "affine");
+    }
+
+    #[test]
+    fn test_linearity_of_expectation_and_variance() {
+        use crate::subjects::math::formalism::extract::Parametrizable;
+        use crate::turn_render::Identifier;
+        use crate::turn_render::math_node::ToTurnMath;
+
+        let x = || Parametrizable::Variable(Identifier::new_simple("X".to_string()));
+        let y = || Parametrizable::Variable(Identifier::new_simple("Y".to_string()));
+        let affine = |scale: i64, shift: i64| ProbabilityExpression::AffineTransform {
+            variable: Box::new(x()),
+            scale: serde_json::Number::from(scale),
+            shift: serde_json::Number::from(shift),
+        };
+
+        // E[2X − 3] = 2·E[X] − 3
+        let expectation = ProbabilityExpression::ExpectedValueOf {
+            expression: Box::new(affine(2, -3)),
+        }
+        .simplify();
+        assert_eq!(
+            expectation,
+            ProbabilityExpression::ScaledExpression {
+                expression: Box::new(ProbabilityExpression::ExpectedValue { variable: x() }),
+                scale: serde_json::Number::from(2),
+                shift: serde_json::Number::from(-3),
+            }
+        );
+        assert_eq!(
+            spelled(&expectation.to_turn_math("mean".into())),
+            "2E(X) − 3"
+        );
+
+        // Var(−3X + 5) = 9·Var(X)
+        let variance = ProbabilityExpression::VarianceOf {
+            expression: Box::new(affine(-3, 5)),
+        }
+        .simplify();
+        assert_eq!(
+            variance,
+            ProbabilityExpression::ScaledExpression {
+                expression: Box::new(ProbabilityExpression::Variance { variable: x() }),
+                scale: serde_json::Number::from(9),
+                shift: serde_json::Number::from(0),
+            }
+        );
+
+        // A shift alone does not change the variance
+        assert_eq!(
+            ProbabilityExpression::VarianceOf {
+                expression: Box::new(affine(1, 5)),
+            }
+            .simplify(),
+            ProbabilityExpression::Variance { variable: x() }
+        );
+
+        // E[X + Y] = E[X] + E[Y]
+        let sum = ProbabilityExpression::ExpectedValueOf {
+            expression: Box::new(ProbabilityExpression::RandomVariableSum {
+                left: Box::new(x()),
+                right: Box::new(y()),
+            }),
+        }
+        .simplify();
+        assert_eq!(
+            sum,
+            ProbabilityExpression::ExpressionSum {
+                left: Box::new(ProbabilityExpression::ExpectedValue { variable: x() }),
+                right: Box::new(ProbabilityExpression::ExpectedValue { variable: y() }),
+            }
+        );
+    }
+
+    #[test]
+    fn test_random_variable_power_rendering() {
+        use crate::subjects::math::formalism::extract::Parametrizable;
+        use crate::turn_render::Identifier;
+        use crate::turn_render::math_node::{MathNodeContent, ToTurnMath};
+
+        let expr = ProbabilityExpression::RandomVariablePower {
+            variable: Box::new(Parametrizable::Variable(Identifier::new_simple(
+                "X".to_string(),
+            ))),
+            exponent: 2,
+        };
+
+        let node = expr.to_turn_math("square".to_string());
+        let MathNodeContent::Power { exponent, .. } = node.content.as_ref() else {
+            panic!("Expected a power node, got {:?}", node.content);
+        };
+        assert!(matches!(
+            exponent.content.as_ref(),
+            MathNodeContent::Quantity { number, .. } if number == "2"
+        ));
+
+        // E[X²] simplifies to the second moment
+        let expectation = ProbabilityExpression::ExpectedValueOf {
+            expression: Box::new(expr),
+        };
+        assert!(matches!(
+            expectation.simplify(),
+            ProbabilityExpression::Moment { order: 2, .. }
+        ));
+    }
+
+    #[test]
+    fn test_covariance_and_correlation_render_their_operands() {
+        use crate::subjects::math::formalism::extract::Parametrizable;
+        use crate::turn_render::Identifier;
+        use crate::turn_render::math_node::{MathNodeContent, ToTurnMath};
+
+        let x = || Parametrizable::Variable(Identifier::new_simple("X".to_string()));
+        let y = || Parametrizable::Variable(Identifier::new_simple("Y".to_string()));
+        let rendered = |expr: ProbabilityExpression| {
+            let node = expr.to_turn_math("expr".to_string());
+            let MathNodeContent::FunctionCall { name, parameters } = node.content.as_ref() else {
+                panic!("Expected a function call, got {:?}", node.content);
+            };
+            let body = |node: &crate::turn_render::MathNode| match node.content.as_ref() {
+                MathNodeContent::Identifier(identifier) => identifier.body.clone(),
+                other => panic!("Expected an identifier, got {:?}", other),
+            };
+            (body(name), parameters.iter().map(body).collect::<Vec<_>>())
+        };
+
+        assert_eq!(
+            rendered(ProbabilityExpression::Covariance {
+                variable1: x(),
+                variable2: y(),
+            }),
+            ("Cov".to_string(), vec!["X".to_string(), "Y".to_string()])
+        );
+        assert_eq!(
+            rendered(ProbabilityExpression::Correlation {
+                variable1: y(),
+                variable2: x(),
+            }),
+            ("Corr".to_string(), vec!["Y".to_string(), "X".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_covariance_with_itself_simplifies_to_variance() {
+        use crate::subjects::math::formalism::extract::Parametrizable;
+        use crate::turn_render::Identifier;
+
+        let x = Parametrizable::Variable(Identifier::new_simple("X".to_string()));
+        let y = Parametrizable::Variable(Identifier::new_simple("Y".to_string()));
+
+        let self_covariance = ProbabilityExpression::Covariance {
+            variable1: x.clone(),
+            variable2: x.clone(),
+        };
+        assert_eq!(
+            self_covariance.simplify(),
+            ProbabilityExpression::Variance {
+                variable: x.clone()
+            }
+        );
+
+        let covariance = ProbabilityExpression::Covariance {
+            variable1: x,
+            variable2: y,
+        };
+        assert_eq!(covariance.simplify(), covariance);
+    }
+
+    #[test]
+    fn test_convergence_in_distribution_renders_both_operands() {
+        use crate::subjects::math::formalism::extract::Parametrizable;
+        use crate::turn_render::Identifier;
+        use crate::turn_render::math_node::{MathNodeContent, RelationOperatorNode, ToTurnMath};
+
+        let relation = ProbabilityRelation::ConvergesTo {
+            sequence: vec![Parametrizable::Variable(Identifier::new_simple(
+                "Xₙ".to_string(),
+            ))],
+            limit: Parametrizable::Variable(Identifier::new_simple("X".to_string())),
+            convergence_type: ConvergenceType::InDistribution,
+        };
+
+        let node = relation.to_turn_math("conv".to_string());
+        let MathNodeContent::Relationship { lhs, operator, rhs } = node.content.as_ref() else {
+            panic!("Expected a relationship, got {:?}", node.content);
+        };
+        assert!(matches!(operator, RelationOperatorNode::Custom(arrow) if arrow == "→ᵈ"));
+        for (operand, name) in [(lhs, "Xₙ"), (rhs, "X")] {
+            match operand.content.as_ref() {
+                MathNodeContent::Identifier(identifier) => assert_eq!(identifier.body, name),
+                other => panic!("Expected the random variable {}, got {:?}", name, other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_conditional_space_renders_conditioning_event() {
+        use crate::subjects::math::theories::VariantSet;
+        use crate::subjects::math::theories::zfc::definitions::Set;
+        use crate::turn_render::RichTextSegment;
+        use crate::turn_render::math_node::{MathNodeContent, ToTurnMath};
+        use std::collections::HashMap;
+
+        let generic = || Box::new(ProbabilitySpace::Generic(GenericProbabilitySpace::default()));
+        let space = ProbabilitySpace::Conditional(ConditionalProbabilitySpace {
+            core: GenericProbabilitySpace::default(),
+            original_space: generic(),
+            conditioning_event: Event {
+                event_set: Set::Parametric {
+                    parameters: HashMap::new(),
+                    description: "B".to_string(),
+                    membership_condition: "ω ∈ B".to_string(),
+                    membership_predicate: None,
+                    properties: VariantSet::new(),
+                },
+                probability_space: generic(),
+                props: VariantSet::new(),
+            },
+            conditional_props: VariantSet::new(),
+        });
+
+        let node = space.to_turn_math("cond".to_string());
+        let MathNodeContent::Bracketed { inner, .. } = node.content.as_ref() else {
+            panic!("Expected a bracketed tuple, got {:?}", node.content);
+        };
+        let MathNodeContent::Multiplications { terms } = inner.content.as_ref() else {
+            panic!("Expected the tuple components, got {:?}", inner.content);
+        };
+        let MathNodeContent::FunctionCall { parameters, .. } = terms[2].1.content.as_ref() else {
+            panic!("Expected the conditioned measure, got {:?}", terms[2].1.content);
+        };
+        let MathNodeContent::Multiplications { terms: given } = parameters[0].content.as_ref()
+        else {
+            panic!("Expected · | B, got {:?}", parameters[0].content);
+        };
+        let bodies: Vec<&str> = given
+            .iter()
+            .filter_map(|(_, n)| match n.content.as_ref() {
+                MathNodeContent::Identifier(identifier) => Some(identifier.body.as_str()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(bodies, vec!["·", "|", "B"]);
+
+        let text = |segments: Vec<RichTextSegment>| {
+            segments
+                .iter()
+                .map(|segment| match segment {
+                    RichTextSegment::Text(text) => text.clone(),
+                    RichTextSegment::Math(node) => match node.content.as_ref() {
+                        MathNodeContent::Identifier(identifier) => identifier.body.clone(),
+                        other => panic!("Expected the event name, got {:?}", other),
+                    },
+                    other => panic!("Expected text or math, got {:?}", other),
+                })
+                .collect::<String>()
+        };
+        assert_eq!(text(space.to_reference_node("cond")), "(Ω, ℱ, P(· | B))");
+        assert!(text(space.to_tooltip_node("cond")).ends_with("given B"));
+    }
+
+    #[test]
+    fn test_conditioning_on_a_finite_event_renders_its_elements() {
+        use crate::subjects::math::theories::VariantSet;
+        use crate::subjects::math::theories::zfc::definitions::{Set, SetElement};
+        use crate::turn_render::math_node::{MathNodeContent, ToTurnMath};
+
+        let generic = || Box::new(ProbabilitySpace::Generic(GenericProbabilitySpace::default()));
+        let space = ConditionalProbabilitySpace {
+            core: GenericProbabilitySpace::default(),
+            original_space: generic(),
+            conditioning_event: Event {
+                event_set: Set::singleton(SetElement::Integer(6)),
+                probability_space: generic(),
+                props: VariantSet::new(),
+            },
+            conditional_props: VariantSet::new(),
+        };
+
+        let node = space.to_turn_math("cond".to_string());
+        let MathNodeContent::Bracketed { inner, .. } = node.content.as_ref() else {
+            panic!("Expected a bracketed tuple, got {:?}", node.content);
+        };
+        let MathNodeContent::Multiplications { terms } = inner.content.as_ref() else {
+            panic!("Expected the tuple components, got {:?}", inner.content);
+        };
+        let MathNodeContent::FunctionCall { parameters, .. } = terms[2].1.content.as_ref() else {
+            panic!("Expected the conditioned measure, got {:?}", terms[2].1.content);
+        };
+        let MathNodeContent::Multiplications { terms: given } = parameters[0].content.as_ref()
+        else {
+            panic!("Expected · | {{6}}, got {:?}", parameters[0].content);
+        };
+        // The event is rendered from its set, {6}, not by a placeholder name
+        let MathNodeContent::Multiplications { terms: event } = given[2].1.content.as_ref() else {
+            panic!("Expected the braced event, got {:?}", given[2].1.content);
+        };
+        let spelled: Vec<String> = event
+            .iter()
+            .map(|(_, n)| match n.content.as_ref() {
+                MathNodeContent::Identifier(identifier) => identifier.body.clone(),
+                MathNodeContent::Quantity { number, .. } => number.clone(),
+                other => panic!("Unexpected event component: {:?}", other),
+            })
+            .collect();
+        assert_eq!(spelled, vec!["{", "6", "}"]);
+    }
+
+    #[test]
+    fn test_generated_sigma_algebra_renders_its_generators() {
+        use crate::subjects::math::formalism::extract::Parametrizable;
+        use crate::subjects::math::theories::VariantSet;
+        use crate::subjects::math::theories::zfc::definitions::Set;
+        use crate::turn_render::math_node::{MathNodeContent, ToTurnMath};
+        use crate::turn_render::{Identifier, MathNode};
+        use std::collections::HashMap;
+
+        let generators = |node: MathNode| {
+            let MathNodeContent::FunctionCall { name, parameters } = node.content.as_ref() else {
+                panic!("Expected σ(…), got {:?}", node.content);
+            };
+            assert!(matches!(
+                name.content.as_ref(),
+                MathNodeContent::Identifier(identifier) if identifier.body == "σ"
+            ));
+            let MathNodeContent::Multiplications { terms } = parameters[0].content.as_ref() else {
+                panic!(
+                    "Expected the generating collection, got {:?}",
+                    parameters[0].content
+                );
+            };
+            terms
+                .iter()
+                .map(|(_, n)| match n.content.as_ref() {
+                    MathNodeContent::Identifier(identifier) => identifier.body.clone(),
+                    other => panic!("Expected an identifier, got {:?}", other),
+                })
+                .collect::<Vec<_>>()
+        };
+        let expected = vec!["{", "A", ",", "B", "}"];
+
+        let event = |name: &str| Parametrizable::Variable(Identifier::new_simple(name.to_string()));
+        let expr = ProbabilityExpression::GeneratedSigmaAlgebra {
+            generators: vec![event("A"), event("B")],
+        };
+        assert_eq!(generators(expr.to_turn_math("sigma".to_string())), expected);
+
+        let set = |name: &str| Set::Parametric {
+            parameters: HashMap::new(),
+            description: name.to_string(),
+            membership_condition: format!("ω ∈ {}", name),
+            membership_predicate: None,
+            properties: VariantSet::new(),
+        };
+        let sigma_algebra = SigmaAlgebra {
+            base_set: Set::Empty,
+            algebra_type: SigmaAlgebraType::Generated {
+                generators: vec![set("A"), set("B")],
+            },
+            props: VariantSet::new(),
+        };
+        assert_eq!(
+            generators(sigma_algebra.to_turn_math("sigma".to_string())),
+            expected
+        );
+    }
 }
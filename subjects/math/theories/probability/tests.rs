@@ -80,12 +80,14 @@ mod tests {
                     parameters: std::collections::HashMap::new(),
                     description: "Natural numbers".to_string(),
                     membership_condition: "n ∈ ℕ".to_string(),
+                    membership_predicate: None,
                     properties: crate::subjects::math::theories::VariantSet::new(),
                 },
                 state_space: crate::subjects::math::theories::zfc::definitions::Set::Parametric {
                     parameters: std::collections::HashMap::new(),
                     description: "Binary states".to_string(),
                     membership_condition: "s ∈ {0,1}".to_string(),
+                    membership_predicate: None,
                     properties: crate::subjects::math::theories::VariantSet::new(),
                 },
                 process_type: StochasticProcessType::Markov,
@@ -95,6 +97,7 @@ mod tests {
                 parameters: std::collections::HashMap::new(),
                 description: "Binary states".to_string(),
                 membership_condition: "s ∈ {0,1}".to_string(),
+                membership_predicate: None,
                 properties: crate::subjects::math::theories::VariantSet::new(),
             },
             transition_matrix,
@@ -108,6 +111,7 @@ mod tests {
                             parameters: std::collections::HashMap::new(),
                             description: "Real numbers".to_string(),
                             membership_condition: "x ∈ ℝ".to_string(),
+                            membership_predicate: None,
                             properties: crate::subjects::math::theories::VariantSet::new(),
                         },
                     variable_type: RandomVariableType::Discrete,
@@ -155,6 +159,7 @@ mod tests {
                     parameters: std::collections::HashMap::new(),
                     description: "Real numbers".to_string(),
                     membership_condition: "x ∈ ℝ".to_string(),
+                    membership_predicate: None,
                     properties: crate::subjects::math::theories::VariantSet::new(),
                 },
                 variable_type: RandomVariableType::Discrete,
@@ -178,4 +183,104 @@ mod tests {
         };
         assert!(validate_distribution(&valid_dist).is_ok());
     }
+
+    fn binary_state_set() -> crate::subjects::math::theories::zfc::definitions::Set {
+        crate::subjects::math::theories::zfc::definitions::Set::Parametric {
+            parameters: std::collections::HashMap::new(),
+            description: "Binary states".to_string(),
+            membership_condition: "s ∈ {0,1}".to_string(),
+            membership_predicate: None,
+            properties: crate::subjects::math::theories::VariantSet::new(),
+        }
+    }
+
+    fn two_state_chain() -> MarkovChain {
+        MarkovChain {
+            core: StochasticProcess {
+                core: GenericProbabilitySpace::default(),
+                index_set: binary_state_set(),
+                state_space: binary_state_set(),
+                process_type: StochasticProcessType::Markov,
+                process_props: crate::subjects::math::theories::VariantSet::new(),
+            },
+            state_space: binary_state_set(),
+            transition_matrix: TransitionMatrix::Finite(vec![
+                vec![
+                    Number::from_f64(0.9).unwrap(),
+                    Number::from_f64(0.1).unwrap(),
+                ],
+                vec![
+                    Number::from_f64(0.5).unwrap(),
+                    Number::from_f64(0.5).unwrap(),
+                ],
+            ]),
+            initial_distribution: Distribution {
+                random_variable: Box::new(RandomVariable {
+                    probability_space: Box::new(ProbabilitySpace::Generic(
+                        GenericProbabilitySpace::default(),
+                    )),
+                    target_space: binary_state_set(),
+                    variable_type: RandomVariableType::Discrete,
+                    props: crate::subjects::math::theories::VariantSet::new(),
+                }),
+                distribution_type: DistributionType::Discrete(
+                    DiscreteDistributionVariant::Bernoulli,
+                ),
+                parameters: DistributionParameters {
+                    parameters: std::collections::HashMap::new(),
+                    constraints: vec![],
+                },
+                props: crate::subjects::math::theories::VariantSet::new(),
+            },
+            markov_props: crate::subjects::math::theories::VariantSet::new(),
+        }
+    }
+
+    #[test]
+    fn test_markov_chain_two_step_matrix() {
+        let chain = two_state_chain();
+        let two_step = chain.n_step(2).expect("finite chain has an n-step matrix");
+        assert!((two_step[0][0] - 0.86).abs() < 1e-9);
+        assert!((two_step[1][1] - 0.30).abs() < 1e-9);
+        for row in &two_step {
+            assert!((row.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_markov_chain_renders_its_transition_matrix() {
+        use crate::turn_render::math_node::{MathNodeContent, RelationOperatorNode, ToTurnMath};
+
+        let node = two_state_chain().to_turn_math("chain".to_string());
+        let MathNodeContent::Relationship {
+            lhs,
+            rhs,
+            operator: RelationOperatorNode::Equal,
+        } = node.content.as_ref()
+        else {
+            panic!("Expected P = [...], got {:?}", node.content);
+        };
+        assert!(matches!(
+            lhs.content.as_ref(),
+            MathNodeContent::Identifier(identifier) if identifier.body == "P"
+        ));
+        let MathNodeContent::Bracketed { inner, .. } = rhs.content.as_ref() else {
+            panic!("Expected a bracketed matrix, got {:?}", rhs.content);
+        };
+        let MathNodeContent::Matrix { rows } = inner.content.as_ref() else {
+            panic!("Expected a matrix node, got {:?}", inner.content);
+        };
+        let entries: Vec<Vec<String>> = rows
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|entry| match entry.content.as_ref() {
+                        MathNodeContent::Quantity { number, .. } => number.clone(),
+                        other => panic!("Expected a number, got {:?}", other),
+                    })
+                    .collect()
+            })
+            .collect();
+        assert_eq!(entries, vec![vec!["0.9", "0.1"], vec!["0.5", "0.5"]]);
+    }
 }
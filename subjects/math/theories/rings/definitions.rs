@@ -4,6 +4,7 @@ use crate::turn_render::Identifier;
 use super::super::super::super::math::formalism::expressions::MathExpression;
 use super::super::super::super::math::formalism::expressions::TheoryExpression;
 use super::super::super::super::math::theories::VariantSet;
+#[cfg(feature = "groups")]
 use super::super::super::super::math::theories::groups::definitions::Group;
 use super::super::super::super::math::theories::zfc::definitions::Set;
 use serde::{Deserialize, Serialize};
@@ -22,6 +22,8 @@ impl GetAbstractionLevel for Set {
                 }
             }
             Set::Empty => AbstractionLevel::Level4,
+            // Intervals are concrete subsets of ℝ given by their endpoints
+            Set::Interval { .. } => AbstractionLevel::Level4,
             // The WellKnown variant has been removed from the Set enum
             // Set::WellKnown(..) => AbstractionLevel::Level4,
             Set::Singleton { element, .. } => {
@@ -30,6 +30,9 @@ impl Complexity for Set {
             } => 1 + source.complexity(),
             Set::OrderedPair { first, second, .. } => 1 + first.complexity() + second.complexity(),
             Set::Complement { set, universe, .. } => 1 + set.complexity() + universe.complexity(),
+            Set::Interval { lower, upper, .. } => {
+                1 + lower.is_some() as usize + upper.is_some() as usize
+            }
             Set::Parametric {
                 parameters,
                 description,
@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use serde_json::Number;
 
 use crate::turn_render::Identifier;
 
@@ -33,6 +34,75 @@ pub enum SetElement {
     Urelement(String),
 }
 
+/// A structured membership condition for parametric sets
+/// Evaluated by [`Set::test_membership`]; elements it cannot classify (e.g. symbols) are undecided
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq, Hash)]
+pub enum MembershipPredicate {
+    /// The element is an integer
+    IsInteger,
+    /// The element is an integer congruent to `remainder` modulo `modulus`
+    IntegerCongruence { modulus: i64, remainder: i64 },
+    /// The element is an integer within the inclusive bounds; a missing bound is unbounded
+    IntegerRange { min: Option<i64>, max: Option<i64> },
+    /// All predicates hold
+    And(Vec<MembershipPredicate>),
+    /// At least one predicate holds
+    Or(Vec<MembershipPredicate>),
+    /// The predicate does not hold
+    Not(Box<MembershipPredicate>),
+}
+
+impl MembershipPredicate {
+    /// Decides the predicate for an element, or returns `None` if it cannot be decided.
+    pub fn evaluate(&self, element: &SetElement) -> Option<bool> {
+        match self {
+            MembershipPredicate::IsInteger => decide_for_integer(element, |_| Some(true)),
+            MembershipPredicate::IntegerCongruence { modulus, remainder } => {
+                decide_for_integer(element, |n| {
+                    (*modulus != 0)
+                        .then(|| n.rem_euclid(*modulus) == remainder.rem_euclid(*modulus))
+                })
+            }
+            MembershipPredicate::IntegerRange { min, max } => decide_for_integer(element, |n| {
+                Some(min.is_none_or(|min| n >= min) && max.is_none_or(|max| n <= max))
+            }),
+            MembershipPredicate::And(predicates) => {
+                let mut result = Some(true);
+                for predicate in predicates {
+                    match predicate.evaluate(element) {
+                        Some(false) => return Some(false),
+                        Some(true) => {}
+                        None => result = None,
+                    }
+                }
+                result
+            }
+            MembershipPredicate::Or(predicates) => {
+                let mut result = Some(false);
+                for predicate in predicates {
+                    match predicate.evaluate(element) {
+                        Some(true) => return Some(true),
+                        Some(false) => {}
+                        None => result = None,
+                    }
+                }
+                result
+            }
+            MembershipPredicate::Not(predicate) => predicate.evaluate(element).map(|holds| !holds),
+        }
+    }
+}
+
+/// Applies an integer test to an element: sets and pairs are never integers,
+/// while symbols and urelements may stand for one, so they stay undecided.
+fn decide_for_integer(element: &SetElement, test: impl Fn(i64) -> Option<bool>) -> Option<bool> {
+    match element {
+        SetElement::Integer(n) => test(*n),
+        SetElement::Set(_) | SetElement::Pair(..) => Some(false),
+        SetElement::Symbol(_) | SetElement::Urelement(_) => None,
+    }
+}
+
 /// A condition for set elements
 /// Used in the Separation axiom to define subsets
 #[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
@@ -171,6 +241,31 @@ pub enum CardinalityPropertyVariant {
     LargerCardinal(usize),
 }
 
+/// The size of a concrete set as far as it can be determined from its structure
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Cardinality {
+    /// A finite set with exactly this many elements
+    Finite(usize),
+    /// ℵ₀ - the set can be put in bijection with ℕ
+    CountablyInfinite,
+    /// Any infinite cardinality larger than ℵ₀, e.g. 2^ℵ₀
+    Uncountable,
+    /// The structure does not determine the size (generic or parametric sets, or
+    /// finite sets whose exact size depends on which elements coincide)
+    Unknown,
+}
+
+impl From<&CardinalityPropertyVariant> for Cardinality {
+    fn from(variant: &CardinalityPropertyVariant) -> Self {
+        match variant {
+            CardinalityPropertyVariant::Finite(n) => Cardinality::Finite(*n),
+            CardinalityPropertyVariant::CountablyInfinite => Cardinality::CountablyInfinite,
+            CardinalityPropertyVariant::ContinuumSize
+            | CardinalityPropertyVariant::LargerCardinal(_) => Cardinality::Uncountable,
+        }
+    }
+}
+
 // impl Hash for VariantSet<SetProperty> {
 //     fn hash<H: Hasher>(&self, state: &mut H) {
 //         // Convert the HashSet to a Vec, sort it, and hash the sorted Vec
@@ -392,6 +487,21 @@ pub enum Set {
         op_properties: VariantSet<SetOpProperty>,
     },
 
+    /// An interval of real numbers, e.g. [a, b], (a, b), [a, b) or (a, b]
+    /// A missing bound stands for ±∞, so (-∞, ∞) is the real line ℝ
+    Interval {
+        /// Lower endpoint (None means -∞)
+        lower: Option<Number>,
+        /// Upper endpoint (None means +∞)
+        upper: Option<Number>,
+        /// Whether the lower endpoint belongs to the interval
+        lower_closed: bool,
+        /// Whether the upper endpoint belongs to the interval
+        upper_closed: bool,
+        /// Properties of the interval
+        properties: VariantSet<SetProperty>,
+    },
+
     /// A parametric set defined by parameters and a membership condition
     /// Examples include Z_n, S_n, GL(n,F), etc.
     Parametric {
@@ -403,6 +513,10 @@ pub enum Set {
         description: String,
         /// Condition for membership in the set
         membership_condition: String,
+        /// Decidable form of the membership condition, when one is known
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[serde(default)]
+        membership_predicate: Option<MembershipPredicate>,
         /// Properties of the parametric set
         properties: VariantSet<SetProperty>,
     },
@@ -824,9 +938,23 @@ impl Hash for Set {
                 properties.hash(state);
                 op_properties.hash(state);
             }
+            Set::Interval {
+                lower,
+                upper,
+                lower_closed,
+                upper_closed,
+                properties,
+            } => {
+                lower.hash(state);
+                upper.hash(state);
+                lower_closed.hash(state);
+                upper_closed.hash(state);
+                properties.hash(state);
+            }
             Set::Parametric {
                 description,
                 membership_condition,
+                membership_predicate,
                 properties,
                 parameters,
             } => {
@@ -840,6 +968,7 @@ impl Hash for Set {
 
                 description.hash(state);
                 membership_condition.hash(state);
+                membership_predicate.hash(state);
                 properties.hash(state);
             }
         }
@@ -1263,6 +1392,7 @@ impl Set {
             // Base cases: L1, L2, or L4 non-reducible forms
             Set::Generic { .. } => self.clone(),
             Set::Empty => self.clone(),
+            Set::Interval { .. } => self.clone(),
             Set::Singleton {
                 element,
                 properties,
@@ -1305,12 +1435,7 @@ impl Set {
                     properties: new_props,
                 }
             }
-            Set::Parametric {
-                parameters,
-                description,
-                membership_condition,
-                properties,
-            } => self.clone(),
+            Set::Parametric { .. } => self.clone(),
         }
     }
 
@@ -1366,6 +1491,117 @@ impl Set {
         }
     }
 
+    /// Creates the real interval between `lower` and `upper`; `None` leaves that side unbounded.
+    pub fn interval(
+        lower: Option<Number>,
+        upper: Option<Number>,
+        lower_closed: bool,
+        upper_closed: bool,
+    ) -> Self {
+        // An infinite endpoint is never a member, whatever the caller asked for
+        let lower_closed = lower_closed && lower.is_some();
+        let upper_closed = upper_closed && upper.is_some();
+
+        let mut properties = VariantSet::new();
+        let bounds = (
+            lower.as_ref().and_then(Number::as_f64),
+            upper.as_ref().and_then(Number::as_f64),
+        );
+        match bounds {
+            (Some(a), Some(b)) if a > b || (a == b && !(lower_closed && upper_closed)) => {
+                properties.insert(SetProperty::IsEmpty(true));
+                properties.insert(SetProperty::IsFinite(true));
+                properties.insert(SetProperty::Cardinality(
+                    CardinalityPropertyVariant::Finite(0),
+                ));
+            }
+            (Some(a), Some(b)) if a == b => {
+                properties.insert(SetProperty::IsEmpty(false));
+                properties.insert(SetProperty::IsFinite(true));
+                properties.insert(SetProperty::Cardinality(
+                    CardinalityPropertyVariant::Finite(1),
+                ));
+            }
+            _ => {
+                properties.insert(SetProperty::IsEmpty(false));
+                properties.insert(SetProperty::IsFinite(false));
+                properties.insert(SetProperty::IsCountable(false));
+                properties.insert(SetProperty::Cardinality(
+                    CardinalityPropertyVariant::ContinuumSize,
+                ));
+            }
+        }
+
+        Set::Interval {
+            lower,
+            upper,
+            lower_closed,
+            upper_closed,
+            properties,
+        }
+    }
+
+    /// The real line ℝ = (-∞, ∞)
+    pub fn reals() -> Self {
+        Set::interval(None, None, false, false)
+    }
+
+    /// Returns true if this set is the whole real line
+    pub fn is_reals(&self) -> bool {
+        matches!(
+            self,
+            Set::Interval {
+                lower: None,
+                upper: None,
+                ..
+            }
+        )
+    }
+
+    /// Checks whether the real number `x` belongs to this set.
+    /// Only sets whose membership is decidable from concrete data are handled;
+    /// anything else conservatively reports `false`.
+    pub fn contains_real(&self, x: f64) -> bool {
+        match self {
+            Set::Interval {
+                lower,
+                upper,
+                lower_closed,
+                upper_closed,
+                ..
+            } => {
+                let above_lower = match lower.as_ref().and_then(Number::as_f64) {
+                    Some(a) if *lower_closed => x >= a,
+                    Some(a) => x > a,
+                    None => lower.is_none(),
+                };
+                let below_upper = match upper.as_ref().and_then(Number::as_f64) {
+                    Some(b) if *upper_closed => x <= b,
+                    Some(b) => x < b,
+                    None => upper.is_none(),
+                };
+                above_lower && below_upper
+            }
+            Set::Singleton { element, .. } => {
+                matches!(element, SetElement::Integer(n) if *n as f64 == x)
+            }
+            Set::Enumeration { elements, .. } => elements
+                .iter()
+                .any(|e| matches!(e, SetElement::Integer(n) if *n as f64 == x)),
+            Set::BinaryUnion { left, right, .. } => left.contains_real(x) || right.contains_real(x),
+            Set::BinaryIntersection { left, right, .. } => {
+                left.contains_real(x) && right.contains_real(x)
+            }
+            Set::SetDifference { left, right, .. } => {
+                left.contains_real(x) && !right.contains_real(x)
+            }
+            Set::Complement { set, universe, .. } => {
+                universe.contains_real(x) && !set.contains_real(x)
+            }
+            _ => false,
+        }
+    }
+
     pub fn union(self, other: Set) -> Self {
         let mut initial_props = VariantSet::new();
         let self_props = self.get_properties();
@@ -1417,6 +1653,7 @@ impl Set {
             | Set::Replacement { properties, .. }
             | Set::OrderedPair { properties, .. }
             | Set::Complement { properties, .. }
+            | Set::Interval { properties, .. }
             | Set::Parametric { properties, .. } => Some(properties),
             Set::Empty => Some(&LAZY_EMPTY_PROPERTIES),
         }
@@ -1426,11 +1663,14 @@ impl Set {
     pub fn contains(&self, element: &SetElement) -> bool {
         match self {
             Set::Empty => false,
+            // Set-valued elements compare extensionally, not by construction
             Set::Singleton {
                 element: set_element,
                 ..
-            } => set_element == element,
-            Set::Enumeration { elements, .. } => elements.contains(element),
+            } => super::element_eq(set_element, element),
+            Set::Enumeration { elements, .. } => {
+                elements.iter().any(|e| super::element_eq(e, element))
+            }
             Set::BinaryUnion { left, right, .. } => {
                 left.contains(element) || right.contains(element)
             }
@@ -1570,8 +1810,26 @@ impl Set {
             Set::Complement { set, universe, .. } => {
                 universe.contains(element) && !set.contains(element)
             }
+            Set::Interval { .. } => match element {
+                SetElement::Integer(n) => self.contains_real(*n as f64),
+                _ => false,
+            },
             Set::Generic { .. } => false, // Abstract sets don't have concrete elements
-            Set::Parametric { .. } => false, // Parametric sets need specific membership logic
+            Set::Parametric { .. } => self.test_membership(element).unwrap_or(false),
+        }
+    }
+
+    /// Decides membership in a parametric set from its structured predicate.
+    ///
+    /// Returns `None` when the set only has the string form of its condition, when the
+    /// predicate cannot classify the element, or when the set is not parametric.
+    pub fn test_membership(&self, element: &SetElement) -> Option<bool> {
+        match self {
+            Set::Parametric {
+                membership_predicate: Some(predicate),
+                ..
+            } => predicate.evaluate(element),
+            _ => None,
         }
     }
 }
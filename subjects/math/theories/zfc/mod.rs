@@ -14,7 +14,7 @@ pub mod definitions;
 pub mod replace;
 pub mod verifier;
 
-use definitions::{Set, SetElement};
+use definitions::{Cardinality, Set, SetElement, SetProperty};
 
 // Import VariantSet directly from the theories module
 use super::super::theories::VariantSet;
@@ -173,7 +173,11 @@ impl Set {
         }
     }
 
-    /// Returns the number of elements in the set
+    /// Returns the number of elements in the set.
+    ///
+    /// Only explicitly listed elements are counted, so infinite, parametric and
+    /// derived sets report 0. Use [`Set::cardinality_kind`] when the set may not be
+    /// a finite enumeration.
     pub fn len(&self) -> usize {
         self.elements().len()
     }
@@ -200,7 +204,7 @@ impl Set {
         visited.insert(ptr);
 
         let result = match self {
-            Set::Empty | Set::Generic(_) | Set::Parametric { .. } => true,
+            Set::Empty | Set::Generic(_) | Set::Interval { .. } | Set::Parametric { .. } => true,
             Set::Singleton { element, .. } => is_element_well_founded_recursive(element, visited),
             Set::Enumeration { elements, .. } => elements
                 .iter()
@@ -234,11 +238,105 @@ impl Set {
         result
     }
 
-    /// Returns the cardinality of this set
+    /// Returns the cardinality of this set.
+    ///
+    /// This is [`Set::len`] and carries the same limitation: it is only meaningful
+    /// for finite enumerations. Use [`Set::cardinality_kind`] otherwise.
     pub fn cardinality(&self) -> usize {
         self.len()
     }
 
+    /// Returns true if the structure of this set shows it to be finite
+    pub fn is_finite(&self) -> bool {
+        matches!(self.cardinality_kind(), Cardinality::Finite(_))
+    }
+
+    /// Determines the cardinality of this set from its structure, falling back to
+    /// the recorded properties when the structure alone does not decide it.
+    pub fn cardinality_kind(&self) -> Cardinality {
+        let structural = match self {
+            Set::Empty => Cardinality::Finite(0),
+            Set::Singleton { .. } => Cardinality::Finite(1),
+            Set::Enumeration { elements, .. } => Cardinality::Finite(elements.len()),
+            Set::OrderedPair { first, second, .. } => {
+                // (a,b) = {{a}, {a,b}} collapses to {{a}} when a = b
+                Cardinality::Finite(if first.set_eq(second) { 1 } else { 2 })
+            }
+            Set::PowerSet { base, .. } => match base.cardinality_kind() {
+                Cardinality::Finite(n) => u32::try_from(n)
+                    .ok()
+                    .and_then(|n| 1usize.checked_shl(n))
+                    .map_or(Cardinality::Unknown, Cardinality::Finite),
+                Cardinality::CountablyInfinite | Cardinality::Uncountable => {
+                    Cardinality::Uncountable
+                }
+                Cardinality::Unknown => Cardinality::Unknown,
+            },
+            Set::CartesianProduct { left, right, .. } => {
+                match (left.cardinality_kind(), right.cardinality_kind()) {
+                    (Cardinality::Finite(0), _) | (_, Cardinality::Finite(0)) => {
+                        Cardinality::Finite(0)
+                    }
+                    (Cardinality::Finite(a), Cardinality::Finite(b)) => a
+                        .checked_mul(b)
+                        .map_or(Cardinality::Unknown, Cardinality::Finite),
+                    (Cardinality::Unknown, _) | (_, Cardinality::Unknown) => Cardinality::Unknown,
+                    (Cardinality::Uncountable, _) | (_, Cardinality::Uncountable) => {
+                        Cardinality::Uncountable
+                    }
+                    _ => Cardinality::CountablyInfinite,
+                }
+            }
+            Set::BinaryUnion { left, right, .. } => {
+                match (left.cardinality_kind(), right.cardinality_kind()) {
+                    (Cardinality::Uncountable, _) | (_, Cardinality::Uncountable) => {
+                        Cardinality::Uncountable
+                    }
+                    (Cardinality::Unknown, _) | (_, Cardinality::Unknown) => Cardinality::Unknown,
+                    (Cardinality::CountablyInfinite, _) | (_, Cardinality::CountablyInfinite) => {
+                        Cardinality::CountablyInfinite
+                    }
+                    // Overlapping elements make the exact size depend on the contents
+                    (Cardinality::Finite(0), other) | (other, Cardinality::Finite(0)) => other,
+                    (Cardinality::Finite(_), Cardinality::Finite(_)) => Cardinality::Unknown,
+                }
+            }
+            _ => Cardinality::Unknown,
+        };
+
+        match structural {
+            Cardinality::Unknown => self.recorded_cardinality(),
+            known => known,
+        }
+    }
+
+    /// Reads the cardinality off the set's properties, if they record one
+    fn recorded_cardinality(&self) -> Cardinality {
+        let Some(properties) = self.get_properties() else {
+            return Cardinality::Unknown;
+        };
+        if let Some(recorded) = properties.iter().find_map(|property| match property {
+            SetProperty::Cardinality(variant) => Some(Cardinality::from(variant)),
+            _ => None,
+        }) {
+            return recorded;
+        }
+
+        let is_finite = properties.iter().find_map(|property| match property {
+            SetProperty::IsFinite(finite) => Some(*finite),
+            _ => None,
+        });
+        let is_countable = properties.iter().find_map(|property| match property {
+            SetProperty::IsCountable(countable) => Some(*countable),
+            _ => None,
+        });
+        match (is_finite, is_countable) {
+            (Some(false), Some(true)) => Cardinality::CountablyInfinite,
+            (_, Some(false)) => Cardinality::Uncountable,
+            _ => Cardinality::Unknown,
+        }
+    }
+
     /// Returns true if this set is a subset of another set
     pub fn is_subset_of(&self, other: &Set) -> bool {
         match (self, other) {
@@ -300,6 +398,10 @@ impl Set {
             (Set::Generic(_), _) => false,
 
             // Singleton set cases
+            (Set::Singleton { element, .. }, Set::Singleton { element: other, .. }) => {
+                // Fast path: {a} ⊆ {b} iff a = b
+                element_eq(element, other)
+            }
             (Set::Singleton { element, .. }, other) => other.contains(element),
 
             // Enumeration cases
@@ -463,6 +565,158 @@ impl Set {
         // Simplified implementation
         Set::Empty // Placeholder
     }
+
+    /// Extensional equality: two sets are equal when they have the same members.
+    /// Enumerable sets are compared element-wise, ignoring order, duplicates and
+    /// cached properties; other constructions fall back to structural equality
+    /// after evaluation.
+    pub fn set_eq(&self, other: &Set) -> bool {
+        if self == other {
+            return true;
+        }
+        let (left, right) = (self.evaluate(), other.evaluate());
+        let enumerable = |s: &Set| {
+            matches!(
+                s,
+                Set::Empty | Set::Singleton { .. } | Set::Enumeration { .. }
+            )
+        };
+        if enumerable(&left) && enumerable(&right) {
+            let (left_elements, right_elements) = (left.elements(), right.elements());
+            left_elements
+                .iter()
+                .all(|a| right_elements.iter().any(|b| element_eq(a, b)))
+                && right_elements
+                    .iter()
+                    .all(|b| left_elements.iter().any(|a| element_eq(a, b)))
+        } else {
+            left == right
+        }
+    }
+
+    /// Simplifies set-difference identities until nothing changes:
+    /// - A \ A = ∅
+    /// - A \ ∅ = A
+    /// - ∅ \ A = ∅
+    /// - A ∩ (A \ B) = A \ B (and (A \ B) ∩ A = A \ B)
+    pub fn simplify(&self) -> Set {
+        let mut current = self.clone();
+        loop {
+            let next = current.simplify_once();
+            if next == current {
+                return current;
+            }
+            current = next;
+        }
+    }
+
+    fn simplify_once(&self) -> Set {
+        match self {
+            Set::SetDifference {
+                left,
+                right,
+                properties,
+                op_properties,
+            } => {
+                let (left, right) = (left.simplify_once(), right.simplify_once());
+                if left.set_eq(&right) || left.set_eq(&Set::Empty) {
+                    Set::Empty
+                } else if right.set_eq(&Set::Empty) {
+                    left
+                } else {
+                    Set::SetDifference {
+                        left: Box::new(left),
+                        right: Box::new(right),
+                        properties: properties.clone(),
+                        op_properties: op_properties.clone(),
+                    }
+                }
+            }
+            Set::BinaryIntersection {
+                left,
+                right,
+                properties,
+                op_properties,
+            } => {
+                let (left, right) = (left.simplify_once(), right.simplify_once());
+                match (&left, &right) {
+                    (a, Set::SetDifference { left: minuend, .. }) if a.set_eq(minuend) => right,
+                    (Set::SetDifference { left: minuend, .. }, a) if a.set_eq(minuend) => left,
+                    _ => Set::BinaryIntersection {
+                        left: Box::new(left),
+                        right: Box::new(right),
+                        properties: properties.clone(),
+                        op_properties: op_properties.clone(),
+                    },
+                }
+            }
+            Set::BinaryUnion {
+                left,
+                right,
+                properties,
+                op_properties,
+            } => Set::BinaryUnion {
+                left: Box::new(left.simplify_once()),
+                right: Box::new(right.simplify_once()),
+                properties: properties.clone(),
+                op_properties: op_properties.clone(),
+            },
+            Set::SymmetricDifference {
+                left,
+                right,
+                properties,
+                op_properties,
+            } => Set::SymmetricDifference {
+                left: Box::new(left.simplify_once()),
+                right: Box::new(right.simplify_once()),
+                properties: properties.clone(),
+                op_properties: op_properties.clone(),
+            },
+            Set::CartesianProduct {
+                left,
+                right,
+                properties,
+                op_properties,
+            } => Set::CartesianProduct {
+                left: Box::new(left.simplify_once()),
+                right: Box::new(right.simplify_once()),
+                properties: properties.clone(),
+                op_properties: op_properties.clone(),
+            },
+            Set::PowerSet {
+                base,
+                properties,
+                op_properties,
+            } => Set::PowerSet {
+                base: Box::new(base.simplify_once()),
+                properties: properties.clone(),
+                op_properties: op_properties.clone(),
+            },
+            Set::Complement {
+                set,
+                universe,
+                properties,
+                op_properties,
+            } => Set::Complement {
+                set: Box::new(set.simplify_once()),
+                universe: Box::new(universe.simplify_once()),
+                properties: properties.clone(),
+                op_properties: op_properties.clone(),
+            },
+            _ => self.clone(),
+        }
+    }
+}
+
+/// Element equality that compares nested sets extensionally.
+fn element_eq(a: &SetElement, b: &SetElement) -> bool {
+    match (a, b) {
+        (SetElement::Set(x), SetElement::Set(y)) => x.set_eq(y),
+        (SetElement::Pair(a1, a2), SetElement::Pair(b1, b2)) => {
+            element_eq(a1, b1) && element_eq(a2, b2)
+        }
+        _ => a == b,
+    }
 }
 
 #[cfg(test)]
@@ -1,4 +1,5 @@
 // This AbstractionLevel is for the GetAbstractionLevel trait implementations for ZFC types.
+use crate::subjects::math::formalism::render::options::{RenderOptions, ToTurnMathConfigurable};
 use crate::subjects::math::formalism::traits::abstraction_level::{
     AbstractionLevel, GetAbstractionLevel,
 };
@@ -8,6 +9,7 @@ use crate::subjects::math::theories::zfc::definitions::{
     SetProperty, SetRelation,
 };
 use crate::turn_render::*;
+use std::sync::Arc;
 
 impl ToSectionNode for Set {
     fn to_section_node(&self, id_prefix: &str) -> Section {
@@ -93,6 +95,30 @@ impl ToSectionNode for Set {
                     });
                 }
             }
+            Set::Interval { properties, .. } => {
+                title_text = if self.is_reals() {
+                    "The Real Line".to_string()
+                } else {
+                    "Interval".to_string()
+                };
+                content_nodes.push(SectionContentNode::RichText(RichText {
+                    segments: vec![RichTextSegment::Math(
+                        self.to_turn_math(format!("{}-interval", id_prefix)),
+                    )],
+                    alignment: None,
+                }));
+                for prop in properties.iter() {
+                    let (prop_name, current_variant, all_variants) = property_to_selectable(prop);
+                    selectable_props.push(SelectableProperty {
+                        name: prop_name,
+                        current_variant,
+                        all_variants,
+                        description: None,
+                        variant_descriptions: None,
+                        property_type_def_id: None,
+                    });
+                }
+            }
             // TODO: Implement for all other Set variants, detailing their construction based on display_level
             _ => {
                 title_text = format!("Set defined by construction: {:?}", self)
@@ -173,6 +199,396 @@ impl ToSectionNode for Set {
     // }
 }
 
+impl ToTurnMath for Set {
+    fn to_turn_math(&self, master_id: String) -> MathNode {
+        match self {
+            Set::Empty => MathNode {
+                id: master_id,
+                content: Arc::new(MathNodeContent::Identifier(Identifier::new_simple(
+                    "∅".to_string(),
+                ))),
+            },
+            Set::Interval {
+                lower: None,
+                upper: None,
+                ..
+            } => MathNode {
+                id: master_id,
+                content: Arc::new(MathNodeContent::Identifier(Identifier::new_simple(
+                    "ℝ".to_string(),
+                ))),
+            },
+            Set::Interval {
+                lower,
+                upper,
+                lower_closed,
+                upper_closed,
+                ..
+            } => {
+                // Mixed brackets such as [a, b) have no bracket style of their own,
+                // so the delimiters are laid out as symbols around the endpoints.
+                let symbol = |text: &str, role: &str| MathNode {
+                    id: format!("{}-{}", master_id, role),
+                    content: Arc::new(MathNodeContent::Identifier(Identifier::new_simple(
+                        text.to_string(),
+                    ))),
+                };
+                let endpoint =
+                    |bound: &Option<serde_json::Number>, infinity: &str, role: &str| match bound {
+                        Some(number) => MathNode {
+                            id: format!("{}-{}", master_id, role),
+                            content: Arc::new(MathNodeContent::Quantity {
+                                number: number.to_string(),
+                                scientific_notation: None,
+                                unit: None,
+                            }),
+                        },
+                        None => symbol(infinity, role),
+                    };
+                let terms = vec![
+                    symbol(if *lower_closed { "[" } else { "(" }, "open"),
+                    endpoint(lower, "-∞", "lower"),
+                    symbol(",", "separator"),
+                    endpoint(upper, "∞", "upper"),
+                    symbol(if *upper_closed { "]" } else { ")" }, "close"),
+                ];
+                MathNode {
+                    id: master_id,
+                    content: Arc::new(MathNodeContent::Multiplications {
+                        terms: terms
+                            .into_iter()
+                            .map(|term| (RefinedMulOrDivOperation::None, term))
+                            .collect(),
+                    }),
+                }
+            }
+            Set::OrderedPair { .. } => self.to_turn_math_with(master_id, &RenderOptions::default()),
+            Set::Parametric { description, .. } if !description.is_empty() => MathNode {
+                id: master_id,
+                content: Arc::new(MathNodeContent::Identifier(Identifier::new_simple(
+                    description.clone(),
+                ))),
+            },
+            // {x | condition}
+            Set::Parametric {
+                membership_condition,
+                ..
+            } => {
+                let condition = MathNode {
+                    id: format!("{}-condition", master_id),
+                    content: Arc::new(MathNodeContent::Text(membership_condition.clone())),
+                };
+                braced(
+                    &master_id,
+                    vec![
+                        symbol(format!("{}-variable", master_id), "x"),
+                        symbol(format!("{}-such-that", master_id), "|"),
+                        condition,
+                    ],
+                )
+            }
+            // An abstract set has no structure to show, only a name
+            Set::Generic(_) => symbol(master_id, "S"),
+            Set::Singleton { element, .. } => braced(
+                &master_id,
+                vec![element.to_turn_math(format!("{}-element-0", master_id))],
+            ),
+            Set::Enumeration { elements, .. } => {
+                let items = elements
+                    .iter()
+                    .enumerate()
+                    .map(|(index, element)| {
+                        element.to_turn_math(format!("{}-element-{}", master_id, index))
+                    })
+                    .collect();
+                braced(&master_id, comma_separated(&master_id, items))
+            }
+            Set::BinaryUnion { left, right, .. } => infix(master_id, left, "∪", right),
+            Set::BinaryIntersection { left, right, .. } => infix(master_id, left, "∩", right),
+            Set::SetDifference { left, right, .. } => infix(master_id, left, "∖", right),
+            Set::SymmetricDifference { left, right, .. } => infix(master_id, left, "△", right),
+            Set::CartesianProduct { left, right, .. } => infix(master_id, left, "×", right),
+            // U ∖ A, the complement of A in U
+            Set::Complement { set, universe, .. } => infix(master_id, universe, "∖", set),
+            Set::BigUnion { family, .. } => prefix(master_id, "⋃", family),
+            Set::BigIntersection { family, .. } => prefix(master_id, "⋂", family),
+            Set::PowerSet { base, .. } => MathNode {
+                id: master_id.clone(),
+                content: Arc::new(MathNodeContent::FunctionCall {
+                    name: Arc::new(function_name(format!("{}-name", master_id), "𝒫")),
+                    parameters: vec![base.to_turn_math(format!("{}-base", master_id))],
+                }),
+            },
+            // {x ∈ A | condition(x)}
+            Set::Separation {
+                source, condition, ..
+            } => {
+                let variable = || symbol(format!("{}-variable", master_id), "x");
+                let membership = MathNode {
+                    id: format!("{}-membership", master_id),
+                    content: Arc::new(MathNodeContent::Relationship {
+                        lhs: Arc::new(variable()),
+                        rhs: Arc::new(source.to_turn_math(format!("{}-source", master_id))),
+                        operator: RelationOperatorNode::ElementOf,
+                    }),
+                };
+                let (lhs, operator, rhs) = match condition {
+                    ElementCondition::IsEmpty => (
+                        variable(),
+                        RelationOperatorNode::Equal,
+                        Set::Empty.to_turn_math(format!("{}-condition-set", master_id)),
+                    ),
+                    ElementCondition::Contains(element) => (
+                        element.to_turn_math(format!("{}-condition-element", master_id)),
+                        RelationOperatorNode::ElementOf,
+                        variable(),
+                    ),
+                    ElementCondition::ContainedIn(set) => (
+                        variable(),
+                        RelationOperatorNode::ElementOf,
+                        set.to_turn_math(format!("{}-condition-set", master_id)),
+                    ),
+                    ElementCondition::NotContainedIn(set) => (
+                        variable(),
+                        RelationOperatorNode::Custom("∉".to_string()),
+                        set.to_turn_math(format!("{}-condition-set", master_id)),
+                    ),
+                };
+                let condition = MathNode {
+                    id: format!("{}-condition", master_id),
+                    content: Arc::new(MathNodeContent::Relationship {
+                        lhs: Arc::new(lhs),
+                        rhs: Arc::new(rhs),
+                        operator,
+                    }),
+                };
+                braced(
+                    &master_id,
+                    vec![
+                        membership,
+                        symbol(format!("{}-such-that", master_id), "|"),
+                        condition,
+                    ],
+                )
+            }
+            // {f(x) | x ∈ A}
+            Set::Replacement {
+                source, mapping, ..
+            } => {
+                let variable = || symbol(format!("{}-variable", master_id), "x");
+                let image = mapping_node(mapping, variable(), &format!("{}-image", master_id));
+                let membership = MathNode {
+                    id: format!("{}-membership", master_id),
+                    content: Arc::new(MathNodeContent::Relationship {
+                        lhs: Arc::new(variable()),
+                        rhs: Arc::new(source.to_turn_math(format!("{}-source", master_id))),
+                        operator: RelationOperatorNode::ElementOf,
+                    }),
+                };
+                braced(
+                    &master_id,
+                    vec![
+                        image,
+                        symbol(format!("{}-such-that", master_id), "|"),
+                        membership,
+                    ],
+                )
+            }
+        }
+    }
+}
+
+impl ToTurnMath for SetElement {
+    fn to_turn_math(&self, master_id: String) -> MathNode {
+        match self {
+            SetElement::Set(set) => set.to_turn_math(master_id),
+            SetElement::Integer(value) => MathNode {
+                id: master_id,
+                content: Arc::new(MathNodeContent::Quantity {
+                    number: value.to_string(),
+                    scientific_notation: None,
+                    unit: None,
+                }),
+            },
+            SetElement::Symbol(name) | SetElement::Urelement(name) => symbol(master_id, name),
+            SetElement::Pair(first, second) => MathNode {
+                id: master_id.clone(),
+                content: Arc::new(MathNodeContent::Bracketed {
+                    inner: Arc::new(row(
+                        format!("{}-components", master_id),
+                        comma_separated(
+                            &master_id,
+                            vec![
+                                first.to_turn_math(format!("{}-first", master_id)),
+                                second.to_turn_math(format!("{}-second", master_id)),
+                            ],
+                        ),
+                    )),
+                    style: BracketStyle::Round,
+                    size: BracketSize::Normal,
+                }),
+            },
+        }
+    }
+}
+
+fn symbol(id: String, text: &str) -> MathNode {
+    MathNode {
+        id,
+        content: Arc::new(MathNodeContent::Identifier(Identifier::new_simple(
+            text.to_string(),
+        ))),
+    }
+}
+
+fn function_name(id: String, name: &str) -> MathNode {
+    MathNode {
+        id,
+        content: Arc::new(MathNodeContent::Identifier(Identifier {
+            is_function: true,
+            ..Identifier::new_simple(name.to_string())
+        })),
+    }
+}
+
+/// Terms laid out side by side, without operators between them.
+fn row(id: String, terms: Vec<MathNode>) -> MathNode {
+    MathNode {
+        id,
+        content: Arc::new(MathNodeContent::Multiplications {
+            terms: terms
+                .into_iter()
+                .map(|term| (RefinedMulOrDivOperation::None, term))
+                .collect(),
+        }),
+    }
+}
+
+/// `{terms}`. Like the delimiters of an interval, the braces are laid out as
+/// symbols since there is no curly bracket style.
+fn braced(master_id: &str, terms: Vec<MathNode>) -> MathNode {
+    let mut row_terms = vec![symbol(format!("{}-open", master_id), "{")];
+    row_terms.extend(terms);
+    row_terms.push(symbol(format!("{}-close", master_id), "}"));
+    row(master_id.to_string(), row_terms)
+}
+
+fn comma_separated(master_id: &str, items: Vec<MathNode>) -> Vec<MathNode> {
+    let mut terms = Vec::new();
+    for (index, item) in items.into_iter().enumerate() {
+        if index > 0 {
+            terms.push(symbol(format!("{}-separator-{}", master_id, index), ","));
+        }
+        terms.push(item);
+    }
+    terms
+}
+
+/// `A op B` for the binary set operations.
+fn infix(master_id: String, left: &Set, operator: &str, right: &Set) -> MathNode {
+    MathNode {
+        id: master_id.clone(),
+        content: Arc::new(MathNodeContent::Relationship {
+            lhs: Arc::new(left.to_turn_math(format!("{}-left", master_id))),
+            rhs: Arc::new(right.to_turn_math(format!("{}-right", master_id))),
+            operator: RelationOperatorNode::Custom(operator.to_string()),
+        }),
+    }
+}
+
+/// `⋃F` and `⋂F`.
+fn prefix(master_id: String, operator: &str, operand: &Set) -> MathNode {
+    MathNode {
+        id: master_id.clone(),
+        content: Arc::new(MathNodeContent::UnaryPrefixOperation {
+            parameter: Arc::new(operand.to_turn_math(format!("{}-operand", master_id))),
+            operator: Arc::new(symbol(format!("{}-operator", master_id), operator)),
+        }),
+    }
+}
+
+/// The image of `argument` under `mapping`, e.g. `{x}` or `π₁(x)`.
+fn mapping_node(mapping: &SetMapping, argument: MathNode, id: &str) -> MathNode {
+    let call = |name: &str, argument: MathNode| MathNode {
+        id: id.to_string(),
+        content: Arc::new(MathNodeContent::FunctionCall {
+            name: Arc::new(function_name(format!("{}-name", id), name)),
+            parameters: vec![argument],
+        }),
+    };
+    match mapping {
+        SetMapping::Identity => argument,
+        SetMapping::Singleton => braced(id, vec![argument]),
+        SetMapping::FirstProjection => call("π₁", argument),
+        SetMapping::SecondProjection => call("π₂", argument),
+        SetMapping::Composition(outer, inner) => {
+            let inner = mapping_node(inner, argument, &format!("{}-inner", id));
+            mapping_node(outer, inner, id)
+        }
+        SetMapping::Custom(name) => call(name, argument),
+    }
+}
+
+impl ToTurnMathConfigurable for Set {
+    /// Ordered pairs render as `(a, b)`, or with `flatten_tuple` as one tuple
+    /// `(a, b, c)` for right-nested pairs. Other sets render as `to_turn_math`.
+    fn to_turn_math_with(&self, master_id: String, options: &RenderOptions) -> MathNode {
+        if options.depth_exhausted() {
+            return MathNode {
+                id: master_id,
+                content: Arc::new(MathNodeContent::Text("…".to_string())),
+            };
+        }
+        let Set::OrderedPair { first, second, .. } = self else {
+            return self.to_turn_math(master_id);
+        };
+
+        let mut components = vec![first.as_ref()];
+        let mut rest = second.as_ref();
+        while options.flatten_tuple {
+            let Set::OrderedPair { first, second, .. } = rest else {
+                break;
+            };
+            components.push(first.as_ref());
+            rest = second.as_ref();
+        }
+        components.push(rest);
+
+        let mut terms = Vec::new();
+        for (index, component) in components.into_iter().enumerate() {
+            if index > 0 {
+                terms.push((
+                    RefinedMulOrDivOperation::None,
+                    MathNode {
+                        id: format!("{}-separator-{}", master_id, index),
+                        content: Arc::new(MathNodeContent::Identifier(Identifier::new_simple(
+                            ",".to_string(),
+                        ))),
+                    },
+                ));
+            }
+            terms.push((
+                RefinedMulOrDivOperation::None,
+                component.to_turn_math_with(
+                    format!("{}-component-{}", master_id, index),
+                    &options.nested(),
+                ),
+            ));
+        }
+        MathNode {
+            id: master_id.clone(),
+            content: Arc::new(MathNodeContent::Bracketed {
+                inner: Arc::new(MathNode {
+                    id: format!("{}-components", master_id),
+                    content: Arc::new(MathNodeContent::Multiplications { terms }),
+                }),
+                style: BracketStyle::Round,
+                size: BracketSize::Normal,
+            }),
+        }
+    }
+}
+
 impl ToMathDocument for Set {
     fn to_math_document(&self, id_prefix: &str) -> MathDocument {
         // Determine the inherent level of this Set object
@@ -288,4 +704,136 @@ fn property_to_selectable(prop: &SetProperty) -> (String, String, Vec<String>) {
     }
 }
 
+/// The ZFC axioms as `(id suffix, name, formal statement, description)`.
+const ZFC_AXIOMS: [(&str, &str, &str, &str); 9] = [
+    (
+        "extensionality",
+        "Axiom of Extensionality",
+        "∀A ∀B (∀x (x ∈ A ↔ x ∈ B) → A = B)",
+        "Two sets are equal if and only if they have the same elements.",
+    ),
+    (
+        "pairing",
+        "Axiom of Pairing",
+        "∀A ∀B ∃C ∀x (x ∈ C ↔ x = A ∨ x = B)",
+        "For any two sets there is a set containing exactly those two sets.",
+    ),
+    (
+        "union",
+        "Axiom of Union",
+        "∀F ∃A ∀x (x ∈ A ↔ ∃B (B ∈ F ∧ x ∈ B))",
+        "For any collection of sets there is a set containing every element of its members.",
+    ),
+    (
+        "power_set",
+        "Axiom of Power Set",
+        "∀A ∃P ∀x (x ∈ P ↔ ∀y (y ∈ x → y ∈ A))",
+        "For any set there is a set containing all of its subsets.",
+    ),
+    (
+        "separation",
+        "Axiom Schema of Separation",
+        "∀A ∃B ∀x (x ∈ B ↔ x ∈ A ∧ φ(x))",
+        "The elements of a set satisfying a property form a set.",
+    ),
+    (
+        "replacement",
+        "Axiom Schema of Replacement",
+        "∀A ∃B ∀y (y ∈ B ↔ ∃x (x ∈ A ∧ φ(x, y)))",
+        "The image of a set under a definable function is a set.",
+    ),
+    (
+        "infinity",
+        "Axiom of Infinity",
+        "∃I (∅ ∈ I ∧ ∀x (x ∈ I → x ∪ {x} ∈ I))",
+        "There is a set containing the empty set and closed under successor.",
+    ),
+    (
+        "foundation",
+        "Axiom of Foundation",
+        "∀A (∃x (x ∈ A) → ∃y (y ∈ A ∧ ¬∃z (z ∈ y ∧ z ∈ A)))",
+        "Every non-empty set has an element disjoint from it.",
+    ),
+    (
+        "choice",
+        "Axiom of Choice",
+        "∀A (∅ ∉ A → ∃f: A → ⋃A ∀B ∈ A (f(B) ∈ B))",
+        "Every collection of non-empty sets has a function choosing one element from each.",
+    ),
+];
+
+/// Renders each ZFC axiom as a document with its formal statement and a prose
+/// description, under `zfc.axiom.<name>` ids.
+pub fn axiom_documents() -> Vec<MathDocument> {
+    ZFC_AXIOMS
+        .iter()
+        .map(|(key, name, formula, description)| {
+            let id = format!("zfc.axiom.{}", key);
+            let section = Section {
+                id: format!("{}-main", id),
+                title: Some(RichText {
+                    segments: vec![RichTextSegment::Text(name.to_string())],
+                    alignment: None,
+                }),
+                content: SectionContentNode::SubSection(vec![
+                    Section {
+                        id: format!("{}-statement", id),
+                        title: None,
+                        content: SectionContentNode::Math(MathNode {
+                            id: format!("{}-formula", id),
+                            content: Arc::new(MathNodeContent::Text(formula.to_string())),
+                        }),
+                        metadata: vec![],
+                        display_options: None,
+                    },
+                    Section {
+                        id: format!("{}-description", id),
+                        title: None,
+                        content: SectionContentNode::RichText(RichText {
+                            segments: vec![RichTextSegment::Text(description.to_string())],
+                            alignment: None,
+                        }),
+                        metadata: vec![],
+                        display_options: None,
+                    },
+                ]),
+                metadata: vec![],
+                display_options: None,
+            };
+
+            MathDocument {
+                id,
+                content_type: MathDocumentType::WikiPage(WikiPageContent {
+                    title: name.to_string(),
+                    theory_domain: "ZFC Set Theory".to_string(),
+                    completeness_level: CompletenessLevel::Basic,
+                    maintainer: None,
+                    content_metadata: ContentMetadata {
+                        language: Some("en-US".to_string()),
+                        version: Some("1.0".to_string()),
+                        created_at: None,
+                        last_modified: None,
+                        content_hash: None,
+                    },
+                    structure: DocumentStructure {
+                        abstract_content: None,
+                        table_of_contents: None,
+                        body: vec![section],
+                        footnotes: vec![],
+                        glossary: vec![],
+                        bibliography: vec![],
+                    },
+                    relationships: DocumentRelationships {
+                        parent_documents: vec![],
+                        child_documents: vec![],
+                        related_concepts: vec![],
+                        cross_references: vec![],
+                        dependency_graph: None,
+                    },
+                }),
+            }
+        })
+        .collect()
+}
+
 // TODO: Implement GetAbstractionLevel and ToSectionNode for SetElement, SetProperty (as a main definition if needed), etc.
@@ -1674,3 +1674,419 @@ mod additional_tests {
         assert!(sym_diff.contains(&a_elem));
     }
 }
+
+// Real intervals and the real line
+#[cfg(test)]
+mod interval_tests {
+    use crate::subjects::math::theories::zfc::definitions::{Set, SetElement};
+    use crate::turn_render::*;
+
+    /// Collect the symbols and endpoints of a rendered interval, e.g. "[0,1]".
+    fn rendered_interval(set: &Set) -> String {
+        let node = set.to_turn_math("interval".to_string());
+        match node.content.as_ref() {
+            MathNodeContent::Multiplications { terms } => terms
+                .iter()
+                .map(|(_, term)| match term.content.as_ref() {
+                    MathNodeContent::Identifier(identifier) => identifier.body.clone(),
+                    MathNodeContent::Quantity { number, .. } => number.clone(),
+                    other => panic!("Unexpected interval component: {:?}", other),
+                })
+                .collect(),
+            other => panic!("Expected interval row, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_closed_interval_rendering() {
+        let unit = Set::interval(Some(0.into()), Some(1.into()), true, true);
+        assert_eq!(rendered_interval(&unit), "[0,1]");
+    }
+
+    #[test]
+    fn test_half_open_interval_rendering() {
+        let half_open = Set::interval(Some(0.into()), Some(1.into()), true, false);
+        assert_eq!(rendered_interval(&half_open), "[0,1)");
+
+        let ray = Set::interval(Some(2.into()), None, false, true);
+        assert_eq!(rendered_interval(&ray), "(2,∞)");
+    }
+
+    #[test]
+    fn test_real_line_renders_as_blackboard_r() {
+        let node = Set::reals().to_turn_math("reals".to_string());
+        match node.content.as_ref() {
+            MathNodeContent::Identifier(identifier) => assert_eq!(identifier.body, "ℝ"),
+            other => panic!("Expected ℝ identifier, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_interval_membership() {
+        let half_open = Set::interval(Some(0.into()), Some(1.into()), true, false);
+        assert!(half_open.contains_real(0.0));
+        assert!(half_open.contains_real(0.5));
+        assert!(!half_open.contains_real(1.0));
+        assert!(!half_open.contains_real(-0.1));
+
+        assert!(half_open.contains(&SetElement::Integer(0)));
+        assert!(!half_open.contains(&SetElement::Integer(1)));
+
+        assert!(Set::reals().contains_real(-1e9));
+    }
+}
+
+// Set-difference simplification rules
+#[cfg(test)]
+mod simplify_tests {
+    use crate::subjects::math::theories::zfc::definitions::{Set, SetElement};
+    use crate::subjects::math::theories::zfc::{difference_set, intersection_set};
+
+    fn numbers(values: &[i64]) -> Set {
+        Set::from_elements(values.iter().map(|v| SetElement::Integer(*v)).collect())
+    }
+
+    #[test]
+    fn test_difference_with_itself_is_empty() {
+        let a = numbers(&[1, 2, 3]);
+        assert_eq!(difference_set(&a, &a).simplify(), Set::Empty);
+    }
+
+    #[test]
+    fn test_difference_with_empty_is_identity() {
+        let a = numbers(&[1, 2, 3]);
+        assert_eq!(difference_set(&a, &Set::Empty).simplify(), a);
+    }
+
+    #[test]
+    fn test_empty_minus_anything_is_empty() {
+        let a = numbers(&[1, 2, 3]);
+        assert_eq!(difference_set(&Set::Empty, &a).simplify(), Set::Empty);
+    }
+
+    #[test]
+    fn test_intersection_with_own_difference() {
+        let a = numbers(&[1, 2, 3]);
+        let b = numbers(&[3, 4]);
+        let a_minus_b = difference_set(&a, &b);
+
+        assert_eq!(
+            intersection_set(&[a.clone(), a_minus_b.clone()]).simplify(),
+            a_minus_b
+        );
+        assert_eq!(
+            intersection_set(&[a_minus_b.clone(), a.clone()]).simplify(),
+            a_minus_b
+        );
+    }
+
+    #[test]
+    fn test_simplify_reaches_fixed_point() {
+        // (A \ A) \ B first collapses to ∅ \ B, which then collapses to ∅.
+        let a = numbers(&[1, 2]);
+        let b = numbers(&[2]);
+        let nested = difference_set(&difference_set(&a, &a), &b);
+        assert_eq!(nested.simplify(), Set::Empty);
+    }
+}
+
+// Structural cardinality
+#[cfg(test)]
+mod cardinality_kind_tests {
+    use std::collections::HashMap;
+
+    use crate::subjects::math::theories::VariantSet;
+    use crate::subjects::math::theories::zfc::definitions::{
+        Cardinality, Set, SetElement, SetProperty,
+    };
+    use crate::subjects::math::theories::zfc::power_set;
+
+    #[test]
+    fn test_finite_enumeration() {
+        let set = Set::from_elements(vec![
+            SetElement::Integer(1),
+            SetElement::Integer(2),
+            SetElement::Integer(3),
+        ]);
+        assert_eq!(set.cardinality_kind(), Cardinality::Finite(3));
+        assert!(set.is_finite());
+        assert_eq!(power_set(&set).cardinality_kind(), Cardinality::Finite(8));
+    }
+
+    #[test]
+    fn test_power_set_of_infinite_set_is_uncountable() {
+        let naturals = Set::Parametric {
+            parameters: HashMap::new(),
+            description: "ℕ".to_string(),
+            membership_condition: "n ≥ 0".to_string(),
+            membership_predicate: None,
+            properties: {
+                let mut properties = VariantSet::new();
+                properties.insert(SetProperty::IsFinite(false));
+                properties.insert(SetProperty::IsCountable(true));
+                properties
+            },
+        };
+        assert_eq!(naturals.cardinality_kind(), Cardinality::CountablyInfinite);
+        assert_eq!(
+            power_set(&naturals).cardinality_kind(),
+            Cardinality::Uncountable
+        );
+        assert_eq!(
+            power_set(&Set::reals()).cardinality_kind(),
+            Cardinality::Uncountable
+        );
+    }
+
+    #[test]
+    fn test_parametric_set_without_properties_is_unknown() {
+        let z_n = Set::Parametric {
+            parameters: HashMap::from([("n".to_string(), "n".to_string())]),
+            description: "ℤ/nℤ".to_string(),
+            membership_condition: "0 ≤ k < n".to_string(),
+            membership_predicate: None,
+            properties: VariantSet::new(),
+        };
+        assert_eq!(z_n.cardinality_kind(), Cardinality::Unknown);
+        assert!(!z_n.is_finite());
+        // The legacy count silently reports 0
+        assert_eq!(z_n.cardinality(), 0);
+    }
+}
+
+#[cfg(test)]
+mod nested_set_containment_tests {
+    use crate::subjects::math::theories::zfc::definitions::{Set, SetElement};
+
+    fn symbol(name: &str) -> SetElement {
+        SetElement::Symbol(name.to_string())
+    }
+
+    #[test]
+    fn test_singleton_of_set_is_subset_despite_construction() {
+        // { {a} } built from a singleton, { {a,}, {b} } from a one-element enumeration
+        let inner = Set::singleton(symbol("a"));
+        let left = Set::singleton(SetElement::Set(Box::new(inner)));
+        let right = Set::from_elements(vec![
+            SetElement::Set(Box::new(Set::from_elements(vec![symbol("a")]))),
+            SetElement::Set(Box::new(Set::singleton(symbol("b")))),
+        ]);
+
+        let only_b = Set::singleton(SetElement::Set(Box::new(Set::singleton(symbol("b")))));
+
+        assert!(left.is_subset_of(&right));
+        assert!(!left.is_subset_of(&only_b));
+    }
+
+    #[test]
+    fn test_singleton_fast_path_compares_set_elements_extensionally() {
+        let a = Set::singleton(SetElement::Set(Box::new(Set::singleton(symbol("a")))));
+        let enumerated_a = Set::from_elements(vec![symbol("a")]);
+        let b = Set::singleton(SetElement::Set(Box::new(enumerated_a)));
+        assert_ne!(a, b);
+        assert!(a.is_subset_of(&b));
+        assert!(b.is_subset_of(&a));
+    }
+}
+
+#[cfg(test)]
+mod membership_predicate_tests {
+    use std::collections::HashMap;
+
+    use crate::subjects::math::theories::VariantSet;
+    use crate::subjects::math::theories::zfc::definitions::{MembershipPredicate, Set, SetElement};
+
+    fn parametric(predicate: Option<MembershipPredicate>) -> Set {
+        Set::Parametric {
+            parameters: HashMap::new(),
+            description: "2ℤ".to_string(),
+            membership_condition: "n ≡ 0 (mod 2)".to_string(),
+            membership_predicate: predicate,
+            properties: VariantSet::new(),
+        }
+    }
+
+    #[test]
+    fn test_even_integers_classify_elements() {
+        let evens = parametric(Some(MembershipPredicate::IntegerCongruence {
+            modulus: 2,
+            remainder: 0,
+        }));
+
+        assert_eq!(evens.test_membership(&SetElement::Integer(2)), Some(true));
+        assert_eq!(evens.test_membership(&SetElement::Integer(3)), Some(false));
+        assert_eq!(evens.test_membership(&SetElement::Integer(-4)), Some(true));
+        // A symbol may denote any integer
+        assert_eq!(
+            evens.test_membership(&SetElement::Symbol("n".to_string())),
+            None
+        );
+        assert!(evens.contains(&SetElement::Integer(2)));
+        assert!(!evens.contains(&SetElement::Integer(3)));
+    }
+
+    #[test]
+    fn test_string_only_condition_is_undecided() {
+        let evens = parametric(None);
+        assert_eq!(evens.test_membership(&SetElement::Integer(2)), None);
+    }
+}
+
+#[cfg(test)]
+mod axiom_document_tests {
+    use std::collections::HashSet;
+
+    use crate::subjects::math::theories::zfc::render::axiom_documents;
+
+    #[test]
+    fn test_axiom_documents_have_distinct_ids() {
+        let documents = axiom_documents();
+        assert_eq!(documents.len(), 9);
+
+        let ids: HashSet<&str> = documents.iter().map(|doc| doc.id.as_str()).collect();
+        assert_eq!(ids.len(), 9);
+        assert!(ids.iter().all(|id| id.starts_with("zfc.axiom.")));
+        assert!(ids.contains("zfc.axiom.choice"));
+    }
+}
+
+#[cfg(test)]
+mod ordered_pair_tests {
+    use crate::subjects::math::formalism::render::options::{
+        RenderOptions, ToTurnMathConfigurable,
+    };
+    use crate::subjects::math::theories::zfc::definitions::Set;
+    use crate::subjects::math::theories::zfc::ordered_pair;
+    use crate::turn_render::*;
+
+    /// Spell out a rendered tuple, e.g. "(∅,ℝ)".
+    fn spelled(node: &MathNode) -> String {
+        match node.content.as_ref() {
+            MathNodeContent::Bracketed {
+                inner,
+                style: BracketStyle::Round,
+                ..
+            } => format!("({})", spelled(inner)),
+            MathNodeContent::Multiplications { terms } => {
+                terms.iter().map(|(_, term)| spelled(term)).collect()
+            }
+            MathNodeContent::Identifier(identifier) => identifier.body.clone(),
+            other => panic!("Unexpected tuple component: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_ordered_pair_renders_both_components_in_round_brackets() {
+        let pair = ordered_pair(Set::empty(), Set::reals());
+        assert_eq!(spelled(&pair.to_turn_math("pair".to_string())), "(∅,ℝ)");
+    }
+
+    #[test]
+    fn test_flattened_triple_renders_as_one_tuple() {
+        let triple = ordered_pair(Set::empty(), ordered_pair(Set::reals(), Set::empty()));
+        assert_eq!(
+            spelled(&triple.to_turn_math("triple".to_string())),
+            "(∅,(ℝ,∅))"
+        );
+
+        let flattened = RenderOptions {
+            flatten_tuple: true,
+            ..RenderOptions::default()
+        };
+        assert_eq!(
+            spelled(&triple.to_turn_math_with("triple".to_string(), &flattened)),
+            "(∅,ℝ,∅)"
+        );
+    }
+}
+
+#[cfg(test)]
+mod set_rendering_tests {
+    use crate::subjects::math::theories::VariantSet;
+    use crate::subjects::math::theories::zfc::definitions::{
+        ElementCondition, Set, SetElement, SetMapping,
+    };
+    use crate::turn_render::*;
+
+    /// Spell out a rendered set, e.g. "{1,2}" or "∅∪ℝ".
+    fn spelled(node: &MathNode) -> String {
+        match node.content.as_ref() {
+            MathNodeContent::Multiplications { terms } => {
+                terms.iter().map(|(_, term)| spelled(term)).collect()
+            }
+            MathNodeContent::Relationship { lhs, rhs, operator } => {
+                let operator = match operator {
+                    RelationOperatorNode::Custom(symbol) => symbol.as_str(),
+                    RelationOperatorNode::ElementOf => "∈",
+                    RelationOperatorNode::Equal => "=",
+                    other => panic!("Unexpected operator: {:?}", other),
+                };
+                format!("{}{}{}", spelled(lhs), operator, spelled(rhs))
+            }
+            MathNodeContent::UnaryPrefixOperation {
+                parameter,
+                operator,
+            } => format!("{}{}", spelled(operator), spelled(parameter)),
+            MathNodeContent::FunctionCall { name, parameters } => format!(
+                "{}({})",
+                spelled(name),
+                parameters.iter().map(spelled).collect::<Vec<_>>().join(",")
+            ),
+            MathNodeContent::Quantity { number, .. } => number.clone(),
+            MathNodeContent::Identifier(identifier) => identifier.body.clone(),
+            other => panic!("Unexpected set component: {:?}", other),
+        }
+    }
+
+    fn rendered(set: &Set) -> String {
+        spelled(&set.to_turn_math("set".to_string()))
+    }
+
+    #[test]
+    fn test_finite_sets_render_their_elements_in_braces() {
+        assert_eq!(rendered(&Set::singleton(SetElement::Integer(1))), "{1}");
+        assert_eq!(
+            rendered(&Set::from_elements(vec![
+                SetElement::Integer(1),
+                SetElement::Symbol("a".to_string()),
+            ])),
+            "{1,a}"
+        );
+    }
+
+    #[test]
+    fn test_set_operations_render_their_operands() {
+        assert_eq!(rendered(&Set::empty().union(Set::reals())), "∅∪ℝ");
+        let power_set = Set::PowerSet {
+            base: Box::new(Set::reals()),
+            properties: VariantSet::new(),
+            op_properties: VariantSet::new(),
+        };
+        assert_eq!(rendered(&power_set), "𝒫(ℝ)");
+        let big_union = Set::BigUnion {
+            family: Box::new(power_set),
+            properties: VariantSet::new(),
+            op_properties: VariantSet::new(),
+        };
+        assert_eq!(rendered(&big_union), "⋃𝒫(ℝ)");
+    }
+
+    #[test]
+    fn test_comprehensions_render_their_condition() {
+        let separation = Set::Separation {
+            source: Box::new(Set::reals()),
+            condition: ElementCondition::NotContainedIn(Box::new(Set::empty())),
+            properties: VariantSet::new(),
+            op_properties: VariantSet::new(),
+        };
+        assert_eq!(rendered(&separation), "{x∈ℝ|x∉∅}");
+
+        let replacement = Set::Replacement {
+            source: Box::new(Set::reals()),
+            mapping: SetMapping::Singleton,
+            properties: VariantSet::new(),
+            op_properties: VariantSet::new(),
+        };
+        assert_eq!(rendered(&replacement), "{{x}|x∈ℝ}");
+    }
+}